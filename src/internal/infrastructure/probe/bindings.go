@@ -162,6 +162,10 @@ func mapProbeErrorCode(code int) error {
 	case probeCodeIO:
 		// Return I/O error.
 		return ErrIO
+	// Timeout error.
+	case probeCodeTimeout:
+		// Return timeout error.
+		return ErrTimeout
 	// Internal error.
 	case probeCodeInternal:
 		// Return internal error.
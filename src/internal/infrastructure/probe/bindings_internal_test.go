@@ -147,6 +147,11 @@ func TestMapProbeErrorCode(t *testing.T) {
 			code:    5,
 			wantErr: ErrIO,
 		},
+		{
+			name:    "ReturnsTimeoutFor6",
+			code:    6,
+			wantErr: ErrTimeout,
+		},
 		{
 			name:    "ReturnsInternalFor99",
 			code:    99,
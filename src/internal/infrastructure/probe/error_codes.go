@@ -19,6 +19,8 @@ const (
 	probeCodeInvalidParam int = 4
 	// probeCodeIO indicates I/O error.
 	probeCodeIO int = 5
+	// probeCodeTimeout indicates the operation exceeded its deadline.
+	probeCodeTimeout int = 6
 	// probeCodeInternal indicates internal error.
 	probeCodeInternal int = 99
 )
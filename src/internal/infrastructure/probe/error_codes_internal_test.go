@@ -63,6 +63,14 @@ func TestConvertResultToError(t *testing.T) {
 			wantErr: true,
 			wantIs:  ErrIO,
 		},
+		{
+			name:    "ReturnsTimeoutForCode6",
+			success: false,
+			code:    6,
+			message: "",
+			wantErr: true,
+			wantIs:  ErrTimeout,
+		},
 		{
 			name:    "ReturnsInternalForCode99",
 			success: false,
@@ -181,6 +189,11 @@ func TestProbeCodeConstants(t *testing.T) {
 			code:      probeCodeIO,
 			wantValue: 5,
 		},
+		{
+			name:      "ProbeCodeTimeoutIs6",
+			code:      probeCodeTimeout,
+			wantValue: 6,
+		},
 		{
 			name:      "ProbeCodeInternalIs99",
 			code:      probeCodeInternal,
@@ -19,6 +19,7 @@ const (
 	probeErrNotFound   C.int = 3
 	probeErrInvalidPar C.int = 4
 	probeErrIO         C.int = 5
+	probeErrTimeout    C.int = 6
 	probeErrInternal   C.int = 99
 )
 
@@ -39,6 +40,9 @@ var (
 	// ErrIO indicates an I/O error occurred.
 	ErrIO error = errors.New("io error")
 
+	// ErrTimeout indicates the operation did not complete before its deadline.
+	ErrTimeout error = errors.New("operation timed out")
+
 	// ErrInternal indicates an internal error occurred.
 	ErrInternal error = errors.New("internal error")
 
@@ -13,6 +13,7 @@ const (
 	ExportProbeErrNotFound   int = 3
 	ExportProbeErrInvalidPar int = 4
 	ExportProbeErrIO         int = 5
+	ExportProbeErrTimeout    int = 6
 	ExportProbeErrInternal   int = 99
 )
 
@@ -57,6 +58,8 @@ func ExportGetProbeErrorMapping(code int) error {
 		return ErrInvalidParam
 	case ExportProbeErrIO:
 		return ErrIO
+	case ExportProbeErrTimeout:
+		return ErrTimeout
 	case ExportProbeErrInternal:
 		return ErrInternal
 	default:
@@ -0,0 +1,180 @@
+//! Adaptive TTL adjustment based on observed value volatility.
+//!
+//! On each refresh the new value is compared to the previous one. Large
+//! changes halve the metric's effective TTL, small changes double it, and the
+//! result is clamped to the configured bounds.
+//!
+//! Percent gauges (CPU usage, PSI averages, disk usage) report the change in
+//! percentage points; other gauges report the relative change in percent.
+//! Cumulative counters (disk I/O, network and I/O statistics) always grow and
+//! keep their static TTL.
+
+use probe_metrics::{
+    CPUPressure, DiskUsage, IOPressure, LoadAverage, MemoryPressure, NetInterface, Partition,
+    SystemCPU, SystemMemory,
+};
+use std::time::Duration;
+
+/// Bounds and thresholds for adaptive TTLs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveTtl {
+    /// Shortest TTL a metric can adapt down to.
+    pub min_ttl: Duration,
+    /// Longest TTL a metric can adapt up to.
+    pub max_ttl: Duration,
+    /// Change (in percent) at or above which the TTL is halved.
+    pub volatile_change: f64,
+    /// Change (in percent) at or below which the TTL is doubled.
+    pub stable_change: f64,
+}
+
+impl Default for AdaptiveTtl {
+    fn default() -> Self {
+        Self {
+            min_ttl: Duration::from_millis(50),
+            max_ttl: Duration::from_secs(30),
+            volatile_change: 10.0,
+            stable_change: 1.0,
+        }
+    }
+}
+
+impl AdaptiveTtl {
+    /// Create adaptive bounds with default thresholds.
+    pub fn new(min_ttl: Duration, max_ttl: Duration) -> Self {
+        Self { min_ttl, max_ttl, ..Default::default() }
+    }
+
+    /// Compute the next TTL from the current one and the observed change.
+    ///
+    /// A zero TTL (caching disabled) stays zero.
+    pub fn next_ttl(&self, current: Duration, change: f64) -> Duration {
+        if current.is_zero() {
+            return Duration::ZERO;
+        }
+        let next = if change >= self.volatile_change {
+            current / 2
+        } else if change <= self.stable_change {
+            current.saturating_mul(2)
+        } else {
+            current
+        };
+        next.min(self.max_ttl).max(self.min_ttl)
+    }
+}
+
+/// How much a metric changed between two refreshes, in percent.
+pub(crate) trait Volatility {
+    fn change_from(&self, previous: &Self) -> f64;
+}
+
+/// Relative change between two gauge readings, in percent (0-100).
+fn relative_change(previous: f64, current: f64) -> f64 {
+    let scale = previous.abs().max(current.abs());
+    if scale == 0.0 { 0.0 } else { (current - previous).abs() / scale * 100.0 }
+}
+
+/// Whether two lists contain the same keys in the same order.
+fn same_keys<T>(a: &[T], b: &[T], key: impl Fn(&T) -> &str) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| key(x) == key(y))
+}
+
+impl Volatility for SystemCPU {
+    fn change_from(&self, previous: &Self) -> f64 {
+        (self.idle_percent - previous.idle_percent).abs()
+    }
+}
+
+impl Volatility for CPUPressure {
+    fn change_from(&self, previous: &Self) -> f64 {
+        (self.some_avg10 - previous.some_avg10).abs()
+    }
+}
+
+impl Volatility for SystemMemory {
+    fn change_from(&self, previous: &Self) -> f64 {
+        relative_change(previous.used_bytes as f64, self.used_bytes as f64)
+    }
+}
+
+impl Volatility for MemoryPressure {
+    fn change_from(&self, previous: &Self) -> f64 {
+        let some = (self.some_avg10 - previous.some_avg10).abs();
+        some.max((self.full_avg10 - previous.full_avg10).abs())
+    }
+}
+
+impl Volatility for IOPressure {
+    fn change_from(&self, previous: &Self) -> f64 {
+        let some = (self.some_avg10 - previous.some_avg10).abs();
+        some.max((self.full_avg10 - previous.full_avg10).abs())
+    }
+}
+
+impl Volatility for LoadAverage {
+    fn change_from(&self, previous: &Self) -> f64 {
+        relative_change(previous.load_1min, self.load_1min)
+    }
+}
+
+impl Volatility for Vec<Partition> {
+    fn change_from(&self, previous: &Self) -> f64 {
+        if same_keys(self, previous, |p| &p.mount_point) { 0.0 } else { 100.0 }
+    }
+}
+
+impl Volatility for Vec<DiskUsage> {
+    fn change_from(&self, previous: &Self) -> f64 {
+        if !same_keys(self, previous, |u| &u.path) {
+            return 100.0;
+        }
+        self.iter()
+            .zip(previous)
+            .map(|(a, b)| (a.used_percent - b.used_percent).abs())
+            .fold(0.0, f64::max)
+    }
+}
+
+impl Volatility for Vec<NetInterface> {
+    fn change_from(&self, previous: &Self) -> f64 {
+        if same_keys(self, previous, |i| &i.name) { 0.0 } else { 100.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_ttl_bounds() {
+        let adaptive = AdaptiveTtl::new(Duration::from_millis(100), Duration::from_secs(1));
+
+        assert_eq!(adaptive.next_ttl(Duration::from_millis(400), 50.0), Duration::from_millis(200));
+        assert_eq!(adaptive.next_ttl(Duration::from_millis(150), 50.0), Duration::from_millis(100));
+        assert_eq!(adaptive.next_ttl(Duration::from_millis(400), 0.0), Duration::from_millis(800));
+        assert_eq!(adaptive.next_ttl(Duration::from_millis(800), 0.0), Duration::from_secs(1));
+        assert_eq!(adaptive.next_ttl(Duration::from_millis(400), 5.0), Duration::from_millis(400));
+        assert_eq!(adaptive.next_ttl(Duration::from_millis(10), 0.0), Duration::from_millis(100));
+        // Disabled caching is not turned back on
+        assert_eq!(adaptive.next_ttl(Duration::ZERO, 0.0), Duration::ZERO);
+        assert_eq!(adaptive.next_ttl(Duration::ZERO, 50.0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_change_per_metric_type() {
+        let memory = |used_bytes| SystemMemory { used_bytes, ..Default::default() };
+        assert_eq!(memory(200).change_from(&memory(100)), 50.0);
+        assert_eq!(memory(0).change_from(&memory(0)), 0.0);
+
+        let cpu = |idle_percent| SystemCPU { idle_percent, ..Default::default() };
+        assert_eq!(cpu(70.0).change_from(&cpu(90.0)), 20.0);
+
+        let usage = |path: &str, used_percent| DiskUsage {
+            path: path.to_string(),
+            used_percent,
+            ..Default::default()
+        };
+        assert_eq!(vec![usage("/", 42.5)].change_from(&vec![usage("/", 40.0)]), 2.5);
+        assert_eq!(vec![usage("/", 40.0)].change_from(&vec![usage("/home", 40.0)]), 100.0);
+    }
+}
@@ -20,20 +20,35 @@
 //! let cpu2 = cached.cpu().collect_system();
 //! ```
 
+mod adaptive;
 mod policy;
+mod rate_limit;
+mod snapshot;
 mod ttl;
 
+pub use adaptive::AdaptiveTtl;
 pub use policy::{CachePolicies, MetricType};
+pub use rate_limit::RateLimitedCollector;
+pub use snapshot::{CacheSnapshot, SnapshotEntry};
 pub use ttl::{CacheEntry, TtlCache};
 
-use parking_lot::RwLock;
+use parking_lot::{Mutex, MutexGuard, RwLock};
 use probe_metrics::{
-    CPUCollector, CPUPressure, DiskCollector, DiskIOStats, DiskUsage, IOCollector, IOPressure,
-    IOStats, LoadAverage, LoadCollector, MemoryCollector, MemoryPressure, NetInterface, NetStats,
-    NetworkCollector, Partition, ProcessCollector, Result, SystemCPU, SystemCollector,
-    SystemMemory,
+    AllPressure, BlockDevice, CPUCollector, CPUPressure, Capabilities, CollectorDecorator,
+    ConntrackStats, CoreUsage, CpuCache, CpuTicks, CpuVuln, DeviceMapping, DiskCollector,
+    DiskIOStats, DiskQueueConfig, DiskUsage, Error, IOCollector, IOPressure, IOStats, IcmpStats,
+    InotifyStats, KernelLogEntry, KernelModule, KernelStats, ListenQueueStats, LoadAverage,
+    LoadCollector, MemoryCollector, MemoryPressure, MemoryZone, MetricsDelta, NamespaceIds,
+    NetInterface, NetStats, NetworkCollector, Partition, ProcessCollector, RawSource, Result,
+    RunQueueStat, SocketMemStats, SwapDevice, SystemCPU, SystemCollector, SystemLimits,
+    SystemMemory, ThpStatus, TimeInfo, UdpProtoStats, UnitStatus, ZfsArcStats,
 };
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use adaptive::Volatility;
 
 /// Cached metrics storage.
 #[derive(Default)]
@@ -50,6 +65,10 @@ struct MetricsCache {
     net_stats: Option<CacheEntry<Vec<NetStats>>>,
     io_stats: Option<CacheEntry<IOStats>>,
     io_pressure: Option<CacheEntry<IOPressure>>,
+    /// Modification time of the watched mounts file when `partitions` was cached.
+    partitions_mtime: Option<SystemTime>,
+    /// Effective TTLs learned in adaptive mode, per metric type.
+    adaptive_ttls: HashMap<MetricType, Duration>,
 }
 
 /// A caching wrapper around a SystemCollector.
@@ -60,12 +79,44 @@ pub struct CachedCollector<T: SystemCollector> {
     inner: Arc<T>,
     cache: RwLock<MetricsCache>,
     policies: CachePolicies,
+    adaptive: Option<AdaptiveTtl>,
+    /// File whose modification invalidates cached partitions.
+    partitions_watch: Option<PathBuf>,
+    /// Held while refreshing a metric, indexed by `MetricType`.
+    refresh_locks: [Mutex<()>; MetricType::ALL.len()],
 }
 
 impl<T: SystemCollector> CachedCollector<T> {
     /// Create a new cached collector with the given policies.
     pub fn new(inner: T, policies: CachePolicies) -> Self {
-        Self { inner: Arc::new(inner), cache: RwLock::new(MetricsCache::default()), policies }
+        Self {
+            inner: Arc::new(inner),
+            cache: RwLock::new(MetricsCache::default()),
+            policies,
+            adaptive: None,
+            partitions_watch: None,
+            refresh_locks: std::array::from_fn(|_| Mutex::new(())),
+        }
+    }
+
+    /// Enable adaptive TTLs within the given bounds.
+    ///
+    /// Each metric starts from its policy TTL, which is then shortened when
+    /// refreshed values change a lot and lengthened when they are stable.
+    pub fn with_adaptive_ttl(mut self, adaptive: AdaptiveTtl) -> Self {
+        self.adaptive = Some(adaptive);
+        self
+    }
+
+    /// Invalidate cached partitions as soon as the modification time of
+    /// `mounts` (e.g. `/proc/mounts`) changes, so mounts are picked up
+    /// without a short TTL.
+    ///
+    /// The TTL still applies: it bounds staleness when the modification time
+    /// cannot be read or is not updated on mount changes, as on some kernels.
+    pub fn with_partitions_watch(mut self, mounts: impl Into<PathBuf>) -> Self {
+        self.partitions_watch = Some(mounts.into());
+        self
     }
 
     /// Create a new cached collector with default policies.
@@ -73,6 +124,62 @@ impl<T: SystemCollector> CachedCollector<T> {
         Self::new(inner, CachePolicies::default())
     }
 
+    /// Export the cached metrics, e.g. to persist them between short-lived runs.
+    pub fn export_snapshot(&self) -> CacheSnapshot {
+        let cache = self.cache.read();
+        let now = snapshot::unix_now_ms();
+        fn export<V: Clone>(entry: &Option<CacheEntry<V>>, now: u64) -> Option<SnapshotEntry<V>> {
+            entry.as_ref().map(|e| SnapshotEntry::from_entry(e, now))
+        }
+
+        CacheSnapshot {
+            cpu_system: export(&cache.cpu_system, now),
+            cpu_pressure: export(&cache.cpu_pressure, now),
+            memory_system: export(&cache.memory_system, now),
+            memory_pressure: export(&cache.memory_pressure, now),
+            load: export(&cache.load, now),
+            partitions: export(&cache.partitions, now),
+            disk_usage: export(&cache.disk_usage, now),
+            disk_io: export(&cache.disk_io, now),
+            net_interfaces: export(&cache.net_interfaces, now),
+            net_stats: export(&cache.net_stats, now),
+            io_stats: export(&cache.io_stats, now),
+            io_pressure: export(&cache.io_pressure, now),
+        }
+    }
+
+    /// Load entries from a snapshot, discarding those older than their TTL.
+    ///
+    /// Metrics missing from the snapshot keep their current cached value.
+    pub fn import_snapshot(&self, snapshot: CacheSnapshot) {
+        let mut cache = self.cache.write();
+        let now = snapshot::unix_now_ms();
+
+        macro_rules! import {
+            ($field:ident, $metric:expr) => {
+                if let Some(entry) = snapshot.$field {
+                    let ttl = self.ttl(&cache, $metric);
+                    if let Some(entry) = entry.into_entry(ttl, now) {
+                        cache.$field = Some(entry);
+                    }
+                }
+            };
+        }
+
+        import!(cpu_system, MetricType::CpuSystem);
+        import!(cpu_pressure, MetricType::CpuPressure);
+        import!(memory_system, MetricType::MemorySystem);
+        import!(memory_pressure, MetricType::MemoryPressure);
+        import!(load, MetricType::Load);
+        import!(partitions, MetricType::DiskPartitions);
+        import!(disk_usage, MetricType::DiskUsage);
+        import!(disk_io, MetricType::DiskIo);
+        import!(net_interfaces, MetricType::NetInterfaces);
+        import!(net_stats, MetricType::NetStats);
+        import!(io_stats, MetricType::IoStats);
+        import!(io_pressure, MetricType::IoPressure);
+    }
+
     /// Invalidate all cached metrics.
     pub fn invalidate_all(&self) {
         let mut cache = self.cache.write();
@@ -98,9 +205,121 @@ impl<T: SystemCollector> CachedCollector<T> {
         }
     }
 
+    /// Age of the cached value of a metric, valid or not; `None` if nothing
+    /// is cached.
+    pub fn entry_age(&self, metric: MetricType) -> Option<Duration> {
+        let cache = self.cache.read();
+        match metric {
+            MetricType::CpuSystem => cache.cpu_system.as_ref().map(CacheEntry::age),
+            MetricType::CpuPressure => cache.cpu_pressure.as_ref().map(CacheEntry::age),
+            MetricType::MemorySystem => cache.memory_system.as_ref().map(CacheEntry::age),
+            MetricType::MemoryPressure => cache.memory_pressure.as_ref().map(CacheEntry::age),
+            MetricType::Load => cache.load.as_ref().map(CacheEntry::age),
+            MetricType::DiskPartitions => cache.partitions.as_ref().map(CacheEntry::age),
+            MetricType::DiskUsage => cache.disk_usage.as_ref().map(CacheEntry::age),
+            MetricType::DiskIo => cache.disk_io.as_ref().map(CacheEntry::age),
+            MetricType::NetInterfaces => cache.net_interfaces.as_ref().map(CacheEntry::age),
+            MetricType::NetStats => cache.net_stats.as_ref().map(CacheEntry::age),
+            MetricType::IoStats => cache.io_stats.as_ref().map(CacheEntry::age),
+            MetricType::IoPressure => cache.io_pressure.as_ref().map(CacheEntry::age),
+        }
+    }
+
     /// Update the TTL for a specific metric type.
+    ///
+    /// Its learned adaptive TTL is discarded and restarts from `ttl`.
     pub fn set_ttl(&mut self, metric: MetricType, ttl: std::time::Duration) {
         self.policies.set_ttl(metric, ttl);
+        self.cache.write().adaptive_ttls.remove(&metric);
+    }
+
+    /// Enable, reconfigure or disable (`None`) adaptive TTLs.
+    ///
+    /// Learned TTLs are discarded and restart from the policy TTLs.
+    pub fn set_adaptive_ttl(&mut self, adaptive: Option<AdaptiveTtl>) {
+        self.adaptive = adaptive;
+        self.cache.write().adaptive_ttls.clear();
+    }
+
+    /// TTL currently applied to a metric type, including adaptive adjustments.
+    pub fn effective_ttl(&self, metric: MetricType) -> Duration {
+        self.ttl(&self.cache.read(), metric)
+    }
+
+    /// Cached CPU metrics if still valid, without ever collecting.
+    ///
+    /// For latency-critical paths that use fresh data opportunistically:
+    /// `None` on a miss or an expired entry, and the inner collector is not
+    /// called.
+    pub fn try_cpu(&self) -> Option<SystemCPU> {
+        self.lookup(MetricType::CpuSystem, |c| c.cpu_system.as_ref())
+    }
+
+    /// Cached memory metrics if still valid, without ever collecting.
+    ///
+    /// See [`try_cpu`](Self::try_cpu).
+    pub fn try_memory(&self) -> Option<SystemMemory> {
+        self.lookup(MetricType::MemorySystem, |c| c.memory_system.as_ref())
+    }
+
+    /// Cached load average if still valid, without ever collecting.
+    ///
+    /// See [`try_cpu`](Self::try_cpu).
+    pub fn try_load(&self) -> Option<LoadAverage> {
+        self.lookup(MetricType::Load, |c| c.load.as_ref())
+    }
+
+    /// Return the cached value of a metric if it is still valid.
+    fn lookup<V: Clone>(
+        &self,
+        metric: MetricType,
+        entry: impl Fn(&MetricsCache) -> Option<&CacheEntry<V>>,
+    ) -> Option<V> {
+        let cache = self.cache.read();
+        let ttl = self.ttl(&cache, metric);
+        entry(&cache).filter(|e| e.is_valid(ttl)).map(|e| e.value.clone())
+    }
+
+    /// Serialize refreshes of a metric so concurrent cache misses collect once.
+    ///
+    /// Metrics with a zero TTL are never reused, so they skip the lock.
+    fn refresh_lock(&self, metric: MetricType) -> Option<MutexGuard<'_, ()>> {
+        if self.effective_ttl(metric).is_zero() {
+            return None;
+        }
+        Some(self.refresh_locks[metric as usize].lock())
+    }
+
+    /// Current modification time of the watched mounts file, if any.
+    fn partitions_watch_mtime(&self) -> Option<SystemTime> {
+        let path = self.partitions_watch.as_ref()?;
+        std::fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+
+    fn ttl(&self, cache: &MetricsCache, metric: MetricType) -> Duration {
+        cache.adaptive_ttls.get(&metric).copied().unwrap_or_else(|| self.policies.get_ttl(metric))
+    }
+
+    /// Adjust the effective TTL of a metric after a refresh.
+    fn adapt<V: Volatility>(
+        &self,
+        cache: &mut MetricsCache,
+        metric: MetricType,
+        previous: Option<CacheEntry<V>>,
+        value: &V,
+    ) {
+        let (Some(adaptive), Some(previous)) = (&self.adaptive, previous) else { return };
+        let next = adaptive.next_ttl(self.ttl(cache, metric), value.change_from(&previous.value));
+        cache.adaptive_ttls.insert(metric, next);
+    }
+
+    /// Multiply every TTL by `factor` (see [`CachePolicies::scale_all`]).
+    ///
+    /// Learned adaptive TTLs are discarded and restart from the scaled TTLs.
+    pub fn scale_ttls(&mut self, factor: f64) -> Result<()> {
+        self.policies.scale_all(factor)?;
+        self.cache.write().adaptive_ttls.clear();
+        Ok(())
     }
 
     /// Get the inner collector reference.
@@ -109,6 +328,38 @@ impl<T: SystemCollector> CachedCollector<T> {
     }
 }
 
+impl<T: SystemCollector + 'static> CollectorDecorator for CachedCollector<T> {
+    type Inner = T;
+
+    fn inner(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T: SystemCollector + 'static> CachedCollector<T> {
+    /// Populate every cached metric once, e.g. at startup.
+    ///
+    /// Metrics the platform does not support are skipped. Every metric is
+    /// attempted; the first other error is returned afterwards.
+    pub fn prefetch_all(&self) -> Result<()> {
+        let results = [
+            CPUCollector::collect_system(self).map(drop),
+            CPUCollector::collect_pressure(self).map(drop),
+            MemoryCollector::collect_system(self).map(drop),
+            MemoryCollector::collect_pressure(self).map(drop),
+            LoadCollector::collect(self).map(drop),
+            self.list_partitions().map(drop),
+            self.collect_all_usage().map(drop),
+            self.collect_io().map(drop),
+            self.list_interfaces().map(drop),
+            self.collect_all_stats().map(drop),
+            IOCollector::collect_stats(self).map(drop),
+            IOCollector::collect_pressure(self).map(drop),
+        ];
+        results.into_iter().filter(|r| !matches!(r, Err(Error::NotSupported))).collect()
+    }
+}
+
 // Implement SystemCollector for CachedCollector
 impl<T: SystemCollector + 'static> SystemCollector for CachedCollector<T> {
     fn cpu(&self) -> &dyn CPUCollector {
@@ -139,126 +390,250 @@ impl<T: SystemCollector + 'static> SystemCollector for CachedCollector<T> {
     fn io(&self) -> &dyn IOCollector {
         self
     }
+
+    fn collect_cgroup_pressure(&self) -> Result<AllPressure> {
+        // Cgroup-scoped pressure is not cached
+        self.inner.collect_cgroup_pressure()
+    }
+
+    fn collect_kernel_stats(&self) -> Result<KernelStats> {
+        // Kernel counters are not cached
+        self.inner.collect_kernel_stats()
+    }
+
+    fn recent_kernel_errors(&self, since: Option<Duration>) -> Result<Vec<KernelLogEntry>> {
+        // Kernel log records are read on demand and not cached
+        self.inner.recent_kernel_errors(since)
+    }
+
+    fn time_info(&self) -> Result<TimeInfo> {
+        // Clock state is read on demand and not cached
+        self.inner.time_info()
+    }
+
+    fn inotify_stats(&self) -> Result<InotifyStats> {
+        // inotify usage is read on demand and not cached
+        self.inner.inotify_stats()
+    }
+
+    fn list_modules(&self) -> Result<Vec<KernelModule>> {
+        // Module list is read on demand and not cached
+        self.inner.list_modules()
+    }
+
+    fn namespace_ids(&self) -> Result<NamespaceIds> {
+        // Namespaces are read on demand and not cached
+        self.inner.namespace_ids()
+    }
+
+    fn system_limits(&self) -> Result<SystemLimits> {
+        // Limits are read on demand and not cached
+        self.inner.system_limits()
+    }
+
+    fn unit_status(&self, unit: &str) -> Result<UnitStatus> {
+        // Unit states are read on demand and not cached
+        self.inner.unit_status(unit)
+    }
+
+    fn read_raw(&self, source: RawSource) -> Result<String> {
+        // Raw inputs are read on demand and not cached
+        self.inner.read_raw(source)
+    }
+
+    fn collect_all_sampled(&self, window: Duration) -> Result<MetricsDelta> {
+        // Cached snapshots would yield zero rates
+        self.inner.collect_all_sampled(window)
+    }
+
+    fn pressure_supported(&self) -> bool {
+        self.inner.pressure_supported()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
 }
 
 // Implement CPUCollector with caching
 impl<T: SystemCollector + 'static> CPUCollector for CachedCollector<T> {
     fn collect_system(&self) -> Result<SystemCPU> {
-        let ttl = self.policies.get_ttl(MetricType::CpuSystem);
-
         // Check cache first (read lock)
-        {
-            let cache = self.cache.read();
-            if let Some(entry) = &cache.cpu_system
-                && entry.is_valid(ttl)
-            {
-                return Ok(entry.value.clone());
-            }
+        if let Some(value) = self.lookup(MetricType::CpuSystem, |c| c.cpu_system.as_ref()) {
+            return Ok(value);
+        }
+
+        // Concurrent misses wait for the first refresh and reuse its result
+        let _refresh = self.refresh_lock(MetricType::CpuSystem);
+        if let Some(value) = self.lookup(MetricType::CpuSystem, |c| c.cpu_system.as_ref()) {
+            return Ok(value);
         }
 
         // Cache miss - collect and store (write lock)
         let value = self.inner.cpu().collect_system()?;
         let mut cache = self.cache.write();
-        cache.cpu_system = Some(CacheEntry::new(value.clone()));
+        let previous = cache.cpu_system.replace(CacheEntry::new(value.clone()));
+        self.adapt(&mut cache, MetricType::CpuSystem, previous, &value);
         Ok(value)
     }
 
     fn collect_pressure(&self) -> Result<CPUPressure> {
-        let ttl = self.policies.get_ttl(MetricType::CpuPressure);
+        if let Some(value) = self.lookup(MetricType::CpuPressure, |c| c.cpu_pressure.as_ref()) {
+            return Ok(value);
+        }
 
-        {
-            let cache = self.cache.read();
-            if let Some(entry) = &cache.cpu_pressure
-                && entry.is_valid(ttl)
-            {
-                return Ok(entry.value.clone());
-            }
+        let _refresh = self.refresh_lock(MetricType::CpuPressure);
+        if let Some(value) = self.lookup(MetricType::CpuPressure, |c| c.cpu_pressure.as_ref()) {
+            return Ok(value);
         }
 
         let value = self.inner.cpu().collect_pressure()?;
         let mut cache = self.cache.write();
-        cache.cpu_pressure = Some(CacheEntry::new(value.clone()));
+        let previous = cache.cpu_pressure.replace(CacheEntry::new(value.clone()));
+        self.adapt(&mut cache, MetricType::CpuPressure, previous, &value);
         Ok(value)
     }
+
+    fn cpu_ticks(&self) -> Result<CpuTicks> {
+        // Tick counters feed caller-owned samplers and are never cached
+        self.inner.cpu().cpu_ticks()
+    }
+
+    fn cpu_vulnerabilities(&self) -> Result<Vec<CpuVuln>> {
+        // Vulnerability reports are read on demand and not cached
+        self.inner.cpu().cpu_vulnerabilities()
+    }
+
+    fn per_cpu_runqueue(&self) -> Result<Vec<RunQueueStat>> {
+        // Run queue counters are read on demand and not cached
+        self.inner.cpu().per_cpu_runqueue()
+    }
+
+    fn cpu_caches(&self) -> Result<Vec<CpuCache>> {
+        // Cache geometry is read on demand and not cached
+        self.inner.cpu().cpu_caches()
+    }
+
+    fn collect_per_core(&self) -> Result<Vec<CoreUsage>> {
+        // Per-core deltas depend on the previous call and are not cached
+        self.inner.cpu().collect_per_core()
+    }
 }
 
 // Implement MemoryCollector with caching
 impl<T: SystemCollector + 'static> MemoryCollector for CachedCollector<T> {
     fn collect_system(&self) -> Result<SystemMemory> {
-        let ttl = self.policies.get_ttl(MetricType::MemorySystem);
+        if let Some(value) = self.lookup(MetricType::MemorySystem, |c| c.memory_system.as_ref()) {
+            return Ok(value);
+        }
 
-        {
-            let cache = self.cache.read();
-            if let Some(entry) = &cache.memory_system
-                && entry.is_valid(ttl)
-            {
-                return Ok(entry.value.clone());
-            }
+        let _refresh = self.refresh_lock(MetricType::MemorySystem);
+        if let Some(value) = self.lookup(MetricType::MemorySystem, |c| c.memory_system.as_ref()) {
+            return Ok(value);
         }
 
         let value = self.inner.memory().collect_system()?;
         let mut cache = self.cache.write();
-        cache.memory_system = Some(CacheEntry::new(value.clone()));
+        let previous = cache.memory_system.replace(CacheEntry::new(value.clone()));
+        self.adapt(&mut cache, MetricType::MemorySystem, previous, &value);
         Ok(value)
     }
 
     fn collect_pressure(&self) -> Result<MemoryPressure> {
-        let ttl = self.policies.get_ttl(MetricType::MemoryPressure);
+        if let Some(value) = self.lookup(MetricType::MemoryPressure, |c| c.memory_pressure.as_ref())
+        {
+            return Ok(value);
+        }
 
+        let _refresh = self.refresh_lock(MetricType::MemoryPressure);
+        if let Some(value) = self.lookup(MetricType::MemoryPressure, |c| c.memory_pressure.as_ref())
         {
-            let cache = self.cache.read();
-            if let Some(entry) = &cache.memory_pressure
-                && entry.is_valid(ttl)
-            {
-                return Ok(entry.value.clone());
-            }
+            return Ok(value);
         }
 
         let value = self.inner.memory().collect_pressure()?;
         let mut cache = self.cache.write();
-        cache.memory_pressure = Some(CacheEntry::new(value.clone()));
+        let previous = cache.memory_pressure.replace(CacheEntry::new(value.clone()));
+        self.adapt(&mut cache, MetricType::MemoryPressure, previous, &value);
         Ok(value)
     }
+
+    fn thp_status(&self) -> Result<ThpStatus> {
+        // THP settings are read on demand and not cached
+        self.inner.memory().thp_status()
+    }
+
+    fn zone_info(&self) -> Result<Vec<MemoryZone>> {
+        // Zone watermarks are read on demand and not cached
+        self.inner.memory().zone_info()
+    }
+
+    fn swap_devices(&self) -> Result<Vec<SwapDevice>> {
+        // Swap areas are read on demand and not cached
+        self.inner.memory().swap_devices()
+    }
+
+    fn zfs_arc(&self) -> Result<ZfsArcStats> {
+        // ARC size is read on demand and not cached
+        self.inner.memory().zfs_arc()
+    }
+
+    fn vmstat(&self) -> Result<HashMap<String, u64>> {
+        // Counters are read on demand and not cached
+        self.inner.memory().vmstat()
+    }
 }
 
 // Implement LoadCollector with caching
 impl<T: SystemCollector + 'static> LoadCollector for CachedCollector<T> {
     fn collect(&self) -> Result<LoadAverage> {
-        let ttl = self.policies.get_ttl(MetricType::Load);
+        if let Some(value) = self.lookup(MetricType::Load, |c| c.load.as_ref()) {
+            return Ok(value);
+        }
 
-        {
-            let cache = self.cache.read();
-            if let Some(entry) = &cache.load
-                && entry.is_valid(ttl)
-            {
-                return Ok(entry.value.clone());
-            }
+        let _refresh = self.refresh_lock(MetricType::Load);
+        if let Some(value) = self.lookup(MetricType::Load, |c| c.load.as_ref()) {
+            return Ok(value);
         }
 
         let value = self.inner.load().collect()?;
         let mut cache = self.cache.write();
-        cache.load = Some(CacheEntry::new(value.clone()));
+        let previous = cache.load.replace(CacheEntry::new(value.clone()));
+        self.adapt(&mut cache, MetricType::Load, previous, &value);
         Ok(value)
     }
+
+    fn runnable_tasks(&self) -> Result<u32> {
+        // Task counts are not cached
+        self.inner.load().runnable_tasks()
+    }
+
+    fn blocked_tasks(&self) -> Result<u32> {
+        self.inner.load().blocked_tasks()
+    }
 }
 
 // Implement DiskCollector with caching
 impl<T: SystemCollector + 'static> DiskCollector for CachedCollector<T> {
     fn list_partitions(&self) -> Result<Vec<Partition>> {
-        let ttl = self.policies.get_ttl(MetricType::DiskPartitions);
+        let mtime = self.partitions_watch_mtime();
+        if mtime.is_some() && self.cache.read().partitions_mtime != mtime {
+            self.invalidate(MetricType::DiskPartitions);
+        }
+        if let Some(value) = self.lookup(MetricType::DiskPartitions, |c| c.partitions.as_ref()) {
+            return Ok(value);
+        }
 
-        {
-            let cache = self.cache.read();
-            if let Some(entry) = &cache.partitions
-                && entry.is_valid(ttl)
-            {
-                return Ok(entry.value.clone());
-            }
+        let _refresh = self.refresh_lock(MetricType::DiskPartitions);
+        if let Some(value) = self.lookup(MetricType::DiskPartitions, |c| c.partitions.as_ref()) {
+            return Ok(value);
         }
 
         let value = self.inner.disk().list_partitions()?;
         let mut cache = self.cache.write();
-        cache.partitions = Some(CacheEntry::new(value.clone()));
+        let previous = cache.partitions.replace(CacheEntry::new(value.clone()));
+        cache.partitions_mtime = mtime;
+        self.adapt(&mut cache, MetricType::DiskPartitions, previous, &value);
         Ok(value)
     }
 
@@ -268,33 +643,30 @@ impl<T: SystemCollector + 'static> DiskCollector for CachedCollector<T> {
     }
 
     fn collect_all_usage(&self) -> Result<Vec<DiskUsage>> {
-        let ttl = self.policies.get_ttl(MetricType::DiskUsage);
+        if let Some(value) = self.lookup(MetricType::DiskUsage, |c| c.disk_usage.as_ref()) {
+            return Ok(value);
+        }
 
-        {
-            let cache = self.cache.read();
-            if let Some(entry) = &cache.disk_usage
-                && entry.is_valid(ttl)
-            {
-                return Ok(entry.value.clone());
-            }
+        let _refresh = self.refresh_lock(MetricType::DiskUsage);
+        if let Some(value) = self.lookup(MetricType::DiskUsage, |c| c.disk_usage.as_ref()) {
+            return Ok(value);
         }
 
         let value = self.inner.disk().collect_all_usage()?;
         let mut cache = self.cache.write();
-        cache.disk_usage = Some(CacheEntry::new(value.clone()));
+        let previous = cache.disk_usage.replace(CacheEntry::new(value.clone()));
+        self.adapt(&mut cache, MetricType::DiskUsage, previous, &value);
         Ok(value)
     }
 
     fn collect_io(&self) -> Result<Vec<DiskIOStats>> {
-        let ttl = self.policies.get_ttl(MetricType::DiskIo);
+        if let Some(value) = self.lookup(MetricType::DiskIo, |c| c.disk_io.as_ref()) {
+            return Ok(value);
+        }
 
-        {
-            let cache = self.cache.read();
-            if let Some(entry) = &cache.disk_io
-                && entry.is_valid(ttl)
-            {
-                return Ok(entry.value.clone());
-            }
+        let _refresh = self.refresh_lock(MetricType::DiskIo);
+        if let Some(value) = self.lookup(MetricType::DiskIo, |c| c.disk_io.as_ref()) {
+            return Ok(value);
         }
 
         let value = self.inner.disk().collect_io()?;
@@ -307,25 +679,44 @@ impl<T: SystemCollector + 'static> DiskCollector for CachedCollector<T> {
         // Individual device lookups are not cached
         self.inner.disk().collect_device_io(device)
     }
+
+    fn block_devices(&self) -> Result<Vec<BlockDevice>> {
+        // Device inventory is read on demand and not cached
+        self.inner.disk().block_devices()
+    }
+
+    fn probe_mount_latency(&self, path: &str) -> Result<Duration> {
+        // Latency probes are always live
+        self.inner.disk().probe_mount_latency(path)
+    }
+
+    fn disk_queue_config(&self, device: &str) -> Result<DiskQueueConfig> {
+        // Queue settings are read on demand and not cached
+        self.inner.disk().disk_queue_config(device)
+    }
+
+    fn device_topology(&self) -> Result<Vec<DeviceMapping>> {
+        // Topology is read on demand and not cached
+        self.inner.disk().device_topology()
+    }
 }
 
 // Implement NetworkCollector with caching
 impl<T: SystemCollector + 'static> NetworkCollector for CachedCollector<T> {
     fn list_interfaces(&self) -> Result<Vec<NetInterface>> {
-        let ttl = self.policies.get_ttl(MetricType::NetInterfaces);
+        if let Some(value) = self.lookup(MetricType::NetInterfaces, |c| c.net_interfaces.as_ref()) {
+            return Ok(value);
+        }
 
-        {
-            let cache = self.cache.read();
-            if let Some(entry) = &cache.net_interfaces
-                && entry.is_valid(ttl)
-            {
-                return Ok(entry.value.clone());
-            }
+        let _refresh = self.refresh_lock(MetricType::NetInterfaces);
+        if let Some(value) = self.lookup(MetricType::NetInterfaces, |c| c.net_interfaces.as_ref()) {
+            return Ok(value);
         }
 
         let value = self.inner.network().list_interfaces()?;
         let mut cache = self.cache.write();
-        cache.net_interfaces = Some(CacheEntry::new(value.clone()));
+        let previous = cache.net_interfaces.replace(CacheEntry::new(value.clone()));
+        self.adapt(&mut cache, MetricType::NetInterfaces, previous, &value);
         Ok(value)
     }
 
@@ -335,15 +726,13 @@ impl<T: SystemCollector + 'static> NetworkCollector for CachedCollector<T> {
     }
 
     fn collect_all_stats(&self) -> Result<Vec<NetStats>> {
-        let ttl = self.policies.get_ttl(MetricType::NetStats);
+        if let Some(value) = self.lookup(MetricType::NetStats, |c| c.net_stats.as_ref()) {
+            return Ok(value);
+        }
 
-        {
-            let cache = self.cache.read();
-            if let Some(entry) = &cache.net_stats
-                && entry.is_valid(ttl)
-            {
-                return Ok(entry.value.clone());
-            }
+        let _refresh = self.refresh_lock(MetricType::NetStats);
+        if let Some(value) = self.lookup(MetricType::NetStats, |c| c.net_stats.as_ref()) {
+            return Ok(value);
         }
 
         let value = self.inner.network().collect_all_stats()?;
@@ -351,20 +740,45 @@ impl<T: SystemCollector + 'static> NetworkCollector for CachedCollector<T> {
         cache.net_stats = Some(CacheEntry::new(value.clone()));
         Ok(value)
     }
+
+    fn collect_stats_for_pid_netns(&self, pid: i32) -> Result<Vec<NetStats>> {
+        // Per-namespace lookups are not cached
+        self.inner.network().collect_stats_for_pid_netns(pid)
+    }
+
+    fn conntrack_stats(&self) -> Result<ConntrackStats> {
+        self.inner.network().conntrack_stats()
+    }
+
+    fn socket_memory(&self) -> Result<SocketMemStats> {
+        self.inner.network().socket_memory()
+    }
+
+    // Listen queue counters are read on demand and not cached
+    fn listen_queue_stats(&self) -> Result<ListenQueueStats> {
+        self.inner.network().listen_queue_stats()
+    }
+
+    // Protocol counters are read on demand and not cached
+    fn udp_stats(&self) -> Result<UdpProtoStats> {
+        self.inner.network().udp_stats()
+    }
+
+    fn icmp_stats(&self) -> Result<IcmpStats> {
+        self.inner.network().icmp_stats()
+    }
 }
 
 // Implement IOCollector with caching
 impl<T: SystemCollector + 'static> IOCollector for CachedCollector<T> {
     fn collect_stats(&self) -> Result<IOStats> {
-        let ttl = self.policies.get_ttl(MetricType::IoStats);
+        if let Some(value) = self.lookup(MetricType::IoStats, |c| c.io_stats.as_ref()) {
+            return Ok(value);
+        }
 
-        {
-            let cache = self.cache.read();
-            if let Some(entry) = &cache.io_stats
-                && entry.is_valid(ttl)
-            {
-                return Ok(entry.value.clone());
-            }
+        let _refresh = self.refresh_lock(MetricType::IoStats);
+        if let Some(value) = self.lookup(MetricType::IoStats, |c| c.io_stats.as_ref()) {
+            return Ok(value);
         }
 
         let value = self.inner.io().collect_stats()?;
@@ -374,20 +788,19 @@ impl<T: SystemCollector + 'static> IOCollector for CachedCollector<T> {
     }
 
     fn collect_pressure(&self) -> Result<IOPressure> {
-        let ttl = self.policies.get_ttl(MetricType::IoPressure);
+        if let Some(value) = self.lookup(MetricType::IoPressure, |c| c.io_pressure.as_ref()) {
+            return Ok(value);
+        }
 
-        {
-            let cache = self.cache.read();
-            if let Some(entry) = &cache.io_pressure
-                && entry.is_valid(ttl)
-            {
-                return Ok(entry.value.clone());
-            }
+        let _refresh = self.refresh_lock(MetricType::IoPressure);
+        if let Some(value) = self.lookup(MetricType::IoPressure, |c| c.io_pressure.as_ref()) {
+            return Ok(value);
         }
 
         let value = self.inner.io().collect_pressure()?;
         let mut cache = self.cache.write();
-        cache.io_pressure = Some(CacheEntry::new(value.clone()));
+        let previous = cache.io_pressure.replace(CacheEntry::new(value.clone()));
+        self.adapt(&mut cache, MetricType::IoPressure, previous, &value);
         Ok(value)
     }
 }
@@ -395,10 +808,385 @@ impl<T: SystemCollector + 'static> IOCollector for CachedCollector<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use probe_metrics::{DiskIOStats, Error, ProcessMetrics, RetryCollector};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::time::Instant;
+
+    /// Collector whose CPU idle time is either constant or swings on every call.
+    ///
+    /// `calls` counts every call reaching the collector.
+    #[derive(Default)]
+    struct MockCollector {
+        volatile: AtomicBool,
+        calls: AtomicUsize,
+        delay: Duration,
+        /// Number of upcoming CPU calls failing with an I/O error.
+        failures: AtomicUsize,
+    }
+
+    impl MockCollector {
+        fn called<V>(&self, value: Result<V>) -> Result<V> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            value
+        }
+    }
+
+    impl CPUCollector for MockCollector {
+        fn collect_system(&self) -> Result<SystemCPU> {
+            let call = self.calls.fetch_add(1, Ordering::Relaxed);
+            std::thread::sleep(self.delay);
+            if self
+                .failures
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+                .is_ok()
+            {
+                return Err(Error::Io(std::io::ErrorKind::Interrupted.into()));
+            }
+            let idle_percent = match (self.volatile.load(Ordering::Relaxed), call % 2) {
+                (false, _) => 50.0,
+                (true, 0) => 10.0,
+                (true, _) => 90.0,
+            };
+            Ok(SystemCPU { idle_percent, ..Default::default() })
+        }
+        fn collect_pressure(&self) -> Result<CPUPressure> {
+            self.called(Err(Error::NotSupported))
+        }
+    }
+
+    impl MemoryCollector for MockCollector {
+        fn collect_system(&self) -> Result<SystemMemory> {
+            self.called(Ok(SystemMemory::default()))
+        }
+        fn collect_pressure(&self) -> Result<MemoryPressure> {
+            self.called(Err(Error::NotSupported))
+        }
+    }
+
+    impl LoadCollector for MockCollector {
+        fn collect(&self) -> Result<LoadAverage> {
+            self.called(Ok(LoadAverage::default()))
+        }
+    }
+
+    impl ProcessCollector for MockCollector {
+        fn collect(&self, pid: i32) -> Result<ProcessMetrics> {
+            self.called(Err(Error::NotFound(format!("process {}", pid))))
+        }
+        fn collect_all(&self) -> Result<Vec<ProcessMetrics>> {
+            self.called(Ok(Vec::new()))
+        }
+    }
+
+    impl DiskCollector for MockCollector {
+        fn list_partitions(&self) -> Result<Vec<Partition>> {
+            self.called(Ok(Vec::new()))
+        }
+        fn collect_usage(&self, _path: &str) -> Result<DiskUsage> {
+            self.called(Ok(DiskUsage::default()))
+        }
+        fn collect_all_usage(&self) -> Result<Vec<DiskUsage>> {
+            self.called(Ok(Vec::new()))
+        }
+        fn collect_io(&self) -> Result<Vec<DiskIOStats>> {
+            self.called(Ok(Vec::new()))
+        }
+        fn collect_device_io(&self, _device: &str) -> Result<DiskIOStats> {
+            self.called(Ok(DiskIOStats::default()))
+        }
+    }
+
+    impl NetworkCollector for MockCollector {
+        fn list_interfaces(&self) -> Result<Vec<NetInterface>> {
+            self.called(Ok(Vec::new()))
+        }
+        fn collect_stats(&self, _interface: &str) -> Result<NetStats> {
+            self.called(Ok(NetStats::default()))
+        }
+        fn collect_all_stats(&self) -> Result<Vec<NetStats>> {
+            self.called(Ok(Vec::new()))
+        }
+    }
+
+    impl IOCollector for MockCollector {
+        fn collect_stats(&self) -> Result<IOStats> {
+            self.called(Ok(IOStats::default()))
+        }
+        fn collect_pressure(&self) -> Result<IOPressure> {
+            self.called(Err(Error::NotSupported))
+        }
+    }
+
+    impl SystemCollector for MockCollector {
+        fn cpu(&self) -> &dyn CPUCollector {
+            self
+        }
+        fn memory(&self) -> &dyn MemoryCollector {
+            self
+        }
+        fn load(&self) -> &dyn LoadCollector {
+            self
+        }
+        fn process(&self) -> &dyn ProcessCollector {
+            self
+        }
+        fn disk(&self) -> &dyn DiskCollector {
+            self
+        }
+        fn network(&self) -> &dyn NetworkCollector {
+            self
+        }
+        fn io(&self) -> &dyn IOCollector {
+            self
+        }
+    }
+
+    /// Refresh the CPU metric, as if its cached entry had expired.
+    fn refresh_cpu(cached: &CachedCollector<MockCollector>) -> Duration {
+        if let Some(entry) = cached.cache.write().cpu_system.as_mut() {
+            entry.cached_at = Instant::now() - Duration::from_secs(60);
+        }
+        cached.cpu().collect_system().unwrap();
+        cached.effective_ttl(MetricType::CpuSystem)
+    }
 
     #[test]
     fn test_cache_policies_default() {
         let policies = CachePolicies::default();
         assert!(policies.get_ttl(MetricType::CpuSystem).as_millis() > 0);
     }
+
+    #[test]
+    fn test_adaptive_ttl_follows_volatility() {
+        let ms = Duration::from_millis;
+        let cached =
+            CachedCollector::new(MockCollector::default(), CachePolicies::uniform(ms(400)))
+                .with_adaptive_ttl(AdaptiveTtl::new(ms(100), ms(1600)));
+
+        // The first collection has nothing to compare against
+        assert_eq!(refresh_cpu(&cached), ms(400));
+
+        // Stable values lengthen the TTL up to the maximum
+        assert_eq!(refresh_cpu(&cached), ms(800));
+        assert_eq!(refresh_cpu(&cached), ms(1600));
+        assert_eq!(refresh_cpu(&cached), ms(1600));
+
+        // Swinging values shorten it down to the minimum
+        cached.inner().volatile.store(true, Ordering::Relaxed);
+        assert_eq!(refresh_cpu(&cached), ms(800));
+        assert_eq!(refresh_cpu(&cached), ms(400));
+        assert_eq!(refresh_cpu(&cached), ms(200));
+        assert_eq!(refresh_cpu(&cached), ms(100));
+        assert_eq!(refresh_cpu(&cached), ms(100));
+
+        // Counters and other metrics keep their static TTL
+        assert_eq!(cached.effective_ttl(MetricType::DiskIo), ms(400));
+    }
+
+    #[test]
+    fn test_explicit_ttl_changes_override_adapted_ttls() {
+        let ms = Duration::from_millis;
+        let mut cached =
+            CachedCollector::new(MockCollector::default(), CachePolicies::uniform(ms(400)))
+                .with_adaptive_ttl(AdaptiveTtl::new(ms(100), ms(1600)));
+        refresh_cpu(&cached);
+        assert_eq!(refresh_cpu(&cached), ms(800));
+
+        cached.set_ttl(MetricType::CpuSystem, ms(300));
+        assert_eq!(cached.effective_ttl(MetricType::CpuSystem), ms(300));
+
+        assert_eq!(refresh_cpu(&cached), ms(600));
+        cached.scale_ttls(2.0).unwrap();
+        assert_eq!(cached.effective_ttl(MetricType::CpuSystem), ms(600));
+
+        // A disabled cache stays disabled however stable the values
+        cached.set_ttl(MetricType::CpuSystem, Duration::ZERO);
+        assert_eq!(refresh_cpu(&cached), Duration::ZERO);
+        assert_eq!(refresh_cpu(&cached), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_snapshot_import_discards_expired_entries() {
+        let policies = CachePolicies::uniform(Duration::from_millis(50));
+        let cached = CachedCollector::new(MockCollector::default(), policies.clone());
+        cached.cpu().collect_system().unwrap();
+        let snapshot = cached.export_snapshot();
+
+        // Imported right away, the entry is still valid and served from cache
+        let restored = CachedCollector::new(MockCollector::default(), policies.clone());
+        restored.import_snapshot(snapshot.clone());
+        restored.cpu().collect_system().unwrap();
+        assert_eq!(restored.inner().calls.load(Ordering::Relaxed), 0);
+
+        // Past its TTL, the entry is dropped and the next call is a miss
+        std::thread::sleep(Duration::from_millis(60));
+        let restored = CachedCollector::new(MockCollector::default(), policies);
+        restored.import_snapshot(snapshot);
+        assert!(restored.cache.read().cpu_system.is_none());
+        restored.cpu().collect_system().unwrap();
+        assert_eq!(restored.inner().calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_snapshot_serde_roundtrip() {
+        let cached = CachedCollector::with_defaults(MockCollector::default());
+        cached.cpu().collect_system().unwrap();
+
+        let json = serde_json::to_string(&cached.export_snapshot()).unwrap();
+        let snapshot: CacheSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(snapshot.cpu_system.unwrap().value.idle_percent, 50.0);
+        assert!(snapshot.disk_io.is_none());
+    }
+
+    #[test]
+    fn test_concurrent_misses_collect_once() {
+        let mock = MockCollector { delay: Duration::from_millis(50), ..Default::default() };
+        let cached = CachedCollector::new(mock, CachePolicies::uniform(Duration::from_secs(10)));
+        cached.cpu().collect_system().unwrap();
+        cached.cache.write().cpu_system.as_mut().unwrap().cached_at =
+            Instant::now() - Duration::from_secs(60);
+
+        let barrier = std::sync::Barrier::new(10);
+        std::thread::scope(|scope| {
+            for _ in 0..10 {
+                scope.spawn(|| {
+                    barrier.wait();
+                    assert_eq!(cached.cpu().collect_system().unwrap().idle_percent, 50.0);
+                });
+            }
+        });
+
+        // One call to prime the cache, one to refresh the expired entry
+        assert_eq!(cached.inner().calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_static_ttl_without_adaptive_mode() {
+        let cached = CachedCollector::new(
+            MockCollector::default(),
+            CachePolicies::uniform(Duration::from_millis(400)),
+        );
+        cached.inner().volatile.store(true, Ordering::Relaxed);
+
+        for _ in 0..3 {
+            assert_eq!(refresh_cpu(&cached), Duration::from_millis(400));
+        }
+    }
+
+    #[test]
+    fn test_partitions_watch_invalidates_on_mounts_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let mounts = dir.path().join("mounts");
+        std::fs::write(&mounts, "/dev/sda1 / ext4 rw 0 0\n").unwrap();
+        let cached =
+            CachedCollector::with_defaults(MockCollector::default()).with_partitions_watch(&mounts);
+
+        cached.list_partitions().unwrap();
+        cached.list_partitions().unwrap();
+        assert_eq!(cached.inner().calls.load(Ordering::Relaxed), 1);
+
+        let file = std::fs::File::options().append(true).open(&mounts).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(60)).unwrap();
+        cached.list_partitions().unwrap();
+        cached.list_partitions().unwrap();
+        assert_eq!(cached.inner().calls.load(Ordering::Relaxed), 2);
+
+        // Without a readable file the TTL alone applies
+        std::fs::remove_file(&mounts).unwrap();
+        cached.list_partitions().unwrap();
+        assert_eq!(cached.inner().calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_prefetch_all_warms_every_supported_metric() {
+        let cached = CachedCollector::with_defaults(MockCollector::default());
+        cached.prefetch_all().unwrap();
+        assert_eq!(cached.inner().calls.load(Ordering::Relaxed), MetricType::ALL.len());
+
+        cached.cpu().collect_system().unwrap();
+        cached.memory().collect_system().unwrap();
+        cached.load().collect().unwrap();
+        cached.disk().list_partitions().unwrap();
+        cached.disk().collect_all_usage().unwrap();
+        cached.disk().collect_io().unwrap();
+        cached.network().list_interfaces().unwrap();
+        cached.network().collect_all_stats().unwrap();
+        cached.io().collect_stats().unwrap();
+        assert_eq!(cached.inner().calls.load(Ordering::Relaxed), MetricType::ALL.len());
+    }
+
+    #[test]
+    fn test_try_reads_never_collect() {
+        let cached = CachedCollector::with_defaults(MockCollector::default());
+        assert!(cached.try_cpu().is_none());
+        assert!(cached.try_memory().is_none());
+        assert!(cached.try_load().is_none());
+        assert_eq!(cached.inner().calls.load(Ordering::Relaxed), 0);
+
+        cached.prefetch_all().unwrap();
+        let calls = cached.inner().calls.load(Ordering::Relaxed);
+        assert_eq!(cached.try_cpu().unwrap().idle_percent, 50.0);
+        assert!(cached.try_memory().is_some());
+        assert!(cached.try_load().is_some());
+        assert_eq!(cached.inner().calls.load(Ordering::Relaxed), calls);
+
+        cached.invalidate(MetricType::CpuSystem);
+        assert!(cached.try_cpu().is_none());
+        assert_eq!(cached.inner().calls.load(Ordering::Relaxed), calls);
+    }
+
+    #[test]
+    fn test_retry_over_cache_composes() {
+        let mock = MockCollector { failures: AtomicUsize::new(1), ..Default::default() };
+        let collector = RetryCollector::new(CachedCollector::with_defaults(mock), 3);
+
+        // The failed attempt is retried and its successful result cached
+        assert_eq!(collector.cpu().collect_system().unwrap().idle_percent, 50.0);
+        assert_eq!(collector.inner().inner().calls.load(Ordering::Relaxed), 2);
+
+        collector.cpu().collect_system().unwrap();
+        assert_eq!(collector.inner().inner().calls.load(Ordering::Relaxed), 2);
+
+        // Unsupported metrics are not retried
+        assert!(matches!(collector.cpu().collect_pressure(), Err(Error::NotSupported)));
+        assert_eq!(collector.inner().inner().calls.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn test_rate_limit_calls_inner_once_per_window() {
+        let window = Duration::from_millis(50);
+        let limited = Arc::new(RateLimitedCollector::new(MockCollector::default(), window));
+
+        let started = Instant::now();
+        let hammer: Vec<_> = (0..4)
+            .map(|_| {
+                let limited = Arc::clone(&limited);
+                std::thread::spawn(move || {
+                    while started.elapsed() < Duration::from_millis(200) {
+                        assert_eq!(limited.cpu().collect_system().unwrap().idle_percent, 50.0);
+                    }
+                })
+            })
+            .collect();
+        hammer.into_iter().for_each(|t| t.join().unwrap());
+
+        let windows = (started.elapsed().as_millis() / window.as_millis()) as usize + 1;
+        let calls = limited.inner().calls.load(Ordering::Relaxed);
+        assert!((1..=windows).contains(&calls), "{} calls in {} windows", calls, windows);
+        assert!(limited.last_age(MetricType::CpuSystem).unwrap() < Duration::from_secs(1));
+        assert!(limited.last_age(MetricType::Load).is_none());
+
+        // A failed collection is not retried within the window either
+        let limited = RateLimitedCollector::new(MockCollector::default(), Duration::from_secs(60));
+        limited.inner().failures.store(1, Ordering::Relaxed);
+        assert!(matches!(limited.cpu().collect_system(), Err(Error::Io(_))));
+        // The original error is replayed, not a generic one
+        assert!(matches!(limited.cpu().collect_system(), Err(Error::Io(_))));
+        assert_eq!(limited.inner().calls.load(Ordering::Relaxed), 1);
+
+        assert!(matches!(limited.cpu().collect_pressure(), Err(Error::NotSupported)));
+        assert!(matches!(limited.cpu().collect_pressure(), Err(Error::NotSupported)));
+        assert_eq!(limited.inner().calls.load(Ordering::Relaxed), 2);
+    }
 }
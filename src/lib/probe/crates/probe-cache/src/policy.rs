@@ -1,5 +1,6 @@
 //! Cache policy configuration for different metric types.
 
+use probe_metrics::{Error, Result};
 use std::time::Duration;
 
 /// Types of metrics that can be cached.
@@ -33,6 +34,22 @@ pub enum MetricType {
 }
 
 impl MetricType {
+    /// All metric types, in discriminant order.
+    pub const ALL: [MetricType; 12] = [
+        Self::CpuSystem,
+        Self::CpuPressure,
+        Self::MemorySystem,
+        Self::MemoryPressure,
+        Self::Load,
+        Self::DiskPartitions,
+        Self::DiskUsage,
+        Self::DiskIo,
+        Self::NetInterfaces,
+        Self::NetStats,
+        Self::IoStats,
+        Self::IoPressure,
+    ];
+
     /// Convert from u8 value.
     pub fn from_u8(value: u8) -> Option<Self> {
         match value {
@@ -51,6 +68,46 @@ impl MetricType {
             _ => None,
         }
     }
+
+    /// Iterate over all metric types, in discriminant order.
+    pub fn all() -> impl Iterator<Item = MetricType> {
+        Self::ALL.into_iter()
+    }
+
+    /// Stable kebab-case name, as used in configuration files.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CpuSystem => "cpu-system",
+            Self::CpuPressure => "cpu-pressure",
+            Self::MemorySystem => "memory-system",
+            Self::MemoryPressure => "memory-pressure",
+            Self::Load => "load",
+            Self::DiskPartitions => "disk-partitions",
+            Self::DiskUsage => "disk-usage",
+            Self::DiskIo => "disk-io",
+            Self::NetInterfaces => "net-interfaces",
+            Self::NetStats => "net-stats",
+            Self::IoStats => "io-stats",
+            Self::IoPressure => "io-pressure",
+        }
+    }
+}
+
+impl std::fmt::Display for MetricType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for MetricType {
+    type Err = Error;
+
+    /// Parse a name returned by [`MetricType::as_str`].
+    fn from_str(s: &str) -> Result<Self> {
+        Self::all()
+            .find(|metric| metric.as_str() == s)
+            .ok_or_else(|| Error::Platform(format!("unknown metric type {:?}", s)))
+    }
 }
 
 /// Cache TTL policies for different metric types.
@@ -215,6 +272,26 @@ impl CachePolicies {
         }
     }
 
+    /// Multiply every TTL by `factor`.
+    ///
+    /// TTLs that would overflow saturate at `Duration::MAX`. Returns an error
+    /// and leaves the policies untouched if `factor` is not positive.
+    pub fn scale_all(&mut self, factor: f64) -> Result<()> {
+        if factor.is_nan() || factor <= 0.0 {
+            return Err(Error::Platform(format!("invalid TTL scale factor {}", factor)));
+        }
+
+        for metric in MetricType::ALL {
+            let ttl = self.get_ttl(metric);
+            if !ttl.is_zero() {
+                let scaled = Duration::try_from_secs_f64(ttl.as_secs_f64() * factor)
+                    .unwrap_or(Duration::MAX);
+                self.set_ttl(metric, scaled);
+            }
+        }
+        Ok(())
+    }
+
     /// Set TTL for CPU-related metrics.
     pub fn with_cpu_ttl(mut self, ttl: Duration) -> Self {
         self.cpu_system_ttl = ttl;
@@ -256,6 +333,17 @@ impl CachePolicies {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_metric_type_name_round_trip() {
+        for metric in MetricType::all() {
+            assert_eq!(metric.as_str().parse::<MetricType>().unwrap(), metric);
+            assert_eq!(metric.to_string(), metric.as_str());
+        }
+        assert_eq!(MetricType::all().count(), MetricType::ALL.len());
+        assert_eq!("disk-usage".parse::<MetricType>().unwrap(), MetricType::DiskUsage);
+        assert!("DiskUsage".parse::<MetricType>().is_err());
+    }
+
     #[test]
     fn test_default_policies() {
         let policies = CachePolicies::default();
@@ -288,6 +376,24 @@ mod tests {
         assert_eq!(policies.get_ttl(MetricType::CpuSystem), new_ttl);
     }
 
+    #[test]
+    fn test_scale_all() {
+        let mut policies = CachePolicies::default();
+        policies.scale_all(2.0).unwrap();
+
+        let defaults = CachePolicies::default();
+        for metric in MetricType::ALL {
+            assert_eq!(policies.get_ttl(metric), defaults.get_ttl(metric) * 2);
+        }
+
+        policies.scale_all(f64::MAX).unwrap();
+        assert_eq!(policies.get_ttl(MetricType::CpuSystem), Duration::MAX);
+
+        assert!(policies.scale_all(0.0).is_err());
+        assert!(policies.scale_all(-1.0).is_err());
+        assert!(policies.scale_all(f64::NAN).is_err());
+    }
+
     #[test]
     fn test_builder_pattern() {
         let policies = CachePolicies::default()
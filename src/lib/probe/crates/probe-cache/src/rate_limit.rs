@@ -0,0 +1,363 @@
+//! Collector wrapper enforcing a minimum interval between collections.
+
+use crate::{CacheEntry, MetricType};
+use parking_lot::Mutex;
+use probe_metrics::{
+    AllPressure, BlockDevice, CPUCollector, CPUPressure, Capabilities, CollectorDecorator,
+    ConntrackStats, CoreUsage, CpuCache, CpuTicks, CpuVuln, DeviceMapping, DiskCollector,
+    DiskIOStats, DiskQueueConfig, DiskUsage, Error, IOCollector, IOPressure, IOStats, IcmpStats,
+    InotifyStats, KernelLogEntry, KernelModule, KernelStats, ListenQueueStats, LoadAverage,
+    LoadCollector, MemoryCollector, MemoryPressure, MemoryZone, MetricsDelta, NamespaceIds,
+    NetInterface, NetStats, NetworkCollector, Partition, ProcessCollector, RawSource, Result,
+    RunQueueStat, SocketMemStats, SwapDevice, SystemCPU, SystemCollector, SystemLimits,
+    SystemMemory, ThpStatus, TimeInfo, UdpProtoStats, UnitStatus, ZfsArcStats,
+};
+use std::any::Any;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Last collection of a metric, or the error it failed with.
+type LastCall = CacheEntry<std::result::Result<Box<dyn Any + Send>, Error>>;
+
+/// Copy of an error for replaying it; I/O errors keep their kind and message.
+fn replay_error(error: &Error) -> Error {
+    match error {
+        Error::NotSupported => Error::NotSupported,
+        Error::Permission(msg) => Error::Permission(msg.clone()),
+        Error::NotFound(msg) => Error::NotFound(msg.clone()),
+        Error::Io(e) => Error::Io(std::io::Error::new(e.kind(), e.to_string())),
+        Error::Platform(msg) => Error::Platform(msg.clone()),
+        Error::Timeout => Error::Timeout,
+    }
+}
+
+/// Calls the wrapped collector at most once per interval for each metric.
+///
+/// Unlike [`CachedCollector`](crate::CachedCollector), which trades freshness
+/// for fewer syscalls, this protects the system from expensive collections:
+/// the interval is counted from the last call to the inner collector, failed
+/// or not, and concurrent callers wait for an ongoing collection instead of
+/// starting their own. Calls made too soon get the last result again; use
+/// [`last_age`](Self::last_age) to know how old it is. If the last collection
+/// failed, they get the same error again until the interval elapses.
+///
+/// Only the metrics listed in [`MetricType`] are limited; other methods are
+/// forwarded unchanged.
+pub struct RateLimitedCollector<T: SystemCollector> {
+    inner: T,
+    default_interval: Duration,
+    intervals: HashMap<MetricType, Duration>,
+    /// Last call of each metric, indexed by `MetricType`.
+    last_calls: [Mutex<Option<LastCall>>; MetricType::ALL.len()],
+}
+
+impl<T: SystemCollector> RateLimitedCollector<T> {
+    /// Wrap `inner`, collecting each metric at most once per `min_interval`.
+    pub fn new(inner: T, min_interval: Duration) -> Self {
+        Self {
+            inner,
+            default_interval: min_interval,
+            intervals: HashMap::new(),
+            last_calls: std::array::from_fn(|_| Mutex::new(None)),
+        }
+    }
+
+    /// Use `min_interval` for `metric` instead of the default; zero disables
+    /// the limit for that metric.
+    pub fn with_min_interval(mut self, metric: MetricType, min_interval: Duration) -> Self {
+        self.intervals.insert(metric, min_interval);
+        self
+    }
+
+    /// Minimum interval between collections of a metric.
+    pub fn min_interval(&self, metric: MetricType) -> Duration {
+        self.intervals.get(&metric).copied().unwrap_or(self.default_interval)
+    }
+
+    /// Time since `metric` was last collected from the inner collector, i.e.
+    /// the age of the value returned while rate limited.
+    pub fn last_age(&self, metric: MetricType) -> Option<Duration> {
+        self.last_calls[metric as usize].lock().as_ref().map(CacheEntry::age)
+    }
+
+    fn limited<V: Clone + Send + 'static>(
+        &self,
+        metric: MetricType,
+        collect: impl FnOnce() -> Result<V>,
+    ) -> Result<V> {
+        let interval = self.min_interval(metric);
+        if interval.is_zero() {
+            return collect();
+        }
+
+        // Held during the collection so concurrent callers reuse its result
+        let mut last_call = self.last_calls[metric as usize].lock();
+        if let Some(last) = last_call.as_ref().filter(|l| l.is_valid(interval)) {
+            return match &last.value {
+                Ok(value) => value.downcast_ref::<V>().cloned().ok_or_else(|| {
+                    Error::Platform(format!("{:?} cached with another type", metric))
+                }),
+                Err(e) => Err(replay_error(e)),
+            };
+        }
+
+        let result = collect();
+        let value = match &result {
+            Ok(v) => Ok(Box::new(v.clone()) as Box<dyn Any + Send>),
+            Err(e) => Err(replay_error(e)),
+        };
+        *last_call = Some(CacheEntry::new(value));
+        result
+    }
+}
+
+impl<T: SystemCollector + 'static> CollectorDecorator for RateLimitedCollector<T> {
+    type Inner = T;
+
+    fn inner(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T: SystemCollector + 'static> SystemCollector for RateLimitedCollector<T> {
+    fn cpu(&self) -> &dyn CPUCollector {
+        self
+    }
+
+    fn memory(&self) -> &dyn MemoryCollector {
+        self
+    }
+
+    fn load(&self) -> &dyn LoadCollector {
+        self
+    }
+
+    fn process(&self) -> &dyn ProcessCollector {
+        self.inner.process()
+    }
+
+    fn disk(&self) -> &dyn DiskCollector {
+        self
+    }
+
+    fn network(&self) -> &dyn NetworkCollector {
+        self
+    }
+
+    fn io(&self) -> &dyn IOCollector {
+        self
+    }
+
+    fn collect_cgroup_pressure(&self) -> Result<AllPressure> {
+        self.inner.collect_cgroup_pressure()
+    }
+
+    fn collect_kernel_stats(&self) -> Result<KernelStats> {
+        self.inner.collect_kernel_stats()
+    }
+
+    fn recent_kernel_errors(&self, since: Option<Duration>) -> Result<Vec<KernelLogEntry>> {
+        self.inner.recent_kernel_errors(since)
+    }
+
+    fn time_info(&self) -> Result<TimeInfo> {
+        self.inner.time_info()
+    }
+
+    fn inotify_stats(&self) -> Result<InotifyStats> {
+        self.inner.inotify_stats()
+    }
+
+    fn list_modules(&self) -> Result<Vec<KernelModule>> {
+        self.inner.list_modules()
+    }
+
+    fn namespace_ids(&self) -> Result<NamespaceIds> {
+        self.inner.namespace_ids()
+    }
+
+    fn system_limits(&self) -> Result<SystemLimits> {
+        self.inner.system_limits()
+    }
+
+    fn unit_status(&self, unit: &str) -> Result<UnitStatus> {
+        self.inner.unit_status(unit)
+    }
+
+    fn read_raw(&self, source: RawSource) -> Result<String> {
+        self.inner.read_raw(source)
+    }
+
+    fn collect_all_sampled(&self, window: Duration) -> Result<MetricsDelta> {
+        // Rate-limited snapshots would yield zero rates
+        self.inner.collect_all_sampled(window)
+    }
+
+    fn pressure_supported(&self) -> bool {
+        self.inner.pressure_supported()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+}
+
+impl<T: SystemCollector + 'static> CPUCollector for RateLimitedCollector<T> {
+    fn collect_system(&self) -> Result<SystemCPU> {
+        self.limited(MetricType::CpuSystem, || self.inner.cpu().collect_system())
+    }
+
+    fn collect_pressure(&self) -> Result<CPUPressure> {
+        self.limited(MetricType::CpuPressure, || self.inner.cpu().collect_pressure())
+    }
+
+    fn cpu_ticks(&self) -> Result<CpuTicks> {
+        self.inner.cpu().cpu_ticks()
+    }
+
+    fn cpu_vulnerabilities(&self) -> Result<Vec<CpuVuln>> {
+        self.inner.cpu().cpu_vulnerabilities()
+    }
+
+    fn per_cpu_runqueue(&self) -> Result<Vec<RunQueueStat>> {
+        self.inner.cpu().per_cpu_runqueue()
+    }
+
+    fn cpu_caches(&self) -> Result<Vec<CpuCache>> {
+        self.inner.cpu().cpu_caches()
+    }
+
+    fn collect_per_core(&self) -> Result<Vec<CoreUsage>> {
+        self.inner.cpu().collect_per_core()
+    }
+}
+
+impl<T: SystemCollector + 'static> MemoryCollector for RateLimitedCollector<T> {
+    fn collect_system(&self) -> Result<SystemMemory> {
+        self.limited(MetricType::MemorySystem, || self.inner.memory().collect_system())
+    }
+
+    fn collect_pressure(&self) -> Result<MemoryPressure> {
+        self.limited(MetricType::MemoryPressure, || self.inner.memory().collect_pressure())
+    }
+
+    fn thp_status(&self) -> Result<ThpStatus> {
+        self.inner.memory().thp_status()
+    }
+
+    fn zone_info(&self) -> Result<Vec<MemoryZone>> {
+        self.inner.memory().zone_info()
+    }
+
+    fn swap_devices(&self) -> Result<Vec<SwapDevice>> {
+        self.inner.memory().swap_devices()
+    }
+
+    fn zfs_arc(&self) -> Result<ZfsArcStats> {
+        self.inner.memory().zfs_arc()
+    }
+
+    fn vmstat(&self) -> Result<HashMap<String, u64>> {
+        self.inner.memory().vmstat()
+    }
+}
+
+impl<T: SystemCollector + 'static> LoadCollector for RateLimitedCollector<T> {
+    fn collect(&self) -> Result<LoadAverage> {
+        self.limited(MetricType::Load, || self.inner.load().collect())
+    }
+
+    fn runnable_tasks(&self) -> Result<u32> {
+        self.inner.load().runnable_tasks()
+    }
+
+    fn blocked_tasks(&self) -> Result<u32> {
+        self.inner.load().blocked_tasks()
+    }
+}
+
+impl<T: SystemCollector + 'static> DiskCollector for RateLimitedCollector<T> {
+    fn list_partitions(&self) -> Result<Vec<Partition>> {
+        self.limited(MetricType::DiskPartitions, || self.inner.disk().list_partitions())
+    }
+
+    fn collect_usage(&self, path: &str) -> Result<DiskUsage> {
+        self.inner.disk().collect_usage(path)
+    }
+
+    fn collect_all_usage(&self) -> Result<Vec<DiskUsage>> {
+        self.limited(MetricType::DiskUsage, || self.inner.disk().collect_all_usage())
+    }
+
+    fn collect_io(&self) -> Result<Vec<DiskIOStats>> {
+        self.limited(MetricType::DiskIo, || self.inner.disk().collect_io())
+    }
+
+    fn collect_device_io(&self, device: &str) -> Result<DiskIOStats> {
+        self.inner.disk().collect_device_io(device)
+    }
+
+    fn block_devices(&self) -> Result<Vec<BlockDevice>> {
+        self.inner.disk().block_devices()
+    }
+
+    fn probe_mount_latency(&self, path: &str) -> Result<Duration> {
+        self.inner.disk().probe_mount_latency(path)
+    }
+
+    fn disk_queue_config(&self, device: &str) -> Result<DiskQueueConfig> {
+        self.inner.disk().disk_queue_config(device)
+    }
+
+    fn device_topology(&self) -> Result<Vec<DeviceMapping>> {
+        self.inner.disk().device_topology()
+    }
+}
+
+impl<T: SystemCollector + 'static> NetworkCollector for RateLimitedCollector<T> {
+    fn list_interfaces(&self) -> Result<Vec<NetInterface>> {
+        self.limited(MetricType::NetInterfaces, || self.inner.network().list_interfaces())
+    }
+
+    fn collect_stats(&self, interface: &str) -> Result<NetStats> {
+        self.inner.network().collect_stats(interface)
+    }
+
+    fn collect_all_stats(&self) -> Result<Vec<NetStats>> {
+        self.limited(MetricType::NetStats, || self.inner.network().collect_all_stats())
+    }
+
+    fn collect_stats_for_pid_netns(&self, pid: i32) -> Result<Vec<NetStats>> {
+        self.inner.network().collect_stats_for_pid_netns(pid)
+    }
+
+    fn conntrack_stats(&self) -> Result<ConntrackStats> {
+        self.inner.network().conntrack_stats()
+    }
+
+    fn socket_memory(&self) -> Result<SocketMemStats> {
+        self.inner.network().socket_memory()
+    }
+
+    fn listen_queue_stats(&self) -> Result<ListenQueueStats> {
+        self.inner.network().listen_queue_stats()
+    }
+
+    fn udp_stats(&self) -> Result<UdpProtoStats> {
+        self.inner.network().udp_stats()
+    }
+
+    fn icmp_stats(&self) -> Result<IcmpStats> {
+        self.inner.network().icmp_stats()
+    }
+}
+
+impl<T: SystemCollector + 'static> IOCollector for RateLimitedCollector<T> {
+    fn collect_stats(&self) -> Result<IOStats> {
+        self.limited(MetricType::IoStats, || self.inner.io().collect_stats())
+    }
+
+    fn collect_pressure(&self) -> Result<IOPressure> {
+        self.limited(MetricType::IoPressure, || self.inner.io().collect_pressure())
+    }
+}
@@ -0,0 +1,67 @@
+//! Serializable snapshot of cached metrics.
+//!
+//! `Instant` is meaningless across processes, so entries are stamped with
+//! wall-clock time on export and converted back to an age on import.
+
+use crate::ttl::CacheEntry;
+use probe_metrics::{
+    CPUPressure, DiskIOStats, DiskUsage, IOPressure, IOStats, LoadAverage, MemoryPressure,
+    NetInterface, NetStats, Partition, SystemCPU, SystemMemory,
+};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// A cached value stamped with the wall-clock time it was collected.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapshotEntry<T> {
+    /// The cached value.
+    pub value: T,
+    /// When the value was cached, in milliseconds since the Unix epoch.
+    pub cached_at_unix_ms: u64,
+}
+
+impl<T: Clone> SnapshotEntry<T> {
+    pub(crate) fn from_entry(entry: &CacheEntry<T>, now_unix_ms: u64) -> Self {
+        Self {
+            value: entry.value.clone(),
+            cached_at_unix_ms: now_unix_ms.saturating_sub(entry.age().as_millis() as u64),
+        }
+    }
+
+    /// Convert back to a cache entry, or `None` if it is older than `ttl`.
+    pub(crate) fn into_entry(self, ttl: Duration, now_unix_ms: u64) -> Option<CacheEntry<T>> {
+        // Entries stamped in the future (clock adjustments) are treated as fresh
+        let age = Duration::from_millis(now_unix_ms.saturating_sub(self.cached_at_unix_ms));
+        if age >= ttl {
+            return None;
+        }
+        let cached_at = Instant::now().checked_sub(age)?;
+        Some(CacheEntry { value: self.value, cached_at })
+    }
+}
+
+/// Cached metrics exported from a `CachedCollector`.
+///
+/// Built by `CachedCollector::export_snapshot` and restored with
+/// `CachedCollector::import_snapshot`.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CacheSnapshot {
+    pub(crate) cpu_system: Option<SnapshotEntry<SystemCPU>>,
+    pub(crate) cpu_pressure: Option<SnapshotEntry<CPUPressure>>,
+    pub(crate) memory_system: Option<SnapshotEntry<SystemMemory>>,
+    pub(crate) memory_pressure: Option<SnapshotEntry<MemoryPressure>>,
+    pub(crate) load: Option<SnapshotEntry<LoadAverage>>,
+    pub(crate) partitions: Option<SnapshotEntry<Vec<Partition>>>,
+    pub(crate) disk_usage: Option<SnapshotEntry<Vec<DiskUsage>>>,
+    pub(crate) disk_io: Option<SnapshotEntry<Vec<DiskIOStats>>>,
+    pub(crate) net_interfaces: Option<SnapshotEntry<Vec<NetInterface>>>,
+    pub(crate) net_stats: Option<SnapshotEntry<Vec<NetStats>>>,
+    pub(crate) io_stats: Option<SnapshotEntry<IOStats>>,
+    pub(crate) io_pressure: Option<SnapshotEntry<IOPressure>>,
+}
+
+/// Current wall-clock time in milliseconds since the Unix epoch.
+pub(crate) fn unix_now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
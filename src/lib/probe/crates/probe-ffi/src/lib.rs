@@ -4,10 +4,11 @@
 //! All types are repr(C) for C ABI compatibility.
 
 use libc::{c_char, c_int};
+use std::collections::HashMap;
 use std::ptr;
 use std::sync::OnceLock;
 
-use probe_metrics::{ProcessState as MetricsProcessState, SystemCollector};
+use probe_metrics::{MetricMask, ProcessState as MetricsProcessState, SystemCollector};
 use probe_platform::{PlatformCollector, new_collector};
 
 // Global collector instance
@@ -29,6 +30,8 @@ pub const PROBE_ERR_NOT_FOUND: c_int = 3;
 pub const PROBE_ERR_INVALID_PARAM: c_int = 4;
 /// I/O error.
 pub const PROBE_ERR_IO: c_int = 5;
+/// Operation did not complete before its deadline.
+pub const PROBE_ERR_TIMEOUT: c_int = 6;
 /// Internal error.
 pub const PROBE_ERR_INTERNAL: c_int = 99;
 
@@ -71,6 +74,9 @@ impl ProbeResult {
             probe_metrics::Error::Platform(_) => {
                 Self::err(PROBE_ERR_INTERNAL, c"platform error".as_ptr())
             }
+            probe_metrics::Error::Timeout => {
+                Self::err(PROBE_ERR_TIMEOUT, c"deadline exceeded".as_ptr())
+            }
         }
     }
 }
@@ -85,6 +91,11 @@ pub struct SystemCPU {
     pub steal_percent: f64,
     pub cores: u32,
     pub frequency_mhz: u64,
+    pub guest_percent: f64,
+    pub guest_nice_percent: f64,
+    pub cores_present: u32,
+    pub cores_online: u32,
+    pub physical_cores: u32,
 }
 
 impl From<probe_metrics::SystemCPU> for SystemCPU {
@@ -97,6 +108,11 @@ impl From<probe_metrics::SystemCPU> for SystemCPU {
             steal_percent: cpu.steal_percent,
             cores: cpu.cores,
             frequency_mhz: cpu.frequency_mhz,
+            guest_percent: cpu.guest_percent,
+            guest_nice_percent: cpu.guest_nice_percent,
+            cores_present: cpu.cores_present,
+            cores_online: cpu.cores_online,
+            physical_cores: cpu.physical_cores,
         }
     }
 }
@@ -165,6 +181,32 @@ impl From<MetricsProcessState> for ProcessState {
     }
 }
 
+/// Process scheduling policy.
+#[repr(C)]
+pub enum SchedPolicy {
+    Normal = 0,
+    Fifo = 1,
+    RoundRobin = 2,
+    Batch = 3,
+    Idle = 5,
+    Deadline = 6,
+    Unknown = 255,
+}
+
+impl From<probe_metrics::SchedPolicy> for SchedPolicy {
+    fn from(policy: probe_metrics::SchedPolicy) -> Self {
+        match policy {
+            probe_metrics::SchedPolicy::Normal => SchedPolicy::Normal,
+            probe_metrics::SchedPolicy::Fifo => SchedPolicy::Fifo,
+            probe_metrics::SchedPolicy::RoundRobin => SchedPolicy::RoundRobin,
+            probe_metrics::SchedPolicy::Batch => SchedPolicy::Batch,
+            probe_metrics::SchedPolicy::Idle => SchedPolicy::Idle,
+            probe_metrics::SchedPolicy::Deadline => SchedPolicy::Deadline,
+            probe_metrics::SchedPolicy::Unknown => SchedPolicy::Unknown,
+        }
+    }
+}
+
 /// Process metrics.
 #[repr(C)]
 pub struct ProcessMetrics {
@@ -177,7 +219,12 @@ pub struct ProcessMetrics {
     pub num_fds: u32,
     pub read_bytes_per_sec: u64,
     pub write_bytes_per_sec: u64,
+    pub minor_faults: u64,
+    pub major_faults: u64,
     pub state: ProcessState,
+    pub sched_policy: SchedPolicy,
+    pub nice: i8,
+    pub rt_priority: u32,
 }
 
 impl From<probe_metrics::ProcessMetrics> for ProcessMetrics {
@@ -192,7 +239,105 @@ impl From<probe_metrics::ProcessMetrics> for ProcessMetrics {
             num_fds: p.num_fds,
             read_bytes_per_sec: p.read_bytes_per_sec,
             write_bytes_per_sec: p.write_bytes_per_sec,
+            minor_faults: p.minor_faults,
+            major_faults: p.major_faults,
             state: p.state.into(),
+            sched_policy: p.sched_policy.into(),
+            nice: p.nice,
+            rt_priority: p.rt_priority,
+        }
+    }
+}
+
+/// Shared vs private memory breakdown of a process, in bytes.
+#[repr(C)]
+#[derive(Default)]
+pub struct MemoryBreakdown {
+    pub rss: u64,
+    pub pss: u64,
+    pub shared_clean: u64,
+    pub shared_dirty: u64,
+    pub private_clean: u64,
+    pub private_dirty: u64,
+    pub swap: u64,
+}
+
+impl From<probe_metrics::MemoryBreakdown> for MemoryBreakdown {
+    fn from(m: probe_metrics::MemoryBreakdown) -> Self {
+        Self {
+            rss: m.rss,
+            pss: m.pss,
+            shared_clean: m.shared_clean,
+            shared_dirty: m.shared_dirty,
+            private_clean: m.private_clean,
+            private_dirty: m.private_dirty,
+            swap: m.swap,
+        }
+    }
+}
+
+/// Scheduler run-queue statistics of a process.
+#[repr(C)]
+#[derive(Default)]
+pub struct SchedStats {
+    /// Time spent running on a CPU, in nanoseconds.
+    pub run_ticks: u64,
+    /// Time spent waiting on a run queue, in nanoseconds.
+    pub wait_ticks: u64,
+    /// Number of timeslices run on a CPU.
+    pub timeslices: u64,
+    /// Average run-queue wait per timeslice, in nanoseconds.
+    pub avg_wait_per_timeslice: f64,
+}
+
+impl From<probe_metrics::SchedStats> for SchedStats {
+    fn from(s: probe_metrics::SchedStats) -> Self {
+        Self {
+            run_ticks: s.run_ticks,
+            wait_ticks: s.wait_ticks,
+            timeslices: s.timeslices,
+            avg_wait_per_timeslice: s.avg_wait_per_timeslice(),
+        }
+    }
+}
+
+/// OOM killer ranking of a process.
+#[repr(C)]
+#[derive(Default)]
+pub struct OomInfo {
+    /// Badness score used to pick a victim (higher is killed first).
+    pub oom_score: i32,
+    /// User adjustment, from -1000 (never kill) to 1000.
+    pub oom_score_adj: i32,
+}
+
+impl From<probe_metrics::OomInfo> for OomInfo {
+    fn from(o: probe_metrics::OomInfo) -> Self {
+        Self { oom_score: o.oom_score, oom_score_adj: o.oom_score_adj }
+    }
+}
+
+/// Capability sets of a process; bit `n` is capability number `n`.
+#[repr(C)]
+#[derive(Default)]
+pub struct ProcessCapabilities {
+    /// Capabilities preserved across `execve`.
+    pub inheritable: u64,
+    /// Capabilities the process may assume.
+    pub permitted: u64,
+    /// Capabilities used for permission checks.
+    pub effective: u64,
+    /// Upper bound of capabilities the process may ever gain.
+    pub bounding: u64,
+}
+
+impl From<probe_metrics::ProcessCapabilities> for ProcessCapabilities {
+    fn from(c: probe_metrics::ProcessCapabilities) -> Self {
+        Self {
+            inheritable: c.inheritable,
+            permitted: c.permitted,
+            effective: c.effective,
+            bounding: c.bounding,
         }
     }
 }
@@ -292,6 +437,14 @@ pub struct QuotaUsage {
     pub cpu_percent: f64,
     /// CPU limit percentage (0 = no limit).
     pub cpu_limit_percent: f64,
+    /// Swap limit in bytes (0 = unknown, u64::MAX = no limit).
+    pub memory_swap_limit_bytes: u64,
+    /// Current swap usage in bytes.
+    pub memory_swap_current_bytes: u64,
+    /// Whether swap accounting is enabled (swap fields are valid).
+    pub swap_accounting_enabled: bool,
+    /// Working set in bytes: memory usage minus inactive file cache.
+    pub memory_working_set_bytes: u64,
 }
 
 impl Default for QuotaUsage {
@@ -303,6 +456,10 @@ impl Default for QuotaUsage {
             pids_limit: 0,
             cpu_percent: 0.0,
             cpu_limit_percent: 0.0,
+            memory_swap_limit_bytes: 0,
+            memory_swap_current_bytes: 0,
+            swap_accounting_enabled: false,
+            memory_working_set_bytes: 0,
         }
     }
 }
@@ -316,6 +473,10 @@ impl From<probe_quota::QuotaUsage> for QuotaUsage {
             pids_limit: u.pids_limit.unwrap_or(0),
             cpu_percent: u.cpu_percent,
             cpu_limit_percent: u.cpu_limit_percent.unwrap_or(0.0),
+            memory_swap_limit_bytes: u.memory_swap_limit_bytes.unwrap_or(0),
+            memory_swap_current_bytes: u.memory_swap_current_bytes.unwrap_or(0),
+            swap_accounting_enabled: u.swap_accounting_enabled,
+            memory_working_set_bytes: u.memory_working_set_bytes,
         }
     }
 }
@@ -392,10 +553,21 @@ pub extern "C" fn probe_init() -> ProbeResult {
 }
 
 /// Shutdown the probe library.
-/// Should be called at program exit.
+/// Should be called at program exit or before unloading the library.
+///
+/// Stops and joins the library's background threads (link and process
+/// watchers). Process watcher handles stay valid and must still be freed
+/// with `probe_process_watch_stop`. Must not be called from a callback.
+///
+/// Timed-out workers (mount latency probes, `probe_collect_all_deadline`)
+/// cannot be cancelled and are not joined; one still blocked in the kernel
+/// outlives shutdown, so do not unload the library while such calls have
+/// timed out recently.
 #[unsafe(no_mangle)]
 pub extern "C" fn probe_shutdown() {
-    // Nothing to clean up currently
+    #[cfg(target_os = "linux")]
+    drop(LINK_WATCHER.lock().unwrap_or_else(|e| e.into_inner()).take());
+    probe_metrics::shutdown_background();
 }
 
 // ============================================================================
@@ -502,6 +674,166 @@ pub unsafe extern "C" fn probe_collect_process(pid: i32, out: *mut ProcessMetric
     }
 }
 
+/// Collect metrics for a list of processes in one call.
+///
+/// PIDs that no longer exist or cannot be read (e.g. permission denied) are
+/// skipped; `written` receives the number of entries stored in `out`. Fails
+/// with `PROBE_ERR_INVALID_PARAM` if `out_cap` is smaller than `count`.
+///
+/// # Safety
+/// `pids` must point to `count` values, `out` to `out_cap` writable entries,
+/// and `written` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_processes(
+    pids: *const i32,
+    count: usize,
+    out: *mut ProcessMetrics,
+    out_cap: usize,
+    written: *mut usize,
+) -> ProbeResult {
+    if written.is_null() || (count > 0 && (pids.is_null() || out.is_null())) {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+    unsafe { *written = 0 };
+    if out_cap < count {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"buffer too small".as_ptr());
+    }
+    if count == 0 {
+        return ProbeResult::ok();
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    let pids = unsafe { std::slice::from_raw_parts(pids, count) };
+    for (n, proc) in collect_each(pids, |pid| collector.process().collect(pid)).enumerate() {
+        unsafe { *out.add(n) = ProcessMetrics::from(proc) };
+        unsafe { *written = n + 1 };
+    }
+    ProbeResult::ok()
+}
+
+/// Collect each of `pids`, skipping those that fail so one process that
+/// exited or is unreadable does not discard the rest.
+fn collect_each<'a>(
+    pids: &'a [i32],
+    collect: impl Fn(i32) -> probe_metrics::Result<probe_metrics::ProcessMetrics> + 'a,
+) -> impl Iterator<Item = probe_metrics::ProcessMetrics> + 'a {
+    pids.iter().filter_map(move |&pid| collect(pid).ok())
+}
+
+/// Collect the shared/private memory breakdown (PSS) of a process.
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_process_memory_breakdown(
+    pid: i32,
+    out: *mut MemoryBreakdown,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.process().memory_breakdown(pid) {
+        Ok(breakdown) => {
+            unsafe { *out = MemoryBreakdown::from(breakdown) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Collect scheduler run-queue statistics of a process.
+///
+/// Returns `PROBE_ERR_NOT_SUPPORTED` on kernels without `CONFIG_SCHEDSTATS`.
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_process_sched_stats(
+    pid: i32,
+    out: *mut SchedStats,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.process().sched_latency(pid) {
+        Ok(stats) => {
+            unsafe { *out = SchedStats::from(stats) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Collect the OOM killer score and adjustment of a process.
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_process_oom_info(
+    pid: i32,
+    out: *mut OomInfo,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.process().oom_info(pid) {
+        Ok(info) => {
+            unsafe { *out = OomInfo::from(info) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Collect the capability sets of a process.
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_process_capabilities(
+    pid: i32,
+    out: *mut ProcessCapabilities,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.process().capability_sets(pid) {
+        Ok(caps) => {
+            unsafe { *out = ProcessCapabilities::from(caps) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
 // ============================================================================
 // RESOURCE QUOTA FUNCTIONS (READ-ONLY DETECTION)
 // ============================================================================
@@ -581,6 +913,63 @@ pub unsafe extern "C" fn probe_quota_read_usage(pid: i32, out: *mut QuotaUsage)
     }
 }
 
+/// Read the current usage of the calling process's own cgroup.
+///
+/// Reports the container's actual usage from cgroup v2 files; `cpu_percent`
+/// covers the interval since the previous call (0 on the first one).
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_quota_self_cgroup_usage(out: *mut QuotaUsage) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    match get_quota_reader().self_cgroup_usage() {
+        Ok(usage) => {
+            unsafe { *out = QuotaUsage::from(usage) };
+            ProbeResult::ok()
+        }
+        Err(e) => match e {
+            probe_quota::Error::Permission(_) => {
+                ProbeResult::err(PROBE_ERR_PERMISSION, c"permission denied".as_ptr())
+            }
+            probe_quota::Error::NotSupported => {
+                ProbeResult::err(PROBE_ERR_NOT_SUPPORTED, c"not supported".as_ptr())
+            }
+            _ => ProbeResult::err(PROBE_ERR_INTERNAL, c"internal error".as_ptr()),
+        },
+    }
+}
+
+/// Read the calling process's file-descriptor limit (`RLIMIT_NOFILE`).
+///
+/// Unlimited values are reported as `UINT64_MAX`.
+///
+/// # Safety
+/// The `soft` and `hard` pointers must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_self_nofile_limit(soft: *mut u64, hard: *mut u64) -> ProbeResult {
+    if soft.is_null() || hard.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    match probe_quota::self_nofile_limit() {
+        Ok((s, h)) => {
+            unsafe {
+                *soft = s;
+                *hard = h;
+            }
+            ProbeResult::ok()
+        }
+        Err(probe_quota::Error::NotSupported) => {
+            ProbeResult::err(PROBE_ERR_NOT_SUPPORTED, c"not supported".as_ptr())
+        }
+        Err(_) => ProbeResult::err(PROBE_ERR_IO, c"getrlimit failed".as_ptr()),
+    }
+}
+
 /// Detect container runtime.
 ///
 /// # Safety
@@ -628,6 +1017,151 @@ pub extern "C" fn probe_get_platform() -> *const c_char {
     return c"unknown".as_ptr();
 }
 
+/// Copy `value` into a caller buffer of `len` bytes as a NUL-terminated string.
+///
+/// Fails with PROBE_ERR_INVALID_PARAM rather than truncating.
+fn write_c_string(value: &str, buf: *mut c_char, len: usize) -> ProbeResult {
+    if buf.is_null() || len == 0 {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+    if value.len() >= len {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"buffer too small".as_ptr());
+    }
+    unsafe {
+        ptr::copy_nonoverlapping(value.as_ptr() as *const c_char, buf, value.len());
+        *buf.add(value.len()) = 0;
+    }
+    ProbeResult::ok()
+}
+
+/// Get the system hostname.
+///
+/// # Safety
+/// `buf` must be valid for writes of `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_get_hostname(buf: *mut c_char, len: usize) -> ProbeResult {
+    match probe_platform::host::hostname() {
+        Ok(name) => write_c_string(&name, buf, len),
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Get the machine identifier (empty string if the host has none).
+///
+/// # Safety
+/// `buf` must be valid for writes of `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_get_machine_id(buf: *mut c_char, len: usize) -> ProbeResult {
+    match probe_platform::host::machine_id() {
+        Ok(id) => write_c_string(&id, buf, len),
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Capability bit: pressure stall information (PSI) is available.
+pub const PROBE_CAP_PRESSURE: u32 = probe_metrics::Capabilities::PRESSURE;
+/// Capability bit: temperature sensors are readable.
+pub const PROBE_CAP_THERMAL: u32 = probe_metrics::Capabilities::THERMAL;
+/// Capability bit: per-core CPU counters are available.
+pub const PROBE_CAP_PER_CORE: u32 = probe_metrics::Capabilities::PER_CORE;
+/// Capability bit: connection enumeration is available.
+pub const PROBE_CAP_CONNECTIONS: u32 = probe_metrics::Capabilities::CONNECTIONS;
+/// Capability bit: per-device disk I/O statistics are available.
+pub const PROBE_CAP_DISK_IO: u32 = probe_metrics::Capabilities::DISK_IO;
+
+/// Get the capabilities of the initialized collector as PROBE_CAP_* bits.
+///
+/// Returns 0 if the library is not initialized.
+#[unsafe(no_mangle)]
+pub extern "C" fn probe_get_capabilities() -> u32 {
+    COLLECTOR.get().map_or(0, |c| c.capabilities().bits())
+}
+
+/// Outcome of probing one subsystem.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubsystemStatus {
+    /// The subsystem returned metrics.
+    Ok = 0,
+    /// The platform does not provide this subsystem.
+    #[default]
+    Unsupported = 1,
+    /// The subsystem failed; see `reason`.
+    Failed = 2,
+}
+
+/// Self-test result for one subsystem.
+#[repr(C)]
+pub struct SubsystemResult {
+    pub status: SubsystemStatus,
+    /// Error message when `status` is `Failed` (null-terminated, truncated).
+    pub reason: [c_char; 128],
+}
+
+impl From<probe_metrics::SubsystemStatus> for SubsystemResult {
+    fn from(s: probe_metrics::SubsystemStatus) -> Self {
+        let mut result = Self { status: SubsystemStatus::Unsupported, reason: [0; 128] };
+        result.status = match s {
+            probe_metrics::SubsystemStatus::Ok => SubsystemStatus::Ok,
+            probe_metrics::SubsystemStatus::Unsupported => SubsystemStatus::Unsupported,
+            probe_metrics::SubsystemStatus::Failed(reason) => {
+                copy_str_to_carray(&reason, &mut result.reason);
+                SubsystemStatus::Failed
+            }
+        };
+        result
+    }
+}
+
+/// Per-subsystem results of `probe_self_test`.
+#[repr(C)]
+pub struct SelfTestReport {
+    pub cpu: SubsystemResult,
+    pub memory: SubsystemResult,
+    pub load: SubsystemResult,
+    pub process: SubsystemResult,
+    pub disk: SubsystemResult,
+    pub network: SubsystemResult,
+    pub io: SubsystemResult,
+    pub pressure: SubsystemResult,
+}
+
+impl From<probe_metrics::SelfTestReport> for SelfTestReport {
+    fn from(r: probe_metrics::SelfTestReport) -> Self {
+        Self {
+            cpu: r.cpu.into(),
+            memory: r.memory.into(),
+            load: r.load.into(),
+            process: r.process.into(),
+            disk: r.disk.into(),
+            network: r.network.into(),
+            io: r.io.into(),
+            pressure: r.pressure.into(),
+        }
+    }
+}
+
+/// Query each subsystem once and report whether it works on this host.
+///
+/// Subsystem failures are reported in `out`, not as the return value.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_self_test(out: *mut SelfTestReport) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    unsafe { *out = SelfTestReport::from(collector.self_test()) };
+    ProbeResult::ok()
+}
+
 // ============================================================================
 // PRESSURE METRICS (PSI - Linux only)
 // ============================================================================
@@ -708,6 +1242,14 @@ impl From<probe_metrics::IOPressure> for IOPressure {
     }
 }
 
+/// Check if pressure stall information is available.
+///
+/// The check is made once per collector; returns false before `probe_init`.
+#[unsafe(no_mangle)]
+pub extern "C" fn probe_pressure_supported() -> bool {
+    COLLECTOR.get().is_some_and(|c| c.pressure_supported())
+}
+
 /// Collect CPU pressure metrics.
 ///
 /// # Safety
@@ -780,170 +1322,335 @@ pub unsafe extern "C" fn probe_collect_io_pressure(out: *mut IOPressure) -> Prob
     }
 }
 
-// ============================================================================
-// DISK METRICS
-// ============================================================================
-
-/// Maximum path length for disk-related strings.
-pub const PROBE_MAX_PATH_LEN: usize = 256;
+/// Resource selector for `probe_pressure_level`.
+pub const PROBE_PRESSURE_CPU: u32 = 0;
+pub const PROBE_PRESSURE_MEMORY: u32 = 1;
+pub const PROBE_PRESSURE_IO: u32 = 2;
 
-/// Partition information.
+/// Severity of a pressure stall.
 #[repr(C)]
-#[derive(Clone, Copy)]
-pub struct Partition {
-    pub device: [c_char; PROBE_MAX_PATH_LEN],
-    pub mount_point: [c_char; PROBE_MAX_PATH_LEN],
-    pub fs_type: [c_char; 64],
-    pub options: [c_char; PROBE_MAX_PATH_LEN],
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PressureLevel {
+    /// No task was stalled.
+    #[default]
+    None = 0,
+    /// Some tasks were stalled.
+    Some = 1,
+    /// All non-idle tasks were stalled at once.
+    Full = 2,
+}
+
+impl From<probe_metrics::PressureLevel> for PressureLevel {
+    fn from(l: probe_metrics::PressureLevel) -> Self {
+        match l {
+            probe_metrics::PressureLevel::None => Self::None,
+            probe_metrics::PressureLevel::Some => Self::Some,
+            probe_metrics::PressureLevel::Full => Self::Full,
+        }
+    }
 }
 
-impl Default for Partition {
-    fn default() -> Self {
-        Self {
-            device: [0; PROBE_MAX_PATH_LEN],
-            mount_point: [0; PROBE_MAX_PATH_LEN],
-            fs_type: [0; 64],
-            options: [0; PROBE_MAX_PATH_LEN],
+/// Classify current pressure of a resource (PROBE_PRESSURE_* constant).
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_pressure_level(
+    resource: u32,
+    out: *mut PressureLevel,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    let level = match resource {
+        PROBE_PRESSURE_CPU => collector.cpu().pressure_level(),
+        PROBE_PRESSURE_MEMORY => collector.memory().pressure_level(),
+        PROBE_PRESSURE_IO => collector.io().pressure_level(),
+        _ => {
+            return ProbeResult::err(
+                PROBE_ERR_INVALID_PARAM,
+                c"unknown pressure resource".as_ptr(),
+            );
+        }
+    };
+    match level {
+        Ok(level) => {
+            unsafe { *out = PressureLevel::from(level) };
+            ProbeResult::ok()
         }
+        Err(e) => ProbeResult::from_metrics_error(e),
     }
 }
 
-fn copy_str_to_carray<const N: usize>(s: &str, dest: &mut [c_char; N]) {
-    let bytes = s.as_bytes();
-    let len = bytes.len().min(N - 1);
-    for (i, &b) in bytes[..len].iter().enumerate() {
-        dest[i] = b as c_char;
+/// Report whether all tasks were recently stalled on memory (`full_avg10 > 0`).
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_memory_stalled(out: *mut bool) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
     }
-    dest[len] = 0;
-}
 
-impl From<probe_metrics::Partition> for Partition {
-    fn from(p: probe_metrics::Partition) -> Self {
-        let mut result = Self::default();
-        copy_str_to_carray(&p.device, &mut result.device);
-        copy_str_to_carray(&p.mount_point, &mut result.mount_point);
-        copy_str_to_carray(&p.fs_type, &mut result.fs_type);
-        copy_str_to_carray(&p.options, &mut result.options);
-        result
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.memory().memory_stalled() {
+        Ok(stalled) => {
+            unsafe { *out = stalled };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
     }
 }
 
-/// Disk usage information.
+// ============================================================================
+// TRANSPARENT HUGE PAGES (Linux only)
+// ============================================================================
+
+/// Transparent huge page status.
 #[repr(C)]
-#[derive(Clone, Copy)]
-pub struct DiskUsage {
-    pub path: [c_char; PROBE_MAX_PATH_LEN],
-    pub total_bytes: u64,
-    pub used_bytes: u64,
-    pub free_bytes: u64,
-    pub used_percent: f64,
-    pub inodes_total: u64,
-    pub inodes_used: u64,
-    pub inodes_free: u64,
+pub struct ThpStatus {
+    /// Allocation mode: "always", "madvise", "never" or "unknown" (null-terminated).
+    pub enabled: [c_char; 16],
+    /// Defrag policy: "always", "defer", "defer+madvise", "madvise", "never"
+    /// or "unknown" (null-terminated).
+    pub defrag: [c_char; 16],
+    /// Anonymous memory backed by huge pages in bytes.
+    pub anon_hugepages_bytes: u64,
+}
+
+impl From<probe_metrics::ThpStatus> for ThpStatus {
+    fn from(t: probe_metrics::ThpStatus) -> Self {
+        let mut result = Self { enabled: [0; 16], defrag: [0; 16], anon_hugepages_bytes: 0 };
+        copy_str_to_carray(t.enabled.as_str(), &mut result.enabled);
+        copy_str_to_carray(t.defrag.as_str(), &mut result.defrag);
+        result.anon_hugepages_bytes = t.anon_hugepages_bytes;
+        result
+    }
 }
 
-impl Default for DiskUsage {
-    fn default() -> Self {
-        Self {
-            path: [0; PROBE_MAX_PATH_LEN],
-            total_bytes: 0,
-            used_bytes: 0,
-            free_bytes: 0,
-            used_percent: 0.0,
-            inodes_total: 0,
-            inodes_used: 0,
-            inodes_free: 0,
+/// Collect transparent huge page status.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_thp_status(out: *mut ThpStatus) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.memory().thp_status() {
+        Ok(status) => {
+            unsafe { *out = ThpStatus::from(status) };
+            ProbeResult::ok()
         }
+        Err(e) => ProbeResult::from_metrics_error(e),
     }
 }
 
-impl From<probe_metrics::DiskUsage> for DiskUsage {
-    fn from(d: probe_metrics::DiskUsage) -> Self {
-        let mut result = Self::default();
-        copy_str_to_carray(&d.path, &mut result.path);
-        result.total_bytes = d.total_bytes;
-        result.used_bytes = d.used_bytes;
-        result.free_bytes = d.free_bytes;
-        result.used_percent = d.used_percent;
-        result.inodes_total = d.inodes_total;
-        result.inodes_used = d.inodes_used;
-        result.inodes_free = d.inodes_free;
+// ============================================================================
+// MEMORY ZONES (Linux only)
+// ============================================================================
+
+/// Memory zone watermarks summed across all zones, in pages.
+#[repr(C)]
+pub struct MemoryZoneSummary {
+    /// Number of zones reported by the kernel.
+    pub zone_count: u32,
+    /// Zones whose free pages are below their low watermark.
+    pub zones_below_low: u32,
+    /// Free pages.
+    pub free_pages: u64,
+    /// Sum of min watermarks.
+    pub min_watermark: u64,
+    /// Sum of low watermarks.
+    pub low_watermark: u64,
+    /// Sum of high watermarks.
+    pub high_watermark: u64,
+}
+
+impl From<&[probe_metrics::MemoryZone]> for MemoryZoneSummary {
+    fn from(zones: &[probe_metrics::MemoryZone]) -> Self {
+        let mut result = Self {
+            zone_count: zones.len() as u32,
+            zones_below_low: 0,
+            free_pages: 0,
+            min_watermark: 0,
+            low_watermark: 0,
+            high_watermark: 0,
+        };
+        for zone in zones {
+            result.zones_below_low += zone.below_low() as u32;
+            result.free_pages += zone.free_pages;
+            result.min_watermark += zone.min_watermark;
+            result.low_watermark += zone.low_watermark;
+            result.high_watermark += zone.high_watermark;
+        }
         result
     }
 }
 
-/// Disk I/O statistics.
-#[repr(C)]
-#[derive(Clone, Copy)]
-pub struct DiskIOStats {
-    pub device: [c_char; 64],
-    pub reads_completed: u64,
-    pub read_bytes: u64,
-    pub read_time_us: u64,
-    pub writes_completed: u64,
-    pub write_bytes: u64,
-    pub write_time_us: u64,
-    pub io_in_progress: u64,
-    pub io_time_us: u64,
-    pub weighted_io_time_us: u64,
+/// Collect memory zone watermarks summed across zones.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_zone_summary(out: *mut MemoryZoneSummary) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.memory().zone_info() {
+        Ok(zones) => {
+            unsafe { *out = MemoryZoneSummary::from(zones.as_slice()) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
 }
 
-impl Default for DiskIOStats {
-    fn default() -> Self {
+// ============================================================================
+// VM STATISTICS (Linux only)
+// ============================================================================
+
+/// Headline virtual memory counters since boot.
+///
+/// Counters missing from the running kernel are reported as 0.
+#[repr(C)]
+#[derive(Default)]
+pub struct VmStatSummary {
+    /// Page faults (`pgfault`).
+    pub page_faults: u64,
+    /// Major page faults requiring I/O (`pgmajfault`).
+    pub major_page_faults: u64,
+    /// Processes killed by the OOM killer (`oom_kill`).
+    pub oom_kills: u64,
+    /// Pages swapped in (`pswpin`).
+    pub swap_ins: u64,
+    /// Pages swapped out (`pswpout`).
+    pub swap_outs: u64,
+}
+
+impl From<&HashMap<String, u64>> for VmStatSummary {
+    fn from(vmstat: &HashMap<String, u64>) -> Self {
+        let counter = |name: &str| vmstat.get(name).copied().unwrap_or(0);
         Self {
-            device: [0; 64],
-            reads_completed: 0,
-            read_bytes: 0,
-            read_time_us: 0,
-            writes_completed: 0,
-            write_bytes: 0,
-            write_time_us: 0,
-            io_in_progress: 0,
-            io_time_us: 0,
-            weighted_io_time_us: 0,
+            page_faults: counter("pgfault"),
+            major_page_faults: counter("pgmajfault"),
+            oom_kills: counter("oom_kill"),
+            swap_ins: counter("pswpin"),
+            swap_outs: counter("pswpout"),
         }
     }
 }
 
-impl From<probe_metrics::DiskIOStats> for DiskIOStats {
-    fn from(d: probe_metrics::DiskIOStats) -> Self {
-        let mut result = Self::default();
-        copy_str_to_carray(&d.device, &mut result.device);
-        result.reads_completed = d.reads_completed;
-        result.read_bytes = d.read_bytes;
-        result.read_time_us = d.read_time_us;
-        result.writes_completed = d.writes_completed;
-        result.write_bytes = d.write_bytes;
-        result.write_time_us = d.write_time_us;
-        result.io_in_progress = d.io_in_progress;
-        result.io_time_us = d.io_time_us;
-        result.weighted_io_time_us = d.weighted_io_time_us;
-        result
+/// Collect headline virtual memory counters.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_vmstat(out: *mut VmStatSummary) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.memory().vmstat() {
+        Ok(vmstat) => {
+            unsafe { *out = VmStatSummary::from(&vmstat) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
     }
 }
 
-/// List result for partitions.
+// ============================================================================
+// CPU VULNERABILITIES
+// ============================================================================
+
+/// Mitigation status of a CPU vulnerability.
 #[repr(C)]
-pub struct PartitionList {
-    pub items: *mut Partition,
-    pub count: usize,
-    pub capacity: usize,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CpuVulnStatus {
+    /// The CPU is not affected.
+    NotAffected = 0,
+    /// The CPU is affected and a mitigation is active.
+    Mitigated = 1,
+    /// The CPU is affected and not (fully) mitigated.
+    Vulnerable = 2,
+    /// Unrecognized status string.
+    #[default]
+    Unknown = 3,
 }
 
-/// List result for disk I/O stats.
+impl From<probe_metrics::CpuVulnStatus> for CpuVulnStatus {
+    fn from(s: probe_metrics::CpuVulnStatus) -> Self {
+        match s {
+            probe_metrics::CpuVulnStatus::NotAffected => Self::NotAffected,
+            probe_metrics::CpuVulnStatus::Mitigated => Self::Mitigated,
+            probe_metrics::CpuVulnStatus::Vulnerable => Self::Vulnerable,
+            probe_metrics::CpuVulnStatus::Unknown => Self::Unknown,
+        }
+    }
+}
+
+/// A CPU hardware vulnerability as reported by the kernel.
 #[repr(C)]
-pub struct DiskIOStatsList {
-    pub items: *mut DiskIOStats,
+pub struct CpuVuln {
+    /// Vulnerability name, e.g. "meltdown" (null-terminated).
+    pub name: [c_char; 64],
+    /// Classified mitigation status.
+    pub status: CpuVulnStatus,
+    /// Raw kernel status line (null-terminated, truncated).
+    pub detail: [c_char; 128],
+}
+
+impl From<probe_metrics::CpuVuln> for CpuVuln {
+    fn from(v: probe_metrics::CpuVuln) -> Self {
+        let mut result = Self { name: [0; 64], status: v.status.into(), detail: [0; 128] };
+        copy_str_to_carray(&v.name, &mut result.name);
+        copy_str_to_carray(&v.detail, &mut result.detail);
+        result
+    }
+}
+
+/// List of CPU vulnerabilities.
+#[repr(C)]
+pub struct CpuVulnList {
+    pub items: *mut CpuVuln,
     pub count: usize,
     pub capacity: usize,
 }
 
-/// List disk partitions.
+/// Collect CPU vulnerability mitigation status.
 ///
 /// # Safety
-/// The `out` pointer must be valid. Caller must call `probe_free_partition_list` when done.
+/// The `out` pointer must be valid. Caller must call `probe_free_cpu_vuln_list` when done.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_list_partitions(out: *mut PartitionList) -> ProbeResult {
+pub unsafe extern "C" fn probe_collect_cpu_vulnerabilities(out: *mut CpuVulnList) -> ProbeResult {
     if out.is_null() {
         return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
     }
@@ -953,9 +1660,9 @@ pub unsafe extern "C" fn probe_list_partitions(out: *mut PartitionList) -> Probe
         None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
     };
 
-    match collector.disk().list_partitions() {
-        Ok(partitions) => {
-            let mut items: Vec<Partition> = partitions.into_iter().map(|p| p.into()).collect();
+    match collector.cpu().cpu_vulnerabilities() {
+        Ok(vulns) => {
+            let mut items: Vec<CpuVuln> = vulns.into_iter().map(|v| v.into()).collect();
             let count = items.len();
             let capacity = items.capacity();
             let ptr = items.as_mut_ptr();
@@ -972,18 +1679,18 @@ pub unsafe extern "C" fn probe_list_partitions(out: *mut PartitionList) -> Probe
     }
 }
 
-/// Free a partition list returned by `probe_list_partitions`.
+/// Free a CPU vulnerability list.
 ///
 /// # Safety
-/// The list must have been allocated by `probe_list_partitions`.
+/// The list must have been allocated by `probe_collect_cpu_vulnerabilities`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_free_partition_list(list: *mut PartitionList) {
+pub unsafe extern "C" fn probe_free_cpu_vuln_list(list: *mut CpuVulnList) {
     if list.is_null() {
         return;
     }
     unsafe {
         let list = &mut *list;
-        if !list.items.is_null() {
+        if !list.items.is_null() && list.capacity > 0 {
             drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
             list.items = ptr::null_mut();
             list.count = 0;
@@ -992,16 +1699,74 @@ pub unsafe extern "C" fn probe_free_partition_list(list: *mut PartitionList) {
     }
 }
 
-/// Collect disk usage for a specific path.
-///
-/// # Safety
-/// The `path` must be a null-terminated C string. The `out` pointer must be valid.
-#[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_collect_disk_usage(
-    path: *const c_char,
-    out: *mut DiskUsage,
-) -> ProbeResult {
-    if path.is_null() || out.is_null() {
+// ============================================================================
+// CPU CACHES
+// ============================================================================
+
+/// Kind of data a CPU cache holds.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CacheKind {
+    /// Data only.
+    Data = 0,
+    /// Instructions only.
+    Instruction = 1,
+    /// Both data and instructions.
+    #[default]
+    Unified = 2,
+}
+
+impl From<probe_metrics::CacheKind> for CacheKind {
+    fn from(k: probe_metrics::CacheKind) -> Self {
+        match k {
+            probe_metrics::CacheKind::Data => Self::Data,
+            probe_metrics::CacheKind::Instruction => Self::Instruction,
+            probe_metrics::CacheKind::Unified => Self::Unified,
+        }
+    }
+}
+
+/// A CPU cache as seen from the first CPU.
+#[repr(C)]
+pub struct CpuCache {
+    /// Cache level (1 for L1, 2 for L2, ...).
+    pub level: u8,
+    /// Kind of data held.
+    pub kind: CacheKind,
+    /// Cache size in bytes.
+    pub size_bytes: u64,
+    /// CPUs sharing this cache, e.g. "0-7" (null-terminated, empty if unknown).
+    pub shared_cpu_list: [c_char; 64],
+}
+
+impl From<probe_metrics::CpuCache> for CpuCache {
+    fn from(c: probe_metrics::CpuCache) -> Self {
+        let mut result = Self {
+            level: c.level,
+            kind: c.kind.into(),
+            size_bytes: c.size_bytes,
+            shared_cpu_list: [0; 64],
+        };
+        copy_str_to_carray(&c.shared_cpu_list, &mut result.shared_cpu_list);
+        result
+    }
+}
+
+/// List of CPU caches.
+#[repr(C)]
+pub struct CpuCacheList {
+    pub items: *mut CpuCache,
+    pub count: usize,
+    pub capacity: usize,
+}
+
+/// List the caches of the first CPU, ordered by level.
+///
+/// # Safety
+/// The `out` pointer must be valid. Caller must call `probe_free_cpu_cache_list` when done.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_cpu_caches(out: *mut CpuCacheList) -> ProbeResult {
+    if out.is_null() {
         return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
     }
 
@@ -1010,23 +1775,209 @@ pub unsafe extern "C" fn probe_collect_disk_usage(
         None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
     };
 
-    let path_str = unsafe { std::ffi::CStr::from_ptr(path).to_string_lossy() };
+    match collector.cpu().cpu_caches() {
+        Ok(caches) => {
+            let mut items: Vec<CpuCache> = caches.into_iter().map(|c| c.into()).collect();
+            let count = items.len();
+            let capacity = items.capacity();
+            let ptr = items.as_mut_ptr();
+            std::mem::forget(items);
 
-    match collector.disk().collect_usage(&path_str) {
-        Ok(usage) => {
-            unsafe { *out = DiskUsage::from(usage) };
+            unsafe {
+                (*out).items = ptr;
+                (*out).count = count;
+                (*out).capacity = capacity;
+            }
             ProbeResult::ok()
         }
         Err(e) => ProbeResult::from_metrics_error(e),
     }
 }
 
-/// Collect disk I/O statistics for all devices.
+/// Free a CPU cache list.
 ///
 /// # Safety
-/// The `out` pointer must be valid. Caller must call `probe_free_disk_io_list` when done.
+/// The list must have been allocated by `probe_collect_cpu_caches`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_collect_disk_io(out: *mut DiskIOStatsList) -> ProbeResult {
+pub unsafe extern "C" fn probe_free_cpu_cache_list(list: *mut CpuCacheList) {
+    if list.is_null() {
+        return;
+    }
+    unsafe {
+        let list = &mut *list;
+        if !list.items.is_null() && list.capacity > 0 {
+            drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
+            list.items = ptr::null_mut();
+            list.count = 0;
+            list.capacity = 0;
+        }
+    }
+}
+
+// ============================================================================
+// DISK METRICS
+// ============================================================================
+
+/// Maximum path length for disk-related strings.
+pub const PROBE_MAX_PATH_LEN: usize = 256;
+
+/// Partition information.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Partition {
+    pub device: [c_char; PROBE_MAX_PATH_LEN],
+    pub mount_point: [c_char; PROBE_MAX_PATH_LEN],
+    pub fs_type: [c_char; 64],
+    pub options: [c_char; PROBE_MAX_PATH_LEN],
+}
+
+impl Default for Partition {
+    fn default() -> Self {
+        Self {
+            device: [0; PROBE_MAX_PATH_LEN],
+            mount_point: [0; PROBE_MAX_PATH_LEN],
+            fs_type: [0; 64],
+            options: [0; PROBE_MAX_PATH_LEN],
+        }
+    }
+}
+
+fn copy_str_to_carray<const N: usize>(s: &str, dest: &mut [c_char; N]) {
+    let bytes = s.as_bytes();
+    let len = bytes.len().min(N - 1);
+    for (i, &b) in bytes[..len].iter().enumerate() {
+        dest[i] = b as c_char;
+    }
+    dest[len] = 0;
+}
+
+impl From<probe_metrics::Partition> for Partition {
+    fn from(p: probe_metrics::Partition) -> Self {
+        let mut result = Self::default();
+        copy_str_to_carray(&p.device, &mut result.device);
+        copy_str_to_carray(&p.mount_point, &mut result.mount_point);
+        copy_str_to_carray(&p.fs_type, &mut result.fs_type);
+        copy_str_to_carray(&p.options, &mut result.options);
+        result
+    }
+}
+
+/// Disk usage information.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct DiskUsage {
+    pub path: [c_char; PROBE_MAX_PATH_LEN],
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+    pub free_bytes: u64,
+    pub used_percent: f64,
+    pub inodes_total: u64,
+    pub inodes_used: u64,
+    pub inodes_free: u64,
+}
+
+impl Default for DiskUsage {
+    fn default() -> Self {
+        Self {
+            path: [0; PROBE_MAX_PATH_LEN],
+            total_bytes: 0,
+            used_bytes: 0,
+            free_bytes: 0,
+            used_percent: 0.0,
+            inodes_total: 0,
+            inodes_used: 0,
+            inodes_free: 0,
+        }
+    }
+}
+
+impl From<probe_metrics::DiskUsage> for DiskUsage {
+    fn from(d: probe_metrics::DiskUsage) -> Self {
+        let mut result = Self::default();
+        copy_str_to_carray(&d.path, &mut result.path);
+        result.total_bytes = d.total_bytes;
+        result.used_bytes = d.used_bytes;
+        result.free_bytes = d.free_bytes;
+        result.used_percent = d.used_percent;
+        result.inodes_total = d.inodes_total;
+        result.inodes_used = d.inodes_used;
+        result.inodes_free = d.inodes_free;
+        result
+    }
+}
+
+/// Disk I/O statistics.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct DiskIOStats {
+    pub device: [c_char; 64],
+    pub reads_completed: u64,
+    pub read_bytes: u64,
+    pub read_time_us: u64,
+    pub writes_completed: u64,
+    pub write_bytes: u64,
+    pub write_time_us: u64,
+    pub io_in_progress: u64,
+    pub io_time_us: u64,
+    pub weighted_io_time_us: u64,
+}
+
+impl Default for DiskIOStats {
+    fn default() -> Self {
+        Self {
+            device: [0; 64],
+            reads_completed: 0,
+            read_bytes: 0,
+            read_time_us: 0,
+            writes_completed: 0,
+            write_bytes: 0,
+            write_time_us: 0,
+            io_in_progress: 0,
+            io_time_us: 0,
+            weighted_io_time_us: 0,
+        }
+    }
+}
+
+impl From<probe_metrics::DiskIOStats> for DiskIOStats {
+    fn from(d: probe_metrics::DiskIOStats) -> Self {
+        let mut result = Self::default();
+        copy_str_to_carray(&d.device, &mut result.device);
+        result.reads_completed = d.reads_completed;
+        result.read_bytes = d.read_bytes;
+        result.read_time_us = d.read_time_us;
+        result.writes_completed = d.writes_completed;
+        result.write_bytes = d.write_bytes;
+        result.write_time_us = d.write_time_us;
+        result.io_in_progress = d.io_in_progress;
+        result.io_time_us = d.io_time_us;
+        result.weighted_io_time_us = d.weighted_io_time_us;
+        result
+    }
+}
+
+/// List result for partitions.
+#[repr(C)]
+pub struct PartitionList {
+    pub items: *mut Partition,
+    pub count: usize,
+    pub capacity: usize,
+}
+
+/// List result for disk I/O stats.
+#[repr(C)]
+pub struct DiskIOStatsList {
+    pub items: *mut DiskIOStats,
+    pub count: usize,
+    pub capacity: usize,
+}
+
+/// List disk partitions.
+///
+/// # Safety
+/// The `out` pointer must be valid. Caller must call `probe_free_partition_list` when done.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_list_partitions(out: *mut PartitionList) -> ProbeResult {
     if out.is_null() {
         return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
     }
@@ -1036,9 +1987,1296 @@ pub unsafe extern "C" fn probe_collect_disk_io(out: *mut DiskIOStatsList) -> Pro
         None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
     };
 
-    match collector.disk().collect_io() {
-        Ok(stats) => {
-            let mut items: Vec<DiskIOStats> = stats.into_iter().map(|s| s.into()).collect();
+    match collector.disk().list_partitions() {
+        Ok(partitions) => {
+            let mut items: Vec<Partition> = partitions.into_iter().map(|p| p.into()).collect();
+            let count = items.len();
+            let capacity = items.capacity();
+            let ptr = items.as_mut_ptr();
+            std::mem::forget(items);
+
+            unsafe {
+                (*out).items = ptr;
+                (*out).count = count;
+                (*out).capacity = capacity;
+            }
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Free a partition list returned by `probe_list_partitions`.
+///
+/// # Safety
+/// The list must have been allocated by `probe_list_partitions`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_free_partition_list(list: *mut PartitionList) {
+    if list.is_null() {
+        return;
+    }
+    unsafe {
+        let list = &mut *list;
+        if !list.items.is_null() {
+            drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
+            list.items = ptr::null_mut();
+            list.count = 0;
+            list.capacity = 0;
+        }
+    }
+}
+
+/// Collect disk usage for a specific path.
+///
+/// # Safety
+/// The `path` must be a null-terminated C string. The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_disk_usage(
+    path: *const c_char,
+    out: *mut DiskUsage,
+) -> ProbeResult {
+    if path.is_null() || out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    let path_str = unsafe { std::ffi::CStr::from_ptr(path).to_string_lossy() };
+
+    match collector.disk().collect_usage(&path_str) {
+        Ok(usage) => {
+            unsafe { *out = DiskUsage::from(usage) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Time a `statvfs` of the filesystem mounted at `path`, in microseconds.
+///
+/// Returns `PROBE_ERR_TIMEOUT` when the mount does not answer within 5s.
+///
+/// # Safety
+/// The `path` must be a null-terminated C string. The `out_us` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_mount_latency(path: *const c_char, out_us: *mut u64) -> ProbeResult {
+    if path.is_null() || out_us.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    let path_str = unsafe { std::ffi::CStr::from_ptr(path).to_string_lossy() };
+
+    match collector.disk().probe_mount_latency(&path_str) {
+        Ok(latency) => {
+            unsafe { *out_us = latency.as_micros() as u64 };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Collect disk I/O statistics for all devices.
+///
+/// # Safety
+/// The `out` pointer must be valid. Caller must call `probe_free_disk_io_list` when done.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_disk_io(out: *mut DiskIOStatsList) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.disk().collect_io() {
+        Ok(stats) => {
+            let mut items: Vec<DiskIOStats> = stats.into_iter().map(|s| s.into()).collect();
+            let count = items.len();
+            let capacity = items.capacity();
+            let ptr = items.as_mut_ptr();
+            std::mem::forget(items);
+
+            unsafe {
+                (*out).items = ptr;
+                (*out).count = count;
+                (*out).capacity = capacity;
+            }
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Free a disk I/O stats list.
+///
+/// # Safety
+/// The list must have been allocated by `probe_collect_disk_io`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_free_disk_io_list(list: *mut DiskIOStatsList) {
+    if list.is_null() {
+        return;
+    }
+    unsafe {
+        let list = &mut *list;
+        if !list.items.is_null() {
+            drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
+            list.items = ptr::null_mut();
+            list.count = 0;
+            list.capacity = 0;
+        }
+    }
+}
+
+/// Physical block device inventory entry.
+#[repr(C)]
+pub struct BlockDevice {
+    /// Device name, e.g. "sda" (null-terminated).
+    pub name: [c_char; 32],
+    /// Model string (null-terminated, empty if unknown).
+    pub model: [c_char; 64],
+    /// Serial number (null-terminated, empty if unknown).
+    pub serial: [c_char; 64],
+    /// Capacity in bytes.
+    pub size_bytes: u64,
+    /// Spinning media (HDD); false for SSDs.
+    pub rotational: bool,
+    /// Removable media.
+    pub removable: bool,
+}
+
+impl From<probe_metrics::BlockDevice> for BlockDevice {
+    fn from(d: probe_metrics::BlockDevice) -> Self {
+        let mut result = Self {
+            name: [0; 32],
+            model: [0; 64],
+            serial: [0; 64],
+            size_bytes: d.size_bytes,
+            rotational: d.rotational,
+            removable: d.removable,
+        };
+        copy_str_to_carray(&d.name, &mut result.name);
+        copy_str_to_carray(&d.model, &mut result.model);
+        copy_str_to_carray(&d.serial, &mut result.serial);
+        result
+    }
+}
+
+/// List of block devices.
+#[repr(C)]
+pub struct BlockDeviceList {
+    pub items: *mut BlockDevice,
+    pub count: usize,
+    pub capacity: usize,
+}
+
+/// List physical block devices.
+///
+/// # Safety
+/// The `out` pointer must be valid. Caller must call `probe_free_block_device_list` when done.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_list_block_devices(out: *mut BlockDeviceList) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.disk().block_devices() {
+        Ok(devices) => {
+            let mut items: Vec<BlockDevice> = devices.into_iter().map(|d| d.into()).collect();
+            let count = items.len();
+            let capacity = items.capacity();
+            let ptr = items.as_mut_ptr();
+            std::mem::forget(items);
+
+            unsafe {
+                (*out).items = ptr;
+                (*out).count = count;
+                (*out).capacity = capacity;
+            }
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Free a block device list.
+///
+/// # Safety
+/// The list must have been allocated by `probe_list_block_devices`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_free_block_device_list(list: *mut BlockDeviceList) {
+    if list.is_null() {
+        return;
+    }
+    unsafe {
+        let list = &mut *list;
+        if !list.items.is_null() && list.capacity > 0 {
+            drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
+            list.items = ptr::null_mut();
+            list.count = 0;
+            list.capacity = 0;
+        }
+    }
+}
+
+/// Check whether the root filesystem is mounted read-only.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_root_filesystem_readonly(out: *mut bool) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.disk().root_filesystem_readonly() {
+        Ok(readonly) => {
+            unsafe { *out = readonly };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Request queue settings of a block device.
+#[repr(C)]
+pub struct DiskQueueConfig {
+    /// Active I/O scheduler, e.g. "mq-deadline" (null-terminated).
+    pub scheduler: [c_char; 32],
+    /// Maximum number of requests queued per direction.
+    pub nr_requests: u32,
+    /// Read-ahead size in KiB.
+    pub read_ahead_kb: u32,
+    /// Spinning media (HDD); false for SSDs.
+    pub rotational: bool,
+}
+
+impl From<probe_metrics::DiskQueueConfig> for DiskQueueConfig {
+    fn from(c: probe_metrics::DiskQueueConfig) -> Self {
+        let mut result = Self {
+            scheduler: [0; 32],
+            nr_requests: c.nr_requests,
+            read_ahead_kb: c.read_ahead_kb,
+            rotational: c.rotational,
+        };
+        copy_str_to_carray(&c.scheduler, &mut result.scheduler);
+        result
+    }
+}
+
+/// Read the I/O scheduler and request queue settings of a block device.
+///
+/// # Safety
+/// `device` must be a valid null-terminated string and `out` a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_disk_queue_config(
+    device: *const c_char,
+    out: *mut DiskQueueConfig,
+) -> ProbeResult {
+    if device.is_null() || out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    let device_str = unsafe { std::ffi::CStr::from_ptr(device).to_string_lossy() };
+
+    match collector.disk().disk_queue_config(&device_str) {
+        Ok(config) => {
+            unsafe { *out = DiskQueueConfig::from(config) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+// ============================================================================
+// NETWORK METRICS
+// ============================================================================
+
+/// Network interface information.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct NetInterface {
+    pub name: [c_char; 64],
+    pub mac_address: [c_char; 18],
+    pub mtu: u32,
+    pub is_up: bool,
+    pub is_loopback: bool,
+}
+
+impl Default for NetInterface {
+    fn default() -> Self {
+        Self { name: [0; 64], mac_address: [0; 18], mtu: 0, is_up: false, is_loopback: false }
+    }
+}
+
+impl From<probe_metrics::NetInterface> for NetInterface {
+    fn from(n: probe_metrics::NetInterface) -> Self {
+        let mut result = Self::default();
+        copy_str_to_carray(&n.name, &mut result.name);
+        copy_str_to_carray(&n.mac_address, &mut result.mac_address);
+        result.mtu = n.mtu;
+        result.is_up = n.is_up;
+        result.is_loopback = n.is_loopback;
+        result
+    }
+}
+
+/// Network interface statistics.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct NetStats {
+    pub interface: [c_char; 64],
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub rx_errors: u64,
+    pub rx_drops: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub tx_errors: u64,
+    pub tx_drops: u64,
+    pub rx_fifo: u64,
+    pub rx_compressed: u64,
+    pub rx_multicast: u64,
+    pub tx_fifo: u64,
+    pub tx_collisions: u64,
+    pub tx_compressed: u64,
+}
+
+impl Default for NetStats {
+    fn default() -> Self {
+        Self {
+            interface: [0; 64],
+            rx_bytes: 0,
+            rx_packets: 0,
+            rx_errors: 0,
+            rx_drops: 0,
+            tx_bytes: 0,
+            tx_packets: 0,
+            tx_errors: 0,
+            tx_drops: 0,
+            rx_fifo: 0,
+            rx_compressed: 0,
+            rx_multicast: 0,
+            tx_fifo: 0,
+            tx_collisions: 0,
+            tx_compressed: 0,
+        }
+    }
+}
+
+impl From<probe_metrics::NetStats> for NetStats {
+    fn from(n: probe_metrics::NetStats) -> Self {
+        let mut result = Self::default();
+        copy_str_to_carray(&n.interface, &mut result.interface);
+        result.rx_bytes = n.rx_bytes;
+        result.rx_packets = n.rx_packets;
+        result.rx_errors = n.rx_errors;
+        result.rx_drops = n.rx_drops;
+        result.tx_bytes = n.tx_bytes;
+        result.tx_packets = n.tx_packets;
+        result.tx_errors = n.tx_errors;
+        result.tx_drops = n.tx_drops;
+        result.rx_fifo = n.rx_fifo;
+        result.rx_compressed = n.rx_compressed;
+        result.rx_multicast = n.rx_multicast;
+        result.tx_fifo = n.tx_fifo;
+        result.tx_collisions = n.tx_collisions;
+        result.tx_compressed = n.tx_compressed;
+        result
+    }
+}
+
+/// List result for network interfaces.
+#[repr(C)]
+pub struct NetInterfaceList {
+    pub items: *mut NetInterface,
+    pub count: usize,
+    pub capacity: usize,
+}
+
+/// List result for network stats.
+#[repr(C)]
+pub struct NetStatsList {
+    pub items: *mut NetStats,
+    pub count: usize,
+    pub capacity: usize,
+}
+
+/// List network interfaces.
+///
+/// # Safety
+/// The `out` pointer must be valid. Caller must call `probe_free_net_interface_list` when done.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_list_net_interfaces(out: *mut NetInterfaceList) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.network().list_interfaces() {
+        Ok(interfaces) => {
+            let mut items: Vec<NetInterface> = interfaces.into_iter().map(|i| i.into()).collect();
+            let count = items.len();
+            let capacity = items.capacity();
+            let ptr = items.as_mut_ptr();
+            std::mem::forget(items);
+
+            unsafe {
+                (*out).items = ptr;
+                (*out).count = count;
+                (*out).capacity = capacity;
+            }
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Free a network interface list.
+///
+/// # Safety
+/// The list must have been allocated by `probe_list_net_interfaces`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_free_net_interface_list(list: *mut NetInterfaceList) {
+    if list.is_null() {
+        return;
+    }
+    unsafe {
+        let list = &mut *list;
+        if !list.items.is_null() {
+            drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
+            list.items = ptr::null_mut();
+            list.count = 0;
+            list.capacity = 0;
+        }
+    }
+}
+
+/// Collect network statistics for all interfaces.
+///
+/// # Safety
+/// The `out` pointer must be valid. Caller must call `probe_free_net_stats_list` when done.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_net_stats(out: *mut NetStatsList) -> ProbeResult {
+    unsafe { probe_collect_net_stats_filtered(false, out) }
+}
+
+/// Collect network statistics, optionally for physical NICs only.
+///
+/// With `physical_only`, loopback and virtual interfaces (`veth*`, `docker*`,
+/// `br-*`, `cni*`) are excluded.
+///
+/// # Safety
+/// The `out` pointer must be valid. Caller must call `probe_free_net_stats_list` when done.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_net_stats_filtered(
+    physical_only: bool,
+    out: *mut NetStatsList,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    let stats = if physical_only {
+        collector.network().collect_physical_stats()
+    } else {
+        collector.network().collect_all_stats()
+    };
+
+    match stats {
+        Ok(stats) => {
+            let mut items: Vec<NetStats> = stats.into_iter().map(|s| s.into()).collect();
+            let count = items.len();
+            let capacity = items.capacity();
+            let ptr = items.as_mut_ptr();
+            std::mem::forget(items);
+
+            unsafe {
+                (*out).items = ptr;
+                (*out).count = count;
+                (*out).capacity = capacity;
+            }
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Free a network stats list.
+///
+/// # Safety
+/// The list must have been allocated by `probe_collect_net_stats`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_free_net_stats_list(list: *mut NetStatsList) {
+    if list.is_null() {
+        return;
+    }
+    unsafe {
+        let list = &mut *list;
+        if !list.items.is_null() {
+            drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
+            list.items = ptr::null_mut();
+            list.count = 0;
+            list.capacity = 0;
+        }
+    }
+}
+
+/// Netfilter connection tracking table usage.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConntrackStats {
+    /// Entries currently tracked.
+    pub count: u64,
+    /// Table capacity.
+    pub max: u64,
+}
+
+impl From<probe_metrics::ConntrackStats> for ConntrackStats {
+    fn from(s: probe_metrics::ConntrackStats) -> Self {
+        Self { count: s.count, max: s.max }
+    }
+}
+
+/// Collect connection tracking table usage.
+///
+/// Returns `PROBE_ERR_NOT_SUPPORTED` when nf_conntrack is not loaded.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_conntrack_stats(out: *mut ConntrackStats) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.network().conntrack_stats() {
+        Ok(stats) => {
+            unsafe { *out = ConntrackStats::from(stats) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Kernel socket usage (memory values in pages).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SocketMemStats {
+    pub tcp_inuse: u64,
+    pub tcp_mem_pages: u64,
+    pub tcp_orphan: u64,
+    pub udp_inuse: u64,
+    pub udp_mem_pages: u64,
+    pub sockets_used: u64,
+}
+
+impl From<probe_metrics::SocketMemStats> for SocketMemStats {
+    fn from(s: probe_metrics::SocketMemStats) -> Self {
+        Self {
+            tcp_inuse: s.tcp_inuse,
+            tcp_mem_pages: s.tcp_mem_pages,
+            tcp_orphan: s.tcp_orphan,
+            udp_inuse: s.udp_inuse,
+            udp_mem_pages: s.udp_mem_pages,
+            sockets_used: s.sockets_used,
+        }
+    }
+}
+
+/// Collect kernel socket counts and socket buffer memory.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_socket_memory(out: *mut SocketMemStats) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.network().socket_memory() {
+        Ok(stats) => {
+            unsafe { *out = SocketMemStats::from(stats) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// UDP counters since boot.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UdpProtoStats {
+    pub in_datagrams: u64,
+    pub no_ports: u64,
+    pub in_errors: u64,
+    pub out_datagrams: u64,
+    pub rcvbuf_errors: u64,
+    pub sndbuf_errors: u64,
+    pub in_csum_errors: u64,
+}
+
+impl From<probe_metrics::UdpProtoStats> for UdpProtoStats {
+    fn from(s: probe_metrics::UdpProtoStats) -> Self {
+        Self {
+            in_datagrams: s.in_datagrams,
+            no_ports: s.no_ports,
+            in_errors: s.in_errors,
+            out_datagrams: s.out_datagrams,
+            rcvbuf_errors: s.rcvbuf_errors,
+            sndbuf_errors: s.sndbuf_errors,
+            in_csum_errors: s.in_csum_errors,
+        }
+    }
+}
+
+/// Collect UDP datagram and error counters.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_udp_stats(out: *mut UdpProtoStats) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.network().udp_stats() {
+        Ok(stats) => {
+            unsafe { *out = UdpProtoStats::from(stats) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// ICMP counters since boot.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IcmpStats {
+    pub in_msgs: u64,
+    pub in_errors: u64,
+    pub in_csum_errors: u64,
+    pub in_dest_unreachs: u64,
+    pub in_time_excds: u64,
+    pub out_msgs: u64,
+    pub out_errors: u64,
+    pub out_dest_unreachs: u64,
+}
+
+impl From<probe_metrics::IcmpStats> for IcmpStats {
+    fn from(s: probe_metrics::IcmpStats) -> Self {
+        Self {
+            in_msgs: s.in_msgs,
+            in_errors: s.in_errors,
+            in_csum_errors: s.in_csum_errors,
+            in_dest_unreachs: s.in_dest_unreachs,
+            in_time_excds: s.in_time_excds,
+            out_msgs: s.out_msgs,
+            out_errors: s.out_errors,
+            out_dest_unreachs: s.out_dest_unreachs,
+        }
+    }
+}
+
+/// Collect ICMP message and error counters.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_icmp_stats(out: *mut IcmpStats) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.network().icmp_stats() {
+        Ok(stats) => {
+            unsafe { *out = IcmpStats::from(stats) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+// ============================================================================
+// I/O METRICS
+// ============================================================================
+
+/// System-wide I/O statistics.
+#[repr(C)]
+pub struct IOStats {
+    pub read_ops: u64,
+    pub read_bytes: u64,
+    pub write_ops: u64,
+    pub write_bytes: u64,
+}
+
+impl From<probe_metrics::IOStats> for IOStats {
+    fn from(io: probe_metrics::IOStats) -> Self {
+        Self {
+            read_ops: io.read_ops,
+            read_bytes: io.read_bytes,
+            write_ops: io.write_ops,
+            write_bytes: io.write_bytes,
+        }
+    }
+}
+
+/// Collect system-wide I/O statistics.
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_io_stats(out: *mut IOStats) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.io().collect_stats() {
+        Ok(stats) => {
+            unsafe { *out = IOStats::from(stats) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+// ============================================================================
+// CONTEXT SWITCHES
+// ============================================================================
+
+/// Context switch statistics.
+#[repr(C)]
+pub struct ContextSwitches {
+    /// Voluntary context switches (process yielded CPU).
+    pub voluntary: u64,
+    /// Involuntary context switches (preempted by scheduler).
+    pub involuntary: u64,
+    /// System-wide total context switches.
+    pub system_total: u64,
+}
+
+impl From<probe_metrics::ContextSwitches> for ContextSwitches {
+    fn from(cs: probe_metrics::ContextSwitches) -> Self {
+        Self { voluntary: cs.voluntary, involuntary: cs.involuntary, system_total: cs.system_total }
+    }
+}
+
+/// Collect system-wide context switch count.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_system_context_switches(out: *mut u64) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        match probe_platform::linux::read_system_context_switches() {
+            Ok(count) => {
+                unsafe { *out = count };
+                ProbeResult::ok()
+            }
+            Err(e) => ProbeResult::from_metrics_error(e),
+        }
+    }
+
+    #[cfg(any(target_os = "freebsd", target_os = "openbsd", target_os = "netbsd"))]
+    {
+        match probe_platform::bsd::read_system_context_switches() {
+            Ok(cs) => {
+                unsafe { *out = cs.voluntary.saturating_add(cs.involuntary) };
+                ProbeResult::ok()
+            }
+            Err(e) => ProbeResult::from_metrics_error(e),
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd"
+    )))]
+    {
+        ProbeResult::err(
+            PROBE_ERR_NOT_SUPPORTED,
+            c"context switches not supported on this platform".as_ptr(),
+        )
+    }
+}
+
+/// Collect context switches for a specific process.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_process_context_switches(
+    pid: i32,
+    out: *mut ContextSwitches,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        match probe_platform::linux::read_process_context_switches(pid) {
+            Ok(switches) => {
+                unsafe { *out = ContextSwitches::from(switches) };
+                ProbeResult::ok()
+            }
+            Err(e) => ProbeResult::from_metrics_error(e),
+        }
+    }
+
+    #[cfg(any(target_os = "freebsd", target_os = "openbsd", target_os = "netbsd"))]
+    {
+        match probe_platform::bsd::read_process_context_switches(pid) {
+            Ok(cs) => {
+                unsafe {
+                    *out = ContextSwitches {
+                        voluntary: cs.voluntary,
+                        involuntary: cs.involuntary,
+                        system_total: 0,
+                    }
+                };
+                ProbeResult::ok()
+            }
+            Err(e) => ProbeResult::from_metrics_error(e),
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd"
+    )))]
+    {
+        let _ = pid;
+        ProbeResult::err(
+            PROBE_ERR_NOT_SUPPORTED,
+            c"context switches not supported on this platform".as_ptr(),
+        )
+    }
+}
+
+/// Collect context switches for the current process.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_self_context_switches(
+    out: *mut ContextSwitches,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        match probe_platform::linux::read_self_context_switches() {
+            Ok(switches) => {
+                unsafe { *out = ContextSwitches::from(switches) };
+                ProbeResult::ok()
+            }
+            Err(e) => ProbeResult::from_metrics_error(e),
+        }
+    }
+
+    #[cfg(any(target_os = "freebsd", target_os = "openbsd", target_os = "netbsd"))]
+    {
+        match probe_platform::bsd::read_self_context_switches() {
+            Ok(cs) => {
+                unsafe {
+                    *out = ContextSwitches {
+                        voluntary: cs.voluntary,
+                        involuntary: cs.involuntary,
+                        system_total: 0,
+                    }
+                };
+                ProbeResult::ok()
+            }
+            Err(e) => ProbeResult::from_metrics_error(e),
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd"
+    )))]
+    {
+        ProbeResult::err(
+            PROBE_ERR_NOT_SUPPORTED,
+            c"context switches not supported on this platform".as_ptr(),
+        )
+    }
+}
+
+// ============================================================================
+// KERNEL STATISTICS
+// ============================================================================
+
+/// Kernel-wide activity counters.
+#[repr(C)]
+pub struct KernelStats {
+    /// Total context switches since boot.
+    pub context_switches_total: u64,
+    /// Total interrupts serviced since boot.
+    pub interrupts_total: u64,
+    /// Total processes/threads created since boot.
+    pub processes_started_total: u64,
+    /// Number of tasks currently runnable.
+    pub procs_running: u32,
+    /// Number of tasks currently blocked on I/O.
+    pub procs_blocked: u32,
+    /// Boot time (seconds since epoch).
+    pub boot_time: u64,
+}
+
+impl From<probe_metrics::KernelStats> for KernelStats {
+    fn from(ks: probe_metrics::KernelStats) -> Self {
+        Self {
+            context_switches_total: ks.context_switches_total,
+            interrupts_total: ks.interrupts_total,
+            processes_started_total: ks.processes_started_total,
+            procs_running: ks.procs_running,
+            procs_blocked: ks.procs_blocked,
+            boot_time: ks.boot_time,
+        }
+    }
+}
+
+/// Collect kernel-wide activity counters.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_kernel_stats(out: *mut KernelStats) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.collect_kernel_stats() {
+        Ok(stats) => {
+            unsafe { *out = KernelStats::from(stats) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Opaque fork-rate sampler handle.
+pub struct ForkRateSampler(probe_metrics::ForkRateSampler);
+
+/// Process creation rate.
+#[repr(C)]
+#[derive(Default)]
+pub struct ForkRate {
+    /// Processes/threads created per second since the previous sample.
+    pub forks_per_sec: f64,
+    /// False on the first sample, which only primes the sampler.
+    pub available: bool,
+}
+
+/// Create a fork-rate sampler. Free it with `probe_fork_rate_sampler_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn probe_fork_rate_sampler_new() -> *mut ForkRateSampler {
+    Box::into_raw(Box::new(ForkRateSampler(probe_metrics::ForkRateSampler::new())))
+}
+
+/// Sample the process creation counter and report the rate since the last call.
+///
+/// Returns `PROBE_ERR_NOT_SUPPORTED` on platforms without kernel stats.
+///
+/// # Safety
+/// `sampler` must come from `probe_fork_rate_sampler_new` and `out` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_fork_rate_sample(
+    sampler: *mut ForkRateSampler,
+    out: *mut ForkRate,
+) -> ProbeResult {
+    if sampler.is_null() || out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.collect_kernel_stats() {
+        Ok(stats) => {
+            let sampler = unsafe { &mut (*sampler).0 };
+            let rate = sampler.sample(stats.processes_started_total);
+            unsafe {
+                *out = ForkRate { forks_per_sec: rate.unwrap_or(0.0), available: rate.is_some() }
+            };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Free a fork-rate sampler.
+///
+/// # Safety
+/// `sampler` must come from `probe_fork_rate_sampler_new` and not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_fork_rate_sampler_free(sampler: *mut ForkRateSampler) {
+    if !sampler.is_null() {
+        drop(unsafe { Box::from_raw(sampler) });
+    }
+}
+
+/// Identity key of the process `pid` refers to now.
+///
+/// Where start times are unknown, the key is the PID alone and PID reuse
+/// goes undetected.
+fn process_key(
+    process: &dyn probe_metrics::ProcessCollector,
+    pid: i32,
+) -> probe_metrics::Result<probe_metrics::ProcessIdentityKey> {
+    match process.identity(pid) {
+        Ok(identity) => Ok((&identity).into()),
+        Err(probe_metrics::Error::NotSupported) => {
+            Ok(probe_metrics::ProcessIdentityKey::new(pid, 0))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Opaque page-fault-rate sampler handle, bound to one process.
+pub struct PageFaultSampler {
+    pid: i32,
+    sampler: probe_metrics::PageFaultRateSampler,
+}
+
+/// Page fault rates of a process.
+#[repr(C)]
+#[derive(Default)]
+pub struct PageFaultRate {
+    /// Minor faults per second since the previous sample.
+    pub minor_per_sec: f64,
+    /// Major faults per second since the previous sample.
+    pub major_per_sec: f64,
+    /// False on the first sample, which only primes the sampler.
+    pub available: bool,
+}
+
+/// Create a page-fault-rate sampler for `pid`.
+/// Free it with `probe_page_fault_sampler_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn probe_page_fault_sampler_new(pid: i32) -> *mut PageFaultSampler {
+    Box::into_raw(Box::new(PageFaultSampler {
+        pid,
+        sampler: probe_metrics::PageFaultRateSampler::new(),
+    }))
+}
+
+/// Sample the process fault counters and report rates since the last call.
+///
+/// # Safety
+/// `sampler` must come from `probe_page_fault_sampler_new` and `out` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_page_fault_sample(
+    sampler: *mut PageFaultSampler,
+    out: *mut PageFaultRate,
+) -> ProbeResult {
+    if sampler.is_null() || out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    let handle = unsafe { &mut *sampler };
+    match sample_page_faults(handle, collector.process()) {
+        Ok(rate) => {
+            unsafe { *out = rate };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Sample the fault counters of the process the handle tracks.
+///
+/// A reused PID restarts the sampler, so the new process reports no rate
+/// on its first sample.
+fn sample_page_faults(
+    handle: &mut PageFaultSampler,
+    process: &dyn probe_metrics::ProcessCollector,
+) -> probe_metrics::Result<PageFaultRate> {
+    let key = process_key(process, handle.pid)?;
+    let metrics = process.collect(handle.pid)?;
+    let rate = handle.sampler.sample_process(key, metrics.minor_faults, metrics.major_faults);
+    Ok(match rate {
+        Some(r) => PageFaultRate {
+            minor_per_sec: r.minor_per_sec,
+            major_per_sec: r.major_per_sec,
+            available: true,
+        },
+        None => PageFaultRate::default(),
+    })
+}
+
+/// Free a page-fault-rate sampler.
+///
+/// # Safety
+/// `sampler` must come from `probe_page_fault_sampler_new` and not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_page_fault_sampler_free(sampler: *mut PageFaultSampler) {
+    if !sampler.is_null() {
+        drop(unsafe { Box::from_raw(sampler) });
+    }
+}
+
+/// Opaque per-thread CPU sampler handle, bound to one process.
+pub struct ThreadSampler {
+    pid: i32,
+    sampler: probe_metrics::ThreadCpuSampler,
+}
+
+/// A thread of a process.
+#[repr(C)]
+pub struct ThreadInfo {
+    /// Thread ID.
+    pub tid: i32,
+    /// Thread name (null-terminated).
+    pub comm: [c_char; 16],
+    /// Thread state.
+    pub state: ProcessState,
+    /// Cumulative CPU time in user mode, in microseconds.
+    pub user_time_us: u64,
+    /// Cumulative CPU time in kernel mode, in microseconds.
+    pub system_time_us: u64,
+    /// CPU usage since the previous sample (0 for threads not seen before).
+    pub cpu_percent: f64,
+}
+
+impl From<probe_metrics::ThreadInfo> for ThreadInfo {
+    fn from(t: probe_metrics::ThreadInfo) -> Self {
+        let mut result = Self {
+            tid: t.tid,
+            comm: [0; 16],
+            state: t.state.into(),
+            user_time_us: t.user_time_us,
+            system_time_us: t.system_time_us,
+            cpu_percent: t.cpu_percent,
+        };
+        copy_str_to_carray(&t.comm, &mut result.comm);
+        result
+    }
+}
+
+/// List of threads.
+#[repr(C)]
+pub struct ThreadInfoList {
+    pub items: *mut ThreadInfo,
+    pub count: usize,
+    pub capacity: usize,
+}
+
+/// Create a per-thread CPU sampler for `pid`.
+/// Free it with `probe_thread_sampler_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn probe_thread_sampler_new(pid: i32) -> *mut ThreadSampler {
+    Box::into_raw(Box::new(ThreadSampler { pid, sampler: probe_metrics::ThreadCpuSampler::new() }))
+}
+
+/// List the threads of the sampled process with their CPU usage since the last call.
+///
+/// # Safety
+/// `sampler` must come from `probe_thread_sampler_new` and `out` must be valid.
+/// Caller must call `probe_free_thread_info_list` when done.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_thread_sample(
+    sampler: *mut ThreadSampler,
+    out: *mut ThreadInfoList,
+) -> ProbeResult {
+    if sampler.is_null() || out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    let handle = unsafe { &mut *sampler };
+    match sample_threads(handle, collector.process()) {
+        Ok(threads) => {
+            let mut items: Vec<ThreadInfo> = threads.into_iter().map(|t| t.into()).collect();
             let count = items.len();
             let capacity = items.capacity();
             let ptr = items.as_mut_ptr();
@@ -1055,18 +3293,32 @@ pub unsafe extern "C" fn probe_collect_disk_io(out: *mut DiskIOStatsList) -> Pro
     }
 }
 
-/// Free a disk I/O stats list.
+/// List the threads of the process the handle tracks with their CPU usage.
+///
+/// A reused PID restarts the sampler, so the threads of the new process
+/// report 0% on its first sample.
+fn sample_threads(
+    handle: &mut ThreadSampler,
+    process: &dyn probe_metrics::ProcessCollector,
+) -> probe_metrics::Result<Vec<probe_metrics::ThreadInfo>> {
+    let key = process_key(process, handle.pid)?;
+    let mut threads = process.list_threads(handle.pid)?;
+    handle.sampler.sample_process(key, &mut threads);
+    Ok(threads)
+}
+
+/// Free a thread list.
 ///
 /// # Safety
-/// The list must have been allocated by `probe_collect_disk_io`.
+/// The list must have been allocated by `probe_thread_sample`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_free_disk_io_list(list: *mut DiskIOStatsList) {
+pub unsafe extern "C" fn probe_free_thread_info_list(list: *mut ThreadInfoList) {
     if list.is_null() {
         return;
     }
     unsafe {
         let list = &mut *list;
-        if !list.items.is_null() {
+        if !list.items.is_null() && list.capacity > 0 {
             drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
             list.items = ptr::null_mut();
             list.count = 0;
@@ -1075,108 +3327,120 @@ pub unsafe extern "C" fn probe_free_disk_io_list(list: *mut DiskIOStatsList) {
     }
 }
 
+/// Free a per-thread CPU sampler.
+///
+/// # Safety
+/// `sampler` must come from `probe_thread_sampler_new` and not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_thread_sampler_free(sampler: *mut ThreadSampler) {
+    if !sampler.is_null() {
+        drop(unsafe { Box::from_raw(sampler) });
+    }
+}
+
 // ============================================================================
-// NETWORK METRICS
+// KERNEL LOG (Linux only)
 // ============================================================================
 
-/// Network interface information.
+/// Summary of recent kernel log errors (severity `err` or worse).
 #[repr(C)]
-#[derive(Clone, Copy)]
-pub struct NetInterface {
-    pub name: [c_char; 64],
-    pub mac_address: [c_char; 18],
-    pub mtu: u32,
-    pub is_up: bool,
-    pub is_loopback: bool,
-}
-
-impl Default for NetInterface {
-    fn default() -> Self {
-        Self { name: [0; 64], mac_address: [0; 18], mtu: 0, is_up: false, is_loopback: false }
+pub struct KernelErrorSummary {
+    /// Number of error-or-worse records.
+    pub error_count: u32,
+    /// Records reporting an OOM kill.
+    pub oom_kills: u32,
+    /// Records reporting a block device I/O error.
+    pub io_errors: u32,
+    /// Time since boot of the latest record in microseconds (0 if none).
+    pub latest_timestamp_us: u64,
+    /// Message of the latest record (null-terminated, truncated).
+    pub latest_message: [c_char; 256],
+}
+
+impl From<&[probe_metrics::KernelLogEntry]> for KernelErrorSummary {
+    fn from(entries: &[probe_metrics::KernelLogEntry]) -> Self {
+        let mut result = Self {
+            error_count: entries.len() as u32,
+            oom_kills: entries.iter().filter(|e| e.is_oom_kill()).count() as u32,
+            io_errors: entries.iter().filter(|e| e.is_io_error()).count() as u32,
+            latest_timestamp_us: 0,
+            latest_message: [0; 256],
+        };
+        if let Some(latest) = entries.last() {
+            result.latest_timestamp_us = latest.timestamp.as_micros() as u64;
+            copy_str_to_carray(&latest.message, &mut result.latest_message);
+        }
+        result
     }
 }
 
-impl From<probe_metrics::NetInterface> for NetInterface {
-    fn from(n: probe_metrics::NetInterface) -> Self {
-        let mut result = Self::default();
-        copy_str_to_carray(&n.name, &mut result.name);
-        copy_str_to_carray(&n.mac_address, &mut result.mac_address);
-        result.mtu = n.mtu;
-        result.is_up = n.is_up;
-        result.is_loopback = n.is_loopback;
-        result
+/// Summarize recent kernel log errors.
+///
+/// Only records logged within the last `since_secs` seconds are counted
+/// (0 = every record still in the kernel ring buffer). Returns
+/// `PROBE_ERR_PERMISSION` when /dev/kmsg is not readable.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_kernel_error_summary(
+    since_secs: u64,
+    out: *mut KernelErrorSummary,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
     }
-}
 
-/// Network interface statistics.
-#[repr(C)]
-#[derive(Clone, Copy)]
-pub struct NetStats {
-    pub interface: [c_char; 64],
-    pub rx_bytes: u64,
-    pub rx_packets: u64,
-    pub rx_errors: u64,
-    pub rx_drops: u64,
-    pub tx_bytes: u64,
-    pub tx_packets: u64,
-    pub tx_errors: u64,
-    pub tx_drops: u64,
-}
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
 
-impl Default for NetStats {
-    fn default() -> Self {
-        Self {
-            interface: [0; 64],
-            rx_bytes: 0,
-            rx_packets: 0,
-            rx_errors: 0,
-            rx_drops: 0,
-            tx_bytes: 0,
-            tx_packets: 0,
-            tx_errors: 0,
-            tx_drops: 0,
+    let since = (since_secs > 0).then(|| Duration::from_secs(since_secs));
+    match collector.recent_kernel_errors(since) {
+        Ok(entries) => {
+            unsafe { *out = KernelErrorSummary::from(entries.as_slice()) };
+            ProbeResult::ok()
         }
+        Err(e) => ProbeResult::from_metrics_error(e),
     }
 }
 
-impl From<probe_metrics::NetStats> for NetStats {
-    fn from(n: probe_metrics::NetStats) -> Self {
-        let mut result = Self::default();
-        copy_str_to_carray(&n.interface, &mut result.interface);
-        result.rx_bytes = n.rx_bytes;
-        result.rx_packets = n.rx_packets;
-        result.rx_errors = n.rx_errors;
-        result.rx_drops = n.rx_drops;
-        result.tx_bytes = n.tx_bytes;
-        result.tx_packets = n.tx_packets;
-        result.tx_errors = n.tx_errors;
-        result.tx_drops = n.tx_drops;
-        result
-    }
-}
-
-/// List result for network interfaces.
-#[repr(C)]
-pub struct NetInterfaceList {
-    pub items: *mut NetInterface,
-    pub count: usize,
-    pub capacity: usize,
-}
+// ============================================================================
+// INOTIFY
+// ============================================================================
 
-/// List result for network stats.
+/// inotify limits and current usage.
 #[repr(C)]
-pub struct NetStatsList {
-    pub items: *mut NetStats,
-    pub count: usize,
-    pub capacity: usize,
+#[derive(Default)]
+pub struct InotifyStats {
+    /// Maximum inotify instances per user.
+    pub max_user_instances: u64,
+    /// Maximum watches per user.
+    pub max_user_watches: u64,
+    /// Maximum events queued on an instance before overflow.
+    pub max_queued_events: u64,
+    /// inotify descriptors held by processes of the current user.
+    pub user_instances: u64,
+}
+
+impl From<probe_metrics::InotifyStats> for InotifyStats {
+    fn from(s: probe_metrics::InotifyStats) -> Self {
+        Self {
+            max_user_instances: s.max_user_instances,
+            max_user_watches: s.max_user_watches,
+            max_queued_events: s.max_queued_events,
+            user_instances: s.user_instances,
+        }
+    }
 }
 
-/// List network interfaces.
+/// Read inotify limits and the current user's instance count.
 ///
 /// # Safety
-/// The `out` pointer must be valid. Caller must call `probe_free_net_interface_list` when done.
+/// The `out` pointer must be valid.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_list_net_interfaces(out: *mut NetInterfaceList) -> ProbeResult {
+pub unsafe extern "C" fn probe_inotify_stats(out: *mut InotifyStats) -> ProbeResult {
     if out.is_null() {
         return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
     }
@@ -1186,51 +3450,52 @@ pub unsafe extern "C" fn probe_list_net_interfaces(out: *mut NetInterfaceList) -
         None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
     };
 
-    match collector.network().list_interfaces() {
-        Ok(interfaces) => {
-            let mut items: Vec<NetInterface> = interfaces.into_iter().map(|i| i.into()).collect();
-            let count = items.len();
-            let capacity = items.capacity();
-            let ptr = items.as_mut_ptr();
-            std::mem::forget(items);
-
-            unsafe {
-                (*out).items = ptr;
-                (*out).count = count;
-                (*out).capacity = capacity;
-            }
+    match collector.inotify_stats() {
+        Ok(stats) => {
+            unsafe { *out = InotifyStats::from(stats) };
             ProbeResult::ok()
         }
         Err(e) => ProbeResult::from_metrics_error(e),
     }
 }
 
-/// Free a network interface list.
-///
-/// # Safety
-/// The list must have been allocated by `probe_list_net_interfaces`.
-#[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_free_net_interface_list(list: *mut NetInterfaceList) {
-    if list.is_null() {
-        return;
-    }
-    unsafe {
-        let list = &mut *list;
-        if !list.items.is_null() {
-            drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
-            list.items = ptr::null_mut();
-            list.count = 0;
-            list.capacity = 0;
-        }
+// ============================================================================
+// KERNEL MODULES
+// ============================================================================
+
+/// A loaded kernel module.
+#[repr(C)]
+pub struct KernelModule {
+    /// Module name (null-terminated).
+    pub name: [c_char; 64],
+    /// Memory used by the module in bytes.
+    pub size_bytes: u64,
+    /// Number of users of the module.
+    pub ref_count: u32,
+}
+
+impl From<probe_metrics::KernelModule> for KernelModule {
+    fn from(m: probe_metrics::KernelModule) -> Self {
+        let mut result = Self { name: [0; 64], size_bytes: m.size_bytes, ref_count: m.ref_count };
+        copy_str_to_carray(&m.name, &mut result.name);
+        result
     }
 }
 
-/// Collect network statistics for all interfaces.
+/// List of loaded kernel modules.
+#[repr(C)]
+pub struct KernelModuleList {
+    pub items: *mut KernelModule,
+    pub count: usize,
+    pub capacity: usize,
+}
+
+/// List loaded kernel modules.
 ///
 /// # Safety
-/// The `out` pointer must be valid. Caller must call `probe_free_net_stats_list` when done.
+/// The `out` pointer must be valid. Caller must call `probe_free_kernel_module_list` when done.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_collect_net_stats(out: *mut NetStatsList) -> ProbeResult {
+pub unsafe extern "C" fn probe_list_kernel_modules(out: *mut KernelModuleList) -> ProbeResult {
     if out.is_null() {
         return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
     }
@@ -1240,9 +3505,9 @@ pub unsafe extern "C" fn probe_collect_net_stats(out: *mut NetStatsList) -> Prob
         None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
     };
 
-    match collector.network().collect_all_stats() {
-        Ok(stats) => {
-            let mut items: Vec<NetStats> = stats.into_iter().map(|s| s.into()).collect();
+    match collector.list_modules() {
+        Ok(modules) => {
+            let mut items: Vec<KernelModule> = modules.into_iter().map(|m| m.into()).collect();
             let count = items.len();
             let capacity = items.capacity();
             let ptr = items.as_mut_ptr();
@@ -1259,18 +3524,18 @@ pub unsafe extern "C" fn probe_collect_net_stats(out: *mut NetStatsList) -> Prob
     }
 }
 
-/// Free a network stats list.
+/// Free a kernel module list.
 ///
 /// # Safety
-/// The list must have been allocated by `probe_collect_net_stats`.
+/// The list must have been allocated by `probe_list_kernel_modules`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_free_net_stats_list(list: *mut NetStatsList) {
+pub unsafe extern "C" fn probe_free_kernel_module_list(list: *mut KernelModuleList) {
     if list.is_null() {
         return;
     }
     unsafe {
         let list = &mut *list;
-        if !list.items.is_null() {
+        if !list.items.is_null() && list.capacity > 0 {
             drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
             list.items = ptr::null_mut();
             list.count = 0;
@@ -1280,35 +3545,46 @@ pub unsafe extern "C" fn probe_free_net_stats_list(list: *mut NetStatsList) {
 }
 
 // ============================================================================
-// I/O METRICS
+// NAMESPACES
 // ============================================================================
 
-/// System-wide I/O statistics.
+/// Namespace inodes of the collecting process (0 when unavailable).
 #[repr(C)]
-pub struct IOStats {
-    pub read_ops: u64,
-    pub read_bytes: u64,
-    pub write_ops: u64,
-    pub write_bytes: u64,
-}
-
-impl From<probe_metrics::IOStats> for IOStats {
-    fn from(io: probe_metrics::IOStats) -> Self {
+#[derive(Default)]
+pub struct NamespaceIds {
+    /// Network namespace.
+    pub net: u64,
+    /// PID namespace.
+    pub pid: u64,
+    /// Mount namespace.
+    pub mnt: u64,
+    /// IPC namespace.
+    pub ipc: u64,
+    /// UTS (hostname) namespace.
+    pub uts: u64,
+    /// Cgroup namespace.
+    pub cgroup: u64,
+}
+
+impl From<probe_metrics::NamespaceIds> for NamespaceIds {
+    fn from(ids: probe_metrics::NamespaceIds) -> Self {
         Self {
-            read_ops: io.read_ops,
-            read_bytes: io.read_bytes,
-            write_ops: io.write_ops,
-            write_bytes: io.write_bytes,
+            net: ids.net,
+            pid: ids.pid,
+            mnt: ids.mnt,
+            ipc: ids.ipc,
+            uts: ids.uts,
+            cgroup: ids.cgroup,
         }
     }
 }
 
-/// Collect system-wide I/O statistics.
+/// Read the namespace identifiers of the collecting process.
 ///
 /// # Safety
-/// The `out` pointer must be valid and properly aligned.
+/// The `out` pointer must be valid.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_collect_io_stats(out: *mut IOStats) -> ProbeResult {
+pub unsafe extern "C" fn probe_namespace_ids(out: *mut NamespaceIds) -> ProbeResult {
     if out.is_null() {
         return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
     }
@@ -1318,9 +3594,9 @@ pub unsafe extern "C" fn probe_collect_io_stats(out: *mut IOStats) -> ProbeResul
         None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
     };
 
-    match collector.io().collect_stats() {
-        Ok(stats) => {
-            unsafe { *out = IOStats::from(stats) };
+    match collector.namespace_ids() {
+        Ok(ids) => {
+            unsafe { *out = NamespaceIds::from(ids) };
             ProbeResult::ok()
         }
         Err(e) => ProbeResult::from_metrics_error(e),
@@ -1328,179 +3604,161 @@ pub unsafe extern "C" fn probe_collect_io_stats(out: *mut IOStats) -> ProbeResul
 }
 
 // ============================================================================
-// CONTEXT SWITCHES
+// SYSTEM LIMITS
 // ============================================================================
 
-/// Context switch statistics.
+/// Key system-wide limits (0 when unavailable).
 #[repr(C)]
-pub struct ContextSwitches {
-    /// Voluntary context switches (process yielded CPU).
-    pub voluntary: u64,
-    /// Involuntary context switches (preempted by scheduler).
-    pub involuntary: u64,
-    /// System-wide total context switches.
-    pub system_total: u64,
-}
-
-impl From<probe_metrics::ContextSwitches> for ContextSwitches {
-    fn from(cs: probe_metrics::ContextSwitches) -> Self {
-        Self { voluntary: cs.voluntary, involuntary: cs.involuntary, system_total: cs.system_total }
-    }
-}
-
-/// Collect system-wide context switch count.
-///
-/// # Safety
-/// The `out` pointer must be valid.
-#[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_collect_system_context_switches(out: *mut u64) -> ProbeResult {
-    if out.is_null() {
-        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
-    }
-
-    #[cfg(target_os = "linux")]
-    {
-        match probe_platform::linux::read_system_context_switches() {
-            Ok(count) => {
-                unsafe { *out = count };
-                ProbeResult::ok()
-            }
-            Err(e) => ProbeResult::from_metrics_error(e),
-        }
-    }
-
-    #[cfg(any(target_os = "freebsd", target_os = "openbsd", target_os = "netbsd"))]
-    {
-        match probe_platform::bsd::read_system_context_switches() {
-            Ok(cs) => {
-                unsafe { *out = cs.voluntary.saturating_add(cs.involuntary) };
-                ProbeResult::ok()
-            }
-            Err(e) => ProbeResult::from_metrics_error(e),
+#[derive(Default)]
+pub struct SystemLimits {
+    /// Maximum open file handles system-wide.
+    pub file_max: u64,
+    /// Highest process ID before wrapping around.
+    pub pid_max: u64,
+    /// Maximum threads system-wide.
+    pub threads_max: u64,
+    /// Maximum memory map areas per process.
+    pub max_map_count: u64,
+    /// Maximum concurrent asynchronous I/O requests system-wide.
+    pub aio_max_nr: u64,
+}
+
+impl From<probe_metrics::SystemLimits> for SystemLimits {
+    fn from(l: probe_metrics::SystemLimits) -> Self {
+        Self {
+            file_max: l.file_max,
+            pid_max: l.pid_max,
+            threads_max: l.threads_max,
+            max_map_count: l.max_map_count,
+            aio_max_nr: l.aio_max_nr,
         }
     }
-
-    #[cfg(not(any(
-        target_os = "linux",
-        target_os = "freebsd",
-        target_os = "openbsd",
-        target_os = "netbsd"
-    )))]
-    {
-        ProbeResult::err(
-            PROBE_ERR_NOT_SUPPORTED,
-            c"context switches not supported on this platform".as_ptr(),
-        )
-    }
 }
 
-/// Collect context switches for a specific process.
+/// Read key system-wide limits in one call.
 ///
 /// # Safety
 /// The `out` pointer must be valid.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_collect_process_context_switches(
-    pid: i32,
-    out: *mut ContextSwitches,
-) -> ProbeResult {
+pub unsafe extern "C" fn probe_system_limits(out: *mut SystemLimits) -> ProbeResult {
     if out.is_null() {
         return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
     }
 
-    #[cfg(target_os = "linux")]
-    {
-        match probe_platform::linux::read_process_context_switches(pid) {
-            Ok(switches) => {
-                unsafe { *out = ContextSwitches::from(switches) };
-                ProbeResult::ok()
-            }
-            Err(e) => ProbeResult::from_metrics_error(e),
-        }
-    }
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
 
-    #[cfg(any(target_os = "freebsd", target_os = "openbsd", target_os = "netbsd"))]
-    {
-        match probe_platform::bsd::read_process_context_switches(pid) {
-            Ok(cs) => {
-                unsafe {
-                    *out = ContextSwitches {
-                        voluntary: cs.voluntary,
-                        involuntary: cs.involuntary,
-                        system_total: 0,
-                    }
-                };
-                ProbeResult::ok()
-            }
-            Err(e) => ProbeResult::from_metrics_error(e),
+    match collector.system_limits() {
+        Ok(limits) => {
+            unsafe { *out = SystemLimits::from(limits) };
+            ProbeResult::ok()
         }
+        Err(e) => ProbeResult::from_metrics_error(e),
     }
+}
 
-    #[cfg(not(any(
-        target_os = "linux",
-        target_os = "freebsd",
-        target_os = "openbsd",
-        target_os = "netbsd"
-    )))]
-    {
-        let _ = pid;
-        ProbeResult::err(
-            PROBE_ERR_NOT_SUPPORTED,
-            c"context switches not supported on this platform".as_ptr(),
-        )
+// ============================================================================
+// TIME
+// ============================================================================
+
+/// Local timezone and clock synchronization state.
+#[repr(C)]
+pub struct TimeInfo {
+    /// Timezone name (e.g. "Europe/Paris") or abbreviation.
+    pub timezone: [c_char; 64],
+    /// Current offset from UTC in seconds.
+    pub utc_offset_secs: i32,
+    /// 1 if NTP-synchronized, 0 if not, -1 if unknown.
+    pub ntp_synchronized: i8,
+}
+
+impl From<probe_metrics::TimeInfo> for TimeInfo {
+    fn from(t: probe_metrics::TimeInfo) -> Self {
+        let mut result = Self {
+            timezone: [0; 64],
+            utc_offset_secs: t.utc_offset_secs,
+            ntp_synchronized: t.ntp_synchronized.map_or(-1, i8::from),
+        };
+        copy_str_to_carray(&t.timezone, &mut result.timezone);
+        result
     }
 }
 
-/// Collect context switches for the current process.
+/// Read the local timezone, UTC offset and NTP synchronization state.
 ///
 /// # Safety
 /// The `out` pointer must be valid.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_collect_self_context_switches(
-    out: *mut ContextSwitches,
-) -> ProbeResult {
+pub unsafe extern "C" fn probe_time_info(out: *mut TimeInfo) -> ProbeResult {
     if out.is_null() {
         return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
     }
 
-    #[cfg(target_os = "linux")]
-    {
-        match probe_platform::linux::read_self_context_switches() {
-            Ok(switches) => {
-                unsafe { *out = ContextSwitches::from(switches) };
-                ProbeResult::ok()
-            }
-            Err(e) => ProbeResult::from_metrics_error(e),
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.time_info() {
+        Ok(info) => {
+            unsafe { *out = TimeInfo::from(info) };
+            ProbeResult::ok()
         }
+        Err(e) => ProbeResult::from_metrics_error(e),
     }
+}
 
-    #[cfg(any(target_os = "freebsd", target_os = "openbsd", target_os = "netbsd"))]
-    {
-        match probe_platform::bsd::read_self_context_switches() {
-            Ok(cs) => {
-                unsafe {
-                    *out = ContextSwitches {
-                        voluntary: cs.voluntary,
-                        involuntary: cs.involuntary,
-                        system_total: 0,
-                    }
-                };
-                ProbeResult::ok()
-            }
-            Err(e) => ProbeResult::from_metrics_error(e),
-        }
+// ============================================================================
+// SYSTEMD UNITS
+// ============================================================================
+
+/// State of a systemd unit.
+#[repr(C)]
+pub struct UnitStatus {
+    /// High-level state (e.g. "active", "failed").
+    pub active_state: [c_char; 32],
+    /// Unit-type specific state (e.g. "running", "exited").
+    pub sub_state: [c_char; 32],
+    /// Main process of a service (0 when none or unknown).
+    pub main_pid: u32,
+}
+
+impl From<probe_metrics::UnitStatus> for UnitStatus {
+    fn from(u: probe_metrics::UnitStatus) -> Self {
+        let mut result = Self { active_state: [0; 32], sub_state: [0; 32], main_pid: u.main_pid };
+        copy_str_to_carray(&u.active_state, &mut result.active_state);
+        copy_str_to_carray(&u.sub_state, &mut result.sub_state);
+        result
     }
+}
 
-    #[cfg(not(any(
-        target_os = "linux",
-        target_os = "freebsd",
-        target_os = "openbsd",
-        target_os = "netbsd"
-    )))]
-    {
-        ProbeResult::err(
-            PROBE_ERR_NOT_SUPPORTED,
-            c"context switches not supported on this platform".as_ptr(),
-        )
+/// Read the status of a systemd unit (e.g. "dbus.service").
+///
+/// # Safety
+/// `unit` must be a valid NUL-terminated string and `out` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_unit_status(
+    unit: *const c_char,
+    out: *mut UnitStatus,
+) -> ProbeResult {
+    if unit.is_null() || out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    let unit = unsafe { std::ffi::CStr::from_ptr(unit).to_string_lossy() };
+    match collector.unit_status(&unit) {
+        Ok(status) => {
+            unsafe { *out = UnitStatus::from(status) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
     }
 }
 
@@ -1612,28 +3870,397 @@ pub unsafe extern "C" fn probe_collect_thermal_zones(out: *mut ThermalZoneList)
     {
         ProbeResult::err(
             PROBE_ERR_NOT_SUPPORTED,
-            c"thermal monitoring not supported on this platform".as_ptr(),
+            c"thermal monitoring not supported on this platform".as_ptr(),
+        )
+    }
+}
+
+/// Free a thermal zone list.
+///
+/// # Safety
+/// The list must have been allocated by `probe_collect_thermal_zones`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_free_thermal_list(list: *mut ThermalZoneList) {
+    if list.is_null() {
+        return;
+    }
+    unsafe {
+        let list = &mut *list;
+        if !list.items.is_null() && list.capacity > 0 {
+            drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
+            list.items = ptr::null_mut();
+            list.count = 0;
+            list.capacity = 0;
+        }
+    }
+}
+
+/// CPU temperature aggregated from its sensors.
+#[repr(C)]
+#[derive(Default)]
+pub struct CpuTemp {
+    /// Hottest package (die) reading, valid when `has_package` is true.
+    pub package_celsius: f64,
+    pub has_package: bool,
+    /// Hottest core reading.
+    pub core_max_celsius: f64,
+    /// Average of the core readings.
+    pub average_celsius: f64,
+}
+
+impl From<probe_metrics::CpuTemp> for CpuTemp {
+    fn from(t: probe_metrics::CpuTemp) -> Self {
+        Self {
+            package_celsius: t.package_celsius.unwrap_or(0.0),
+            has_package: t.package_celsius.is_some(),
+            core_max_celsius: t.core_max_celsius,
+            average_celsius: t.average_celsius,
+        }
+    }
+}
+
+/// Aggregate the CPU temperature sensors (coretemp, k10temp, ...).
+///
+/// Returns `PROBE_ERR_NOT_SUPPORTED` when no CPU sensor is found.
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_cpu_temperature(out: *mut CpuTemp) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        use probe_metrics::ThermalCollector;
+
+        match probe_platform::linux::LinuxThermalCollector::new().cpu_temperature() {
+            Ok(temp) => {
+                unsafe { *out = CpuTemp::from(temp) };
+                ProbeResult::ok()
+            }
+            Err(e) => ProbeResult::from_metrics_error(e),
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        ProbeResult::err(
+            PROBE_ERR_NOT_SUPPORTED,
+            c"thermal monitoring not supported on this platform".as_ptr(),
+        )
+    }
+}
+
+// ============================================================================
+// WIRELESS METRICS (Linux only)
+// ============================================================================
+
+/// Wireless interface signal metrics.
+#[repr(C)]
+pub struct WirelessInfo {
+    pub interface: [c_char; 16],
+    pub ssid: [c_char; 33],
+    pub signal_dbm: f64,
+    pub link_quality: f64,
+    pub bitrate_mbps: f64,
+    pub frequency_mhz: u32,
+}
+
+impl Default for WirelessInfo {
+    fn default() -> Self {
+        Self {
+            interface: [0; 16],
+            ssid: [0; 33],
+            signal_dbm: 0.0,
+            link_quality: 0.0,
+            bitrate_mbps: 0.0,
+            frequency_mhz: 0,
+        }
+    }
+}
+
+impl From<probe_metrics::WirelessInfo> for WirelessInfo {
+    fn from(info: probe_metrics::WirelessInfo) -> Self {
+        let mut result = Self::default();
+        copy_str_to_carray(&info.interface, &mut result.interface);
+        copy_str_to_carray(&info.ssid, &mut result.ssid);
+        result.signal_dbm = info.signal_dbm;
+        result.link_quality = info.link_quality;
+        result.bitrate_mbps = info.bitrate_mbps;
+        result.frequency_mhz = info.frequency_mhz;
+        result
+    }
+}
+
+/// Collect signal metrics for a wireless interface.
+///
+/// Returns `PROBE_ERR_NOT_SUPPORTED` for wired interfaces.
+///
+/// # Safety
+/// The `interface` must be a null-terminated C string. The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_wireless(
+    interface: *const c_char,
+    out: *mut WirelessInfo,
+) -> ProbeResult {
+    if interface.is_null() || out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let interface = unsafe { std::ffi::CStr::from_ptr(interface).to_string_lossy() };
+        match probe_platform::linux::read_wireless_info(&interface) {
+            Ok(info) => {
+                unsafe { *out = WirelessInfo::from(info) };
+                ProbeResult::ok()
+            }
+            Err(e) => ProbeResult::from_metrics_error(e),
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        ProbeResult::err(
+            PROBE_ERR_NOT_SUPPORTED,
+            c"wireless metrics not supported on this platform".as_ptr(),
+        )
+    }
+}
+
+// ============================================================================
+// LINK EVENTS
+// ============================================================================
+
+/// Interface up/down transition passed to a `ProbeLinkCallback`.
+#[repr(C)]
+pub struct LinkEvent {
+    /// Interface name (null-terminated).
+    pub interface: [c_char; 16],
+    /// Whether the interface is now up.
+    pub up: bool,
+}
+
+impl From<probe_metrics::LinkEvent> for LinkEvent {
+    fn from(e: probe_metrics::LinkEvent) -> Self {
+        let mut result = Self { interface: [0; 16], up: e.up };
+        copy_str_to_carray(&e.interface, &mut result.interface);
+        result
+    }
+}
+
+/// Callback invoked from the watcher thread for each link transition.
+///
+/// The event pointer is only valid for the duration of the call.
+pub type ProbeLinkCallback =
+    Option<unsafe extern "C" fn(event: *const LinkEvent, user_data: *mut std::ffi::c_void)>;
+
+/// Caller-owned context handed back to the callback.
+#[cfg(target_os = "linux")]
+struct LinkUserData(*mut std::ffi::c_void);
+
+// SAFETY: the caller guarantees user_data may be used from the watcher thread
+#[cfg(target_os = "linux")]
+unsafe impl Send for LinkUserData {}
+
+#[cfg(target_os = "linux")]
+static LINK_WATCHER: std::sync::Mutex<Option<probe_platform::linux::LinkWatcher>> =
+    std::sync::Mutex::new(None);
+
+/// Start watching interface up/down transitions, replacing any previous watch.
+///
+/// Uses netlink where available and polls /sys/class/net otherwise.
+///
+/// # Safety
+/// `callback` must be safe to call from another thread with `user_data`
+/// until `probe_link_watch_stop` returns.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_link_watch_start(
+    callback: ProbeLinkCallback,
+    user_data: *mut std::ffi::c_void,
+) -> ProbeResult {
+    let Some(callback) = callback else {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    };
+
+    #[cfg(target_os = "linux")]
+    {
+        let mut guard = LINK_WATCHER.lock().unwrap_or_else(|e| e.into_inner());
+        // Stop the previous watcher before its callback can be replaced
+        guard.take();
+
+        let user_data = LinkUserData(user_data);
+        let started = probe_platform::linux::LinkWatcher::start(move |event| {
+            let user_data = &user_data;
+            let event = LinkEvent::from(event);
+            unsafe { callback(&event, user_data.0) };
+        });
+        match started {
+            Ok(watcher) => {
+                *guard = Some(watcher);
+                ProbeResult::ok()
+            }
+            Err(e) => ProbeResult::from_metrics_error(e),
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = (callback, user_data);
+        ProbeResult::err(
+            PROBE_ERR_NOT_SUPPORTED,
+            c"link events not supported on this platform".as_ptr(),
         )
     }
 }
 
-/// Free a thermal zone list.
+/// Stop watching interface transitions.
+///
+/// Blocks until the watcher thread has exited; the callback is not invoked
+/// after this returns. Must not be called from within the callback.
+#[unsafe(no_mangle)]
+pub extern "C" fn probe_link_watch_stop() -> ProbeResult {
+    #[cfg(target_os = "linux")]
+    {
+        let watcher = LINK_WATCHER.lock().unwrap_or_else(|e| e.into_inner()).take();
+        drop(watcher);
+    }
+    ProbeResult::ok()
+}
+
+// ============================================================================
+// PROCESS EVENTS (Linux only)
+// ============================================================================
+
+/// Identity of a process reported by a process watcher.
+#[repr(C)]
+pub struct ProcessIdentity {
+    pub pid: i32,
+    /// Command name (null-terminated).
+    pub name: [c_char; 64],
+    /// Start time in ticks since boot; differs when a PID is reused.
+    pub start_time: u64,
+}
+
+impl From<probe_metrics::ProcessIdentity> for ProcessIdentity {
+    fn from(p: probe_metrics::ProcessIdentity) -> Self {
+        let mut result = Self { pid: p.pid, name: [0; 64], start_time: p.start_time };
+        copy_str_to_carray(&p.name, &mut result.name);
+        result
+    }
+}
+
+/// Callback invoked from the watcher thread when a matching process starts.
+///
+/// The identity pointer is only valid for the duration of the call.
+pub type ProbeProcessStartCallback =
+    Option<unsafe extern "C" fn(process: *const ProcessIdentity, user_data: *mut std::ffi::c_void)>;
+
+/// Callback invoked from the watcher thread when a matching process exits.
+pub type ProbeProcessExitCallback =
+    Option<unsafe extern "C" fn(pid: i32, user_data: *mut std::ffi::c_void)>;
+
+/// Opaque process watcher handle.
+pub struct ProcessWatcher {
+    // Only held so that dropping the handle stops the thread
+    _watcher: probe_metrics::ProcessWatcher,
+}
+
+/// Caller-owned context handed back to the process callbacks.
+#[derive(Clone, Copy)]
+struct ProcessUserData(*mut std::ffi::c_void);
+
+// SAFETY: the caller guarantees user_data may be used from the watcher thread
+unsafe impl Send for ProcessUserData {}
+
+/// Start watching processes, reporting starts and exits to the callbacks.
+///
+/// Processes are polled every `interval_ms`. Only processes whose PID is in
+/// `pids` or whose name contains `name_contains` are reported; with no PIDs
+/// and a null `name_contains`, every process is. Either callback may be null,
+/// but not both. Stop with `probe_process_watch_stop`.
 ///
 /// # Safety
-/// The list must have been allocated by `probe_collect_thermal_zones`.
+/// `pids` must point to `pid_count` values, `name_contains` must be null or
+/// a valid C string and `out` must be valid. The callbacks must be safe to
+/// call from another thread with `user_data` until
+/// `probe_process_watch_stop` returns.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_free_thermal_list(list: *mut ThermalZoneList) {
-    if list.is_null() {
-        return;
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn probe_process_watch_start(
+    interval_ms: u64,
+    pids: *const i32,
+    pid_count: usize,
+    name_contains: *const c_char,
+    on_start: ProbeProcessStartCallback,
+    on_exit: ProbeProcessExitCallback,
+    user_data: *mut std::ffi::c_void,
+    out: *mut *mut ProcessWatcher,
+) -> ProbeResult {
+    if out.is_null() || (pid_count > 0 && pids.is_null()) {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
     }
-    unsafe {
-        let list = &mut *list;
-        if !list.items.is_null() && list.capacity > 0 {
-            drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
-            list.items = ptr::null_mut();
-            list.count = 0;
-            list.capacity = 0;
+    if on_start.is_none() && on_exit.is_none() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"no callback".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    let filter = probe_metrics::ProcessFilter {
+        pids: if pid_count == 0 {
+            Vec::new()
+        } else {
+            unsafe { std::slice::from_raw_parts(pids, pid_count) }.to_vec()
+        },
+        name_contains: (!name_contains.is_null()).then(|| {
+            unsafe { std::ffi::CStr::from_ptr(name_contains) }.to_string_lossy().into_owned()
+        }),
+    };
+
+    let user_data = ProcessUserData(user_data);
+    let started = probe_metrics::ProcessWatcher::start(
+        move || collector.process().list_identities(),
+        Duration::from_millis(interval_ms),
+        filter,
+        move |process| {
+            let user_data = &user_data;
+            if let Some(on_start) = on_start {
+                let process = ProcessIdentity::from(process);
+                unsafe { on_start(&process, user_data.0) };
+            }
+        },
+        move |pid| {
+            let user_data = &user_data;
+            if let Some(on_exit) = on_exit {
+                unsafe { on_exit(pid, user_data.0) };
+            }
+        },
+    );
+    match started {
+        Ok(watcher) => {
+            unsafe { *out = Box::into_raw(Box::new(ProcessWatcher { _watcher: watcher })) };
+            ProbeResult::ok()
         }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Stop a process watcher and free it.
+///
+/// Blocks until the watcher thread has exited; the callbacks are not invoked
+/// after this returns. Must not be called from within a callback.
+///
+/// # Safety
+/// `watcher` must come from `probe_process_watch_start` and not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_process_watch_stop(watcher: *mut ProcessWatcher) {
+    if !watcher.is_null() {
+        drop(unsafe { Box::from_raw(watcher) });
     }
 }
 
@@ -1725,6 +4352,8 @@ pub struct AllMetrics {
     pub net_interfaces: [NetInterface; MAX_ALL_METRICS_ITEMS],
     /// Network statistics (up to MAX_ALL_METRICS_ITEMS).
     pub net_stats: [NetStats; MAX_ALL_METRICS_ITEMS],
+    /// Time spent gathering the snapshot in microseconds.
+    pub collection_duration_us: u64,
 }
 
 impl Default for AllMetrics {
@@ -1738,6 +4367,11 @@ impl Default for AllMetrics {
                 steal_percent: 0.0,
                 cores: 0,
                 frequency_mhz: 0,
+                guest_percent: 0.0,
+                guest_nice_percent: 0.0,
+                cores_present: 0,
+                cores_online: 0,
+                physical_cores: 0,
             },
             memory: SystemMemory {
                 total_bytes: 0,
@@ -1762,6 +4396,7 @@ impl Default for AllMetrics {
             disk_io: [DiskIOStats::default(); MAX_ALL_METRICS_ITEMS],
             net_interfaces: [NetInterface::default(); MAX_ALL_METRICS_ITEMS],
             net_stats: [NetStats::default(); MAX_ALL_METRICS_ITEMS],
+            collection_duration_us: 0,
         }
     }
 }
@@ -1786,65 +4421,450 @@ pub unsafe extern "C" fn probe_collect_all(out: *mut AllMetrics) -> ProbeResult
 
     match collector.collect_all() {
         Ok(metrics) => {
-            let result = unsafe { &mut *out };
+            write_all_metrics(metrics, unsafe { &mut *out });
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
 
-            // Copy basic metrics
-            result.cpu = SystemCPU::from(metrics.cpu);
-            result.memory = SystemMemory::from(metrics.memory);
-            result.load = LoadAverage::from(metrics.load);
-            result.io_stats = IOStats::from(metrics.io_stats);
-            result.timestamp_us = metrics.timestamp_us;
-
-            // Copy pressure if available
-            if let Some(pressure) = metrics.pressure {
-                result.pressure = AllPressure {
-                    cpu: CPUPressure::from(pressure.cpu),
-                    memory: MemoryPressure::from(pressure.memory),
-                    io: IOPressure::from(pressure.io),
-                    available: true,
-                };
-            } else {
-                result.pressure = AllPressure::default();
-            }
+/// Binary encoding version written by `probe_collect_all_binary`.
+pub const PROBE_BINARY_FORMAT_VERSION: u8 = probe_metrics::binary::FORMAT_VERSION;
+
+/// Collect all system metrics as a compact binary snapshot.
+///
+/// The snapshot is a format version byte followed by the postcard encoding
+/// of the metrics (see `probe_metrics::binary`). `written` receives the
+/// encoded size even when `len` is too small, in which case
+/// `PROBE_ERR_INVALID_PARAM` is returned and `buf` is left untouched.
+///
+/// # Safety
+/// `buf` must point to `len` writable bytes and `written` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_all_binary(
+    buf: *mut u8,
+    len: usize,
+    written: *mut usize,
+) -> ProbeResult {
+    if written.is_null() || (len > 0 && buf.is_null()) {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+    unsafe { *written = 0 };
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
 
-            // Copy partitions
-            let part_count = metrics.partitions.len().min(MAX_ALL_METRICS_ITEMS);
-            result.partition_count = part_count as u32;
-            for (i, p) in metrics.partitions.into_iter().take(part_count).enumerate() {
-                result.partitions[i] = Partition::from(p);
+    match collector.collect_all() {
+        Ok(metrics) => {
+            let bytes = probe_metrics::binary::encode(&metrics);
+            unsafe { *written = bytes.len() };
+            if bytes.len() > len {
+                return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"buffer too small".as_ptr());
             }
+            unsafe { std::ptr::copy_nonoverlapping(bytes.as_ptr(), buf, bytes.len()) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Collect all system metrics, giving up once `deadline_unix_ms` is reached.
+///
+/// Returns `PROBE_ERR_TIMEOUT` when collection has not finished by the
+/// deadline (milliseconds since the Unix epoch). The abandoned collection
+/// keeps running on a background thread and its result is discarded; until
+/// it finishes, further calls fail with `PROBE_ERR_TIMEOUT` at once instead
+/// of starting another one.
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_all_deadline(
+    deadline_unix_ms: u64,
+    out: *mut AllMetrics,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    let deadline = UNIX_EPOCH + Duration::from_millis(deadline_unix_ms);
+    let in_flight = COLLECT_ALL_IN_FLIGHT.get_or_init(probe_metrics::InFlight::new);
+    match run_until(in_flight, deadline, move || collector.collect_all()) {
+        Ok(metrics) => {
+            write_all_metrics(metrics, unsafe { &mut *out });
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Collection abandoned by `probe_collect_all_deadline`, if still running.
+static COLLECT_ALL_IN_FLIGHT: OnceLock<probe_metrics::InFlight> = OnceLock::new();
+
+/// Run `work` on a worker thread and wait for it until `deadline`.
+///
+/// Returns `Error::Timeout` if the deadline passes first, including when it
+/// has already passed on entry, and while the worker of a previous call is
+/// still running in `in_flight`.
+fn run_until<T: Send + 'static>(
+    in_flight: &probe_metrics::InFlight,
+    deadline: SystemTime,
+    work: impl FnOnce() -> probe_metrics::Result<T> + Send + 'static,
+) -> probe_metrics::Result<T> {
+    let remaining =
+        deadline.duration_since(SystemTime::now()).map_err(|_| probe_metrics::Error::Timeout)?;
+    in_flight.run("collect_all", remaining, work)
+}
+
+/// Metric selection bits for `probe_collect_selected`.
+pub const PROBE_SELECT_CPU: u32 = MetricMask::CPU.bits();
+pub const PROBE_SELECT_MEMORY: u32 = MetricMask::MEMORY.bits();
+pub const PROBE_SELECT_LOAD: u32 = MetricMask::LOAD.bits();
+pub const PROBE_SELECT_IO: u32 = MetricMask::IO.bits();
+pub const PROBE_SELECT_DISK: u32 = MetricMask::DISK.bits();
+pub const PROBE_SELECT_NETWORK: u32 = MetricMask::NETWORK.bits();
+pub const PROBE_SELECT_PRESSURE: u32 = MetricMask::PRESSURE.bits();
+pub const PROBE_SELECT_ALL: u32 = MetricMask::ALL.bits();
+
+/// Collect only the subsystems selected by `mask` (PROBE_SELECT_* bits).
+///
+/// Unselected subsystems are left zeroed in `out`. Unknown bits are ignored.
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_selected(mask: u32, out: *mut AllMetrics) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.collect_selected(MetricMask::from_bits_truncate(mask)) {
+        Ok(metrics) => {
+            write_all_metrics(metrics, unsafe { &mut *out });
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Copy aggregated metrics into the C-compatible structure.
+fn write_all_metrics(metrics: probe_metrics::AllMetrics, result: &mut AllMetrics) {
+    // Copy basic metrics
+    result.cpu = SystemCPU::from(metrics.cpu);
+    result.memory = SystemMemory::from(metrics.memory);
+    result.load = LoadAverage::from(metrics.load);
+    result.io_stats = IOStats::from(metrics.io_stats);
+    result.timestamp_us = metrics.timestamp_us;
+    result.collection_duration_us = metrics.collection_duration_us;
+
+    // Copy pressure if available
+    if let Some(pressure) = metrics.pressure {
+        result.pressure = AllPressure {
+            cpu: CPUPressure::from(pressure.cpu),
+            memory: MemoryPressure::from(pressure.memory),
+            io: IOPressure::from(pressure.io),
+            available: true,
+        };
+    } else {
+        result.pressure = AllPressure::default();
+    }
+
+    // Copy partitions
+    let part_count = metrics.partitions.len().min(MAX_ALL_METRICS_ITEMS);
+    result.partition_count = part_count as u32;
+    for (i, p) in metrics.partitions.into_iter().take(part_count).enumerate() {
+        result.partitions[i] = Partition::from(p);
+    }
+
+    // Copy disk usage
+    let usage_count = metrics.disk_usage.len().min(MAX_ALL_METRICS_ITEMS);
+    result.disk_usage_count = usage_count as u32;
+    for (i, u) in metrics.disk_usage.into_iter().take(usage_count).enumerate() {
+        result.disk_usage[i] = DiskUsage::from(u);
+    }
+
+    // Copy disk I/O
+    let io_count = metrics.disk_io.len().min(MAX_ALL_METRICS_ITEMS);
+    result.disk_io_count = io_count as u32;
+    for (i, io) in metrics.disk_io.into_iter().take(io_count).enumerate() {
+        result.disk_io[i] = DiskIOStats::from(io);
+    }
+
+    // Copy network interfaces
+    let iface_count = metrics.net_interfaces.len().min(MAX_ALL_METRICS_ITEMS);
+    result.net_interface_count = iface_count as u32;
+    for (i, iface) in metrics.net_interfaces.into_iter().take(iface_count).enumerate() {
+        result.net_interfaces[i] = NetInterface::from(iface);
+    }
+
+    // Copy network stats
+    let stats_count = metrics.net_stats.len().min(MAX_ALL_METRICS_ITEMS);
+    result.net_stats_count = stats_count as u32;
+    for (i, stats) in metrics.net_stats.into_iter().take(stats_count).enumerate() {
+        result.net_stats[i] = NetStats::from(stats);
+    }
+}
+
+/// Per-device disk I/O rates.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct DiskIORate {
+    pub device: [c_char; 64],
+    pub reads_per_sec: f64,
+    pub writes_per_sec: f64,
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    pub utilization_percent: f64,
+    pub avg_request_size_bytes: f64,
+    pub avg_queue_depth: f64,
+}
+
+impl Default for DiskIORate {
+    fn default() -> Self {
+        Self {
+            device: [0; 64],
+            reads_per_sec: 0.0,
+            writes_per_sec: 0.0,
+            read_bytes_per_sec: 0.0,
+            write_bytes_per_sec: 0.0,
+            utilization_percent: 0.0,
+            avg_request_size_bytes: 0.0,
+            avg_queue_depth: 0.0,
+        }
+    }
+}
+
+impl From<probe_metrics::DiskIORate> for DiskIORate {
+    fn from(r: probe_metrics::DiskIORate) -> Self {
+        let mut result = Self::default();
+        copy_str_to_carray(&r.device, &mut result.device);
+        result.reads_per_sec = r.reads_per_sec;
+        result.writes_per_sec = r.writes_per_sec;
+        result.read_bytes_per_sec = r.read_bytes_per_sec;
+        result.write_bytes_per_sec = r.write_bytes_per_sec;
+        result.utilization_percent = r.utilization_percent;
+        result.avg_request_size_bytes = r.avg_request_size_bytes;
+        result.avg_queue_depth = r.avg_queue_depth;
+        result
+    }
+}
+
+/// Per-interface network rates.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct NetRate {
+    pub interface: [c_char; 64],
+    pub rx_bytes_per_sec: f64,
+    pub rx_packets_per_sec: f64,
+    pub rx_errors_per_sec: f64,
+    pub rx_drops_per_sec: f64,
+    pub tx_bytes_per_sec: f64,
+    pub tx_packets_per_sec: f64,
+    pub tx_errors_per_sec: f64,
+    pub tx_drops_per_sec: f64,
+}
+
+impl Default for NetRate {
+    fn default() -> Self {
+        Self {
+            interface: [0; 64],
+            rx_bytes_per_sec: 0.0,
+            rx_packets_per_sec: 0.0,
+            rx_errors_per_sec: 0.0,
+            rx_drops_per_sec: 0.0,
+            tx_bytes_per_sec: 0.0,
+            tx_packets_per_sec: 0.0,
+            tx_errors_per_sec: 0.0,
+            tx_drops_per_sec: 0.0,
+        }
+    }
+}
+
+impl From<probe_metrics::NetRate> for NetRate {
+    fn from(r: probe_metrics::NetRate) -> Self {
+        let mut result = Self::default();
+        copy_str_to_carray(&r.interface, &mut result.interface);
+        result.rx_bytes_per_sec = r.rx_bytes_per_sec;
+        result.rx_packets_per_sec = r.rx_packets_per_sec;
+        result.rx_errors_per_sec = r.rx_errors_per_sec;
+        result.rx_drops_per_sec = r.rx_drops_per_sec;
+        result.tx_bytes_per_sec = r.tx_bytes_per_sec;
+        result.tx_packets_per_sec = r.tx_packets_per_sec;
+        result.tx_errors_per_sec = r.tx_errors_per_sec;
+        result.tx_drops_per_sec = r.tx_drops_per_sec;
+        result
+    }
+}
+
+/// Rates of the cumulative counters of `AllMetrics` between two collections.
+#[repr(C)]
+pub struct AllMetricsDelta {
+    /// Timestamp of the latest collection (microseconds since epoch).
+    pub timestamp_us: u64,
+    /// Time between the two collections in microseconds.
+    pub interval_us: u64,
+    /// CPU usage between the two collections.
+    pub cpu: SystemCPU,
+    /// System-wide I/O rates.
+    pub read_ops_per_sec: f64,
+    pub read_bytes_per_sec: f64,
+    pub write_ops_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    /// Disk I/O rate count.
+    pub disk_io_count: u32,
+    /// Network rate count.
+    pub net_count: u32,
+    /// Per-device I/O rates (up to MAX_ALL_METRICS_ITEMS).
+    pub disk_io: [DiskIORate; MAX_ALL_METRICS_ITEMS],
+    /// Per-interface network rates (up to MAX_ALL_METRICS_ITEMS).
+    pub net: [NetRate; MAX_ALL_METRICS_ITEMS],
+    /// False on the first collection, which only primes the sampler.
+    pub available: bool,
+}
+
+impl Default for AllMetricsDelta {
+    fn default() -> Self {
+        Self {
+            timestamp_us: 0,
+            interval_us: 0,
+            cpu: SystemCPU::from(probe_metrics::SystemCPU::default()),
+            read_ops_per_sec: 0.0,
+            read_bytes_per_sec: 0.0,
+            write_ops_per_sec: 0.0,
+            write_bytes_per_sec: 0.0,
+            disk_io_count: 0,
+            net_count: 0,
+            disk_io: [DiskIORate::default(); MAX_ALL_METRICS_ITEMS],
+            net: [NetRate::default(); MAX_ALL_METRICS_ITEMS],
+            available: false,
+        }
+    }
+}
+
+/// Opaque handle keeping the previous `AllMetrics` counters between calls.
+pub struct Sampler(probe_metrics::MetricsDeltaSampler);
+
+/// Create a metrics sampler. Free it with `probe_sampler_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn probe_sampler_new() -> *mut Sampler {
+    Box::into_raw(Box::new(Sampler(probe_metrics::MetricsDeltaSampler::new())))
+}
+
+/// Collect all metrics and report counter rates since the previous call.
+///
+/// The first call on a handle only primes it and reports `available = false`.
+/// Handles are independent; each must only be used by one thread at a time.
+///
+/// # Safety
+/// `sampler` must come from `probe_sampler_new` and `out` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_sampler_collect(
+    sampler: *mut Sampler,
+    out: *mut AllMetricsDelta,
+) -> ProbeResult {
+    if sampler.is_null() || out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
 
-            // Copy disk usage
-            let usage_count = metrics.disk_usage.len().min(MAX_ALL_METRICS_ITEMS);
-            result.disk_usage_count = usage_count as u32;
-            for (i, u) in metrics.disk_usage.into_iter().take(usage_count).enumerate() {
-                result.disk_usage[i] = DiskUsage::from(u);
+    match collector.collect_all() {
+        Ok(metrics) => {
+            let sampler = unsafe { &mut (*sampler).0 };
+            let result = unsafe { &mut *out };
+            *result = AllMetricsDelta { timestamp_us: metrics.timestamp_us, ..Default::default() };
+            let ticks = collector.cpu().cpu_ticks().ok();
+            if let Some(delta) = sampler.sample_with_ticks(&metrics, ticks) {
+                write_metrics_delta(delta, result);
             }
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
+/// Collect all metrics twice, `window_ms` apart, and report the rates
+/// between the two collections.
+///
+/// Blocks the calling thread for the whole window; meant for one-shot
+/// callers that cannot keep a `Sampler` between calls. Bypasses the cache.
+///
+/// # Safety
+/// The `out` pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_all_sampled(
+    window_ms: u64,
+    out: *mut AllMetricsDelta,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let collector = match COLLECTOR.get() {
+        Some(c) => c,
+        None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+    };
+
+    match collector.collect_all_sampled(Duration::from_millis(window_ms)) {
+        Ok(delta) => {
+            let result = unsafe { &mut *out };
+            *result = AllMetricsDelta::default();
+            write_metrics_delta(delta, result);
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
 
-            // Copy disk I/O
-            let io_count = metrics.disk_io.len().min(MAX_ALL_METRICS_ITEMS);
-            result.disk_io_count = io_count as u32;
-            for (i, io) in metrics.disk_io.into_iter().take(io_count).enumerate() {
-                result.disk_io[i] = DiskIOStats::from(io);
-            }
+/// Free a metrics sampler.
+///
+/// # Safety
+/// `sampler` must come from `probe_sampler_new` and not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_sampler_free(sampler: *mut Sampler) {
+    if !sampler.is_null() {
+        drop(unsafe { Box::from_raw(sampler) });
+    }
+}
 
-            // Copy network interfaces
-            let iface_count = metrics.net_interfaces.len().min(MAX_ALL_METRICS_ITEMS);
-            result.net_interface_count = iface_count as u32;
-            for (i, iface) in metrics.net_interfaces.into_iter().take(iface_count).enumerate() {
-                result.net_interfaces[i] = NetInterface::from(iface);
-            }
+/// Copy counter rates into the C-compatible structure.
+fn write_metrics_delta(delta: probe_metrics::MetricsDelta, result: &mut AllMetricsDelta) {
+    result.timestamp_us = delta.timestamp_us;
+    result.interval_us = delta.interval.as_micros() as u64;
+    result.cpu = SystemCPU::from(delta.cpu);
+    result.read_ops_per_sec = delta.read_ops_per_sec;
+    result.read_bytes_per_sec = delta.read_bytes_per_sec;
+    result.write_ops_per_sec = delta.write_ops_per_sec;
+    result.write_bytes_per_sec = delta.write_bytes_per_sec;
+    result.available = true;
 
-            // Copy network stats
-            let stats_count = metrics.net_stats.len().min(MAX_ALL_METRICS_ITEMS);
-            result.net_stats_count = stats_count as u32;
-            for (i, stats) in metrics.net_stats.into_iter().take(stats_count).enumerate() {
-                result.net_stats[i] = NetStats::from(stats);
-            }
+    let disk_count = delta.disk_io.len().min(MAX_ALL_METRICS_ITEMS);
+    result.disk_io_count = disk_count as u32;
+    for (i, rate) in delta.disk_io.into_iter().take(disk_count).enumerate() {
+        result.disk_io[i] = DiskIORate::from(rate);
+    }
 
-            ProbeResult::ok()
-        }
-        Err(e) => ProbeResult::from_metrics_error(e),
+    let net_count = delta.net.len().min(MAX_ALL_METRICS_ITEMS);
+    result.net_count = net_count as u32;
+    for (i, rate) in delta.net.into_iter().take(net_count).enumerate() {
+        result.net[i] = NetRate::from(rate);
     }
 }
 
@@ -2157,12 +5177,9 @@ pub extern "C" fn probe_cache_enable() -> ProbeResult {
 /// - 3: No cache (TTL=0, for testing)
 #[unsafe(no_mangle)]
 pub extern "C" fn probe_cache_enable_with_policy(policy: u32) -> ProbeResult {
-    let policies = match policy {
-        0 => CachePolicies::default(),
-        1 => CachePolicies::high_frequency(),
-        2 => CachePolicies::low_frequency(),
-        3 => CachePolicies::no_cache(),
-        _ => return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"invalid policy".as_ptr()),
+    let policies = match policies_from_preset(policy) {
+        Some(p) => p,
+        None => return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"invalid policy".as_ptr()),
     };
 
     let mut guard = get_cached_collector().write();
@@ -2170,6 +5187,17 @@ pub extern "C" fn probe_cache_enable_with_policy(policy: u32) -> ProbeResult {
     ProbeResult::ok()
 }
 
+/// TTL policies of a preset accepted by `probe_cache_enable_with_policy`.
+fn policies_from_preset(policy: u32) -> Option<CachePolicies> {
+    match policy {
+        0 => Some(CachePolicies::default()),
+        1 => Some(CachePolicies::high_frequency()),
+        2 => Some(CachePolicies::low_frequency()),
+        3 => Some(CachePolicies::no_cache()),
+        _ => None,
+    }
+}
+
 /// Disable caching and revert to direct collection.
 #[unsafe(no_mangle)]
 pub extern "C" fn probe_cache_disable() -> ProbeResult {
@@ -2218,6 +5246,64 @@ pub extern "C" fn probe_cache_set_ttl(metric_type: u8, ttl_ms: u64) -> ProbeResu
     }
 }
 
+/// Set the TTL of a metric type by name (e.g. `"cpu-system"`, `"disk-usage"`).
+///
+/// Names are those of `MetricType::as_str`. TTL is specified in milliseconds.
+///
+/// # Safety
+/// The `name` must be a null-terminated C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_cache_set_ttl_by_name(
+    name: *const c_char,
+    ttl_ms: u64,
+) -> ProbeResult {
+    if name.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let name = unsafe { std::ffi::CStr::from_ptr(name).to_string_lossy() };
+    let metric = match name.parse::<MetricType>() {
+        Ok(m) => m,
+        Err(_) => {
+            return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"invalid metric type".as_ptr());
+        }
+    };
+
+    probe_cache_set_ttl(metric as u8, ttl_ms)
+}
+
+/// Multiply every cache TTL by `factor`.
+///
+/// Useful to relax caching under load (e.g. `2.0` doubles all TTLs).
+/// TTLs saturate instead of overflowing. Returns `PROBE_ERR_INVALID_PARAM`
+/// if `factor` is not positive.
+#[unsafe(no_mangle)]
+pub extern "C" fn probe_cache_scale_ttls(factor: f64) -> ProbeResult {
+    let mut guard = get_cached_collector().write();
+    match guard.as_mut() {
+        Some(collector) => match collector.scale_ttls(factor) {
+            Ok(()) => ProbeResult::ok(),
+            Err(_) => ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"invalid scale factor".as_ptr()),
+        },
+        None => ProbeResult::err(PROBE_ERR_INTERNAL, c"caching not enabled".as_ptr()),
+    }
+}
+
+/// Populate every cached metric once so the first real request is a hit.
+///
+/// Metrics unsupported on the platform are skipped.
+#[unsafe(no_mangle)]
+pub extern "C" fn probe_cache_prefetch() -> ProbeResult {
+    let guard = get_cached_collector().read();
+    match guard.as_ref() {
+        Some(collector) => match collector.prefetch_all() {
+            Ok(()) => ProbeResult::ok(),
+            Err(e) => ProbeResult::from_metrics_error(e),
+        },
+        None => ProbeResult::err(PROBE_ERR_INTERNAL, c"caching not enabled".as_ptr()),
+    }
+}
+
 /// Invalidate all cached metrics.
 #[unsafe(no_mangle)]
 pub extern "C" fn probe_cache_invalidate_all() -> ProbeResult {
@@ -2283,6 +5369,40 @@ pub unsafe extern "C" fn probe_collect_cpu_cached(out: *mut SystemCPU) -> ProbeR
     unsafe { probe_collect_cpu(out) }
 }
 
+/// Read system CPU metrics from the cache only, never collecting.
+///
+/// Sets `found` to true and fills `out` if a valid cached value exists, and
+/// sets `found` to false otherwise (`out` is left untouched). Use it on
+/// latency-critical paths that must not wait for a system read.
+/// Returns `PROBE_ERR_INTERNAL` if caching is not enabled.
+///
+/// # Safety
+/// The `out` and `found` pointers must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_try_collect_cpu(
+    out: *mut SystemCPU,
+    found: *mut bool,
+) -> ProbeResult {
+    if out.is_null() || found.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let guard = get_cached_collector().read();
+    match guard.as_ref() {
+        Some(collector) => {
+            let cpu = collector.try_cpu();
+            unsafe {
+                *found = cpu.is_some();
+                if let Some(cpu) = cpu {
+                    *out = SystemCPU::from(cpu);
+                }
+            }
+            ProbeResult::ok()
+        }
+        None => ProbeResult::err(PROBE_ERR_INTERNAL, c"caching not enabled".as_ptr()),
+    }
+}
+
 /// Collect system memory metrics with caching (if enabled).
 ///
 /// # Safety
@@ -2335,6 +5455,199 @@ pub unsafe extern "C" fn probe_collect_load_cached(out: *mut LoadAverage) -> Pro
     unsafe { probe_collect_load(out) }
 }
 
+// ============================================================================
+// CACHE HANDLES
+// ============================================================================
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Cached collectors created with `probe_cache_create`, by handle.
+static CACHE_HANDLES: OnceLock<RwLock<HashMap<u64, CachedCollector<PlatformCollector>>>> =
+    OnceLock::new();
+
+/// Next handle to hand out; 0 is never used so it can signal failure.
+static NEXT_CACHE_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+fn cache_handles() -> &'static RwLock<HashMap<u64, CachedCollector<PlatformCollector>>> {
+    CACHE_HANDLES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Create a cached collector independent from the global one and from other
+/// handles, e.g. to sample at a different cadence.
+///
+/// `policy` takes the presets of `probe_cache_enable_with_policy`. Returns
+/// 0 for an unknown policy. Release the handle with `probe_cache_destroy`.
+#[unsafe(no_mangle)]
+pub extern "C" fn probe_cache_create(policy: u32) -> u64 {
+    let Some(policies) = policies_from_preset(policy) else { return 0 };
+    let handle = NEXT_CACHE_HANDLE.fetch_add(1, Ordering::Relaxed);
+    cache_handles().write().insert(handle, CachedCollector::new(new_collector(), policies));
+    handle
+}
+
+/// Destroy a cached collector created with `probe_cache_create`.
+///
+/// Returns `PROBE_ERR_INVALID_PARAM` if the handle is unknown.
+#[unsafe(no_mangle)]
+pub extern "C" fn probe_cache_destroy(handle: u64) -> ProbeResult {
+    match cache_handles().write().remove(&handle) {
+        Some(_) => ProbeResult::ok(),
+        None => ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"unknown cache handle".as_ptr()),
+    }
+}
+
+/// Run `collect` on the cached collector of `handle`.
+fn with_cache_handle(
+    handle: u64,
+    collect: impl FnOnce(&CachedCollector<PlatformCollector>) -> ProbeResult,
+) -> ProbeResult {
+    match cache_handles().read().get(&handle) {
+        Some(collector) => collect(collector),
+        None => ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"unknown cache handle".as_ptr()),
+    }
+}
+
+/// Collect system CPU metrics through the cache of `handle`.
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_cpu_cached_h(
+    handle: u64,
+    out: *mut SystemCPU,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    with_cache_handle(handle, |collector| match collector.cpu().collect_system() {
+        Ok(cpu) => {
+            unsafe { *out = SystemCPU::from(cpu) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    })
+}
+
+/// Collect system memory metrics through the cache of `handle`.
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_memory_cached_h(
+    handle: u64,
+    out: *mut SystemMemory,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    with_cache_handle(handle, |collector| match collector.memory().collect_system() {
+        Ok(mem) => {
+            unsafe { *out = SystemMemory::from(mem) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    })
+}
+
+/// Collect system load average through the cache of `handle`.
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_load_cached_h(
+    handle: u64,
+    out: *mut LoadAverage,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    with_cache_handle(handle, |collector| match collector.load().collect() {
+        Ok(load) => {
+            unsafe { *out = LoadAverage::from(load) };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    })
+}
+
+// ============================================================================
+// SYSTEM SUMMARY
+// ============================================================================
+
+/// Headline gauges for a compact status line.
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct SystemSummary {
+    /// CPU busy percentage (100 - idle).
+    pub cpu_percent: f64,
+    /// Memory used percentage.
+    pub memory_percent: f64,
+    /// 1-minute load average divided by online cores.
+    pub load_per_core: f64,
+    /// Used percentage of the root filesystem.
+    pub root_disk_percent: f64,
+    /// All tasks stalled on memory or I/O in the last 10s (PSI `full`).
+    /// False where PSI is unavailable.
+    pub under_pressure: bool,
+}
+
+fn collect_summary(collector: &dyn SystemCollector) -> probe_metrics::Result<SystemSummary> {
+    let cpu = collector.cpu().collect_system()?;
+    let memory = collector.memory().collect_system()?;
+    let load = collector.load().collect()?;
+    let root = collector.disk().collect_usage("/")?;
+
+    let is_full = |level: probe_metrics::Result<probe_metrics::PressureLevel>| {
+        matches!(level, Ok(probe_metrics::PressureLevel::Full))
+    };
+
+    Ok(SystemSummary {
+        cpu_percent: (100.0 - cpu.idle_percent).clamp(0.0, 100.0),
+        memory_percent: if memory.total_bytes == 0 {
+            0.0
+        } else {
+            memory.used_bytes as f64 / memory.total_bytes as f64 * 100.0
+        },
+        load_per_core: load.load_1min / cpu.cores.max(1) as f64,
+        root_disk_percent: root.used_percent,
+        under_pressure: is_full(collector.memory().pressure_level())
+            || is_full(collector.io().pressure_level()),
+    })
+}
+
+/// Collect the headline gauges in one call, using the cache when enabled.
+///
+/// # Safety
+/// The `out` pointer must be valid and properly aligned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_summary(out: *mut SystemSummary) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    let result = {
+        let guard = get_cached_collector().read();
+        match guard.as_ref() {
+            Some(cached) => collect_summary(cached),
+            None => match COLLECTOR.get() {
+                Some(c) => collect_summary(c),
+                None => return ProbeResult::err(PROBE_ERR_INTERNAL, c"not initialized".as_ptr()),
+            },
+        }
+    };
+
+    match result {
+        Ok(summary) => {
+            unsafe { *out = summary };
+            ProbeResult::ok()
+        }
+        Err(e) => ProbeResult::from_metrics_error(e),
+    }
+}
+
 // ============================================================================
 // NETWORK CONNECTIONS
 // ============================================================================
@@ -2434,9 +5747,9 @@ pub struct TcpConnection {
     pub process_name: [c_char; 64],
     /// Socket inode number.
     pub inode: u64,
-    /// Receive queue size.
+    /// Receive queue size (accept queue length for listeners).
     pub rx_queue: u32,
-    /// Transmit queue size.
+    /// Transmit queue size (maximum backlog for listeners, 0 if unknown).
     pub tx_queue: u32,
 }
 
@@ -2558,6 +5871,10 @@ pub struct UnixSocket {
     pub process_name: [c_char; 64],
     /// Socket inode number.
     pub inode: u64,
+    /// Process ID of the connected peer (-1 if unknown or unconnected).
+    pub peer_pid: i32,
+    /// User ID of the connected peer (0 if unknown).
+    pub peer_uid: u32,
 }
 
 impl Default for UnixSocket {
@@ -2569,6 +5886,8 @@ impl Default for UnixSocket {
             pid: -1,
             process_name: [0; 64],
             inode: 0,
+            peer_pid: -1,
+            peer_uid: 0,
         }
     }
 }
@@ -2582,6 +5901,8 @@ impl From<probe_metrics::UnixSocket> for UnixSocket {
         result.pid = s.pid;
         copy_str_to_carray(&s.process_name, &mut result.process_name);
         result.inode = s.inode;
+        result.peer_pid = s.peer_pid;
+        result.peer_uid = s.peer_uid;
         result
     }
 }
@@ -2632,6 +5953,71 @@ impl From<probe_metrics::TcpStats> for TcpStats {
     }
 }
 
+/// Transport protocol of a listening socket.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransportProtocol {
+    /// TCP.
+    #[default]
+    Tcp = 0,
+    /// UDP.
+    Udp = 1,
+}
+
+impl From<probe_metrics::TransportProtocol> for TransportProtocol {
+    fn from(p: probe_metrics::TransportProtocol) -> Self {
+        match p {
+            probe_metrics::TransportProtocol::Tcp => Self::Tcp,
+            probe_metrics::TransportProtocol::Udp => Self::Udp,
+        }
+    }
+}
+
+/// A socket accepting traffic on a local port.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ListeningPort {
+    /// Transport protocol.
+    pub protocol: TransportProtocol,
+    /// Address family (IPv4 or IPv6).
+    pub family: AddressFamily,
+    /// Local IP address the socket is bound to (null-terminated).
+    pub address: [c_char; MAX_ADDR_LEN],
+    /// Local port.
+    pub port: u16,
+    /// Process ID owning the socket (-1 if unknown).
+    pub pid: i32,
+    /// Process name (null-terminated, empty if unknown).
+    pub process_name: [c_char; 64],
+}
+
+impl Default for ListeningPort {
+    fn default() -> Self {
+        Self {
+            protocol: TransportProtocol::Tcp,
+            family: AddressFamily::IPv4,
+            address: [0; MAX_ADDR_LEN],
+            port: 0,
+            pid: -1,
+            process_name: [0; 64],
+        }
+    }
+}
+
+#[allow(clippy::field_reassign_with_default)]
+impl From<probe_metrics::ListeningPort> for ListeningPort {
+    fn from(p: probe_metrics::ListeningPort) -> Self {
+        let mut result = Self::default();
+        result.protocol = p.protocol.into();
+        result.family = p.family.into();
+        copy_str_to_carray(&p.address, &mut result.address);
+        result.port = p.port;
+        result.pid = p.pid;
+        copy_str_to_carray(&p.process_name, &mut result.process_name);
+        result
+    }
+}
+
 /// List of TCP connections.
 #[repr(C)]
 pub struct TcpConnectionList {
@@ -2648,28 +6034,102 @@ pub struct UdpConnectionList {
     pub capacity: usize,
 }
 
-/// List of Unix sockets.
-#[repr(C)]
-pub struct UnixSocketList {
-    pub items: *mut UnixSocket,
-    pub count: usize,
-    pub capacity: usize,
+/// List of Unix sockets.
+#[repr(C)]
+pub struct UnixSocketList {
+    pub items: *mut UnixSocket,
+    pub count: usize,
+    pub capacity: usize,
+}
+
+/// List of listening ports.
+#[repr(C)]
+pub struct ListeningPortList {
+    pub items: *mut ListeningPort,
+    pub count: usize,
+    pub capacity: usize,
+}
+
+/// Number of established TCP connections to a remote address.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct RemoteConnectionCount {
+    /// Remote IP address (null-terminated).
+    pub remote_addr: [c_char; MAX_ADDR_LEN],
+    /// Established connections to this address.
+    pub count: u32,
+}
+
+/// List of per-remote connection counts.
+#[repr(C)]
+pub struct RemoteConnectionCountList {
+    pub items: *mut RemoteConnectionCount,
+    pub count: usize,
+    pub capacity: usize,
+}
+
+/// Collect all TCP connections.
+///
+/// # Safety
+/// The `out` pointer must be valid. Caller must call `probe_free_tcp_connection_list` when done.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_tcp_connections(out: *mut TcpConnectionList) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        match probe_platform::linux::collect_tcp_connections() {
+            Ok(connections) => {
+                let mut items: Vec<TcpConnection> =
+                    connections.into_iter().map(|c| c.into()).collect();
+                let count = items.len();
+                let capacity = items.capacity();
+                let ptr = items.as_mut_ptr();
+                std::mem::forget(items);
+
+                unsafe {
+                    (*out).items = ptr;
+                    (*out).count = count;
+                    (*out).capacity = capacity;
+                }
+                ProbeResult::ok()
+            }
+            Err(e) => ProbeResult::from_metrics_error(e),
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        ProbeResult::err(
+            PROBE_ERR_NOT_SUPPORTED,
+            c"TCP connections not supported on this platform".as_ptr(),
+        )
+    }
 }
 
-/// Collect all TCP connections.
+/// Collect at most `limit` TCP connections.
+///
+/// `truncated` is set when connections beyond the limit were left out.
 ///
 /// # Safety
-/// The `out` pointer must be valid. Caller must call `probe_free_tcp_connection_list` when done.
+/// The `out` and `truncated` pointers must be valid. Caller must call
+/// `probe_free_tcp_connection_list` when done.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn probe_collect_tcp_connections(out: *mut TcpConnectionList) -> ProbeResult {
-    if out.is_null() {
+pub unsafe extern "C" fn probe_collect_tcp_connections_limited(
+    limit: usize,
+    out: *mut TcpConnectionList,
+    truncated: *mut bool,
+) -> ProbeResult {
+    if out.is_null() || truncated.is_null() {
         return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
     }
 
     #[cfg(target_os = "linux")]
     {
-        match probe_platform::linux::collect_tcp_connections() {
-            Ok(connections) => {
+        match probe_platform::linux::collect_tcp_connections_limited(limit) {
+            Ok((connections, more)) => {
                 let mut items: Vec<TcpConnection> =
                     connections.into_iter().map(|c| c.into()).collect();
                 let count = items.len();
@@ -2681,6 +6141,7 @@ pub unsafe extern "C" fn probe_collect_tcp_connections(out: *mut TcpConnectionLi
                     (*out).items = ptr;
                     (*out).count = count;
                     (*out).capacity = capacity;
+                    *truncated = more;
                 }
                 ProbeResult::ok()
             }
@@ -2690,6 +6151,7 @@ pub unsafe extern "C" fn probe_collect_tcp_connections(out: *mut TcpConnectionLi
 
     #[cfg(not(target_os = "linux"))]
     {
+        let _ = limit;
         ProbeResult::err(
             PROBE_ERR_NOT_SUPPORTED,
             c"TCP connections not supported on this platform".as_ptr(),
@@ -2700,7 +6162,8 @@ pub unsafe extern "C" fn probe_collect_tcp_connections(out: *mut TcpConnectionLi
 /// Free a TCP connection list.
 ///
 /// # Safety
-/// The list must have been allocated by `probe_collect_tcp_connections`.
+/// The list must have been allocated by `probe_collect_tcp_connections`
+/// or `probe_collect_tcp_connections_limited`.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn probe_free_tcp_connection_list(list: *mut TcpConnectionList) {
     if list.is_null() {
@@ -2906,3 +6369,733 @@ pub unsafe extern "C" fn probe_find_process_by_port(
         )
     }
 }
+
+/// Collect listening TCP ports and bound UDP sockets, one entry per
+/// (protocol, address, port).
+///
+/// # Safety
+/// The `out` pointer must be valid. Caller must call `probe_free_listening_port_list` when done.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_collect_listening_ports(out: *mut ListeningPortList) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        match probe_platform::linux::collect_listening_ports() {
+            Ok(ports) => {
+                let mut items: Vec<ListeningPort> = ports.into_iter().map(|p| p.into()).collect();
+                let count = items.len();
+                let capacity = items.capacity();
+                let ptr = items.as_mut_ptr();
+                std::mem::forget(items);
+
+                unsafe {
+                    (*out).items = ptr;
+                    (*out).count = count;
+                    (*out).capacity = capacity;
+                }
+                ProbeResult::ok()
+            }
+            Err(e) => ProbeResult::from_metrics_error(e),
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        ProbeResult::err(
+            PROBE_ERR_NOT_SUPPORTED,
+            c"listening ports not supported on this platform".as_ptr(),
+        )
+    }
+}
+
+/// Collect the remote addresses with the most established TCP connections.
+///
+/// Entries are sorted by descending count, then address; at most `top_n`
+/// are returned (all of them when `top_n` is 0).
+///
+/// # Safety
+/// The `out` pointer must be valid. Caller must call
+/// `probe_free_remote_connection_count_list` when done.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_tcp_connections_by_remote(
+    top_n: usize,
+    out: *mut RemoteConnectionCountList,
+) -> ProbeResult {
+    if out.is_null() {
+        return ProbeResult::err(PROBE_ERR_INVALID_PARAM, c"null pointer".as_ptr());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        use probe_metrics::ConnectionCollector;
+
+        match probe_platform::linux::LinuxConnectionCollector::new().tcp_connections_by_remote() {
+            Ok(counts) => {
+                let mut counts: Vec<(String, u32)> = counts.into_iter().collect();
+                counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+                if top_n > 0 {
+                    counts.truncate(top_n);
+                }
+
+                let mut items: Vec<RemoteConnectionCount> = counts
+                    .into_iter()
+                    .map(|(addr, count)| {
+                        let mut item =
+                            RemoteConnectionCount { remote_addr: [0; MAX_ADDR_LEN], count };
+                        copy_str_to_carray(&addr, &mut item.remote_addr);
+                        item
+                    })
+                    .collect();
+                let count = items.len();
+                let capacity = items.capacity();
+                let ptr = items.as_mut_ptr();
+                std::mem::forget(items);
+
+                unsafe {
+                    (*out).items = ptr;
+                    (*out).count = count;
+                    (*out).capacity = capacity;
+                }
+                ProbeResult::ok()
+            }
+            Err(e) => ProbeResult::from_metrics_error(e),
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = top_n;
+        ProbeResult::err(
+            PROBE_ERR_NOT_SUPPORTED,
+            c"TCP connections not supported on this platform".as_ptr(),
+        )
+    }
+}
+
+/// Free a per-remote connection count list.
+///
+/// # Safety
+/// The list must have been allocated by `probe_tcp_connections_by_remote`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_free_remote_connection_count_list(
+    list: *mut RemoteConnectionCountList,
+) {
+    if list.is_null() {
+        return;
+    }
+    unsafe {
+        let list = &mut *list;
+        if !list.items.is_null() && list.capacity > 0 {
+            drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
+            list.items = ptr::null_mut();
+            list.count = 0;
+            list.capacity = 0;
+        }
+    }
+}
+
+/// Free a listening port list.
+///
+/// # Safety
+/// The list must have been allocated by `probe_collect_listening_ports`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn probe_free_listening_port_list(list: *mut ListeningPortList) {
+    if list.is_null() {
+        return;
+    }
+    unsafe {
+        let list = &mut *list;
+        if !list.items.is_null() && list.capacity > 0 {
+            drop(Vec::from_raw_parts(list.items, list.count, list.capacity));
+            list.items = ptr::null_mut();
+            list.count = 0;
+            list.capacity = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+    use std::time::Instant;
+
+    #[test]
+    fn test_run_until_times_out_slow_work() {
+        let in_flight = probe_metrics::InFlight::new();
+        let started = Instant::now();
+        let deadline = SystemTime::now() + Duration::from_millis(20);
+
+        let result = run_until(&in_flight, deadline, || {
+            std::thread::sleep(Duration::from_millis(300));
+            Ok(42)
+        });
+
+        assert!(matches!(result, Err(probe_metrics::Error::Timeout)));
+        assert!(started.elapsed() < Duration::from_millis(200));
+
+        // The stuck worker is not joined by another one
+        let deadline = SystemTime::now() + Duration::from_secs(5);
+        let spawned = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&spawned);
+        let result = run_until(&in_flight, deadline, move || {
+            flag.store(true, Ordering::Relaxed);
+            Ok(1)
+        });
+        assert!(matches!(result, Err(probe_metrics::Error::Timeout)));
+        assert!(!spawned.load(Ordering::Relaxed));
+
+        while in_flight.contains("collect_all") {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(run_until(&in_flight, deadline, || Ok(2)).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_run_until_returns_fast_work() {
+        let in_flight = probe_metrics::InFlight::new();
+        let deadline = SystemTime::now() + Duration::from_secs(5);
+        assert_eq!(run_until(&in_flight, deadline, || Ok(42)).unwrap(), 42);
+
+        let past = SystemTime::now() - Duration::from_secs(1);
+        assert!(run_until(&in_flight, past, || Ok(42)).is_err());
+    }
+
+    #[test]
+    fn test_timed_out_collection_reports_timeout_code() {
+        let deadline = SystemTime::now() + Duration::from_millis(10);
+        let err = run_until(&probe_metrics::InFlight::new(), deadline, || {
+            std::thread::sleep(Duration::from_secs(1));
+            Ok(())
+        })
+        .unwrap_err();
+
+        let result = ProbeResult::from_metrics_error(err);
+        assert!(!result.success);
+        assert_eq!(result.error_code, PROBE_ERR_TIMEOUT);
+    }
+
+    #[test]
+    fn test_collect_processes_skips_missing_pids() {
+        assert!(probe_init().success);
+        let pids = [std::process::id() as i32, i32::MAX];
+        let mut out: Vec<ProcessMetrics> =
+            (0..pids.len()).map(|_| unsafe { std::mem::zeroed() }).collect();
+        let mut written = usize::MAX;
+
+        let result = unsafe {
+            probe_collect_processes(
+                pids.as_ptr(),
+                pids.len(),
+                out.as_mut_ptr(),
+                out.len(),
+                &mut written,
+            )
+        };
+
+        assert!(result.success);
+        assert_eq!(written, 1);
+        assert_eq!(out[0].pid, pids[0]);
+
+        let result = unsafe {
+            probe_collect_processes(pids.as_ptr(), pids.len(), out.as_mut_ptr(), 1, &mut written)
+        };
+        assert_eq!(result.error_code, PROBE_ERR_INVALID_PARAM);
+        assert_eq!(written, 0);
+    }
+
+    #[test]
+    fn test_collect_each_skips_unreadable_pids() {
+        let collected: Vec<i32> = collect_each(&[1, 2, 3, 4], |pid| match pid {
+            2 => Err(probe_metrics::Error::Permission(format!("cannot read pid {}", pid))),
+            3 => Err(probe_metrics::Error::NotFound(format!("process {} not found", pid))),
+            _ => Ok(probe_metrics::ProcessMetrics { pid, ..Default::default() }),
+        })
+        .map(|proc| proc.pid)
+        .collect();
+
+        assert_eq!(collected, vec![1, 4]);
+    }
+
+    /// One process whose PID can be handed to a new process.
+    struct ReusedPid {
+        start_time: AtomicU64,
+        cpu_time_us: AtomicU64,
+    }
+
+    impl ReusedPid {
+        fn reuse(&self, start_time: u64, cpu_time_us: u64) {
+            self.start_time.store(start_time, Ordering::Relaxed);
+            self.cpu_time_us.store(cpu_time_us, Ordering::Relaxed);
+        }
+    }
+
+    impl probe_metrics::ProcessCollector for ReusedPid {
+        fn collect(&self, pid: i32) -> probe_metrics::Result<probe_metrics::ProcessMetrics> {
+            let faults = self.cpu_time_us.load(Ordering::Relaxed);
+            Ok(probe_metrics::ProcessMetrics { pid, minor_faults: faults, ..Default::default() })
+        }
+
+        fn collect_all(&self) -> probe_metrics::Result<Vec<probe_metrics::ProcessMetrics>> {
+            Ok(vec![self.collect(42)?])
+        }
+
+        fn list_threads(&self, pid: i32) -> probe_metrics::Result<Vec<probe_metrics::ThreadInfo>> {
+            let user_time_us = self.cpu_time_us.load(Ordering::Relaxed);
+            Ok(vec![probe_metrics::ThreadInfo { tid: pid, user_time_us, ..Default::default() }])
+        }
+
+        fn identity(&self, pid: i32) -> probe_metrics::Result<probe_metrics::ProcessIdentity> {
+            let start_time = self.start_time.load(Ordering::Relaxed);
+            Ok(probe_metrics::ProcessIdentity { pid, name: "worker".to_string(), start_time })
+        }
+    }
+
+    #[test]
+    fn test_samplers_restart_on_pid_reuse() {
+        let process = ReusedPid { start_time: AtomicU64::new(100), cpu_time_us: AtomicU64::new(0) };
+        let mut faults =
+            PageFaultSampler { pid: 42, sampler: probe_metrics::PageFaultRateSampler::new() };
+        let mut threads =
+            ThreadSampler { pid: 42, sampler: probe_metrics::ThreadCpuSampler::new() };
+
+        assert!(!sample_page_faults(&mut faults, &process).unwrap().available);
+        sample_threads(&mut threads, &process).unwrap();
+        std::thread::sleep(Duration::from_millis(5));
+        process.reuse(100, 1_000);
+        assert!(sample_page_faults(&mut faults, &process).unwrap().available);
+        assert!(sample_threads(&mut threads, &process).unwrap()[0].cpu_percent > 0.0);
+
+        // A new process with the same PID and higher counters is not a delta
+        process.reuse(900, 50_000_000);
+        assert!(!sample_page_faults(&mut faults, &process).unwrap().available);
+        assert_eq!(sample_threads(&mut threads, &process).unwrap()[0].cpu_percent, 0.0);
+    }
+
+    #[test]
+    fn test_self_test_reports_cpu_and_memory_ok() {
+        assert!(probe_init().success);
+        let mut report = std::mem::MaybeUninit::<SelfTestReport>::uninit();
+
+        let result = unsafe { probe_self_test(report.as_mut_ptr()) };
+        let report = unsafe { report.assume_init() };
+
+        assert!(result.success);
+        assert_eq!(report.cpu.status, SubsystemStatus::Ok);
+        assert_eq!(report.memory.status, SubsystemStatus::Ok);
+        assert_eq!(report.process.status, SubsystemStatus::Ok);
+    }
+
+    #[test]
+    fn test_fork_rate_sampler_handle() {
+        assert!(probe_init().success);
+        let sampler = probe_fork_rate_sampler_new();
+        let mut rate = ForkRate::default();
+
+        let first = unsafe { probe_fork_rate_sample(sampler, &mut rate) };
+        #[cfg(target_os = "linux")]
+        {
+            assert!(first.success);
+            assert!(!rate.available);
+            assert!(unsafe { probe_fork_rate_sample(sampler, &mut rate) }.success);
+            assert!(rate.available);
+            assert!(rate.forks_per_sec >= 0.0);
+        }
+        #[cfg(not(target_os = "linux"))]
+        assert_eq!(first.error_code, PROBE_ERR_NOT_SUPPORTED);
+
+        unsafe { probe_fork_rate_sampler_free(sampler) };
+    }
+
+    #[test]
+    fn test_page_fault_sampler_handle() {
+        assert!(probe_init().success);
+        let sampler = probe_page_fault_sampler_new(std::process::id() as i32);
+        let mut rate = PageFaultRate::default();
+
+        assert!(unsafe { probe_page_fault_sample(sampler, &mut rate) }.success);
+        assert!(!rate.available);
+        assert!(unsafe { probe_page_fault_sample(sampler, &mut rate) }.success);
+        assert!(rate.available);
+        assert!(rate.major_per_sec >= 0.0);
+
+        unsafe { probe_page_fault_sampler_free(sampler) };
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_thread_sampler_handle() {
+        assert!(probe_init().success);
+        let sampler = probe_thread_sampler_new(std::process::id() as i32);
+        let mut list = ThreadInfoList { items: ptr::null_mut(), count: 0, capacity: 0 };
+
+        for _ in 0..2 {
+            assert!(unsafe { probe_thread_sample(sampler, &mut list) }.success);
+            let threads = unsafe { std::slice::from_raw_parts(list.items, list.count) };
+            assert!(!threads.is_empty());
+            assert!(threads.iter().all(|t| t.tid > 0 && t.cpu_percent >= 0.0));
+            unsafe { probe_free_thread_info_list(&mut list) };
+        }
+
+        unsafe { probe_thread_sampler_free(sampler) };
+    }
+
+    #[test]
+    fn test_pressure_supported_matches_collection() {
+        assert!(probe_init().success);
+        let mut pressure = std::mem::MaybeUninit::<CPUPressure>::uninit();
+        let result = unsafe { probe_collect_cpu_pressure(pressure.as_mut_ptr()) };
+        if !probe_pressure_supported() {
+            assert!(!result.success);
+        }
+    }
+
+    #[test]
+    fn test_pressure_level_rejects_unknown_resource() {
+        assert!(probe_init().success);
+        let mut level = PressureLevel::default();
+
+        let result = unsafe { probe_pressure_level(7, &mut level) };
+        assert_eq!(result.error_code, PROBE_ERR_INVALID_PARAM);
+    }
+
+    #[test]
+    fn test_summary_fields_in_plausible_ranges() {
+        assert!(probe_init().success);
+        let mut summary = SystemSummary::default();
+
+        let result = unsafe { probe_collect_summary(&mut summary) };
+
+        assert!(result.success);
+        assert!((0.0..=100.0).contains(&summary.cpu_percent));
+        assert!(summary.memory_percent > 0.0 && summary.memory_percent <= 100.0);
+        assert!(summary.load_per_core >= 0.0);
+        assert!((0.0..=100.0).contains(&summary.root_disk_percent));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_collect_zone_summary() {
+        probe_init();
+
+        let mut summary = std::mem::MaybeUninit::<MemoryZoneSummary>::uninit();
+        let result = unsafe { probe_collect_zone_summary(summary.as_mut_ptr()) };
+        assert!(result.success);
+
+        let summary = unsafe { summary.assume_init() };
+        assert!(summary.zone_count > 0);
+        assert!(summary.zones_below_low <= summary.zone_count);
+        assert!(summary.low_watermark >= summary.min_watermark);
+        assert!(summary.high_watermark >= summary.low_watermark);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_collect_process_oom_info() {
+        probe_init();
+
+        let mut info = OomInfo::default();
+        let result =
+            unsafe { probe_collect_process_oom_info(std::process::id() as i32, &mut info) };
+        assert!(result.success);
+        assert!((0..=2000).contains(&info.oom_score));
+        assert!((-1000..=1000).contains(&info.oom_score_adj));
+
+        let result = unsafe { probe_collect_process_oom_info(i32::MAX, &mut info) };
+        assert!(!result.success);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_collect_process_capabilities() {
+        probe_init();
+
+        let mut caps = ProcessCapabilities::default();
+        let result =
+            unsafe { probe_collect_process_capabilities(std::process::id() as i32, &mut caps) };
+        assert!(result.success);
+        assert_eq!(caps.effective & !caps.permitted, 0);
+        assert_ne!(caps.bounding, 0);
+
+        let result = unsafe { probe_collect_process_capabilities(1, std::ptr::null_mut()) };
+        assert!(!result.success);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_inotify_stats() {
+        probe_init();
+
+        let mut stats = InotifyStats::default();
+        let result = unsafe { probe_inotify_stats(&mut stats) };
+        assert!(result.success);
+        assert!(stats.max_user_instances > 0);
+        assert!(stats.max_user_watches > 0);
+
+        let result = unsafe { probe_inotify_stats(std::ptr::null_mut()) };
+        assert_eq!(result.error_code, PROBE_ERR_INVALID_PARAM);
+    }
+
+    #[test]
+    fn test_list_kernel_modules() {
+        probe_init();
+
+        let mut list = KernelModuleList { items: ptr::null_mut(), count: 0, capacity: 0 };
+        let result = unsafe { probe_list_kernel_modules(&mut list) };
+        if result.success {
+            let modules = unsafe { std::slice::from_raw_parts(list.items, list.count) };
+            assert!(modules.iter().all(|m| m.name[0] != 0));
+            unsafe { probe_free_kernel_module_list(&mut list) };
+            assert_eq!(list.count, 0);
+        } else {
+            assert_eq!(result.error_code, PROBE_ERR_NOT_SUPPORTED);
+        }
+    }
+
+    #[test]
+    fn test_kernel_error_summary() {
+        probe_init();
+
+        let mut summary = std::mem::MaybeUninit::<KernelErrorSummary>::uninit();
+        let result = unsafe { probe_kernel_error_summary(0, summary.as_mut_ptr()) };
+        if result.success {
+            let summary = unsafe { summary.assume_init() };
+            assert!(summary.oom_kills <= summary.error_count);
+            assert!(summary.io_errors <= summary.error_count);
+        } else {
+            assert!(matches!(result.error_code, PROBE_ERR_PERMISSION | PROBE_ERR_NOT_SUPPORTED));
+        }
+    }
+
+    #[test]
+    fn test_collect_all_binary() {
+        probe_init();
+
+        let mut written = 0;
+        let result = unsafe { probe_collect_all_binary(std::ptr::null_mut(), 0, &mut written) };
+        assert_eq!(result.error_code, PROBE_ERR_INVALID_PARAM);
+        assert!(written > 1);
+
+        let mut buf = vec![0u8; written + 1024];
+        let result = unsafe { probe_collect_all_binary(buf.as_mut_ptr(), buf.len(), &mut written) };
+        assert!(result.success);
+        assert_eq!(buf[0], PROBE_BINARY_FORMAT_VERSION);
+        let metrics = probe_metrics::binary::decode(&buf[..written]).unwrap();
+        assert!(metrics.timestamp_us > 0);
+    }
+
+    #[test]
+    fn test_cache_handles_are_independent() {
+        let slow = probe_cache_create(2); // 1s CPU TTL
+        let uncached = probe_cache_create(3);
+        assert!(slow != 0 && uncached != 0 && slow != uncached);
+        assert_eq!(probe_cache_create(42), 0);
+
+        let cpu_age =
+            |handle: u64| cache_handles().read()[&handle].entry_age(MetricType::CpuSystem).unwrap();
+
+        let mut cpu = std::mem::MaybeUninit::<SystemCPU>::uninit();
+        for handle in [slow, uncached] {
+            assert!(unsafe { probe_collect_cpu_cached_h(handle, cpu.as_mut_ptr()) }.success);
+        }
+        std::thread::sleep(Duration::from_millis(20));
+        for handle in [slow, uncached] {
+            assert!(unsafe { probe_collect_cpu_cached_h(handle, cpu.as_mut_ptr()) }.success);
+        }
+
+        // Hit on the slow handle keeps the first value, miss on the uncached one refreshes it
+        assert!(cpu_age(slow) >= Duration::from_millis(20));
+        assert!(cpu_age(uncached) < Duration::from_millis(20));
+
+        assert!(probe_cache_destroy(slow).success);
+        assert!(!probe_cache_destroy(slow).success);
+        let result = unsafe { probe_collect_cpu_cached_h(slow, cpu.as_mut_ptr()) };
+        assert_eq!(result.error_code, PROBE_ERR_INVALID_PARAM);
+        assert!(unsafe { probe_collect_cpu_cached_h(uncached, cpu.as_mut_ptr()) }.success);
+        assert!(probe_cache_destroy(uncached).success);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_root_filesystem_readonly() {
+        probe_init();
+
+        // The answer depends on the host (read-only rootfs containers exist)
+        let mut readonly = false;
+        let result = unsafe { probe_root_filesystem_readonly(&mut readonly) };
+        assert!(result.success);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_disk_queue_config_unknown_device() {
+        probe_init();
+
+        let mut config = DiskQueueConfig::from(probe_metrics::DiskQueueConfig::default());
+        let result = unsafe { probe_disk_queue_config(c"nosuchdisk".as_ptr(), &mut config) };
+        assert_eq!(result.error_code, PROBE_ERR_NOT_FOUND);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_mount_latency() {
+        probe_init();
+
+        let mut latency_us = u64::MAX;
+        let result = unsafe { probe_mount_latency(c"/".as_ptr(), &mut latency_us) };
+        assert!(result.success);
+        assert!(latency_us < 5_000_000);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_tcp_connections_by_remote_top_n() {
+        let mut list = RemoteConnectionCountList { items: ptr::null_mut(), count: 0, capacity: 0 };
+        let result = unsafe { probe_tcp_connections_by_remote(3, &mut list) };
+        assert!(result.success);
+        assert!(list.count <= 3);
+
+        let items = unsafe { std::slice::from_raw_parts(list.items, list.count) };
+        assert!(items.windows(2).all(|w| w[0].count >= w[1].count));
+        unsafe { probe_free_remote_connection_count_list(&mut list) };
+    }
+
+    #[test]
+    fn test_quota_self_cgroup_usage() {
+        let result = unsafe { probe_quota_self_cgroup_usage(std::ptr::null_mut()) };
+        assert_eq!(result.error_code, PROBE_ERR_INVALID_PARAM);
+
+        let mut usage = QuotaUsage::default();
+        let result = unsafe { probe_quota_self_cgroup_usage(&mut usage) };
+        if result.success {
+            assert!(usage.memory_bytes > 0);
+        } else {
+            assert_eq!(result.error_code, PROBE_ERR_NOT_SUPPORTED);
+        }
+    }
+
+    #[test]
+    fn test_sampler_collect_twice() {
+        probe_init();
+
+        let sampler = probe_sampler_new();
+        let mut delta = AllMetricsDelta::default();
+        let result = unsafe { probe_sampler_collect(sampler, &mut delta) };
+        assert!(result.success);
+        assert!(!delta.available);
+        assert!(delta.timestamp_us > 0);
+
+        std::thread::sleep(Duration::from_millis(20));
+        let result = unsafe { probe_sampler_collect(sampler, &mut delta) };
+        assert!(result.success);
+        assert!(delta.available);
+        assert!(delta.interval_us >= 20_000);
+        assert!(delta.read_bytes_per_sec >= 0.0 && delta.read_bytes_per_sec.is_finite());
+        assert!(delta.net_count as usize <= MAX_ALL_METRICS_ITEMS);
+        for rate in &delta.net[..delta.net_count as usize] {
+            assert!(rate.rx_bytes_per_sec >= 0.0 && rate.tx_bytes_per_sec >= 0.0);
+        }
+        for rate in &delta.disk_io[..delta.disk_io_count as usize] {
+            assert!((0.0..=100.0).contains(&rate.utilization_percent));
+        }
+
+        unsafe { probe_sampler_free(sampler) };
+    }
+
+    #[test]
+    fn test_collect_all_sampled() {
+        probe_init();
+
+        let mut delta = AllMetricsDelta::default();
+        let result = unsafe { probe_collect_all_sampled(50, &mut delta) };
+        assert!(result.success);
+        assert!(delta.available);
+        assert!(delta.interval_us >= 50_000);
+        assert!(delta.timestamp_us > 0);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_time_info() {
+        probe_init();
+
+        let mut info = std::mem::MaybeUninit::<TimeInfo>::uninit();
+        let result = unsafe { probe_time_info(info.as_mut_ptr()) };
+        assert!(result.success);
+        let info = unsafe { info.assume_init() };
+        assert!((-1..=1).contains(&info.ntp_synchronized));
+        assert!(info.utc_offset_secs.abs() <= 14 * 3600);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_collect_vmstat() {
+        probe_init();
+
+        let mut summary = VmStatSummary::default();
+        let result = unsafe { probe_collect_vmstat(&mut summary) };
+        assert!(result.success);
+        assert!(summary.page_faults > 0);
+        assert!(summary.major_page_faults <= summary.page_faults);
+    }
+
+    #[test]
+    fn test_hostname_matches_gethostname() {
+        let mut expected = [0 as c_char; 256];
+        assert_eq!(unsafe { libc::gethostname(expected.as_mut_ptr(), expected.len()) }, 0);
+
+        let mut buf = [0 as c_char; 256];
+        let result = unsafe { probe_get_hostname(buf.as_mut_ptr(), buf.len()) };
+        assert!(result.success);
+        let (got, want) = unsafe {
+            (std::ffi::CStr::from_ptr(buf.as_ptr()), std::ffi::CStr::from_ptr(expected.as_ptr()))
+        };
+        assert_eq!(got, want);
+
+        let mut small = [0 as c_char; 1];
+        let result = unsafe { probe_get_hostname(small.as_mut_ptr(), small.len()) };
+        assert_eq!(result.error_code, PROBE_ERR_INVALID_PARAM);
+    }
+
+    #[test]
+    fn test_machine_id_fills_buffer() {
+        let mut buf = [1 as c_char; 128];
+        let result = unsafe { probe_get_machine_id(buf.as_mut_ptr(), buf.len()) };
+        assert!(result.success);
+        assert!(buf.contains(&0));
+    }
+
+    unsafe extern "C" fn ignore_link_event(
+        _event: *const LinkEvent,
+        _user_data: *mut std::ffi::c_void,
+    ) {
+    }
+
+    #[test]
+    fn test_link_watch_start_and_stop() {
+        let result = unsafe { probe_link_watch_start(None, ptr::null_mut()) };
+        assert_eq!(result.error_code, PROBE_ERR_INVALID_PARAM);
+
+        let result = unsafe { probe_link_watch_start(Some(ignore_link_event), ptr::null_mut()) };
+        #[cfg(target_os = "linux")]
+        assert!(result.success);
+        #[cfg(not(target_os = "linux"))]
+        assert_eq!(result.error_code, PROBE_ERR_NOT_SUPPORTED);
+
+        assert!(probe_link_watch_stop().success);
+        assert!(probe_link_watch_stop().success);
+    }
+
+    #[test]
+    fn test_wireless_on_wired_interface_is_not_supported() {
+        let mut info = WirelessInfo::default();
+        let result = unsafe { probe_collect_wireless(c"lo".as_ptr(), &mut info) };
+
+        assert!(!result.success);
+        assert_eq!(result.error_code, PROBE_ERR_NOT_SUPPORTED);
+    }
+}
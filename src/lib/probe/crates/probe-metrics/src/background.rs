@@ -0,0 +1,156 @@
+//! Registry of the library's background threads.
+//!
+//! Watchers and other long-running helpers start their thread with
+//! [`spawn_background`], which hands the thread a stop channel and records
+//! its `JoinHandle`. [`shutdown_background`] stops and joins every registered
+//! thread, so a host unloading the library does not leave threads behind.
+//!
+//! Workers started by [`run_with_timeout`](crate::run_with_timeout) are not
+//! registered: they cannot be stopped, and one blocked on a hung mount would
+//! make shutdown hang too. Such a worker outlives shutdown until its call
+//! returns.
+
+use crate::{Error, Result};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Mutex, MutexGuard};
+use std::thread::JoinHandle;
+
+/// Stop channel and handle of each running background thread, by ID.
+type Registry = BTreeMap<u64, (Sender<()>, JoinHandle<()>)>;
+
+static REGISTRY: Mutex<Registry> = Mutex::new(BTreeMap::new());
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Set while [`shutdown_background`] runs; new threads are refused meanwhile.
+/// Only changed and checked with the registry lock held, so a thread is
+/// either refused or registered before shutdown takes the registry.
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+fn registry() -> MutexGuard<'static, Registry> {
+    REGISTRY.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// A registered background thread.
+///
+/// Dropping it stops the thread and waits for it, unless
+/// [`shutdown_background`] already did.
+#[derive(Debug)]
+pub struct BackgroundThread {
+    id: u64,
+}
+
+impl BackgroundThread {
+    /// Whether the thread is still registered and has not returned.
+    pub fn is_running(&self) -> bool {
+        registry().get(&self.id).is_some_and(|(_, handle)| !handle.is_finished())
+    }
+}
+
+impl Drop for BackgroundThread {
+    fn drop(&mut self) {
+        let Some((stop, handle)) = registry().remove(&self.id) else { return };
+        // Disconnecting the channel is the stop signal
+        drop(stop);
+        // A thread dropping its own handle cannot wait for itself
+        if handle.thread().id() != std::thread::current().id() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Start a named background thread and register it for shutdown.
+///
+/// `run` must return once the receiver reports a disconnect, typically by
+/// sleeping with `recv_timeout` between iterations. Returns `Error::Platform`
+/// while [`shutdown_background`] is in progress.
+pub fn spawn_background(
+    name: &str,
+    run: impl FnOnce(Receiver<()>) + Send + 'static,
+) -> Result<BackgroundThread> {
+    let mut threads = registry();
+    if SHUTTING_DOWN.load(Ordering::Acquire) {
+        return Err(Error::Platform("library is shutting down".to_string()));
+    }
+
+    let (stop, stop_rx) = mpsc::channel();
+    let handle = std::thread::Builder::new()
+        .name(name.to_string())
+        .spawn(move || run(stop_rx))
+        .map_err(Error::Io)?;
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    threads.insert(id, (stop, handle));
+    Ok(BackgroundThread { id })
+}
+
+/// Stop every registered background thread and wait for them to exit.
+///
+/// Returns the number of threads joined. Handles of the stopped threads
+/// remain valid and dropping them afterwards is a no-op. Must not be called
+/// from a background thread.
+pub fn shutdown_background() -> usize {
+    // Join outside the lock: exiting threads may drop other handles
+    let threads = {
+        let mut threads = registry();
+        SHUTTING_DOWN.store(true, Ordering::Release);
+        std::mem::take(&mut *threads)
+    };
+    let count = threads.len();
+    let handles: Vec<JoinHandle<()>> = threads
+        .into_values()
+        .map(|(stop, handle)| {
+            // Signal every thread first so they wind down concurrently
+            drop(stop);
+            handle
+        })
+        .collect();
+    for handle in handles {
+        let _ = handle.join();
+    }
+    let _threads = registry();
+    SHUTTING_DOWN.store(false, Ordering::Release);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_shutdown_joins_running_threads() {
+        let exited = Arc::new(AtomicBool::new(false));
+        let poller = {
+            let exited = exited.clone();
+            spawn_background("probe-test-poller", move |stop| {
+                while let Err(mpsc::RecvTimeoutError::Timeout) =
+                    stop.recv_timeout(Duration::from_millis(10))
+                {}
+                exited.store(true, Ordering::Release);
+            })
+            .unwrap()
+        };
+        assert!(poller.is_running());
+
+        assert!(shutdown_background() >= 1);
+        assert!(exited.load(Ordering::Acquire));
+        assert!(!poller.is_running());
+        // Dropping a handle after shutdown is a no-op
+        drop(poller);
+
+        let stopped = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let stopped = stopped.clone();
+            spawn_background("probe-test-worker", move |stop| {
+                let _ = stop.recv();
+                stopped.store(true, Ordering::Release);
+            })
+            .unwrap()
+        };
+        drop(thread);
+        assert!(stopped.load(Ordering::Acquire));
+    }
+}
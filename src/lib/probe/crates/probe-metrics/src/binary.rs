@@ -0,0 +1,111 @@
+//! Compact binary encoding of [`AllMetrics`] for IPC.
+//!
+//! An encoded snapshot is one format version byte followed by the
+//! [postcard](https://docs.rs/postcard) encoding of `AllMetrics`, which
+//! writes fields in declaration order with variable-length integers.
+//! Readers must check the version byte: any change to `AllMetrics` or the
+//! types it contains bumps [`FORMAT_VERSION`].
+
+use crate::{AllMetrics, Error, Result};
+
+/// Version of the encoding produced by [`encode`].
+pub const FORMAT_VERSION: u8 = 2;
+
+/// Encode a metrics snapshot.
+pub fn encode(metrics: &AllMetrics) -> Vec<u8> {
+    // Serializing plain structs into a growable buffer cannot fail
+    postcard::to_extend(metrics, vec![FORMAT_VERSION]).expect("AllMetrics is serializable")
+}
+
+/// Decode a snapshot produced by [`encode`].
+///
+/// Returns `Error::Platform` if the version byte is not [`FORMAT_VERSION`]
+/// or the payload is malformed.
+pub fn decode(bytes: &[u8]) -> Result<AllMetrics> {
+    match bytes.split_first() {
+        Some((&FORMAT_VERSION, payload)) => postcard::from_bytes(payload)
+            .map_err(|e| Error::Platform(format!("invalid metrics encoding: {}", e))),
+        Some((version, _)) => {
+            Err(Error::Platform(format!("unsupported metrics encoding version {}", version)))
+        }
+        None => Err(Error::Platform("empty metrics encoding".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AllPressure, DiskUsage, NetStats, Partition, SystemCPU, SystemMemory};
+
+    fn sample() -> AllMetrics {
+        AllMetrics {
+            cpu: SystemCPU {
+                user_percent: 12.5,
+                idle_percent: 80.0,
+                cores: 8,
+                ..Default::default()
+            },
+            memory: SystemMemory {
+                total_bytes: 16 << 30,
+                available_bytes: 6 << 30,
+                slab_reclaimable_bytes: 512 << 20,
+                ..Default::default()
+            },
+            partitions: vec![Partition {
+                device: "/dev/sda1".to_string(),
+                mount_point: "/".to_string(),
+                fs_type: "ext4".to_string(),
+                options: "rw,relatime".to_string(),
+            }],
+            disk_usage: vec![DiskUsage {
+                path: "/".to_string(),
+                total_bytes: 100 << 30,
+                used_bytes: 40 << 30,
+                ..Default::default()
+            }],
+            net_stats: vec![NetStats {
+                interface: "eth0".to_string(),
+                rx_bytes: 123_456_789,
+                tx_bytes: 987_654,
+                ..Default::default()
+            }],
+            pressure: Some(AllPressure::default()),
+            timestamp_us: 1_700_000_000_000_000,
+            collection_duration_us: 850,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_round_trip_is_smaller_than_json() {
+        let metrics = sample();
+        let bytes = encode(&metrics);
+        assert_eq!(bytes[0], FORMAT_VERSION);
+
+        let decoded = decode(&bytes).unwrap();
+        assert_eq!(format!("{:?}", decoded), format!("{:?}", metrics));
+
+        let json = serde_json::to_vec(&metrics).unwrap();
+        assert!(bytes.len() * 2 < json.len(), "{} bytes vs {} JSON", bytes.len(), json.len());
+    }
+
+    #[test]
+    fn test_decode_rejects_unknown_version() {
+        let mut bytes = encode(&sample());
+        bytes[0] = FORMAT_VERSION + 1;
+        assert!(matches!(decode(&bytes), Err(Error::Platform(_))));
+        assert!(matches!(decode(&[]), Err(Error::Platform(_))));
+        assert!(matches!(decode(&bytes[..1]), Err(Error::Platform(_))));
+    }
+
+    #[test]
+    fn test_decode_rejects_version_1_snapshots() {
+        // Version 1 predates `SystemMemory::slab_reclaimable_bytes`
+        let mut bytes = encode(&sample());
+        bytes[0] = 1;
+        assert!(matches!(decode(&bytes), Err(Error::Platform(_))));
+
+        bytes[0] = FORMAT_VERSION;
+        assert_eq!(decode(&bytes).unwrap().memory.slab_reclaimable_bytes, 512 << 20);
+    }
+}
@@ -0,0 +1,27 @@
+//! Composable collector wrappers.
+//!
+//! A decorator owns another `SystemCollector`, implements `SystemCollector`
+//! itself by delegating every method (including the optional ones) to it,
+//! and exposes the wrapped collector through [`CollectorDecorator::inner`].
+//! Wrappers take any `T: SystemCollector + 'static`, so they stack freely:
+//!
+//! ```ignore
+//! let collector = RetryCollector::new(CachedCollector::with_defaults(new_collector()), 3);
+//! let base = collector.inner().inner();
+//! ```
+//!
+//! Recommended order, outermost first: retry, cache, platform collector.
+//! The cache sits closest to the platform so hits never pay for retries.
+//! Deadlines are not a decorator: bound a blocking call with
+//! [`run_with_timeout`](crate::run_with_timeout) around the stack instead.
+
+use crate::SystemCollector;
+
+/// A `SystemCollector` that wraps and delegates to another one.
+pub trait CollectorDecorator: SystemCollector {
+    /// The wrapped collector type.
+    type Inner: SystemCollector;
+
+    /// Get the wrapped collector.
+    fn inner(&self) -> &Self::Inner;
+}
@@ -3,6 +3,35 @@
 //! This crate defines the interfaces for system metrics collection
 //! that are implemented by platform-specific code.
 
+mod background;
+#[cfg(feature = "binary")]
+pub mod binary;
+mod decorator;
+mod process_watch;
+mod rate;
+mod retry;
+mod saturation;
+mod stats;
+mod timeout;
+mod trend;
+
+pub use background::{BackgroundThread, shutdown_background, spawn_background};
+pub use decorator::CollectorDecorator;
+pub use process_watch::{DEFAULT_PROCESS_POLL_INTERVAL, ProcessFilter, ProcessWatcher};
+pub use rate::{
+    CpuSampler, DiskIORate, DiskIORateSampler, ForkRateSampler, MetricsDelta, MetricsDeltaSampler,
+    NetRate, PageFaultRate, PageFaultRateSampler, PressureRate, PressureRateSampler, ReclaimRate,
+    ReclaimRateSampler, ThreadCpuSampler,
+};
+pub use retry::RetryCollector;
+pub use saturation::{SATURATION_SAMPLE_WINDOW, SaturationScore, SaturationWeights};
+pub use stats::RollingStats;
+pub use timeout::{DEFAULT_MOUNT_PROBE_TIMEOUT, InFlight, run_with_timeout};
+pub use trend::DiskTrend;
+
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
 use thiserror::Error;
 
 /// Error types for metrics collection.
@@ -27,6 +56,10 @@ pub enum Error {
     /// Platform-specific error.
     #[error("platform error: {0}")]
     Platform(String),
+
+    /// Operation did not complete before its deadline.
+    #[error("operation timed out")]
+    Timeout,
 }
 
 /// Result type alias for metrics operations.
@@ -37,7 +70,11 @@ pub type Result<T> = std::result::Result<T, Error>;
 // ============================================================================
 
 /// System CPU metrics.
+///
+/// Percentages are averages since boot. For current usage, feed successive
+/// [`CPUCollector::cpu_ticks`] readings to a [`CpuSampler`].
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SystemCPU {
     /// User CPU percentage (0-100).
     pub user_percent: f64,
@@ -49,14 +86,159 @@ pub struct SystemCPU {
     pub iowait_percent: f64,
     /// Steal percentage (VMs only, 0 otherwise).
     pub steal_percent: f64,
-    /// Number of CPU cores.
+    /// Number of online CPU cores (same as `cores_online`).
     pub cores: u32,
     /// CPU frequency in MHz.
     pub frequency_mhz: u64,
+    /// Time spent running guest vCPUs (Linux hypervisors only, 0 otherwise).
+    ///
+    /// Already included in `user_percent`.
+    pub guest_percent: f64,
+    /// Time spent running niced guest vCPUs (Linux only, 0 otherwise).
+    pub guest_nice_percent: f64,
+    /// Number of CPU cores physically present, including offline ones.
+    pub cores_present: u32,
+    /// Number of CPU cores online and available to the scheduler.
+    pub cores_online: u32,
+    /// Number of physical cores, counting SMT siblings once.
+    ///
+    /// Equals `cores` where the platform does not report core topology.
+    pub physical_cores: u32,
+}
+
+/// Cumulative CPU time of all cores since boot, in platform ticks.
+///
+/// States a platform does not account stay 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CpuTicks {
+    /// Time running user code.
+    pub user: u64,
+    /// Time running niced user code.
+    pub nice: u64,
+    /// Time running kernel code.
+    pub system: u64,
+    /// Idle time.
+    pub idle: u64,
+    /// Idle time with I/O outstanding.
+    pub iowait: u64,
+    /// Time servicing hardware interrupts.
+    pub irq: u64,
+    /// Time servicing softirqs.
+    pub softirq: u64,
+    /// Time stolen by the hypervisor.
+    pub steal: u64,
+    /// Time running guest vCPUs, already counted in `user`.
+    pub guest: u64,
+    /// Time running niced guest vCPUs, already counted in `nice`.
+    pub guest_nice: u64,
+}
+
+impl CpuTicks {
+    /// Sum of all states; guest time is part of user and nice.
+    pub fn total(&self) -> u64 {
+        self.user
+            + self.nice
+            + self.system
+            + self.idle
+            + self.iowait
+            + self.irq
+            + self.softirq
+            + self.steal
+    }
+
+    /// Ticks accumulated between `prev` and this reading.
+    ///
+    /// A counter that went backwards (Linux iowait can) counts as 0.
+    pub fn since(&self, prev: &Self) -> Self {
+        let delta = |curr: u64, prev: u64| curr.saturating_sub(prev);
+        Self {
+            user: delta(self.user, prev.user),
+            nice: delta(self.nice, prev.nice),
+            system: delta(self.system, prev.system),
+            idle: delta(self.idle, prev.idle),
+            iowait: delta(self.iowait, prev.iowait),
+            irq: delta(self.irq, prev.irq),
+            softirq: delta(self.softirq, prev.softirq),
+            steal: delta(self.steal, prev.steal),
+            guest: delta(self.guest, prev.guest),
+            guest_nice: delta(self.guest_nice, prev.guest_nice),
+        }
+    }
+
+    /// Share of each state in these ticks, `None` if no tick is accounted.
+    pub fn usage(&self) -> Option<CpuUsage> {
+        let total = self.total();
+        if total == 0 {
+            return None;
+        }
+        let percent = |ticks: u64| ticks as f64 / total as f64 * 100.0;
+        Some(CpuUsage {
+            user_percent: percent(self.user + self.nice),
+            system_percent: percent(self.system + self.irq + self.softirq),
+            idle_percent: percent(self.idle),
+            iowait_percent: percent(self.iowait),
+            steal_percent: percent(self.steal),
+            guest_percent: percent(self.guest),
+            guest_nice_percent: percent(self.guest_nice),
+        })
+    }
+}
+
+/// CPU usage over an interval, computed by a [`CpuSampler`].
+///
+/// Fields have the meaning of their [`SystemCPU`] counterparts.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CpuUsage {
+    /// User CPU percentage, including nice (0-100).
+    pub user_percent: f64,
+    /// System CPU percentage, including interrupts (0-100).
+    pub system_percent: f64,
+    /// Idle CPU percentage (0-100).
+    pub idle_percent: f64,
+    /// I/O wait percentage (0-100).
+    pub iowait_percent: f64,
+    /// Steal percentage (0-100).
+    pub steal_percent: f64,
+    /// Guest percentage, included in `user_percent`.
+    pub guest_percent: f64,
+    /// Niced guest percentage, included in `user_percent`.
+    pub guest_nice_percent: f64,
+}
+
+impl CpuUsage {
+    /// Overwrite the percentages of `cpu` with this usage.
+    pub fn apply_to(&self, cpu: &mut SystemCPU) {
+        cpu.user_percent = self.user_percent;
+        cpu.system_percent = self.system_percent;
+        cpu.idle_percent = self.idle_percent;
+        cpu.iowait_percent = self.iowait_percent;
+        cpu.steal_percent = self.steal_percent;
+        cpu.guest_percent = self.guest_percent;
+        cpu.guest_nice_percent = self.guest_nice_percent;
+    }
+}
+
+/// CPU usage of a single core.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoreUsage {
+    /// Core ID (N in the kernel's `cpuN` naming).
+    pub core_id: u32,
+    /// User CPU percentage (0-100).
+    pub user_percent: f64,
+    /// System CPU percentage (0-100).
+    pub system_percent: f64,
+    /// Idle CPU percentage (0-100).
+    pub idle_percent: f64,
+    /// I/O wait percentage (0-100).
+    pub iowait_percent: f64,
+    /// Steal percentage (VMs only, 0 otherwise).
+    pub steal_percent: f64,
 }
 
 /// Load average (Unix systems).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LoadAverage {
     /// 1-minute load average.
     pub load_1min: f64,
@@ -69,6 +251,7 @@ pub struct LoadAverage {
 /// CPU pressure metrics (PSI - Pressure Stall Information).
 /// Available on Linux 4.20+ via /proc/pressure/cpu.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CPUPressure {
     /// Percentage of time some tasks were stalled (10s average).
     pub some_avg10: f64,
@@ -80,12 +263,129 @@ pub struct CPUPressure {
     pub some_total_us: u64,
 }
 
+/// Severity of a pressure stall, derived from the 10s averages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PressureLevel {
+    /// No task was stalled.
+    #[default]
+    None,
+    /// Some tasks were stalled (`some_avg10 > 0`).
+    Some,
+    /// All non-idle tasks were stalled at once (`full_avg10 > 0`).
+    Full,
+}
+
+impl PressureLevel {
+    /// Classify from the `some` and `full` 10s averages.
+    pub fn from_avg10(some_avg10: f64, full_avg10: f64) -> Self {
+        if full_avg10 > 0.0 {
+            Self::Full
+        } else if some_avg10 > 0.0 {
+            Self::Some
+        } else {
+            Self::None
+        }
+    }
+}
+
+impl CPUPressure {
+    /// Current stall level; CPU pressure reports no `full` line here.
+    pub fn level(&self) -> PressureLevel {
+        PressureLevel::from_avg10(self.some_avg10, 0.0)
+    }
+}
+
+/// Mitigation status of a CPU vulnerability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CpuVulnStatus {
+    /// The CPU is not affected.
+    NotAffected,
+    /// The CPU is affected and a mitigation is active.
+    Mitigated,
+    /// The CPU is affected and not (fully) mitigated.
+    Vulnerable,
+    /// Unrecognized status string.
+    #[default]
+    Unknown,
+}
+
+impl CpuVulnStatus {
+    /// Classify a kernel status line (e.g. "Mitigation: PTI").
+    ///
+    /// Some reports carry a subsystem prefix (e.g. "KVM: Mitigation: VMX
+    /// disabled"); a single leading `<word>: ` is skipped when the line is
+    /// not recognized as is.
+    pub fn classify(status: &str) -> Self {
+        let status = status.trim();
+        match Self::classify_bare(status) {
+            Self::Unknown => match status.split_once(": ") {
+                Some((prefix, rest)) if !prefix.is_empty() && !prefix.contains(' ') => {
+                    Self::classify_bare(rest.trim_start())
+                }
+                _ => Self::Unknown,
+            },
+            known => known,
+        }
+    }
+
+    fn classify_bare(status: &str) -> Self {
+        if status == "Not affected" {
+            Self::NotAffected
+        } else if status.starts_with("Mitigation") {
+            Self::Mitigated
+        } else if status.starts_with("Vulnerable") || status.starts_with("Processor vulnerable") {
+            // "Processor vulnerable": itlb_multihit without KVM_INTEL
+            Self::Vulnerable
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+/// A CPU hardware vulnerability as reported by the kernel.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CpuVuln {
+    /// Vulnerability name (e.g. "meltdown", "spectre_v2").
+    pub name: String,
+    /// Classified mitigation status.
+    pub status: CpuVulnStatus,
+    /// Raw kernel status line.
+    pub detail: String,
+}
+
+/// Kind of data a CPU cache holds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CacheKind {
+    /// Data only (e.g. L1d).
+    Data,
+    /// Instructions only (e.g. L1i).
+    Instruction,
+    /// Both data and instructions.
+    #[default]
+    Unified,
+}
+
+/// A CPU cache as seen from the first CPU.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CpuCache {
+    /// Cache level (1 for L1, 2 for L2, ...).
+    pub level: u8,
+    /// Kind of data held.
+    pub kind: CacheKind,
+    /// Cache size in bytes.
+    pub size_bytes: u64,
+    /// CPUs sharing this cache as a sysfs CPU list (e.g. `0-7`), empty when
+    /// unknown.
+    pub shared_cpu_list: String,
+}
+
 // ============================================================================
 // MEMORY METRICS
 // ============================================================================
 
 /// System memory metrics.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SystemMemory {
     /// Total physical memory in bytes.
     pub total_bytes: u64,
@@ -97,15 +397,34 @@ pub struct SystemMemory {
     pub cached_bytes: u64,
     /// Buffer memory in bytes (Linux only, 0 on other platforms).
     pub buffers_bytes: u64,
+    /// Reclaimable kernel slab in bytes (Linux only, 0 on other platforms).
+    pub slab_reclaimable_bytes: u64,
     /// Total swap in bytes.
     pub swap_total_bytes: u64,
     /// Used swap in bytes.
     pub swap_used_bytes: u64,
 }
 
+impl SystemMemory {
+    /// Memory that can be allocated once the kernel drops its caches.
+    ///
+    /// `MemAvailable` already counts reclaimable slab, minus the smaller of
+    /// half of it and the low watermark. This adds back that half, an upper
+    /// bound of the share left out, to `available_bytes`, capped at the total.
+    pub fn truly_available_bytes(&self) -> u64 {
+        self.available_bytes.saturating_add(self.slab_reclaimable_bytes / 2).min(self.total_bytes)
+    }
+
+    /// Memory actually committed, excluding page cache and reclaimable slab.
+    pub fn effective_used_bytes(&self) -> u64 {
+        self.total_bytes.saturating_sub(self.truly_available_bytes())
+    }
+}
+
 /// Memory pressure metrics (PSI).
 /// Available on Linux 4.20+ via /proc/pressure/memory.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryPressure {
     /// Percentage of time some tasks were stalled (10s average).
     pub some_avg10: f64,
@@ -125,6 +444,178 @@ pub struct MemoryPressure {
     pub full_total_us: u64,
 }
 
+impl MemoryPressure {
+    /// Current stall level.
+    pub fn level(&self) -> PressureLevel {
+        PressureLevel::from_avg10(self.some_avg10, self.full_avg10)
+    }
+}
+
+/// Transparent huge page allocation mode (`enabled` setting).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThpMode {
+    /// THP used for all eligible mappings.
+    Always,
+    /// THP used only for regions marked with `madvise(MADV_HUGEPAGE)`.
+    MadviseOnly,
+    /// THP disabled.
+    Never,
+    /// Unrecognized or unreadable setting.
+    #[default]
+    Unknown,
+}
+
+impl ThpMode {
+    /// Parse a kernel setting name (e.g. "madvise").
+    pub fn from_kernel_name(name: &str) -> Self {
+        match name {
+            "always" => Self::Always,
+            "madvise" => Self::MadviseOnly,
+            "never" => Self::Never,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Kernel setting name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Always => "always",
+            Self::MadviseOnly => "madvise",
+            Self::Never => "never",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// Transparent huge page defragmentation policy (`defrag` setting).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThpDefrag {
+    /// Stall allocations to reclaim and compact immediately.
+    Always,
+    /// Wake kswapd/kcompactd and fall back to regular pages.
+    Defer,
+    /// Direct reclaim for madvised regions, deferred for the rest.
+    DeferMadvise,
+    /// Direct reclaim only for madvised regions.
+    Madvise,
+    /// Never defragment.
+    Never,
+    /// Unrecognized or unreadable setting.
+    #[default]
+    Unknown,
+}
+
+impl ThpDefrag {
+    /// Parse a kernel setting name (e.g. "defer+madvise").
+    pub fn from_kernel_name(name: &str) -> Self {
+        match name {
+            "always" => Self::Always,
+            "defer" => Self::Defer,
+            "defer+madvise" => Self::DeferMadvise,
+            "madvise" => Self::Madvise,
+            "never" => Self::Never,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Kernel setting name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Always => "always",
+            Self::Defer => "defer",
+            Self::DeferMadvise => "defer+madvise",
+            Self::Madvise => "madvise",
+            Self::Never => "never",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// Transparent huge page status.
+/// Available on Linux via /sys/kernel/mm/transparent_hugepage.
+#[derive(Debug, Clone, Default)]
+pub struct ThpStatus {
+    /// Allocation mode.
+    pub enabled: ThpMode,
+    /// Defragmentation policy.
+    pub defrag: ThpDefrag,
+    /// Anonymous memory currently backed by huge pages in bytes.
+    pub anon_hugepages_bytes: u64,
+}
+
+/// Backing store of a swap area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SwapKind {
+    /// Disk partition (or other block device).
+    #[default]
+    Partition,
+    /// Swap file on a filesystem.
+    File,
+    /// Compressed RAM disk (`/dev/zram*`): swapping there costs CPU, not disk I/O.
+    Zram,
+}
+
+/// An active swap area.
+/// Available on Linux via /proc/swaps.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SwapDevice {
+    /// Device or file path (e.g. `/dev/sda2`, `/swapfile`, `/dev/zram0`).
+    pub path: String,
+    /// Backing store.
+    pub kind: SwapKind,
+    /// Size in bytes.
+    pub size_bytes: u64,
+    /// Used space in bytes.
+    pub used_bytes: u64,
+    /// Priority; higher-priority areas are used first.
+    pub priority: i32,
+}
+
+/// Size of the ZFS adaptive replacement cache (ARC).
+///
+/// The ARC is reported as used memory but shrinks under pressure down to
+/// its minimum target. Available on Linux via /proc/spl/kstat/zfs/arcstats
+/// and on FreeBSD via the `kstat.zfs.misc.arcstats` sysctls.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ZfsArcStats {
+    /// Current ARC size in bytes (`size`).
+    pub size_bytes: u64,
+    /// Minimum ARC size target in bytes (`c_min`).
+    pub min_bytes: u64,
+}
+
+impl ZfsArcStats {
+    /// ARC memory the kernel gives back under pressure.
+    pub fn reclaimable_bytes(&self) -> u64 {
+        self.size_bytes.saturating_sub(self.min_bytes)
+    }
+}
+
+/// Free pages and reclaim watermarks of one memory zone.
+/// Available on Linux via /proc/zoneinfo. Values are in pages.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryZone {
+    /// NUMA node the zone belongs to.
+    pub node: u32,
+    /// Zone name (e.g. "DMA32", "Normal").
+    pub zone_name: String,
+    /// Free pages in the zone.
+    pub free_pages: u64,
+    /// Below this, allocations enter direct reclaim.
+    pub min_watermark: u64,
+    /// Below this, kswapd starts reclaiming.
+    pub low_watermark: u64,
+    /// kswapd stops reclaiming once free pages reach this.
+    pub high_watermark: u64,
+}
+
+impl MemoryZone {
+    /// Whether kswapd would currently be reclaiming in this zone.
+    pub fn below_low(&self) -> bool {
+        self.free_pages < self.low_watermark
+    }
+}
+
 // ============================================================================
 // PROCESS METRICS
 // ============================================================================
@@ -148,6 +639,101 @@ pub enum ProcessState {
     Unknown = 255,
 }
 
+/// A thread of a process (`/proc/[pid]/task/[tid]/stat`).
+#[derive(Debug, Clone, Default)]
+pub struct ThreadInfo {
+    /// Thread ID.
+    pub tid: i32,
+    /// Thread name.
+    pub comm: String,
+    /// Thread state.
+    pub state: ProcessState,
+    /// Cumulative CPU time in user mode, in microseconds.
+    pub user_time_us: u64,
+    /// Cumulative CPU time in kernel mode, in microseconds.
+    pub system_time_us: u64,
+    /// CPU usage since the previous sample, filled in by a `ThreadCpuSampler`
+    /// (0 otherwise).
+    pub cpu_percent: f64,
+}
+
+/// Process scheduling policy.
+///
+/// Discriminants match the Linux `SCHED_*` constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum SchedPolicy {
+    /// Default time-sharing policy (`SCHED_OTHER`).
+    Normal = 0,
+    /// Real-time first-in first-out.
+    Fifo = 1,
+    /// Real-time round-robin.
+    RoundRobin = 2,
+    /// Time-sharing for CPU-bound batch work.
+    Batch = 3,
+    /// Runs only when nothing else is runnable.
+    Idle = 5,
+    /// Earliest-deadline-first real-time.
+    Deadline = 6,
+    /// Policy not reported by the platform.
+    #[default]
+    Unknown = 255,
+}
+
+impl SchedPolicy {
+    /// Map a Linux `SCHED_*` policy value.
+    pub fn from_linux(policy: u32) -> Self {
+        match policy {
+            0 => Self::Normal,
+            1 => Self::Fifo,
+            2 => Self::RoundRobin,
+            3 => Self::Batch,
+            5 => Self::Idle,
+            6 => Self::Deadline,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Identifies a process instance across PID reuse.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ProcessIdentity {
+    /// Process ID.
+    pub pid: i32,
+    /// Command name.
+    pub name: String,
+    /// Start time in platform ticks since boot.
+    ///
+    /// Only meaningful for comparison: a PID with a different start time is
+    /// a different process.
+    pub start_time: u64,
+}
+
+/// Compact key of a process instance, stable across PID reuse.
+///
+/// Per-process samplers keep the key of the process they track: the same
+/// PID with a different start time is a new process.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ProcessIdentityKey {
+    /// Process ID.
+    pub pid: i32,
+    /// Start time in platform ticks since boot.
+    pub start_time_ticks: u64,
+}
+
+impl ProcessIdentityKey {
+    /// Create a key from a PID and its start time.
+    pub fn new(pid: i32, start_time_ticks: u64) -> Self {
+        Self { pid, start_time_ticks }
+    }
+}
+
+impl From<&ProcessIdentity> for ProcessIdentityKey {
+    fn from(process: &ProcessIdentity) -> Self {
+        Self::new(process.pid, process.start_time)
+    }
+}
+
 /// Process metrics.
 #[derive(Debug, Clone, Default)]
 pub struct ProcessMetrics {
@@ -169,8 +755,171 @@ pub struct ProcessMetrics {
     pub read_bytes_per_sec: u64,
     /// Write bytes per second.
     pub write_bytes_per_sec: u64,
+    /// Cumulative minor page faults (no disk I/O required).
+    pub minor_faults: u64,
+    /// Cumulative major page faults (required disk I/O).
+    pub major_faults: u64,
     /// Process state.
     pub state: ProcessState,
+    /// Scheduling policy.
+    pub sched_policy: SchedPolicy,
+    /// Nice value (-20 to 19).
+    pub nice: i8,
+    /// Real-time priority (1-99 for real-time policies, 0 otherwise).
+    pub rt_priority: u32,
+}
+
+/// Shared vs private memory breakdown of a process (from smaps).
+///
+/// All values are in bytes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryBreakdown {
+    /// Resident set size.
+    pub rss: u64,
+    /// Proportional set size: private pages plus a fair share of shared pages.
+    pub pss: u64,
+    /// Shared pages not modified since mapping.
+    pub shared_clean: u64,
+    /// Shared pages modified since mapping.
+    pub shared_dirty: u64,
+    /// Private pages not modified since mapping.
+    pub private_clean: u64,
+    /// Private pages modified since mapping.
+    pub private_dirty: u64,
+    /// Pages swapped out.
+    pub swap: u64,
+}
+
+/// Scheduler statistics of a process (`/proc/[pid]/schedstat`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SchedStats {
+    /// Time spent running on a CPU, in nanoseconds.
+    pub run_ticks: u64,
+    /// Time spent runnable but waiting on a run queue, in nanoseconds.
+    pub wait_ticks: u64,
+    /// Number of timeslices run on a CPU.
+    pub timeslices: u64,
+}
+
+impl SchedStats {
+    /// Average run-queue wait per timeslice in nanoseconds (0 before the first timeslice).
+    pub fn avg_wait_per_timeslice(&self) -> f64 {
+        if self.timeslices == 0 { 0.0 } else { self.wait_ticks as f64 / self.timeslices as f64 }
+    }
+}
+
+/// Scheduler statistics of one CPU's run queue (`/proc/schedstat`).
+///
+/// All counters are cumulative since boot; diff two samples to find the CPU
+/// whose tasks wait the longest.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunQueueStat {
+    /// CPU number.
+    pub cpu: u32,
+    /// Calls to `sched_yield()`.
+    pub yld_count: u64,
+    /// Calls to `schedule()`.
+    pub sched_count: u64,
+    /// Time tasks spent running on this CPU, in nanoseconds.
+    pub run_time_ns: u64,
+    /// Time tasks spent waiting on this run queue, in nanoseconds.
+    pub wait_time_ns: u64,
+    /// Number of timeslices run on this CPU.
+    pub timeslices: u64,
+}
+
+/// OOM killer ranking of a process (`/proc/[pid]/oom_score{,_adj}`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OomInfo {
+    /// Badness score the OOM killer uses to pick a victim (higher dies first).
+    pub oom_score: i32,
+    /// User adjustment applied to the score, from -1000 (never kill) to 1000.
+    pub oom_score_adj: i32,
+}
+
+/// Linux capability names indexed by capability number.
+pub const CAPABILITY_NAMES: &[&str] = &[
+    "CAP_CHOWN",
+    "CAP_DAC_OVERRIDE",
+    "CAP_DAC_READ_SEARCH",
+    "CAP_FOWNER",
+    "CAP_FSETID",
+    "CAP_KILL",
+    "CAP_SETGID",
+    "CAP_SETUID",
+    "CAP_SETPCAP",
+    "CAP_LINUX_IMMUTABLE",
+    "CAP_NET_BIND_SERVICE",
+    "CAP_NET_BROADCAST",
+    "CAP_NET_ADMIN",
+    "CAP_NET_RAW",
+    "CAP_IPC_LOCK",
+    "CAP_IPC_OWNER",
+    "CAP_SYS_MODULE",
+    "CAP_SYS_RAWIO",
+    "CAP_SYS_CHROOT",
+    "CAP_SYS_PTRACE",
+    "CAP_SYS_PACCT",
+    "CAP_SYS_ADMIN",
+    "CAP_SYS_BOOT",
+    "CAP_SYS_NICE",
+    "CAP_SYS_RESOURCE",
+    "CAP_SYS_TIME",
+    "CAP_SYS_TTY_CONFIG",
+    "CAP_MKNOD",
+    "CAP_LEASE",
+    "CAP_AUDIT_WRITE",
+    "CAP_AUDIT_CONTROL",
+    "CAP_SETFCAP",
+    "CAP_MAC_OVERRIDE",
+    "CAP_MAC_ADMIN",
+    "CAP_SYSLOG",
+    "CAP_WAKE_ALARM",
+    "CAP_BLOCK_SUSPEND",
+    "CAP_AUDIT_READ",
+    "CAP_PERFMON",
+    "CAP_BPF",
+    "CAP_CHECKPOINT_RESTORE",
+];
+
+/// Decode a capability mask into capability names, lowest bit first.
+///
+/// Bits newer than [`CAPABILITY_NAMES`] are reported as `CAP_<n>`.
+pub fn capability_names(mask: u64) -> Vec<String> {
+    (0..64)
+        .filter(|bit| mask & (1 << bit) != 0)
+        .map(|bit| match CAPABILITY_NAMES.get(bit) {
+            Some(name) => name.to_string(),
+            None => format!("CAP_{}", bit),
+        })
+        .collect()
+}
+
+/// Capability sets of a process (`Cap*` masks of `/proc/[pid]/status`).
+///
+/// Bit `n` of each mask is capability number `n`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcessCapabilities {
+    /// Capabilities preserved across `execve` (`CapInh`).
+    pub inheritable: u64,
+    /// Capabilities the process may assume (`CapPrm`).
+    pub permitted: u64,
+    /// Capabilities used for permission checks (`CapEff`).
+    pub effective: u64,
+    /// Upper bound of capabilities the process may ever gain (`CapBnd`).
+    pub bounding: u64,
+}
+
+impl ProcessCapabilities {
+    /// Names of the effective capabilities.
+    pub fn effective_names(&self) -> Vec<String> {
+        capability_names(self.effective)
+    }
+
+    /// Names of the permitted capabilities.
+    pub fn permitted_names(&self) -> Vec<String> {
+        capability_names(self.permitted)
+    }
 }
 
 // ============================================================================
@@ -179,6 +928,7 @@ pub struct ProcessMetrics {
 
 /// Mounted partition information.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Partition {
     /// Device path (e.g., /dev/sda1).
     pub device: String,
@@ -190,8 +940,64 @@ pub struct Partition {
     pub options: String,
 }
 
+impl Partition {
+    /// Parse the comma-separated `options` string into structured flags.
+    pub fn parse_options(&self) -> MountOptions {
+        MountOptions::parse(&self.options)
+    }
+
+    /// Whether the filesystem is mounted read-only (`ro` option).
+    ///
+    /// False when the options are unknown (empty).
+    pub fn read_only(&self) -> bool {
+        self.parse_options().ro
+    }
+}
+
+/// Common mount options decoded from a partition's option string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MountOptions {
+    /// Mounted read-write.
+    pub rw: bool,
+    /// Mounted read-only.
+    pub ro: bool,
+    /// Access times are not updated.
+    pub noatime: bool,
+    /// Device files are not interpreted.
+    pub nodev: bool,
+    /// Set-user-ID and set-group-ID bits are ignored.
+    pub nosuid: bool,
+    /// Binaries cannot be executed.
+    pub noexec: bool,
+    /// Access times are updated relative to modify/change times.
+    pub relatime: bool,
+    /// Remaining options in their original order (e.g. `data=ordered`).
+    pub extra: Vec<String>,
+}
+
+impl MountOptions {
+    /// Parse a comma-separated option string such as `rw,nosuid,relatime`.
+    pub fn parse(options: &str) -> Self {
+        let mut parsed = Self::default();
+        for option in options.split(',').filter(|o| !o.is_empty()) {
+            match option {
+                "rw" => (parsed.rw, parsed.ro) = (true, false),
+                "ro" => (parsed.rw, parsed.ro) = (false, true),
+                "noatime" => parsed.noatime = true,
+                "nodev" => parsed.nodev = true,
+                "nosuid" => parsed.nosuid = true,
+                "noexec" => parsed.noexec = true,
+                "relatime" => parsed.relatime = true,
+                other => parsed.extra.push(other.to_string()),
+            }
+        }
+        parsed
+    }
+}
+
 /// Disk usage for a mount point.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DiskUsage {
     /// Mount point path.
     pub path: String,
@@ -213,6 +1019,7 @@ pub struct DiskUsage {
 
 /// Block device I/O statistics.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DiskIOStats {
     /// Device name (e.g., sda).
     pub device: String,
@@ -236,12 +1043,76 @@ pub struct DiskIOStats {
     pub weighted_io_time_us: u64,
 }
 
+/// Physical block device inventory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockDevice {
+    /// Device name (e.g., sda, nvme0n1).
+    pub name: String,
+    /// Model string reported by the device (empty if unknown).
+    pub model: String,
+    /// Serial number (empty if unknown).
+    pub serial: String,
+    /// Capacity in bytes.
+    pub size_bytes: u64,
+    /// Whether the device has spinning media (HDD); false for SSDs.
+    pub rotational: bool,
+    /// Whether the media is removable.
+    pub removable: bool,
+}
+
+/// Kind of block device in the storage stack.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BlockDeviceKind {
+    /// Whole disk (including loop and other virtual disks).
+    #[default]
+    Disk,
+    /// Partition of a disk.
+    Partition,
+    /// Device-mapper target (LVM, dm-crypt, multipath).
+    Dm,
+    /// Software RAID (md) array.
+    Md,
+}
+
+/// Position of a block device in the storage stack.
+///
+/// Holders are the devices built on top of this one and slaves the devices
+/// it is built from: for an LVM volume on a RAID array, `md0` lists `dm-0`
+/// as holder and its member partitions as slaves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceMapping {
+    /// Kernel device name (e.g. `sda1`, `dm-0`, `md0`).
+    pub device: String,
+    /// Kind of device.
+    pub kind: BlockDeviceKind,
+    /// Devices stacked on top of this one.
+    pub holders: Vec<String>,
+    /// Devices this one is built from.
+    pub slaves: Vec<String>,
+    /// Device-mapper name (e.g. `vg0-root`), for `Dm` devices only.
+    pub dm_name: Option<String>,
+}
+
+/// Request queue settings of a block device.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiskQueueConfig {
+    /// Active I/O scheduler (e.g. `mq-deadline`, `none`).
+    pub scheduler: String,
+    /// Maximum number of requests queued per direction.
+    pub nr_requests: u32,
+    /// Read-ahead size in KiB.
+    pub read_ahead_kb: u32,
+    /// Whether the device has spinning media (HDD); false for SSDs.
+    pub rotational: bool,
+}
+
 // ============================================================================
 // NETWORK METRICS
 // ============================================================================
 
 /// Network interface information.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetInterface {
     /// Interface name (e.g., eth0).
     pub name: String,
@@ -259,8 +1130,18 @@ pub struct NetInterface {
     pub is_loopback: bool,
 }
 
+/// Operational state change of a network interface.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinkEvent {
+    /// Interface name (e.g., eth0).
+    pub interface: String,
+    /// Whether the interface is now up with carrier (`IFF_RUNNING`).
+    pub up: bool,
+}
+
 /// Network interface statistics.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetStats {
     /// Interface name.
     pub interface: String,
@@ -280,6 +1161,109 @@ pub struct NetStats {
     pub tx_errors: u64,
     /// Transmit drops.
     pub tx_drops: u64,
+    /// Receive FIFO buffer errors.
+    pub rx_fifo: u64,
+    /// Compressed packets received.
+    pub rx_compressed: u64,
+    /// Multicast packets received.
+    pub rx_multicast: u64,
+    /// Transmit FIFO buffer errors.
+    pub tx_fifo: u64,
+    /// Collisions detected while transmitting.
+    pub tx_collisions: u64,
+    /// Compressed packets transmitted.
+    pub tx_compressed: u64,
+}
+
+/// Netfilter connection tracking table usage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConntrackStats {
+    /// Entries currently tracked.
+    pub count: u64,
+    /// Table capacity (`nf_conntrack_max`).
+    pub max: u64,
+}
+
+impl ConntrackStats {
+    /// Table usage as a percentage of capacity (0 when `max` is 0).
+    pub fn usage_percent(&self) -> f64 {
+        if self.max == 0 { 0.0 } else { self.count as f64 / self.max as f64 * 100.0 }
+    }
+}
+
+/// Kernel socket usage from /proc/net/sockstat.
+///
+/// Memory values are in pages; compare `tcp_mem_pages` against the
+/// `net.ipv4.tcp_mem` thresholds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SocketMemStats {
+    /// TCP sockets in use.
+    pub tcp_inuse: u64,
+    /// Pages allocated to TCP socket buffers.
+    pub tcp_mem_pages: u64,
+    /// TCP sockets no longer attached to a file descriptor.
+    pub tcp_orphan: u64,
+    /// UDP sockets in use.
+    pub udp_inuse: u64,
+    /// Pages allocated to UDP socket buffers.
+    pub udp_mem_pages: u64,
+    /// Sockets in use across all protocols.
+    pub sockets_used: u64,
+}
+
+/// UDP counters since boot (`Udp:` section of `/proc/net/snmp`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UdpProtoStats {
+    /// Datagrams delivered to sockets.
+    pub in_datagrams: u64,
+    /// Datagrams received for a port without a listener.
+    pub no_ports: u64,
+    /// Datagrams dropped on receive for any reason other than `no_ports`.
+    pub in_errors: u64,
+    /// Datagrams sent.
+    pub out_datagrams: u64,
+    /// Datagrams dropped because a socket receive buffer was full.
+    pub rcvbuf_errors: u64,
+    /// Datagrams dropped because a socket send buffer was full.
+    pub sndbuf_errors: u64,
+    /// Datagrams dropped for a bad checksum.
+    pub in_csum_errors: u64,
+}
+
+/// ICMP counters since boot (`Icmp:` section of `/proc/net/snmp`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IcmpStats {
+    /// Messages received.
+    pub in_msgs: u64,
+    /// Messages received with errors (bad length, checksum...).
+    pub in_errors: u64,
+    /// Messages received with a bad checksum.
+    pub in_csum_errors: u64,
+    /// Destination unreachable messages received.
+    pub in_dest_unreachs: u64,
+    /// Time exceeded messages received.
+    pub in_time_excds: u64,
+    /// Messages sent.
+    pub out_msgs: u64,
+    /// Messages not sent because of errors.
+    pub out_errors: u64,
+    /// Destination unreachable messages sent.
+    pub out_dest_unreachs: u64,
+}
+
+/// TCP accept queue overflow counters since boot (`/proc/net/netstat`).
+///
+/// A growing `listen_overflows` means a listener's accept queue was full:
+/// the application does not `accept()` fast enough or its backlog is too
+/// small. The current queue length of each listener is the `rx_queue` of its
+/// [`TcpConnection`], and its maximum backlog the `tx_queue`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListenQueueStats {
+    /// Connections dropped because an accept queue was full.
+    pub listen_overflows: u64,
+    /// Incoming connections dropped by listeners for any reason, overflows
+    /// included.
+    pub listen_drops: u64,
 }
 
 // ============================================================================
@@ -288,6 +1272,7 @@ pub struct NetStats {
 
 /// System-wide I/O statistics.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IOStats {
     /// Total read operations.
     pub read_ops: u64,
@@ -299,6 +1284,18 @@ pub struct IOStats {
     pub write_bytes: u64,
 }
 
+impl IOStats {
+    /// Sum per-device counters, saturating instead of wrapping on overflow.
+    pub fn from_disks<'a>(disks: impl IntoIterator<Item = &'a DiskIOStats>) -> Self {
+        disks.into_iter().fold(Self::default(), |total, disk| Self {
+            read_ops: total.read_ops.saturating_add(disk.reads_completed),
+            read_bytes: total.read_bytes.saturating_add(disk.read_bytes),
+            write_ops: total.write_ops.saturating_add(disk.writes_completed),
+            write_bytes: total.write_bytes.saturating_add(disk.write_bytes),
+        })
+    }
+}
+
 /// Context switch statistics.
 ///
 /// Includes both per-process and system-wide context switches.
@@ -312,38 +1309,372 @@ pub struct ContextSwitches {
     pub system_total: u64,
 }
 
-/// I/O pressure metrics (PSI).
-/// Available on Linux 4.20+ via /proc/pressure/io.
+/// Kernel-wide activity counters.
+///
+/// On Linux these come from the aggregate lines of /proc/stat.
 #[derive(Debug, Clone, Default)]
-pub struct IOPressure {
-    /// Percentage of time some tasks were stalled (10s average).
-    pub some_avg10: f64,
-    /// Percentage of time some tasks were stalled (60s average).
-    pub some_avg60: f64,
-    /// Percentage of time some tasks were stalled (300s average).
-    pub some_avg300: f64,
-    /// Total microseconds some tasks were stalled.
-    pub some_total_us: u64,
-    /// Percentage of time all tasks were stalled (10s average).
-    pub full_avg10: f64,
-    /// Percentage of time all tasks were stalled (60s average).
-    pub full_avg60: f64,
-    /// Percentage of time all tasks were stalled (300s average).
-    pub full_avg300: f64,
-    /// Total microseconds all tasks were stalled.
-    pub full_total_us: u64,
+pub struct KernelStats {
+    /// Total context switches since boot.
+    pub context_switches_total: u64,
+    /// Total interrupts serviced since boot.
+    pub interrupts_total: u64,
+    /// Total processes/threads created since boot (forks).
+    pub processes_started_total: u64,
+    /// Number of tasks currently runnable.
+    pub procs_running: u32,
+    /// Number of tasks currently blocked waiting for I/O.
+    pub procs_blocked: u32,
+    /// Boot time (seconds since epoch).
+    pub boot_time: u64,
 }
 
-// ============================================================================
-// COLLECTOR TRAITS
-// ============================================================================
-
+/// inotify limits (`/proc/sys/fs/inotify`) and current usage.
+///
+/// Watchers fail with `EMFILE`/`ENOSPC` once a limit is reached.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InotifyStats {
+    /// Maximum inotify instances per user.
+    pub max_user_instances: u64,
+    /// Maximum watches per user.
+    pub max_user_watches: u64,
+    /// Maximum events queued on an instance before overflow.
+    pub max_queued_events: u64,
+    /// inotify descriptors held by processes of the current user.
+    ///
+    /// Only processes whose descriptors are readable are counted.
+    pub user_instances: u64,
+}
+
+impl InotifyStats {
+    /// Instance usage as a percentage of `max_user_instances` (0 when unknown).
+    pub fn instances_usage_percent(&self) -> f64 {
+        if self.max_user_instances == 0 {
+            0.0
+        } else {
+            self.user_instances as f64 / self.max_user_instances as f64 * 100.0
+        }
+    }
+}
+
+/// Namespace identifiers of the collecting process.
+///
+/// Each value is the inode number of the matching `/proc/self/ns/*` link,
+/// which uniquely identifies the namespace on a host: two processes share a
+/// namespace exactly when the inodes are equal. Tag metrics with these to
+/// tell apart identical series collected from different containers.
+/// A value of 0 means the kernel does not provide that namespace type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NamespaceIds {
+    /// Network namespace.
+    pub net: u64,
+    /// PID namespace.
+    pub pid: u64,
+    /// Mount namespace.
+    pub mnt: u64,
+    /// IPC namespace.
+    pub ipc: u64,
+    /// UTS (hostname) namespace.
+    pub uts: u64,
+    /// Cgroup namespace.
+    pub cgroup: u64,
+}
+
+/// Key system-wide kernel limits.
+///
+/// A value of 0 means the limit is not available on this platform.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemLimits {
+    /// Maximum open file handles system-wide.
+    pub file_max: u64,
+    /// Highest process ID before wrapping around.
+    pub pid_max: u64,
+    /// Maximum threads system-wide.
+    pub threads_max: u64,
+    /// Maximum memory map areas per process.
+    pub max_map_count: u64,
+    /// Maximum concurrent asynchronous I/O requests system-wide.
+    pub aio_max_nr: u64,
+}
+
+/// Local timezone and clock synchronization state.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimeInfo {
+    /// Timezone name (e.g. `Europe/Paris`), or its abbreviation when no
+    /// name is configured.
+    pub timezone: String,
+    /// Current offset from UTC in seconds, including daylight saving time.
+    pub utc_offset_secs: i32,
+    /// Whether the clock is NTP-synchronized (`None` when unknown).
+    pub ntp_synchronized: Option<bool>,
+}
+
+/// State of a systemd unit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnitStatus {
+    /// High-level state (e.g. `active`, `inactive`, `failed`).
+    pub active_state: String,
+    /// Unit-type specific state (e.g. `running`, `exited`, `dead`).
+    pub sub_state: String,
+    /// Main process of a service (0 when none or unknown).
+    pub main_pid: u32,
+}
+
+/// Kernel interface file parsed by the collectors, for
+/// [`SystemCollector::read_raw`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RawSource {
+    /// `/proc/stat`.
+    ProcStat,
+    /// `/proc/meminfo`.
+    MemInfo,
+    /// `/proc/loadavg`.
+    LoadAvg,
+    /// `/proc/diskstats`.
+    Diskstats,
+    /// `/proc/net/dev`.
+    NetDev,
+    /// `/proc/vmstat`.
+    VmStat,
+    /// `/proc/zoneinfo`.
+    ZoneInfo,
+    /// `/proc/cpuinfo`.
+    CpuInfo,
+    /// `/proc/mounts`.
+    Mounts,
+    /// `/proc/pressure/cpu`.
+    CpuPressure,
+    /// `/proc/pressure/memory`.
+    MemoryPressure,
+    /// `/proc/pressure/io`.
+    IoPressure,
+    /// `/proc/net/tcp`.
+    NetTcp,
+    /// `/proc/net/tcp6`.
+    NetTcp6,
+    /// `/proc/net/udp`.
+    NetUdp,
+    /// `/proc/net/udp6`.
+    NetUdp6,
+    /// `/proc/net/unix`.
+    NetUnix,
+    /// `/proc/net/sockstat`.
+    NetSockstat,
+    /// `/proc/net/netstat`.
+    NetNetstat,
+    /// `/proc/modules`.
+    Modules,
+    /// `/proc/schedstat`.
+    Schedstat,
+    /// `/proc/net/snmp`.
+    NetSnmp,
+    /// `/proc/swaps`.
+    Swaps,
+}
+
+impl RawSource {
+    /// Path of the file relative to the procfs mount (e.g. `net/dev`).
+    pub fn proc_path(&self) -> &'static str {
+        match self {
+            Self::ProcStat => "stat",
+            Self::MemInfo => "meminfo",
+            Self::LoadAvg => "loadavg",
+            Self::Diskstats => "diskstats",
+            Self::NetDev => "net/dev",
+            Self::VmStat => "vmstat",
+            Self::ZoneInfo => "zoneinfo",
+            Self::CpuInfo => "cpuinfo",
+            Self::Mounts => "mounts",
+            Self::CpuPressure => "pressure/cpu",
+            Self::MemoryPressure => "pressure/memory",
+            Self::IoPressure => "pressure/io",
+            Self::NetTcp => "net/tcp",
+            Self::NetTcp6 => "net/tcp6",
+            Self::NetUdp => "net/udp",
+            Self::NetUdp6 => "net/udp6",
+            Self::NetUnix => "net/unix",
+            Self::NetSockstat => "net/sockstat",
+            Self::NetNetstat => "net/netstat",
+            Self::Modules => "modules",
+            Self::Schedstat => "schedstat",
+            Self::NetSnmp => "net/snmp",
+            Self::Swaps => "swaps",
+        }
+    }
+}
+
+/// Load state of a kernel module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ModuleState {
+    /// Loaded and usable.
+    #[default]
+    Live,
+    /// Being loaded.
+    Loading,
+    /// Being unloaded.
+    Unloading,
+}
+
+/// A loaded kernel module.
+/// Available on Linux via /proc/modules.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KernelModule {
+    /// Module name (e.g. `nf_conntrack`).
+    pub name: String,
+    /// Memory used by the module in bytes.
+    pub size_bytes: u64,
+    /// Number of users of the module (0 when the kernel cannot unload modules).
+    pub ref_count: u32,
+    /// Load state.
+    pub state: ModuleState,
+    /// Modules depending on this one.
+    pub dependencies: Vec<String>,
+}
+
+/// Syslog severity of a kernel log record (lower is more severe).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[repr(u8)]
+pub enum LogSeverity {
+    /// System is unusable.
+    Emergency = 0,
+    /// Action must be taken immediately.
+    Alert = 1,
+    /// Critical conditions.
+    Critical = 2,
+    /// Error conditions.
+    Error = 3,
+    /// Warning conditions.
+    Warning = 4,
+    /// Normal but significant condition.
+    Notice = 5,
+    /// Informational message.
+    #[default]
+    Info = 6,
+    /// Debug-level message.
+    Debug = 7,
+}
+
+impl LogSeverity {
+    /// Convert a syslog level (the low 3 bits of a priority).
+    pub fn from_level(level: u8) -> Self {
+        match level & 7 {
+            0 => Self::Emergency,
+            1 => Self::Alert,
+            2 => Self::Critical,
+            3 => Self::Error,
+            4 => Self::Warning,
+            5 => Self::Notice,
+            6 => Self::Info,
+            _ => Self::Debug,
+        }
+    }
+}
+
+/// A kernel log record.
+/// Available on Linux via /dev/kmsg.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KernelLogEntry {
+    /// Time since boot when the record was logged.
+    pub timestamp: Duration,
+    /// Record severity.
+    pub severity: LogSeverity,
+    /// Syslog facility (0 for kernel messages).
+    pub facility: u8,
+    /// Message text, without continuation metadata.
+    pub message: String,
+}
+
+impl KernelLogEntry {
+    /// Whether the record reports a process killed by the OOM killer.
+    pub fn is_oom_kill(&self) -> bool {
+        self.message.contains("out of memory: Killed process")
+            || self.message.contains("Out of memory: Killed process")
+    }
+
+    /// Whether the record reports a block device I/O error.
+    pub fn is_io_error(&self) -> bool {
+        self.message.contains("I/O error")
+    }
+}
+
+/// I/O pressure metrics (PSI).
+/// Available on Linux 4.20+ via /proc/pressure/io.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IOPressure {
+    /// Percentage of time some tasks were stalled (10s average).
+    pub some_avg10: f64,
+    /// Percentage of time some tasks were stalled (60s average).
+    pub some_avg60: f64,
+    /// Percentage of time some tasks were stalled (300s average).
+    pub some_avg300: f64,
+    /// Total microseconds some tasks were stalled.
+    pub some_total_us: u64,
+    /// Percentage of time all tasks were stalled (10s average).
+    pub full_avg10: f64,
+    /// Percentage of time all tasks were stalled (60s average).
+    pub full_avg60: f64,
+    /// Percentage of time all tasks were stalled (300s average).
+    pub full_avg300: f64,
+    /// Total microseconds all tasks were stalled.
+    pub full_total_us: u64,
+}
+
+impl IOPressure {
+    /// Current stall level.
+    pub fn level(&self) -> PressureLevel {
+        PressureLevel::from_avg10(self.some_avg10, self.full_avg10)
+    }
+}
+
+// ============================================================================
+// COLLECTOR TRAITS
+// ============================================================================
+
 /// Trait for CPU metrics collection.
 pub trait CPUCollector: Send + Sync {
     /// Collect system-wide CPU metrics.
     fn collect_system(&self) -> Result<SystemCPU>;
+    /// Read the cumulative CPU time counters.
+    ///
+    /// Returns `Error::NotSupported` on platforms without tick counters.
+    fn cpu_ticks(&self) -> Result<CpuTicks> {
+        Err(Error::NotSupported)
+    }
     /// Collect CPU pressure metrics (PSI).
     fn collect_pressure(&self) -> Result<CPUPressure>;
+    /// Classify current CPU pressure.
+    fn pressure_level(&self) -> Result<PressureLevel> {
+        Ok(self.collect_pressure()?.level())
+    }
+    /// List CPU hardware vulnerabilities and their mitigation status.
+    ///
+    /// Returns `Error::NotSupported` on platforms without a vulnerability report.
+    fn cpu_vulnerabilities(&self) -> Result<Vec<CpuVuln>> {
+        Err(Error::NotSupported)
+    }
+    /// Read per-CPU run queue scheduler statistics.
+    ///
+    /// Returns `Error::NotSupported` when the kernel lacks scheduler
+    /// statistics (`CONFIG_SCHEDSTATS`) or on other platforms.
+    fn per_cpu_runqueue(&self) -> Result<Vec<RunQueueStat>> {
+        Err(Error::NotSupported)
+    }
+    /// List the caches of the first CPU, ordered by level.
+    ///
+    /// Returns `Error::NotSupported` on platforms without cache information.
+    fn cpu_caches(&self) -> Result<Vec<CpuCache>> {
+        Err(Error::NotSupported)
+    }
+    /// Collect per-core CPU usage since the previous call.
+    ///
+    /// Cores are identified by ID rather than position, so entries stay
+    /// correct when cores go offline or online between calls. A core without
+    /// a previous sample (including on the first call) is omitted until the
+    /// next call. Returns `Error::NotSupported` on platforms without per-core
+    /// counters.
+    fn collect_per_core(&self) -> Result<Vec<CoreUsage>> {
+        Err(Error::NotSupported)
+    }
 }
 
 /// Trait for memory metrics collection.
@@ -352,12 +1683,113 @@ pub trait MemoryCollector: Send + Sync {
     fn collect_system(&self) -> Result<SystemMemory>;
     /// Collect memory pressure metrics (PSI).
     fn collect_pressure(&self) -> Result<MemoryPressure>;
+    /// Classify current memory pressure.
+    fn pressure_level(&self) -> Result<PressureLevel> {
+        Ok(self.collect_pressure()?.level())
+    }
+
+    /// Memory that can be allocated once the kernel drops its caches.
+    ///
+    /// See [`SystemMemory::truly_available_bytes`].
+    fn memory_truly_available(&self) -> Result<u64> {
+        self.collect_system().map(|memory| memory.truly_available_bytes())
+    }
+
+    /// Whether all tasks were recently stalled on memory (`full_avg10 > 0`).
+    ///
+    /// A stronger out-of-memory warning than `some` pressure.
+    fn memory_stalled(&self) -> Result<bool> {
+        Ok(self.pressure_level()? == PressureLevel::Full)
+    }
+    /// Read transparent huge page status.
+    ///
+    /// Returns `Error::NotSupported` on platforms without THP.
+    fn thp_status(&self) -> Result<ThpStatus> {
+        Err(Error::NotSupported)
+    }
+    /// Read per-zone free pages and watermarks.
+    ///
+    /// Returns `Error::NotSupported` on platforms without zone accounting.
+    fn zone_info(&self) -> Result<Vec<MemoryZone>> {
+        Err(Error::NotSupported)
+    }
+    /// List active swap areas with their backing store.
+    ///
+    /// Returns `Error::NotSupported` on platforms without it.
+    fn swap_devices(&self) -> Result<Vec<SwapDevice>> {
+        Err(Error::NotSupported)
+    }
+    /// Read the ZFS ARC size.
+    ///
+    /// Returns `Error::NotSupported` when ZFS is not loaded.
+    fn zfs_arc(&self) -> Result<ZfsArcStats> {
+        Err(Error::NotSupported)
+    }
+    /// Collect system memory, counting reclaimable ZFS ARC as available.
+    ///
+    /// Identical to [`collect_system`](Self::collect_system) without ZFS.
+    fn collect_system_zfs_aware(&self) -> Result<SystemMemory> {
+        let mut memory = self.collect_system()?;
+        let arc = match self.zfs_arc() {
+            Ok(arc) => arc,
+            Err(Error::NotSupported) => return Ok(memory),
+            Err(e) => return Err(e),
+        };
+        memory.available_bytes =
+            memory.available_bytes.saturating_add(arc.reclaimable_bytes()).min(memory.total_bytes);
+        memory.used_bytes = memory.total_bytes.saturating_sub(memory.available_bytes);
+        Ok(memory)
+    }
+    /// Read the kernel's virtual memory counters by name.
+    ///
+    /// On Linux this is the whole /proc/vmstat table. Returns
+    /// `Error::NotSupported` on other platforms.
+    fn vmstat(&self) -> Result<HashMap<String, u64>> {
+        Err(Error::NotSupported)
+    }
+    /// Total page faults since boot (`pgfault`).
+    fn page_faults(&self) -> Result<u64> {
+        vmstat_counter(&self.vmstat()?, "pgfault")
+    }
+    /// Major page faults (requiring I/O) since boot (`pgmajfault`).
+    fn major_page_faults(&self) -> Result<u64> {
+        vmstat_counter(&self.vmstat()?, "pgmajfault")
+    }
+    /// Pages swapped in plus pages swapped out since boot (`pswpin + pswpout`).
+    fn swapped_pages(&self) -> Result<u64> {
+        let vmstat = self.vmstat()?;
+        Ok(vmstat_counter(&vmstat, "pswpin")? + vmstat_counter(&vmstat, "pswpout")?)
+    }
+    /// Processes killed by the OOM killer since boot (`oom_kill`, Linux 4.13+).
+    fn oom_kills(&self) -> Result<u64> {
+        vmstat_counter(&self.vmstat()?, "oom_kill")
+    }
+}
+
+/// Look up a vmstat counter, reporting `NotSupported` if the kernel lacks it.
+fn vmstat_counter(vmstat: &HashMap<String, u64>, name: &str) -> Result<u64> {
+    vmstat.get(name).copied().ok_or(Error::NotSupported)
 }
 
 /// Trait for load average collection.
 pub trait LoadCollector: Send + Sync {
     /// Collect system load average.
     fn collect(&self) -> Result<LoadAverage>;
+
+    /// Number of tasks runnable right now.
+    ///
+    /// Cheap enough for high-frequency polling. The default implementation
+    /// approximates it with the rounded 1-minute load average.
+    fn runnable_tasks(&self) -> Result<u32> {
+        Ok(self.collect()?.load_1min.round() as u32)
+    }
+
+    /// Number of tasks blocked on I/O right now.
+    ///
+    /// Returns `Error::NotSupported` on platforms without an equivalent source.
+    fn blocked_tasks(&self) -> Result<u32> {
+        Err(Error::NotSupported)
+    }
 }
 
 /// Trait for process metrics collection.
@@ -366,6 +1798,59 @@ pub trait ProcessCollector: Send + Sync {
     fn collect(&self, pid: i32) -> Result<ProcessMetrics>;
     /// Collect metrics for all processes.
     fn collect_all(&self) -> Result<Vec<ProcessMetrics>>;
+
+    /// Collect the shared/private memory breakdown of a process.
+    ///
+    /// Returns `Error::Permission` when the process memory maps are not readable.
+    fn memory_breakdown(&self, _pid: i32) -> Result<MemoryBreakdown> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read the scheduler run-queue statistics of a process.
+    ///
+    /// Returns `Error::NotSupported` when the kernel lacks scheduler
+    /// statistics (`CONFIG_SCHEDSTATS`) or on other platforms.
+    fn sched_latency(&self, _pid: i32) -> Result<SchedStats> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read the OOM killer score and adjustment of a process.
+    ///
+    /// Returns `Error::NotSupported` on platforms without an OOM killer score.
+    fn oom_info(&self, _pid: i32) -> Result<OomInfo> {
+        Err(Error::NotSupported)
+    }
+
+    /// List the threads of a process, sorted by thread ID.
+    ///
+    /// Threads that exit while the list is read are skipped. `cpu_percent`
+    /// is left at 0; feed the list to a `ThreadCpuSampler` to fill it in.
+    /// Returns `Error::NotSupported` on platforms without per-thread counters.
+    fn list_threads(&self, _pid: i32) -> Result<Vec<ThreadInfo>> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read the capability sets of a process.
+    ///
+    /// Returns `Error::Permission` when the process status is not readable
+    /// and `Error::NotSupported` on platforms without capabilities.
+    fn capability_sets(&self, _pid: i32) -> Result<ProcessCapabilities> {
+        Err(Error::NotSupported)
+    }
+
+    /// List the identity of every running process.
+    ///
+    /// Returns `Error::NotSupported` on platforms without process start times.
+    fn list_identities(&self) -> Result<Vec<ProcessIdentity>> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read the identity of a process.
+    ///
+    /// Returns `Error::NotSupported` on platforms without process start times.
+    fn identity(&self, _pid: i32) -> Result<ProcessIdentity> {
+        Err(Error::NotSupported)
+    }
 }
 
 /// Trait for disk metrics collection.
@@ -380,6 +1865,86 @@ pub trait DiskCollector: Send + Sync {
     fn collect_io(&self) -> Result<Vec<DiskIOStats>>;
     /// Collect I/O statistics for a specific device.
     fn collect_device_io(&self, device: &str) -> Result<DiskIOStats>;
+    /// List physical block devices with model, serial and media type.
+    ///
+    /// Returns `Error::NotSupported` on platforms without a device inventory.
+    fn block_devices(&self) -> Result<Vec<BlockDevice>> {
+        Err(Error::NotSupported)
+    }
+    /// Time a `statvfs` of the filesystem mounted at `path`.
+    ///
+    /// A growing latency is an early sign of a degrading network mount.
+    /// Returns `Error::Timeout` after [`DEFAULT_MOUNT_PROBE_TIMEOUT`] for a
+    /// hung mount, immediately while the previous probe of `path` is still
+    /// stuck, and `Error::NotSupported` on platforms without it.
+    fn probe_mount_latency(&self, _path: &str) -> Result<Duration> {
+        Err(Error::NotSupported)
+    }
+    /// Read the I/O scheduler and request queue settings of a block device
+    /// (e.g. `sda`).
+    ///
+    /// Returns `Error::NotFound` for unknown devices and
+    /// `Error::NotSupported` on platforms without them.
+    fn disk_queue_config(&self, _device: &str) -> Result<DiskQueueConfig> {
+        Err(Error::NotSupported)
+    }
+
+    /// Map block devices and partitions to the devices stacked on them,
+    /// e.g. to resolve `dm-0` in [`DiskIOStats`] to `vg0-root` and its disks.
+    ///
+    /// Returns `Error::NotSupported` on platforms without it.
+    fn device_topology(&self) -> Result<Vec<DeviceMapping>> {
+        Err(Error::NotSupported)
+    }
+
+    /// Whether the root filesystem is mounted read-only.
+    ///
+    /// The last mount on `/` is used, as it hides the ones below it.
+    /// Returns `Error::NotFound` when no partition is mounted on `/`.
+    fn root_filesystem_readonly(&self) -> Result<bool> {
+        let partitions = self.list_partitions()?;
+        let root = partitions
+            .iter()
+            .rev()
+            .find(|p| p.mount_point == "/")
+            .ok_or_else(|| Error::NotFound("no filesystem mounted on /".to_string()))?;
+        Ok(root.read_only())
+    }
+}
+
+/// Interface name prefixes treated as virtual by [`NetInterfaceFilter::default`].
+pub const DEFAULT_VIRTUAL_INTERFACE_PREFIXES: &[&str] = &["veth", "docker", "br-", "cni"];
+
+/// Selects which interfaces count towards network aggregates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetInterfaceFilter {
+    /// Drop interfaces flagged as loopback.
+    pub exclude_loopback: bool,
+    /// Drop interfaces whose name starts with any of these prefixes.
+    pub exclude_prefixes: Vec<String>,
+}
+
+impl Default for NetInterfaceFilter {
+    /// Exclude loopback and [`DEFAULT_VIRTUAL_INTERFACE_PREFIXES`].
+    fn default() -> Self {
+        Self {
+            exclude_loopback: true,
+            exclude_prefixes: DEFAULT_VIRTUAL_INTERFACE_PREFIXES
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl NetInterfaceFilter {
+    /// Check whether an interface passes the filter.
+    pub fn allows(&self, name: &str, is_loopback: bool) -> bool {
+        if self.exclude_loopback && is_loopback {
+            return false;
+        }
+        !self.exclude_prefixes.iter().any(|p| name.starts_with(p.as_str()))
+    }
 }
 
 /// Trait for network metrics collection.
@@ -390,6 +1955,71 @@ pub trait NetworkCollector: Send + Sync {
     fn collect_stats(&self, interface: &str) -> Result<NetStats>;
     /// Collect statistics for all interfaces.
     fn collect_all_stats(&self) -> Result<Vec<NetStats>>;
+
+    /// Collect statistics for all interfaces in the network namespace of `pid`.
+    ///
+    /// Lets a host-level agent attribute traffic to a container. Returns
+    /// `Error::NotSupported` on platforms without network namespaces.
+    fn collect_stats_for_pid_netns(&self, _pid: i32) -> Result<Vec<NetStats>> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read connection tracking table usage.
+    ///
+    /// Returns `Error::NotSupported` when conntrack is unavailable (module not
+    /// loaded or non-Linux platform).
+    fn conntrack_stats(&self) -> Result<ConntrackStats> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read kernel socket counts and socket buffer memory.
+    ///
+    /// Returns `Error::NotSupported` on non-Linux platforms.
+    fn socket_memory(&self) -> Result<SocketMemStats> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read TCP accept queue overflow counters.
+    ///
+    /// Returns `Error::NotSupported` on non-Linux platforms.
+    fn listen_queue_stats(&self) -> Result<ListenQueueStats> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read UDP datagram and error counters.
+    ///
+    /// Returns `Error::NotSupported` on non-Linux platforms.
+    fn udp_stats(&self) -> Result<UdpProtoStats> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read ICMP message and error counters.
+    ///
+    /// Returns `Error::NotSupported` on non-Linux platforms.
+    fn icmp_stats(&self) -> Result<IcmpStats> {
+        Err(Error::NotSupported)
+    }
+
+    /// Collect statistics for physical NICs only.
+    ///
+    /// Excludes loopback and virtual interfaces per [`NetInterfaceFilter::default`].
+    fn collect_physical_stats(&self) -> Result<Vec<NetStats>> {
+        self.collect_filtered_stats(&NetInterfaceFilter::default())
+    }
+
+    /// Collect statistics for the interfaces allowed by `filter`.
+    fn collect_filtered_stats(&self, filter: &NetInterfaceFilter) -> Result<Vec<NetStats>> {
+        let loopback: Vec<String> = if filter.exclude_loopback {
+            self.list_interfaces()?.into_iter().filter(|i| i.is_loopback).map(|i| i.name).collect()
+        } else {
+            Vec::new()
+        };
+        Ok(self
+            .collect_all_stats()?
+            .into_iter()
+            .filter(|s| filter.allows(&s.interface, loopback.contains(&s.interface)))
+            .collect())
+    }
 }
 
 /// Trait for I/O metrics collection.
@@ -398,6 +2028,10 @@ pub trait IOCollector: Send + Sync {
     fn collect_stats(&self) -> Result<IOStats>;
     /// Collect I/O pressure metrics (PSI).
     fn collect_pressure(&self) -> Result<IOPressure>;
+    /// Classify current I/O pressure.
+    fn pressure_level(&self) -> Result<PressureLevel> {
+        Ok(self.collect_pressure()?.level())
+    }
 }
 
 // ============================================================================
@@ -419,6 +2053,46 @@ pub struct ThermalZone {
     pub temp_crit: Option<f64>,
 }
 
+/// Sensor drivers reporting CPU temperatures (Intel, AMD, ARM SoCs).
+pub const CPU_THERMAL_SENSORS: &[&str] =
+    &["coretemp", "k10temp", "zenpower", "cpu_thermal", "cpu-thermal"];
+
+/// CPU temperature aggregated from its sensors.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CpuTemp {
+    /// Hottest package (die) reading: Intel `Package id N`, AMD `Tctl`/`Tdie`.
+    /// `None` when the sensors only report per-core readings.
+    pub package_celsius: Option<f64>,
+    /// Hottest core reading.
+    pub core_max_celsius: f64,
+    /// Average of the core readings.
+    pub average_celsius: f64,
+}
+
+impl CpuTemp {
+    /// Aggregate the zones of [`CPU_THERMAL_SENSORS`]; `None` if there is none.
+    ///
+    /// Without per-core readings (e.g. AMD with only `Tctl`), the package
+    /// readings stand in for the cores.
+    pub fn from_zones(zones: &[ThermalZone]) -> Option<Self> {
+        let is_package = |zone: &ThermalZone| {
+            zone.label.starts_with("Package id") || zone.label == "Tctl" || zone.label == "Tdie"
+        };
+        let (packages, cores): (Vec<&ThermalZone>, Vec<&ThermalZone>) = zones
+            .iter()
+            .filter(|zone| CPU_THERMAL_SENSORS.contains(&zone.name.as_str()))
+            .partition(|zone| is_package(zone));
+
+        let package_celsius = packages.iter().map(|z| z.temp_celsius).reduce(f64::max);
+        let cores = if cores.is_empty() { packages } else { cores };
+        let core_max_celsius = cores.iter().map(|z| z.temp_celsius).reduce(f64::max)?;
+        let average_celsius =
+            cores.iter().map(|z| z.temp_celsius).sum::<f64>() / cores.len() as f64;
+
+        Some(Self { package_celsius, core_max_celsius, average_celsius })
+    }
+}
+
 /// Trait for thermal metrics collection.
 pub trait ThermalCollector: Send + Sync {
     /// Check if thermal monitoring is supported.
@@ -427,6 +2101,44 @@ pub trait ThermalCollector: Send + Sync {
     fn list_zones(&self) -> Result<Vec<ThermalZone>>;
     /// Collect current temperatures for all zones.
     fn collect_temperatures(&self) -> Result<Vec<ThermalZone>>;
+    /// Aggregate the CPU sensors into a single temperature.
+    ///
+    /// Returns `Error::NotSupported` when no CPU sensor is found.
+    fn cpu_temperature(&self) -> Result<CpuTemp> {
+        CpuTemp::from_zones(&self.collect_temperatures()?).ok_or(Error::NotSupported)
+    }
+}
+
+// ============================================================================
+// WIRELESS METRICS
+// ============================================================================
+
+/// Signal and link information for a wireless interface.
+#[derive(Debug, Clone, Default)]
+pub struct WirelessInfo {
+    /// Interface name (e.g., "wlan0").
+    pub interface: String,
+    /// Network the interface is associated with (empty when unknown).
+    pub ssid: String,
+    /// Received signal level in dBm.
+    pub signal_dbm: f64,
+    /// Link quality as reported by the driver (scale is driver-specific,
+    /// commonly out of 70).
+    pub link_quality: f64,
+    /// Current transmit bitrate in Mbit/s (0 when unknown).
+    pub bitrate_mbps: f64,
+    /// Operating frequency in MHz (0 when unknown).
+    pub frequency_mhz: u32,
+}
+
+/// Trait for wireless interface metrics collection.
+pub trait WirelessCollector: Send + Sync {
+    /// List wireless interfaces (empty on hosts without any).
+    fn list_interfaces(&self) -> Result<Vec<String>>;
+    /// Collect signal metrics for a wireless interface.
+    ///
+    /// Returns `Error::NotSupported` for wired interfaces.
+    fn collect(&self, interface: &str) -> Result<WirelessInfo>;
 }
 
 // ============================================================================
@@ -516,9 +2228,11 @@ pub struct TcpConnection {
     pub process_name: String,
     /// Socket inode number.
     pub inode: u64,
-    /// Receive queue size.
+    /// Receive queue size; for `Listen` sockets, the number of connections
+    /// waiting in the accept queue.
     pub rx_queue: u32,
-    /// Transmit queue size.
+    /// Transmit queue size; for `Listen` sockets, the maximum accept queue
+    /// length (backlog) where the platform reports it, 0 otherwise.
     pub tx_queue: u32,
 }
 
@@ -549,6 +2263,76 @@ pub struct UdpConnection {
     pub tx_queue: u32,
 }
 
+/// Transport protocol of a listening socket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(u8)]
+pub enum TransportProtocol {
+    /// TCP.
+    #[default]
+    Tcp = 0,
+    /// UDP.
+    Udp = 1,
+}
+
+/// A socket accepting traffic on a local port.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListeningPort {
+    /// Transport protocol.
+    pub protocol: TransportProtocol,
+    /// Address family (IPv4 or IPv6).
+    pub family: AddressFamily,
+    /// Local IP address the socket is bound to.
+    pub address: String,
+    /// Local port.
+    pub port: u16,
+    /// Process ID owning the socket (-1 if unknown).
+    pub pid: i32,
+    /// Process name (empty if unknown).
+    pub process_name: String,
+}
+
+impl ListeningPort {
+    /// Build a listening port summary from connection listings.
+    ///
+    /// Keeps TCP sockets in `Listen` state and unconnected bound UDP sockets,
+    /// deduplicated by (protocol, address, port). When several sockets share
+    /// a key (e.g. `SO_REUSEPORT`), the first one with a known owner wins.
+    pub fn summarize(tcp: &[TcpConnection], udp: &[UdpConnection]) -> Vec<Self> {
+        let tcp = tcp.iter().filter(|c| c.state == SocketState::Listen).map(|c| Self {
+            protocol: TransportProtocol::Tcp,
+            family: c.family,
+            address: c.local_addr.clone(),
+            port: c.local_port,
+            pid: c.pid,
+            process_name: c.process_name.clone(),
+        });
+        let udp = udp.iter().filter(|c| c.local_port != 0 && c.remote_port == 0).map(|c| Self {
+            protocol: TransportProtocol::Udp,
+            family: c.family,
+            address: c.local_addr.clone(),
+            port: c.local_port,
+            pid: c.pid,
+            process_name: c.process_name.clone(),
+        });
+
+        // Keyed in output order: protocol, port, address
+        let mut ports: BTreeMap<(TransportProtocol, u16, String), Self> = BTreeMap::new();
+        for port in tcp.chain(udp) {
+            match ports.entry((port.protocol, port.port, port.address.clone())) {
+                Entry::Occupied(mut existing) => {
+                    if existing.get().pid <= 0 && port.pid > 0 {
+                        existing.insert(port);
+                    }
+                }
+                Entry::Vacant(slot) => {
+                    slot.insert(port);
+                }
+            }
+        }
+        ports.into_values().collect()
+    }
+}
+
 /// Unix domain socket information.
 #[derive(Debug, Clone, Default)]
 pub struct UnixSocket {
@@ -564,6 +2348,10 @@ pub struct UnixSocket {
     pub process_name: String,
     /// Socket inode number.
     pub inode: u64,
+    /// Process ID of the connected peer (-1 if unknown or unconnected).
+    pub peer_pid: i32,
+    /// User ID of the connected peer (0 if unknown).
+    pub peer_uid: u32,
 }
 
 /// Aggregated TCP connection statistics.
@@ -598,6 +2386,18 @@ pub trait ConnectionCollector: Send + Sync {
     /// Collect all TCP connections.
     fn collect_tcp(&self) -> Result<Vec<TcpConnection>>;
 
+    /// Collect at most `limit` TCP connections.
+    ///
+    /// Returns the connections and whether more were left out. The default
+    /// truncates [`collect_tcp`](Self::collect_tcp); platforms override it
+    /// to stop reading once the limit is reached.
+    fn collect_tcp_limited(&self, limit: usize) -> Result<(Vec<TcpConnection>, bool)> {
+        let mut connections = self.collect_tcp()?;
+        let truncated = connections.len() > limit;
+        connections.truncate(limit);
+        Ok((connections, truncated))
+    }
+
     /// Collect all UDP sockets.
     fn collect_udp(&self) -> Result<Vec<UdpConnection>>;
 
@@ -615,6 +2415,42 @@ pub trait ConnectionCollector: Send + Sync {
 
     /// Find which process owns a specific port.
     fn find_process_by_port(&self, port: u16, tcp: bool) -> Result<Option<i32>>;
+
+    /// Summarize listening TCP ports and bound UDP sockets with their owners.
+    fn listening_ports(&self) -> Result<Vec<ListeningPort>> {
+        Ok(ListeningPort::summarize(&self.collect_tcp()?, &self.collect_udp()?))
+    }
+
+    /// Count established TCP connections per remote IP address.
+    ///
+    /// IPv4-mapped peers of IPv6 sockets (`::ffff:a.b.c.d`) are counted
+    /// under their IPv4 address.
+    fn tcp_connections_by_remote(&self) -> Result<HashMap<String, u32>> {
+        let mut counts = HashMap::new();
+        for conn in self.collect_tcp()? {
+            if conn.state == SocketState::Established {
+                *counts.entry(unmap_ipv4(conn.remote_addr)).or_insert(0) += 1;
+            }
+        }
+        Ok(counts)
+    }
+
+    /// Check if IPv6 is enabled, i.e. whether IPv6 sockets can be reported.
+    ///
+    /// When `false`, connection listings contain IPv4 sockets only.
+    fn ipv6_enabled(&self) -> bool {
+        true
+    }
+}
+
+/// The IPv4 address of an IPv4-mapped IPv6 address, in any notation
+/// (`::ffff:10.0.0.1`, or uncompressed as in /proc/net/tcp6); other
+/// addresses are returned unchanged.
+fn unmap_ipv4(addr: String) -> String {
+    match addr.parse::<std::net::Ipv6Addr>().ok().and_then(|ip| ip.to_ipv4_mapped()) {
+        Some(v4) => v4.to_string(),
+        None => addr,
+    }
 }
 
 // ============================================================================
@@ -623,6 +2459,7 @@ pub trait ConnectionCollector: Send + Sync {
 
 /// All pressure metrics combined (Linux PSI).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AllPressure {
     /// CPU pressure metrics.
     pub cpu: CPUPressure,
@@ -637,6 +2474,7 @@ pub struct AllPressure {
 /// This structure contains all the metrics that can be collected
 /// by the system collector in a single aggregated call.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AllMetrics {
     /// System CPU metrics.
     pub cpu: SystemCPU,
@@ -660,6 +2498,184 @@ pub struct AllMetrics {
     pub pressure: Option<AllPressure>,
     /// Timestamp when metrics were collected (microseconds since epoch).
     pub timestamp_us: u64,
+    /// Time spent gathering the snapshot in microseconds.
+    ///
+    /// Subsystems are read one after another, so readings may be up to this
+    /// far apart; large values hint at an incoherent snapshot.
+    pub collection_duration_us: u64,
+}
+
+/// Operations a collector instance supports on this host.
+///
+/// Bit values from [`Capabilities::bits`] match the `PROBE_CAP_*` constants
+/// exposed over FFI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Capabilities {
+    /// Pressure stall information (PSI) is available.
+    pub has_pressure: bool,
+    /// Temperature sensors are readable.
+    pub has_thermal: bool,
+    /// The platform exposes per-core CPU counters.
+    pub has_per_core: bool,
+    /// Socket/connection enumeration is available.
+    pub has_connections: bool,
+    /// Per-device disk I/O statistics are available.
+    pub has_disk_io: bool,
+}
+
+impl Capabilities {
+    /// Bit for `has_pressure`.
+    pub const PRESSURE: u32 = 1 << 0;
+    /// Bit for `has_thermal`.
+    pub const THERMAL: u32 = 1 << 1;
+    /// Bit for `has_per_core`.
+    pub const PER_CORE: u32 = 1 << 2;
+    /// Bit for `has_connections`.
+    pub const CONNECTIONS: u32 = 1 << 3;
+    /// Bit for `has_disk_io`.
+    pub const DISK_IO: u32 = 1 << 4;
+
+    /// Encode the capabilities as a bitmask.
+    pub fn bits(&self) -> u32 {
+        [
+            (self.has_pressure, Self::PRESSURE),
+            (self.has_thermal, Self::THERMAL),
+            (self.has_per_core, Self::PER_CORE),
+            (self.has_connections, Self::CONNECTIONS),
+            (self.has_disk_io, Self::DISK_IO),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .fold(0, |bits, (_, bit)| bits | bit)
+    }
+}
+
+/// Outcome of probing one subsystem in [`SystemCollector::self_test`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SubsystemStatus {
+    /// The subsystem returned metrics.
+    Ok,
+    /// The platform does not provide this subsystem.
+    #[default]
+    Unsupported,
+    /// The subsystem is expected to work but failed, with the error message.
+    Failed(String),
+}
+
+impl SubsystemStatus {
+    /// Classify the result of a collector call.
+    pub fn from_result<T>(result: &Result<T>) -> Self {
+        match result {
+            Ok(_) => Self::Ok,
+            Err(Error::NotSupported) => Self::Unsupported,
+            Err(e) => Self::Failed(e.to_string()),
+        }
+    }
+
+    /// Whether the subsystem returned metrics.
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Self::Ok)
+    }
+}
+
+/// Per-subsystem results of [`SystemCollector::self_test`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SelfTestReport {
+    /// System CPU metrics.
+    pub cpu: SubsystemStatus,
+    /// System memory metrics.
+    pub memory: SubsystemStatus,
+    /// Load average.
+    pub load: SubsystemStatus,
+    /// Metrics of the calling process.
+    pub process: SubsystemStatus,
+    /// Partition listing.
+    pub disk: SubsystemStatus,
+    /// Network interface listing.
+    pub network: SubsystemStatus,
+    /// System I/O statistics.
+    pub io: SubsystemStatus,
+    /// CPU pressure (PSI).
+    pub pressure: SubsystemStatus,
+}
+
+impl SelfTestReport {
+    /// Whether no subsystem failed (unsupported ones are not failures).
+    pub fn is_healthy(&self) -> bool {
+        [
+            &self.cpu,
+            &self.memory,
+            &self.load,
+            &self.process,
+            &self.disk,
+            &self.network,
+            &self.io,
+            &self.pressure,
+        ]
+        .iter()
+        .all(|status| !matches!(status, SubsystemStatus::Failed(_)))
+    }
+}
+
+/// Set of subsystems to collect with [`SystemCollector::collect_selected`].
+///
+/// Bit values match the `PROBE_METRIC_*` constants exposed over FFI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MetricMask(u32);
+
+impl MetricMask {
+    /// No subsystems.
+    pub const NONE: Self = Self(0);
+    /// System CPU usage.
+    pub const CPU: Self = Self(1 << 0);
+    /// System memory usage.
+    pub const MEMORY: Self = Self(1 << 1);
+    /// Load average.
+    pub const LOAD: Self = Self(1 << 2);
+    /// System-wide I/O statistics.
+    pub const IO: Self = Self(1 << 3);
+    /// Partitions, disk usage and disk I/O.
+    pub const DISK: Self = Self(1 << 4);
+    /// Network interfaces and statistics.
+    pub const NETWORK: Self = Self(1 << 5);
+    /// Pressure metrics (PSI).
+    pub const PRESSURE: Self = Self(1 << 6);
+    /// Every subsystem.
+    pub const ALL: Self = Self((1 << 7) - 1);
+
+    /// Build a mask from raw bits, dropping unknown bits.
+    pub const fn from_bits_truncate(bits: u32) -> Self {
+        Self(bits & Self::ALL.0)
+    }
+
+    /// Raw bit representation.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Check if every subsystem in `other` is also in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Check if no subsystem is selected.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl std::ops::BitOr for MetricMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for MetricMask {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
 }
 
 /// Combined system collector interface.
@@ -679,54 +2695,694 @@ pub trait SystemCollector: Send + Sync {
     /// Get I/O collector.
     fn io(&self) -> &dyn IOCollector;
 
+    /// Collect pressure metrics scoped to the current process's cgroup.
+    ///
+    /// The default implementation returns the system-wide pressure metrics.
+    /// Platforms with cgroup v2 override this to report the container's own
+    /// stall time, falling back to system-wide values when unavailable.
+    fn collect_cgroup_pressure(&self) -> Result<AllPressure> {
+        Ok(AllPressure {
+            cpu: self.cpu().collect_pressure()?,
+            memory: self.memory().collect_pressure()?,
+            io: self.io().collect_pressure()?,
+        })
+    }
+
+    /// Collect kernel-wide activity counters.
+    ///
+    /// Returns `Error::NotSupported` on platforms without an equivalent source.
+    fn collect_kernel_stats(&self) -> Result<KernelStats> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read recent kernel log records of severity `Error` or worse.
+    ///
+    /// Only records logged within `since` are returned (all buffered records
+    /// when `None`), bounded to the most recent ones. Returns
+    /// `Error::Permission` when the kernel log is not readable and
+    /// `Error::NotSupported` on platforms without one.
+    fn recent_kernel_errors(&self, _since: Option<Duration>) -> Result<Vec<KernelLogEntry>> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read the local timezone, UTC offset and NTP synchronization state.
+    ///
+    /// Returns `Error::NotSupported` on platforms without an implementation.
+    fn time_info(&self) -> Result<TimeInfo> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read inotify limits and the current user's instance count.
+    ///
+    /// Returns `Error::NotSupported` on platforms without inotify.
+    fn inotify_stats(&self) -> Result<InotifyStats> {
+        Err(Error::NotSupported)
+    }
+
+    /// List loaded kernel modules.
+    ///
+    /// Returns `Error::NotSupported` on platforms without loadable modules
+    /// and on kernels built without module support.
+    fn list_modules(&self) -> Result<Vec<KernelModule>> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read the namespace identifiers of the collecting process.
+    ///
+    /// Returns `Error::NotSupported` on platforms without namespaces.
+    fn namespace_ids(&self) -> Result<NamespaceIds> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read key system-wide limits in one call.
+    ///
+    /// Limits the platform does not have are reported as 0. Returns
+    /// `Error::NotSupported` on platforms without an implementation.
+    fn system_limits(&self) -> Result<SystemLimits> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read the status of a systemd unit (e.g. `dbus.service`).
+    ///
+    /// Returns `Error::NotFound` for unknown units and `Error::NotSupported`
+    /// on hosts not running systemd.
+    fn unit_status(&self, _unit: &str) -> Result<UnitStatus> {
+        Err(Error::NotSupported)
+    }
+
+    /// Read the unparsed contents of a file the collectors parse, e.g. to
+    /// attach the exact input to a bug report.
+    ///
+    /// Returns `Error::NotSupported` on platforms without procfs.
+    fn read_raw(&self, _source: RawSource) -> Result<String> {
+        Err(Error::NotSupported)
+    }
+
+    /// Whether pressure stall information can be collected at all.
+    ///
+    /// Cheap to call repeatedly: implementations check once and remember
+    /// the answer. [`collect_selected`](Self::collect_selected) skips the
+    /// pressure reads when this is `false`. The default implementation
+    /// assumes support and lets the reads fail.
+    fn pressure_supported(&self) -> bool {
+        true
+    }
+
+    /// Report which optional operations this collector supports.
+    ///
+    /// The default implementation reports nothing as supported.
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::default()
+    }
+
+    /// Query each subsystem once and report whether it works on this host.
+    ///
+    /// Errors are folded into the report rather than returned, so this is
+    /// safe to call as a startup diagnostic.
+    fn self_test(&self) -> SelfTestReport {
+        SelfTestReport {
+            cpu: SubsystemStatus::from_result(&self.cpu().collect_system()),
+            memory: SubsystemStatus::from_result(&self.memory().collect_system()),
+            load: SubsystemStatus::from_result(&self.load().collect()),
+            process: SubsystemStatus::from_result(
+                &self.process().collect(std::process::id() as i32),
+            ),
+            disk: SubsystemStatus::from_result(&self.disk().list_partitions()),
+            network: SubsystemStatus::from_result(&self.network().list_interfaces()),
+            io: SubsystemStatus::from_result(&self.io().collect_stats()),
+            pressure: SubsystemStatus::from_result(&self.cpu().collect_pressure()),
+        }
+    }
+
+    /// Compute a host saturation score with the default weights.
+    ///
+    /// See [`SaturationScore`] for how each component is derived.
+    fn saturation_score(&self) -> Result<SaturationScore> {
+        self.saturation_score_with(&SaturationWeights::default())
+    }
+
+    /// Compute a host saturation score with custom weights.
+    ///
+    /// Blocks for [`SATURATION_SAMPLE_WINDOW`] to measure swap activity and,
+    /// where [`CPUCollector::cpu_ticks`] is supported, CPU usage over that
+    /// window. I/O pressure and swap activity are optional: when unavailable
+    /// their weight is left out and the score is normalized over the
+    /// remaining components.
+    fn saturation_score_with(&self, weights: &SaturationWeights) -> Result<SaturationScore> {
+        let mut cpu_sampler = CpuSampler::new();
+        let started = std::time::Instant::now();
+        let swapped_before = self.memory().swapped_pages().ok();
+        if let Ok(ticks) = self.cpu().cpu_ticks() {
+            cpu_sampler.sample(ticks);
+        }
+        std::thread::sleep(SATURATION_SAMPLE_WINDOW);
+
+        let mut cpu = self.cpu().collect_system()?;
+        if let Some(usage) = self.cpu().cpu_ticks().ok().and_then(|t| cpu_sampler.sample(t)) {
+            usage.apply_to(&mut cpu);
+        }
+        let swap_pages_per_sec =
+            swapped_before.zip(self.memory().swapped_pages().ok()).map(|(before, after)| {
+                after.saturating_sub(before) as f64 / started.elapsed().as_secs_f64()
+            });
+        let memory = self.memory().collect_system()?;
+        let load = self.load().collect()?;
+        let io_pressure = self.io().collect_pressure().ok();
+        Ok(SaturationScore::compute(
+            &cpu,
+            &memory,
+            &load,
+            io_pressure.as_ref(),
+            swap_pages_per_sec,
+            weights,
+        ))
+    }
+
     /// Collect all metrics in one call.
     ///
     /// This is more efficient than calling each collector individually
     /// as it reduces the number of system calls and provides a consistent
     /// snapshot of all metrics at approximately the same point in time.
     fn collect_all(&self) -> Result<AllMetrics> {
-        use std::time::{SystemTime, UNIX_EPOCH};
+        self.collect_selected(MetricMask::ALL)
+    }
+
+    /// Collect all metrics twice, `window` apart, and return the rates
+    /// between the two snapshots.
+    ///
+    /// Blocks the calling thread for `window` plus two collections. CPU
+    /// percentages also cover the window where the platform reports
+    /// [`CPUCollector::cpu_ticks`]. Suited to one-shot callers that cannot
+    /// keep a [`MetricsDeltaSampler`] between calls.
+    fn collect_all_sampled(&self, window: Duration) -> Result<MetricsDelta> {
+        let mut sampler = MetricsDeltaSampler::new();
+        sampler.sample_with_ticks(&self.collect_all()?, self.cpu().cpu_ticks().ok());
+        std::thread::sleep(window);
+        let metrics = self.collect_all()?;
+        // A primed sampler always returns a delta
+        Ok(sampler
+            .sample_with_ticks(&metrics, self.cpu().cpu_ticks().ok())
+            .expect("sampler is primed"))
+    }
 
+    /// Collect only the subsystems in `mask`.
+    ///
+    /// Subsystems not in the mask are never queried and are left at their
+    /// default values (`pressure` is `None`).
+    fn collect_selected(&self, mask: MetricMask) -> Result<AllMetrics> {
+        use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+        let started = Instant::now();
         let timestamp_us =
             SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_micros() as u64).unwrap_or(0);
 
-        // Collect all metrics, using defaults for any that fail
-        let cpu = self.cpu().collect_system().unwrap_or_default();
-        let memory = self.memory().collect_system().unwrap_or_default();
-        let load = self.load().collect().unwrap_or_default();
-        let io_stats = self.io().collect_stats().unwrap_or_default();
+        let mut metrics = AllMetrics { timestamp_us, ..Default::default() };
 
-        let partitions = self.disk().list_partitions().unwrap_or_default();
-        let disk_usage = self.disk().collect_all_usage().unwrap_or_default();
-        let disk_io = self.disk().collect_io().unwrap_or_default();
+        // Collect requested metrics, using defaults for any that fail
+        if mask.contains(MetricMask::CPU) {
+            metrics.cpu = self.cpu().collect_system().unwrap_or_default();
+        }
+        if mask.contains(MetricMask::MEMORY) {
+            metrics.memory = self.memory().collect_system().unwrap_or_default();
+        }
+        if mask.contains(MetricMask::LOAD) {
+            metrics.load = self.load().collect().unwrap_or_default();
+        }
+        if mask.contains(MetricMask::IO) {
+            metrics.io_stats = self.io().collect_stats().unwrap_or_default();
+        }
+
+        if mask.contains(MetricMask::DISK) {
+            metrics.partitions = self.disk().list_partitions().unwrap_or_default();
+            metrics.disk_usage = self.disk().collect_all_usage().unwrap_or_default();
+            metrics.disk_io = self.disk().collect_io().unwrap_or_default();
+        }
 
-        let net_interfaces = self.network().list_interfaces().unwrap_or_default();
-        let net_stats = self.network().collect_all_stats().unwrap_or_default();
+        if mask.contains(MetricMask::NETWORK) {
+            metrics.net_interfaces = self.network().list_interfaces().unwrap_or_default();
+            metrics.net_stats = self.network().collect_all_stats().unwrap_or_default();
+        }
 
         // Try to collect pressure metrics (Linux only)
-        let pressure = match (
-            self.cpu().collect_pressure(),
-            self.memory().collect_pressure(),
-            self.io().collect_pressure(),
-        ) {
-            (Ok(cpu_p), Ok(mem_p), Ok(io_p)) => {
-                Some(AllPressure { cpu: cpu_p, memory: mem_p, io: io_p })
+        if mask.contains(MetricMask::PRESSURE) && self.pressure_supported() {
+            metrics.pressure = match (
+                self.cpu().collect_pressure(),
+                self.memory().collect_pressure(),
+                self.io().collect_pressure(),
+            ) {
+                (Ok(cpu_p), Ok(mem_p), Ok(io_p)) => {
+                    Some(AllPressure { cpu: cpu_p, memory: mem_p, io: io_p })
+                }
+                _ => None,
+            };
+        }
+
+        metrics.collection_duration_us = started.elapsed().as_micros() as u64;
+        Ok(metrics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_cpu_vuln_status_classification() {
+        assert_eq!(
+            CpuVulnStatus::classify("KVM: Mitigation: VMX disabled"),
+            CpuVulnStatus::Mitigated
+        );
+        assert_eq!(CpuVulnStatus::classify("KVM: Vulnerable"), CpuVulnStatus::Vulnerable);
+        assert_eq!(
+            CpuVulnStatus::classify("Vulnerable: Retpoline without IBPB"),
+            CpuVulnStatus::Vulnerable
+        );
+        assert_eq!(CpuVulnStatus::classify("Unknown: No mitigations"), CpuVulnStatus::Unknown);
+        assert_eq!(CpuVulnStatus::classify("Processor vulnerable"), CpuVulnStatus::Vulnerable);
+        assert_eq!(CpuVulnStatus::classify("Not affected"), CpuVulnStatus::NotAffected);
+    }
+
+    /// Collector whose subsystems count how often they are queried.
+    #[derive(Default)]
+    struct MockCollector {
+        cpu_calls: AtomicUsize,
+        disk_calls: AtomicUsize,
+        network_calls: AtomicUsize,
+    }
+
+    impl CPUCollector for MockCollector {
+        fn collect_system(&self) -> Result<SystemCPU> {
+            self.cpu_calls.fetch_add(1, Ordering::Relaxed);
+            Ok(SystemCPU { user_percent: 42.0, ..Default::default() })
+        }
+        fn collect_pressure(&self) -> Result<CPUPressure> {
+            Err(Error::NotSupported)
+        }
+    }
+
+    impl MemoryCollector for MockCollector {
+        fn collect_system(&self) -> Result<SystemMemory> {
+            Ok(SystemMemory { total_bytes: 1024, ..Default::default() })
+        }
+        fn collect_pressure(&self) -> Result<MemoryPressure> {
+            Err(Error::NotSupported)
+        }
+    }
+
+    impl LoadCollector for MockCollector {
+        fn collect(&self) -> Result<LoadAverage> {
+            Ok(LoadAverage::default())
+        }
+    }
+
+    impl ProcessCollector for MockCollector {
+        fn collect(&self, pid: i32) -> Result<ProcessMetrics> {
+            Err(Error::NotFound(format!("process {}", pid)))
+        }
+        fn collect_all(&self) -> Result<Vec<ProcessMetrics>> {
+            Ok(Vec::new())
+        }
+    }
+
+    impl DiskCollector for MockCollector {
+        fn list_partitions(&self) -> Result<Vec<Partition>> {
+            self.disk_calls.fetch_add(1, Ordering::Relaxed);
+            Ok(Vec::new())
+        }
+        fn collect_usage(&self, _path: &str) -> Result<DiskUsage> {
+            self.disk_calls.fetch_add(1, Ordering::Relaxed);
+            Ok(DiskUsage::default())
+        }
+        fn collect_all_usage(&self) -> Result<Vec<DiskUsage>> {
+            self.disk_calls.fetch_add(1, Ordering::Relaxed);
+            Ok(Vec::new())
+        }
+        fn collect_io(&self) -> Result<Vec<DiskIOStats>> {
+            self.disk_calls.fetch_add(1, Ordering::Relaxed);
+            Ok(Vec::new())
+        }
+        fn collect_device_io(&self, _device: &str) -> Result<DiskIOStats> {
+            self.disk_calls.fetch_add(1, Ordering::Relaxed);
+            Ok(DiskIOStats::default())
+        }
+    }
+
+    impl NetworkCollector for MockCollector {
+        fn list_interfaces(&self) -> Result<Vec<NetInterface>> {
+            self.network_calls.fetch_add(1, Ordering::Relaxed);
+            Ok(Vec::new())
+        }
+        fn collect_stats(&self, _interface: &str) -> Result<NetStats> {
+            self.network_calls.fetch_add(1, Ordering::Relaxed);
+            Ok(NetStats::default())
+        }
+        fn collect_all_stats(&self) -> Result<Vec<NetStats>> {
+            self.network_calls.fetch_add(1, Ordering::Relaxed);
+            Ok(Vec::new())
+        }
+    }
+
+    impl IOCollector for MockCollector {
+        fn collect_stats(&self) -> Result<IOStats> {
+            Ok(IOStats::default())
+        }
+        fn collect_pressure(&self) -> Result<IOPressure> {
+            Err(Error::NotSupported)
+        }
+    }
+
+    impl SystemCollector for MockCollector {
+        fn cpu(&self) -> &dyn CPUCollector {
+            self
+        }
+        fn memory(&self) -> &dyn MemoryCollector {
+            self
+        }
+        fn load(&self) -> &dyn LoadCollector {
+            self
+        }
+        fn process(&self) -> &dyn ProcessCollector {
+            self
+        }
+        fn disk(&self) -> &dyn DiskCollector {
+            self
+        }
+        fn network(&self) -> &dyn NetworkCollector {
+            self
+        }
+        fn io(&self) -> &dyn IOCollector {
+            self
+        }
+    }
+
+    #[test]
+    fn test_cpu_temperature_from_coretemp_zones() {
+        let zone = |name: &str, label: &str, temp_celsius: f64| ThermalZone {
+            name: name.to_string(),
+            label: label.to_string(),
+            temp_celsius,
+            ..Default::default()
+        };
+        let zones = [
+            zone("coretemp", "Package id 0", 58.0),
+            zone("coretemp", "Core 0", 50.0),
+            zone("coretemp", "Core 1", 56.0),
+            zone("coretemp", "Core 2", 51.0),
+            zone("coretemp", "Core 3", 55.0),
+            zone("nvme", "Composite", 70.0),
+            zone("acpitz", "", 90.0),
+        ];
+
+        let temp = CpuTemp::from_zones(&zones).unwrap();
+        assert_eq!(temp.package_celsius, Some(58.0));
+        assert_eq!(temp.core_max_celsius, 56.0);
+        assert_eq!(temp.average_celsius, 53.0);
+
+        // AMD without per-core sensors
+        let temp = CpuTemp::from_zones(&[zone("k10temp", "Tctl", 61.5)]).unwrap();
+        assert_eq!(temp.package_celsius, Some(61.5));
+        assert_eq!(temp.core_max_celsius, 61.5);
+
+        assert_eq!(CpuTemp::from_zones(&zones[5..]), None);
+    }
+
+    #[test]
+    fn test_collect_selected_cpu_only() {
+        let collector = MockCollector::default();
+
+        let metrics = collector.collect_selected(MetricMask::CPU).unwrap();
+
+        assert_eq!(metrics.cpu.user_percent, 42.0);
+        assert_eq!(metrics.memory.total_bytes, 0);
+        assert!(metrics.pressure.is_none());
+        assert!(metrics.timestamp_us > 0);
+        assert_eq!(collector.cpu_calls.load(Ordering::Relaxed), 1);
+        assert_eq!(collector.disk_calls.load(Ordering::Relaxed), 0);
+        assert_eq!(collector.network_calls.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_collect_all_queries_every_subsystem() {
+        let collector = MockCollector::default();
+
+        let metrics = SystemCollector::collect_all(&collector).unwrap();
+
+        assert_eq!(metrics.memory.total_bytes, 1024);
+        assert_eq!(collector.disk_calls.load(Ordering::Relaxed), 3);
+        assert_eq!(collector.network_calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_parse_mount_options() {
+        let partition = Partition {
+            options: "rw,nosuid,nodev,noexec,relatime,size=65536k,mode=755".to_string(),
+            ..Default::default()
+        };
+
+        let options = partition.parse_options();
+
+        assert!(options.rw);
+        assert!(!options.ro);
+        assert!(options.nosuid);
+        assert!(options.nodev);
+        assert!(options.noexec);
+        assert!(options.relatime);
+        assert!(!options.noatime);
+        assert_eq!(options.extra, vec!["size=65536k", "mode=755"]);
+
+        let options = MountOptions::parse("ro,noatime");
+        assert!(!options.rw);
+        assert!(options.ro);
+        assert!(options.noatime);
+        assert!(options.extra.is_empty());
+    }
+
+    /// Network collector reporting a fixed set of (name, is_loopback) interfaces.
+    struct MockNetwork(Vec<(&'static str, bool)>);
+
+    impl NetworkCollector for MockNetwork {
+        fn list_interfaces(&self) -> Result<Vec<NetInterface>> {
+            Ok(self
+                .0
+                .iter()
+                .map(|&(name, is_loopback)| NetInterface {
+                    name: name.to_string(),
+                    is_loopback,
+                    ..Default::default()
+                })
+                .collect())
+        }
+        fn collect_stats(&self, interface: &str) -> Result<NetStats> {
+            Ok(NetStats { interface: interface.to_string(), ..Default::default() })
+        }
+        fn collect_all_stats(&self) -> Result<Vec<NetStats>> {
+            self.0.iter().map(|(name, _)| self.collect_stats(name)).collect()
+        }
+    }
+
+    #[test]
+    fn test_physical_stats_exclude_virtual_interfaces() {
+        let network = MockNetwork(vec![
+            ("lo", true),
+            ("eth0", false),
+            ("docker0", false),
+            ("veth12ab", false),
+            ("br-3f2a", false),
+            ("cni0", false),
+            ("enp3s0", false),
+        ]);
+        let names =
+            |stats: Vec<NetStats>| stats.into_iter().map(|s| s.interface).collect::<Vec<_>>();
+
+        assert_eq!(names(network.collect_physical_stats().unwrap()), vec!["eth0", "enp3s0"]);
+
+        let filter = NetInterfaceFilter {
+            exclude_loopback: false,
+            exclude_prefixes: vec!["en".to_string()],
+        };
+        assert_eq!(
+            names(network.collect_filtered_stats(&filter).unwrap()),
+            vec!["lo", "eth0", "docker0", "veth12ab", "br-3f2a", "cni0"]
+        );
+    }
+
+    #[test]
+    fn test_metric_mask_bits() {
+        let mask = MetricMask::CPU | MetricMask::MEMORY;
+        assert!(mask.contains(MetricMask::CPU));
+        assert!(!mask.contains(MetricMask::DISK));
+        assert_eq!(MetricMask::from_bits_truncate(u32::MAX), MetricMask::ALL);
+        assert!(MetricMask::NONE.is_empty());
+    }
+
+    #[test]
+    fn test_self_test_classifies_each_subsystem() {
+        let report = MockCollector::default().self_test();
+
+        assert_eq!(report.cpu, SubsystemStatus::Ok);
+        assert_eq!(report.memory, SubsystemStatus::Ok);
+        assert_eq!(report.pressure, SubsystemStatus::Unsupported);
+        assert!(matches!(
+            report.process,
+            SubsystemStatus::Failed(ref reason) if reason.contains("not found")
+        ));
+        assert!(!report.is_healthy());
+    }
+
+    #[test]
+    fn test_truly_available_counts_reclaimable_slab() {
+        let memory = SystemMemory {
+            total_bytes: 1000,
+            available_bytes: 600,
+            used_bytes: 400,
+            slab_reclaimable_bytes: 150,
+            ..Default::default()
+        };
+        // MemAvailable already holds at least half of the reclaimable slab
+        assert_eq!(memory.truly_available_bytes(), 675);
+        assert_eq!(memory.effective_used_bytes(), 325);
+
+        let full = SystemMemory { slab_reclaimable_bytes: 1200, ..memory };
+        assert_eq!(full.truly_available_bytes(), 1000);
+        assert_eq!(full.effective_used_bytes(), 0);
+    }
+
+    #[test]
+    fn test_pressure_level_classification() {
+        let memory = |some_avg10, full_avg10| MemoryPressure {
+            some_avg10,
+            full_avg10,
+            ..Default::default()
+        };
+
+        assert_eq!(memory(0.0, 0.0).level(), PressureLevel::None);
+        assert_eq!(memory(12.5, 0.0).level(), PressureLevel::Some);
+        assert_eq!(memory(12.5, 0.31).level(), PressureLevel::Full);
+        assert_eq!(
+            IOPressure { some_avg10: 4.0, ..Default::default() }.level(),
+            PressureLevel::Some
+        );
+        assert_eq!(
+            CPUPressure { some_avg10: 99.0, ..Default::default() }.level(),
+            PressureLevel::Some
+        );
+        assert!(MockCollector::default().memory().memory_stalled().is_err());
+    }
+
+    #[test]
+    fn test_capabilities_bits() {
+        let caps = Capabilities { has_pressure: true, has_disk_io: true, ..Default::default() };
+        assert_eq!(caps.bits(), Capabilities::PRESSURE | Capabilities::DISK_IO);
+        assert_eq!(Capabilities::default().bits(), 0);
+        assert_eq!(MockCollector::default().capabilities(), Capabilities::default());
+    }
+
+    #[test]
+    fn test_io_stats_from_disks_saturates() {
+        let disks = [
+            DiskIOStats {
+                reads_completed: 10,
+                read_bytes: u64::MAX - 100,
+                write_bytes: 4096,
+                ..Default::default()
+            },
+            DiskIOStats {
+                reads_completed: 5,
+                read_bytes: 4096,
+                write_bytes: 4096,
+                ..Default::default()
+            },
+        ];
+
+        let total = IOStats::from_disks(&disks);
+        assert_eq!(total.read_ops, 15);
+        assert_eq!(total.read_bytes, u64::MAX);
+        assert_eq!(total.write_bytes, 8192);
+        assert_eq!(IOStats::from_disks(&[]).read_bytes, 0);
+    }
+
+    #[test]
+    fn test_tcp_connections_by_remote() {
+        struct MockConnections;
+
+        impl ConnectionCollector for MockConnections {
+            fn collect_tcp(&self) -> Result<Vec<TcpConnection>> {
+                let conn = |remote: &str, state| TcpConnection {
+                    remote_addr: remote.to_string(),
+                    state,
+                    ..Default::default()
+                };
+                Ok(vec![
+                    conn("10.0.0.1", SocketState::Established),
+                    conn("10.0.0.1", SocketState::Established),
+                    conn("10.0.0.1", SocketState::TimeWait),
+                    conn("::ffff:10.0.0.1", SocketState::Established),
+                    conn("2001:db8::2", SocketState::Established),
+                    conn("0.0.0.0", SocketState::Listen),
+                ])
+            }
+            fn collect_udp(&self) -> Result<Vec<UdpConnection>> {
+                Ok(Vec::new())
+            }
+            fn collect_unix(&self) -> Result<Vec<UnixSocket>> {
+                Ok(Vec::new())
+            }
+            fn collect_tcp_stats(&self) -> Result<TcpStats> {
+                Ok(TcpStats::default())
+            }
+            fn collect_process_connections(
+                &self,
+                _pid: i32,
+            ) -> Result<(Vec<TcpConnection>, Vec<UdpConnection>)> {
+                Ok((Vec::new(), Vec::new()))
+            }
+            fn find_process_by_port(&self, _port: u16, _tcp: bool) -> Result<Option<i32>> {
+                Ok(None)
             }
-            _ => None,
+        }
+
+        let counts = MockConnections.tcp_connections_by_remote().unwrap();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["10.0.0.1"], 3);
+        assert_eq!(counts["2001:db8::2"], 1);
+    }
+
+    #[test]
+    fn test_root_filesystem_readonly() {
+        struct MockDisk(Vec<Partition>);
+
+        impl DiskCollector for MockDisk {
+            fn list_partitions(&self) -> Result<Vec<Partition>> {
+                Ok(self.0.clone())
+            }
+            fn collect_usage(&self, _path: &str) -> Result<DiskUsage> {
+                Err(Error::NotSupported)
+            }
+            fn collect_all_usage(&self) -> Result<Vec<DiskUsage>> {
+                Err(Error::NotSupported)
+            }
+            fn collect_io(&self) -> Result<Vec<DiskIOStats>> {
+                Err(Error::NotSupported)
+            }
+            fn collect_device_io(&self, _device: &str) -> Result<DiskIOStats> {
+                Err(Error::NotSupported)
+            }
+        }
+
+        let mount = |mount_point: &str, options: &str| Partition {
+            mount_point: mount_point.to_string(),
+            options: options.to_string(),
+            ..Default::default()
         };
+        let rw_root = vec![mount("/", "rw,relatime"), mount("/data", "ro")];
+        assert!(!MockDisk(rw_root).root_filesystem_readonly().unwrap());
 
-        Ok(AllMetrics {
-            cpu,
-            memory,
-            load,
-            io_stats,
-            partitions,
-            disk_usage,
-            disk_io,
-            net_interfaces,
-            net_stats,
-            pressure,
-            timestamp_us,
-        })
+        // An overlay remounted read-only hides the writable root below it
+        let ro_root = vec![mount("/", "rw,relatime"), mount("/", "ro,relatime")];
+        assert!(MockDisk(ro_root).root_filesystem_readonly().unwrap());
+
+        let no_root = vec![mount("/data", "rw")];
+        assert!(matches!(MockDisk(no_root).root_filesystem_readonly(), Err(Error::NotFound(_))));
     }
 }
@@ -0,0 +1,145 @@
+//! Polled process start/exit notifications.
+//!
+//! [`ProcessWatcher`] lists processes from a background thread at a fixed
+//! interval and diffs successive lists. Processes are keyed by PID and start
+//! time, so a reused PID reports an exit followed by a start.
+
+use crate::{BackgroundThread, ProcessIdentity, Result, spawn_background};
+use std::collections::HashMap;
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::Duration;
+
+/// Polling interval suggested for process watching.
+pub const DEFAULT_PROCESS_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Selects the processes a [`ProcessWatcher`] reports on.
+///
+/// An empty filter matches every process; otherwise a process matches when
+/// its PID is listed or its name contains `name_contains`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProcessFilter {
+    /// PIDs to watch.
+    pub pids: Vec<i32>,
+    /// Substring to look for in process names.
+    pub name_contains: Option<String>,
+}
+
+impl ProcessFilter {
+    /// Whether `process` is selected by this filter.
+    pub fn matches(&self, process: &ProcessIdentity) -> bool {
+        if self.pids.is_empty() && self.name_contains.is_none() {
+            return true;
+        }
+        self.pids.contains(&process.pid)
+            || self.name_contains.as_deref().is_some_and(|name| process.name.contains(name))
+    }
+}
+
+/// Reports processes matching a filter as they start and exit.
+///
+/// Processes already running when the watcher starts are not reported as
+/// started. Callbacks run on the watcher thread. Dropping the watcher, or
+/// [`shutdown_background`](crate::shutdown_background), stops the thread and
+/// waits for it, so callbacks are never invoked afterwards.
+pub struct ProcessWatcher {
+    thread: BackgroundThread,
+}
+
+impl ProcessWatcher {
+    /// Start polling `list` every `interval`.
+    ///
+    /// `list` is typically `move || collector.process().list_identities()`.
+    /// The first listing happens before this returns and its error, if any,
+    /// is returned; later listing errors skip that poll.
+    pub fn start<L, S, E>(
+        mut list: L,
+        interval: Duration,
+        filter: ProcessFilter,
+        mut on_start: S,
+        mut on_exit: E,
+    ) -> Result<Self>
+    where
+        L: FnMut() -> Result<Vec<ProcessIdentity>> + Send + 'static,
+        S: FnMut(ProcessIdentity) + Send + 'static,
+        E: FnMut(i32) + Send + 'static,
+    {
+        let mut table = ProcessTable::default();
+        table.sync(list()?, &filter);
+
+        let thread = spawn_background("probe-process-watch", move |stop| {
+            while let Err(RecvTimeoutError::Timeout) = stop.recv_timeout(interval) {
+                let Ok(current) = list() else { continue };
+                let (started, exited) = table.sync(current, &filter);
+                exited.into_iter().for_each(&mut on_exit);
+                started.into_iter().for_each(&mut on_start);
+            }
+        })?;
+        Ok(Self { thread })
+    }
+
+    /// Whether the watcher thread is still running.
+    pub fn is_running(&self) -> bool {
+        self.thread.is_running()
+    }
+}
+
+/// Start time of each tracked process, by PID.
+#[derive(Debug, Default)]
+struct ProcessTable(HashMap<i32, u64>);
+
+impl ProcessTable {
+    /// Replace the tracked processes with the matching ones in `current`,
+    /// returning those that started and the PIDs that exited.
+    fn sync(
+        &mut self,
+        current: Vec<ProcessIdentity>,
+        filter: &ProcessFilter,
+    ) -> (Vec<ProcessIdentity>, Vec<i32>) {
+        let current: HashMap<i32, ProcessIdentity> =
+            current.into_iter().filter(|p| filter.matches(p)).map(|p| (p.pid, p)).collect();
+
+        let mut exited: Vec<i32> = self
+            .0
+            .iter()
+            .filter(|&(pid, start)| current.get(pid).map(|p| p.start_time) != Some(*start))
+            .map(|(&pid, _)| pid)
+            .collect();
+        exited.sort_unstable();
+
+        let mut started: Vec<ProcessIdentity> =
+            current.into_values().filter(|p| self.0.get(&p.pid) != Some(&p.start_time)).collect();
+        started.sort_unstable_by_key(|p| p.pid);
+
+        for pid in &exited {
+            self.0.remove(pid);
+        }
+        self.0.extend(started.iter().map(|p| (p.pid, p.start_time)));
+        (started, exited)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: i32, name: &str, start_time: u64) -> ProcessIdentity {
+        ProcessIdentity { pid, name: name.to_string(), start_time }
+    }
+
+    #[test]
+    fn test_table_detects_pid_reuse() {
+        let filter =
+            ProcessFilter { name_contains: Some("nginx".to_string()), ..Default::default() };
+        let mut table = ProcessTable::default();
+        table.sync(vec![process(10, "nginx", 100), process(11, "bash", 100)], &filter);
+
+        let (started, exited) =
+            table.sync(vec![process(10, "nginx", 500), process(12, "nginx", 510)], &filter);
+        assert_eq!(exited, vec![10]);
+        assert_eq!(started, vec![process(10, "nginx", 500), process(12, "nginx", 510)]);
+
+        let (started, exited) = table.sync(vec![process(12, "nginx", 510)], &filter);
+        assert_eq!(exited, vec![10]);
+        assert!(started.is_empty());
+    }
+}
@@ -0,0 +1,1063 @@
+//! Rate computation over successive counter snapshots.
+
+use crate::{
+    AllMetrics, CpuTicks, CpuUsage, DiskIOStats, Error, IOStats, NetStats, ProcessIdentityKey,
+    Result, SystemCPU, ThreadInfo,
+};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Record `key` as the process a sampler tracks.
+///
+/// Returns true when it replaces a different process, i.e. the PID was
+/// reused or the caller switched processes, and the sampler must reset.
+fn replace_process(tracked: &mut Option<ProcessIdentityKey>, key: ProcessIdentityKey) -> bool {
+    tracked.replace(key).is_some_and(|previous| previous != key)
+}
+
+/// Per-device I/O rates derived from two `DiskIOStats` snapshots.
+///
+/// Field semantics follow `iostat -x`.
+#[derive(Debug, Clone, Default)]
+pub struct DiskIORate {
+    /// Device name (e.g., sda).
+    pub device: String,
+    /// Read operations per second.
+    pub reads_per_sec: f64,
+    /// Write operations per second.
+    pub writes_per_sec: f64,
+    /// Bytes read per second.
+    pub read_bytes_per_sec: f64,
+    /// Bytes written per second.
+    pub write_bytes_per_sec: f64,
+    /// Percentage of the interval the device was busy (%util).
+    pub utilization_percent: f64,
+    /// Average size of completed requests in bytes (avgrq-sz).
+    pub avg_request_size_bytes: f64,
+    /// Average number of requests in flight (avgqu-sz).
+    pub avg_queue_depth: f64,
+}
+
+impl DiskIORate {
+    /// Compute rates between two snapshots of the same device.
+    ///
+    /// Counter resets yield zero deltas. Zero-op intervals report a request
+    /// size of 0 and a zero interval reports all rates as 0.
+    pub fn between(prev: &DiskIOStats, curr: &DiskIOStats, interval: Duration) -> Self {
+        let secs = interval.as_secs_f64();
+        let interval_us = interval.as_micros() as f64;
+        if secs <= 0.0 {
+            return Self { device: curr.device.clone(), ..Default::default() };
+        }
+
+        let reads = curr.reads_completed.saturating_sub(prev.reads_completed);
+        let writes = curr.writes_completed.saturating_sub(prev.writes_completed);
+        let read_bytes = curr.read_bytes.saturating_sub(prev.read_bytes);
+        let write_bytes = curr.write_bytes.saturating_sub(prev.write_bytes);
+        let io_time_us = curr.io_time_us.saturating_sub(prev.io_time_us);
+        let weighted_us = curr.weighted_io_time_us.saturating_sub(prev.weighted_io_time_us);
+
+        let ops = reads + writes;
+        let avg_request_size_bytes =
+            if ops == 0 { 0.0 } else { (read_bytes + write_bytes) as f64 / ops as f64 };
+
+        Self {
+            device: curr.device.clone(),
+            reads_per_sec: reads as f64 / secs,
+            writes_per_sec: writes as f64 / secs,
+            read_bytes_per_sec: read_bytes as f64 / secs,
+            write_bytes_per_sec: write_bytes as f64 / secs,
+            utilization_percent: (io_time_us as f64 / interval_us * 100.0).min(100.0),
+            avg_request_size_bytes,
+            avg_queue_depth: weighted_us as f64 / interval_us,
+        }
+    }
+}
+
+/// Turns successive `DiskIOStats` snapshots into per-device rates.
+///
+/// The first sample only primes the sampler and returns no rates.
+#[derive(Debug, Default)]
+pub struct DiskIORateSampler {
+    previous: HashMap<String, DiskIOStats>,
+    last_sample: Option<Instant>,
+}
+
+impl DiskIORateSampler {
+    /// Create an empty sampler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a snapshot taken now and return rates since the previous one.
+    pub fn sample(&mut self, stats: &[DiskIOStats]) -> Vec<DiskIORate> {
+        let now = Instant::now();
+        let interval = self.last_sample.map(|t| now.duration_since(t));
+        self.last_sample = Some(now);
+        match interval {
+            Some(interval) => self.sample_with_interval(stats, interval),
+            None => {
+                self.store(stats);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Record a snapshot taken `interval` after the previous one.
+    ///
+    /// Devices absent from the previous snapshot are skipped.
+    pub fn sample_with_interval(
+        &mut self,
+        stats: &[DiskIOStats],
+        interval: Duration,
+    ) -> Vec<DiskIORate> {
+        let rates = stats
+            .iter()
+            .filter_map(|curr| {
+                self.previous
+                    .get(&curr.device)
+                    .map(|prev| DiskIORate::between(prev, curr, interval))
+            })
+            .collect();
+        self.store(stats);
+        rates
+    }
+
+    /// Forget all previous snapshots.
+    pub fn reset(&mut self) {
+        self.previous.clear();
+        self.last_sample = None;
+    }
+
+    fn store(&mut self, stats: &[DiskIOStats]) {
+        self.previous = stats.iter().map(|s| (s.device.clone(), s.clone())).collect();
+    }
+}
+
+/// Turns successive process-creation counters into a fork rate.
+///
+/// Fed with `KernelStats::processes_started_total` (the `processes` line of
+/// /proc/stat). The first sample only primes the sampler and returns `None`.
+#[derive(Debug, Default)]
+pub struct ForkRateSampler {
+    previous: Option<u64>,
+    last_sample: Option<Instant>,
+}
+
+impl ForkRateSampler {
+    /// Create an empty sampler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a counter read now and return forks/sec since the previous one.
+    pub fn sample(&mut self, processes_total: u64) -> Option<f64> {
+        let now = Instant::now();
+        let interval = self.last_sample.map(|t| now.duration_since(t));
+        self.last_sample = Some(now);
+        match interval {
+            Some(interval) => self.sample_with_interval(processes_total, interval),
+            None => {
+                self.previous = Some(processes_total);
+                None
+            }
+        }
+    }
+
+    /// Record a counter read `interval` after the previous one.
+    ///
+    /// A counter lower than the previous one (reboot) yields a rate of 0 and
+    /// becomes the new baseline. A zero interval also reports 0.
+    pub fn sample_with_interval(
+        &mut self,
+        processes_total: u64,
+        interval: Duration,
+    ) -> Option<f64> {
+        let previous = self.previous.replace(processes_total)?;
+        let secs = interval.as_secs_f64();
+        if secs <= 0.0 {
+            return Some(0.0);
+        }
+        Some(processes_total.saturating_sub(previous) as f64 / secs)
+    }
+
+    /// Forget the previous sample.
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.last_sample = None;
+    }
+}
+
+/// Turns successive thread listings of one process into per-thread CPU usage.
+///
+/// Fed with `ProcessCollector::list_threads`. Threads are matched by TID:
+/// threads seen for the first time (all of them on the first sample) report
+/// 0% and threads that exited are forgotten.
+#[derive(Debug, Default)]
+pub struct ThreadCpuSampler {
+    previous: HashMap<i32, u64>,
+    last_sample: Option<Instant>,
+    process: Option<ProcessIdentityKey>,
+}
+
+impl ThreadCpuSampler {
+    /// Create an empty sampler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fill in `cpu_percent` of threads listed now, relative to the previous call.
+    pub fn sample(&mut self, threads: &mut [ThreadInfo]) {
+        let now = Instant::now();
+        let interval =
+            self.last_sample.replace(now).map_or(Duration::ZERO, |t| now.duration_since(t));
+        self.sample_with_interval(threads, interval);
+    }
+
+    /// Fill in `cpu_percent` of threads listed `interval` after the previous call.
+    ///
+    /// A zero interval reports 0 for every thread.
+    pub fn sample_with_interval(&mut self, threads: &mut [ThreadInfo], interval: Duration) {
+        let interval_us = interval.as_micros() as f64;
+        let previous = std::mem::take(&mut self.previous);
+        for thread in threads.iter_mut() {
+            let total_us = thread.user_time_us + thread.system_time_us;
+            thread.cpu_percent = match previous.get(&thread.tid) {
+                Some(&prev_us) if interval_us > 0.0 => {
+                    total_us.saturating_sub(prev_us) as f64 / interval_us * 100.0
+                }
+                _ => 0.0,
+            };
+            self.previous.insert(thread.tid, total_us);
+        }
+    }
+
+    /// Fill in `cpu_percent` of the threads of the process identified by `key`.
+    ///
+    /// If the PID now belongs to another process, the sampler restarts and
+    /// every thread reports 0%, as on the first sample.
+    pub fn sample_process(&mut self, key: ProcessIdentityKey, threads: &mut [ThreadInfo]) {
+        if replace_process(&mut self.process, key) {
+            self.reset();
+        }
+        self.sample(threads);
+    }
+
+    /// Like [`sample_process`](Self::sample_process), `interval` after the previous call.
+    pub fn sample_process_with_interval(
+        &mut self,
+        key: ProcessIdentityKey,
+        threads: &mut [ThreadInfo],
+        interval: Duration,
+    ) {
+        if replace_process(&mut self.process, key) {
+            self.reset();
+        }
+        self.sample_with_interval(threads, interval);
+    }
+
+    /// Forget the previous sample.
+    pub fn reset(&mut self) {
+        self.previous.clear();
+        self.last_sample = None;
+    }
+}
+
+/// Turns successive [`CpuTicks`] readings into CPU usage over the interval.
+///
+/// Fed with `CPUCollector::cpu_ticks`. Each caller owns its sampler, so
+/// the baseline only moves when that caller samples.
+#[derive(Debug, Default)]
+pub struct CpuSampler {
+    previous: Option<CpuTicks>,
+}
+
+impl CpuSampler {
+    /// Create an empty sampler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a reading and return the usage since the previous one.
+    ///
+    /// Returns `None` when there is no delta yet: on the first reading and
+    /// when no tick elapsed since the previous one.
+    pub fn sample(&mut self, ticks: CpuTicks) -> Option<CpuUsage> {
+        let prev = self.previous.replace(ticks)?;
+        ticks.since(&prev).usage()
+    }
+
+    /// Forget the previous reading.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+/// Page fault rates of a process.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PageFaultRate {
+    /// Minor faults per second.
+    pub minor_per_sec: f64,
+    /// Major faults per second.
+    pub major_per_sec: f64,
+}
+
+/// Turns successive cumulative fault counters of one process into rates.
+///
+/// Fed with `ProcessMetrics::minor_faults` and `major_faults`. The first
+/// sample only primes the sampler and returns `None`.
+#[derive(Debug, Default)]
+pub struct PageFaultRateSampler {
+    previous: Option<(u64, u64)>,
+    last_sample: Option<Instant>,
+    process: Option<ProcessIdentityKey>,
+}
+
+impl PageFaultRateSampler {
+    /// Create an empty sampler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record counters read now and return fault rates since the previous read.
+    pub fn sample(&mut self, minor_faults: u64, major_faults: u64) -> Option<PageFaultRate> {
+        let now = Instant::now();
+        let interval = self.last_sample.map(|t| now.duration_since(t));
+        self.last_sample = Some(now);
+        match interval {
+            Some(interval) => self.sample_with_interval(minor_faults, major_faults, interval),
+            None => {
+                self.previous = Some((minor_faults, major_faults));
+                None
+            }
+        }
+    }
+
+    /// Record counters read `interval` after the previous read.
+    ///
+    /// Counters lower than the previous ones (PID reuse) yield a rate of 0
+    /// and become the new baseline. A zero interval also reports 0.
+    pub fn sample_with_interval(
+        &mut self,
+        minor_faults: u64,
+        major_faults: u64,
+        interval: Duration,
+    ) -> Option<PageFaultRate> {
+        let (prev_minor, prev_major) = self.previous.replace((minor_faults, major_faults))?;
+        let secs = interval.as_secs_f64();
+        if secs <= 0.0 {
+            return Some(PageFaultRate::default());
+        }
+        Some(PageFaultRate {
+            minor_per_sec: minor_faults.saturating_sub(prev_minor) as f64 / secs,
+            major_per_sec: major_faults.saturating_sub(prev_major) as f64 / secs,
+        })
+    }
+
+    /// Record counters of the process identified by `key`.
+    ///
+    /// If the PID now belongs to another process, the sampler restarts: this
+    /// read only primes it and returns `None` instead of a bogus delta.
+    pub fn sample_process(
+        &mut self,
+        key: ProcessIdentityKey,
+        minor_faults: u64,
+        major_faults: u64,
+    ) -> Option<PageFaultRate> {
+        if replace_process(&mut self.process, key) {
+            self.reset();
+        }
+        self.sample(minor_faults, major_faults)
+    }
+
+    /// Like [`sample_process`](Self::sample_process), `interval` after the previous read.
+    pub fn sample_process_with_interval(
+        &mut self,
+        key: ProcessIdentityKey,
+        minor_faults: u64,
+        major_faults: u64,
+        interval: Duration,
+    ) -> Option<PageFaultRate> {
+        if replace_process(&mut self.process, key) {
+            self.reset();
+        }
+        self.sample_with_interval(minor_faults, major_faults, interval)
+    }
+
+    /// Forget the previous sample.
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.last_sample = None;
+    }
+}
+
+/// Pressure stall percentages over a caller-chosen window.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PressureRate {
+    /// Percentage of the window some tasks were stalled.
+    pub some_percent: f64,
+    /// Percentage of the window all non-idle tasks were stalled at once.
+    pub full_percent: f64,
+}
+
+/// Turns successive PSI stall totals into stall percentages over the actual
+/// interval between samples, for windows other than the kernel's 10/60/300s
+/// averages.
+///
+/// Fed with the `some_total_us` and `full_total_us` fields of a pressure
+/// snapshot; pass 0 as the full total of `CPUPressure`, which has none. The
+/// first sample only primes the sampler and returns `None`.
+#[derive(Debug, Default)]
+pub struct PressureRateSampler {
+    previous: Option<(u64, u64)>,
+    last_sample: Option<Instant>,
+}
+
+impl PressureRateSampler {
+    /// Create an empty sampler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record totals read now and return stall percentages since the previous read.
+    pub fn sample(&mut self, some_total_us: u64, full_total_us: u64) -> Option<PressureRate> {
+        let now = Instant::now();
+        let interval = self.last_sample.map(|t| now.duration_since(t));
+        self.last_sample = Some(now);
+        match interval {
+            Some(interval) => self.sample_with_interval(some_total_us, full_total_us, interval),
+            None => {
+                self.previous = Some((some_total_us, full_total_us));
+                None
+            }
+        }
+    }
+
+    /// Record totals read `interval` after the previous read.
+    ///
+    /// Totals lower than the previous ones (e.g. a recreated cgroup) yield 0
+    /// and become the new baseline. A zero interval also reports 0.
+    /// Percentages are capped at 100 to absorb timing jitter.
+    pub fn sample_with_interval(
+        &mut self,
+        some_total_us: u64,
+        full_total_us: u64,
+        interval: Duration,
+    ) -> Option<PressureRate> {
+        let (prev_some, prev_full) = self.previous.replace((some_total_us, full_total_us))?;
+        let window_us = interval.as_micros() as f64;
+        if window_us <= 0.0 {
+            return Some(PressureRate::default());
+        }
+        let percent = |curr: u64, prev: u64| {
+            (curr.saturating_sub(prev) as f64 / window_us * 100.0).min(100.0)
+        };
+        Some(PressureRate {
+            some_percent: percent(some_total_us, prev_some),
+            full_percent: percent(full_total_us, prev_full),
+        })
+    }
+
+    /// Forget the previous sample.
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.last_sample = None;
+    }
+}
+
+/// Per-interface network rates derived from two `NetStats` snapshots.
+#[derive(Debug, Clone, Default)]
+pub struct NetRate {
+    /// Interface name (e.g., eth0).
+    pub interface: String,
+    /// Bytes received per second.
+    pub rx_bytes_per_sec: f64,
+    /// Packets received per second.
+    pub rx_packets_per_sec: f64,
+    /// Receive errors per second.
+    pub rx_errors_per_sec: f64,
+    /// Received packets dropped per second.
+    pub rx_drops_per_sec: f64,
+    /// Bytes transmitted per second.
+    pub tx_bytes_per_sec: f64,
+    /// Packets transmitted per second.
+    pub tx_packets_per_sec: f64,
+    /// Transmit errors per second.
+    pub tx_errors_per_sec: f64,
+    /// Transmitted packets dropped per second.
+    pub tx_drops_per_sec: f64,
+}
+
+impl NetRate {
+    /// Compute rates between two snapshots of the same interface.
+    ///
+    /// Counter resets yield zero deltas and a zero interval reports all
+    /// rates as 0.
+    pub fn between(prev: &NetStats, curr: &NetStats, interval: Duration) -> Self {
+        let secs = interval.as_secs_f64();
+        let rate = |prev: u64, curr: u64| {
+            if secs <= 0.0 { 0.0 } else { curr.saturating_sub(prev) as f64 / secs }
+        };
+        Self {
+            interface: curr.interface.clone(),
+            rx_bytes_per_sec: rate(prev.rx_bytes, curr.rx_bytes),
+            rx_packets_per_sec: rate(prev.rx_packets, curr.rx_packets),
+            rx_errors_per_sec: rate(prev.rx_errors, curr.rx_errors),
+            rx_drops_per_sec: rate(prev.rx_drops, curr.rx_drops),
+            tx_bytes_per_sec: rate(prev.tx_bytes, curr.tx_bytes),
+            tx_packets_per_sec: rate(prev.tx_packets, curr.tx_packets),
+            tx_errors_per_sec: rate(prev.tx_errors, curr.tx_errors),
+            tx_drops_per_sec: rate(prev.tx_drops, curr.tx_drops),
+        }
+    }
+}
+
+/// Rates of every cumulative counter in [`AllMetrics`] between two snapshots.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsDelta {
+    /// Timestamp of the latest snapshot (microseconds since epoch).
+    pub timestamp_us: u64,
+    /// Time between the two snapshots, from their timestamps.
+    pub interval: Duration,
+    /// CPU metrics of the latest snapshot.
+    ///
+    /// Percentages cover the interval when sampled with
+    /// [`MetricsDeltaSampler::sample_with_ticks`], and are all 0 if no tick
+    /// elapsed in it. Otherwise they are the snapshot's averages since boot.
+    pub cpu: SystemCPU,
+    /// System-wide read operations per second.
+    pub read_ops_per_sec: f64,
+    /// System-wide bytes read per second.
+    pub read_bytes_per_sec: f64,
+    /// System-wide write operations per second.
+    pub write_ops_per_sec: f64,
+    /// System-wide bytes written per second.
+    pub write_bytes_per_sec: f64,
+    /// Per-device I/O rates.
+    pub disk_io: Vec<DiskIORate>,
+    /// Per-interface network rates.
+    pub net: Vec<NetRate>,
+}
+
+/// Turns successive [`AllMetrics`] snapshots into [`MetricsDelta`]s.
+///
+/// The interval is taken from `AllMetrics::timestamp_us`. Devices and
+/// interfaces absent from the previous snapshot are skipped. The first
+/// sample only primes the sampler and returns `None`.
+#[derive(Debug, Default)]
+pub struct MetricsDeltaSampler {
+    previous: Option<CounterSnapshot>,
+    cpu: CpuSampler,
+}
+
+/// The cumulative counters of an `AllMetrics` snapshot.
+#[derive(Debug)]
+struct CounterSnapshot {
+    timestamp_us: u64,
+    io_stats: IOStats,
+    disk_io: HashMap<String, DiskIOStats>,
+    net_stats: HashMap<String, NetStats>,
+}
+
+impl MetricsDeltaSampler {
+    /// Create an empty sampler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a snapshot and return rates since the previous one.
+    pub fn sample(&mut self, metrics: &AllMetrics) -> Option<MetricsDelta> {
+        let current = CounterSnapshot {
+            timestamp_us: metrics.timestamp_us,
+            io_stats: metrics.io_stats.clone(),
+            disk_io: metrics.disk_io.iter().map(|d| (d.device.clone(), d.clone())).collect(),
+            net_stats: metrics.net_stats.iter().map(|n| (n.interface.clone(), n.clone())).collect(),
+        };
+        let prev = self.previous.replace(current)?;
+
+        let interval =
+            Duration::from_micros(metrics.timestamp_us.saturating_sub(prev.timestamp_us));
+        let secs = interval.as_secs_f64();
+        let rate = |prev: u64, curr: u64| {
+            if secs <= 0.0 { 0.0 } else { curr.saturating_sub(prev) as f64 / secs }
+        };
+        let (prev_io, curr_io) = (&prev.io_stats, &metrics.io_stats);
+
+        Some(MetricsDelta {
+            timestamp_us: metrics.timestamp_us,
+            interval,
+            cpu: metrics.cpu.clone(),
+            read_ops_per_sec: rate(prev_io.read_ops, curr_io.read_ops),
+            read_bytes_per_sec: rate(prev_io.read_bytes, curr_io.read_bytes),
+            write_ops_per_sec: rate(prev_io.write_ops, curr_io.write_ops),
+            write_bytes_per_sec: rate(prev_io.write_bytes, curr_io.write_bytes),
+            disk_io: metrics
+                .disk_io
+                .iter()
+                .filter_map(|curr| {
+                    prev.disk_io.get(&curr.device).map(|p| DiskIORate::between(p, curr, interval))
+                })
+                .collect(),
+            net: metrics
+                .net_stats
+                .iter()
+                .filter_map(|curr| {
+                    prev.net_stats.get(&curr.interface).map(|p| NetRate::between(p, curr, interval))
+                })
+                .collect(),
+        })
+    }
+
+    /// Like [`sample`](Self::sample), with CPU percentages over the interval.
+    ///
+    /// `ticks` is the `CPUCollector::cpu_ticks` reading taken with the
+    /// snapshot; without it the snapshot's CPU metrics are kept.
+    pub fn sample_with_ticks(
+        &mut self,
+        metrics: &AllMetrics,
+        ticks: Option<CpuTicks>,
+    ) -> Option<MetricsDelta> {
+        let Some(ticks) = ticks else {
+            self.cpu.reset();
+            return self.sample(metrics);
+        };
+        let usage = self.cpu.sample(ticks);
+        let mut delta = self.sample(metrics)?;
+        usage.unwrap_or_default().apply_to(&mut delta.cpu);
+        Some(delta)
+    }
+
+    /// Forget the previous snapshot.
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.cpu.reset();
+    }
+}
+
+/// Page reclaim rates derived from /proc/vmstat counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ReclaimRate {
+    /// Pages scanned for reclaim per second (kswapd, direct and khugepaged).
+    pub pgscan_per_sec: f64,
+    /// Pages reclaimed per second.
+    pub pgsteal_per_sec: f64,
+    /// Pages moved from the active to the inactive lists per second.
+    pub pgrefill_per_sec: f64,
+    /// Reclaimed pages per scanned page (steal/scan), `None` when nothing
+    /// was scanned. Low values mean the kernel works hard for little memory.
+    pub efficiency: Option<f64>,
+}
+
+/// Cumulative reclaim counters summed from vmstat.
+#[derive(Debug, Clone, Copy)]
+struct ReclaimCounters {
+    pgscan: u64,
+    pgsteal: u64,
+    pgrefill: u64,
+}
+
+impl ReclaimCounters {
+    /// Sum the reclaim counters, including the per-zone ones of kernels
+    /// before 4.8 (`pgscan_kswapd_normal`, ...).
+    fn from_vmstat(vmstat: &HashMap<String, u64>) -> Result<Self> {
+        let sum = |prefixes: &[&str]| -> Option<u64> {
+            let mut matched = vmstat
+                .iter()
+                .filter(|(name, _)| prefixes.iter().any(|p| name.starts_with(p)))
+                // Counts throttling events, not pages
+                .filter(|(name, _)| name.as_str() != "pgscan_direct_throttle")
+                .map(|(_, v)| *v)
+                .peekable();
+            matched.peek()?;
+            Some(matched.sum())
+        };
+
+        Ok(Self {
+            pgscan: sum(&["pgscan_kswapd", "pgscan_direct", "pgscan_khugepaged"])
+                .ok_or(Error::NotSupported)?,
+            pgsteal: sum(&["pgsteal_kswapd", "pgsteal_direct", "pgsteal_khugepaged"])
+                .ok_or(Error::NotSupported)?,
+            pgrefill: sum(&["pgrefill"]).unwrap_or(0),
+        })
+    }
+}
+
+/// Turns successive vmstat tables into page reclaim rates.
+///
+/// Fed with `MemoryCollector::vmstat`. The first sample only primes the
+/// sampler and returns `None`. Returns `Error::NotSupported` when the table
+/// has no reclaim counters, as on non-Linux platforms.
+#[derive(Debug, Default)]
+pub struct ReclaimRateSampler {
+    previous: Option<ReclaimCounters>,
+    last_sample: Option<Instant>,
+}
+
+impl ReclaimRateSampler {
+    /// Create an empty sampler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record counters read now and return reclaim rates since the previous read.
+    pub fn sample(&mut self, vmstat: &HashMap<String, u64>) -> Result<Option<ReclaimRate>> {
+        let now = Instant::now();
+        let interval = self.last_sample.map(|t| now.duration_since(t));
+        let counters = ReclaimCounters::from_vmstat(vmstat)?;
+        self.last_sample = Some(now);
+        match interval {
+            Some(interval) => Ok(self.rate_since_previous(counters, interval)),
+            None => {
+                self.previous = Some(counters);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Record counters read `interval` after the previous read.
+    ///
+    /// Counters lower than the previous ones (reboot) yield a rate of 0 and
+    /// become the new baseline. A zero interval also reports 0.
+    pub fn sample_with_interval(
+        &mut self,
+        vmstat: &HashMap<String, u64>,
+        interval: Duration,
+    ) -> Result<Option<ReclaimRate>> {
+        let counters = ReclaimCounters::from_vmstat(vmstat)?;
+        Ok(self.rate_since_previous(counters, interval))
+    }
+
+    /// Forget the previous sample.
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.last_sample = None;
+    }
+
+    fn rate_since_previous(
+        &mut self,
+        counters: ReclaimCounters,
+        interval: Duration,
+    ) -> Option<ReclaimRate> {
+        let prev = self.previous.replace(counters)?;
+        let secs = interval.as_secs_f64();
+        let scanned = counters.pgscan.saturating_sub(prev.pgscan);
+        let stolen = counters.pgsteal.saturating_sub(prev.pgsteal);
+        let refilled = counters.pgrefill.saturating_sub(prev.pgrefill);
+        if secs <= 0.0 {
+            return Some(ReclaimRate::default());
+        }
+        Some(ReclaimRate {
+            pgscan_per_sec: scanned as f64 / secs,
+            pgsteal_per_sec: stolen as f64 / secs,
+            pgrefill_per_sec: refilled as f64 / secs,
+            efficiency: (scanned > 0).then(|| stolen as f64 / scanned as f64),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(
+        reads: u64,
+        writes: u64,
+        bytes: u64,
+        io_time_us: u64,
+        weighted_us: u64,
+    ) -> DiskIOStats {
+        DiskIOStats {
+            device: "sda".to_string(),
+            reads_completed: reads,
+            read_bytes: bytes / 2,
+            writes_completed: writes,
+            write_bytes: bytes / 2,
+            io_time_us,
+            weighted_io_time_us: weighted_us,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_derived_metrics_from_two_snapshots() {
+        let mut sampler = DiskIORateSampler::new();
+        assert!(
+            sampler.sample_with_interval(&[snapshot(100, 100, 0, 0, 0)], Duration::ZERO).is_empty()
+        );
+
+        // 200 ops moving 800 KiB over 2s, busy 1s, 4s of weighted queue time
+        let rates = sampler.sample_with_interval(
+            &[snapshot(200, 200, 819_200, 1_000_000, 4_000_000)],
+            Duration::from_secs(2),
+        );
+
+        assert_eq!(rates.len(), 1);
+        let rate = &rates[0];
+        assert_eq!(rate.reads_per_sec, 50.0);
+        assert_eq!(rate.writes_per_sec, 50.0);
+        assert_eq!(rate.avg_request_size_bytes, 4096.0);
+        assert_eq!(rate.avg_queue_depth, 2.0);
+        assert_eq!(rate.utilization_percent, 50.0);
+    }
+
+    #[test]
+    fn test_zero_op_interval() {
+        let prev = snapshot(10, 10, 4096, 100, 100);
+        let rate = DiskIORate::between(&prev, &prev, Duration::from_secs(1));
+
+        assert_eq!(rate.avg_request_size_bytes, 0.0);
+        assert_eq!(rate.avg_queue_depth, 0.0);
+        assert!(!rate.avg_request_size_bytes.is_nan());
+
+        let rate = DiskIORate::between(&prev, &prev, Duration::ZERO);
+        assert_eq!(rate.avg_queue_depth, 0.0);
+    }
+
+    #[test]
+    fn test_fork_rate_from_two_snapshots() {
+        let mut sampler = ForkRateSampler::new();
+        assert_eq!(sampler.sample_with_interval(10_000, Duration::from_secs(1)), None);
+
+        assert_eq!(sampler.sample_with_interval(10_250, Duration::from_secs(5)), Some(50.0));
+        assert_eq!(sampler.sample_with_interval(10_250, Duration::ZERO), Some(0.0));
+
+        // Counter went backwards (reboot): no negative rate, new baseline
+        assert_eq!(sampler.sample_with_interval(300, Duration::from_secs(1)), Some(0.0));
+        assert_eq!(sampler.sample_with_interval(400, Duration::from_secs(2)), Some(50.0));
+
+        sampler.reset();
+        assert_eq!(sampler.sample(500), None);
+    }
+
+    #[test]
+    fn test_pressure_rate_over_custom_window() {
+        use crate::MemoryPressure;
+
+        let memory = MemoryPressure {
+            some_total_us: 1_000_000,
+            full_total_us: 200_000,
+            ..Default::default()
+        };
+        let mut sampler = PressureRateSampler::new();
+        assert_eq!(
+            sampler.sample_with_interval(
+                memory.some_total_us,
+                memory.full_total_us,
+                Duration::from_secs(1)
+            ),
+            None
+        );
+
+        // 1.5s of some and 0.3s of full stall over a 15s window
+        let later = MemoryPressure {
+            some_total_us: 2_500_000,
+            full_total_us: 500_000,
+            ..Default::default()
+        };
+        let rate = sampler
+            .sample_with_interval(later.some_total_us, later.full_total_us, Duration::from_secs(15))
+            .unwrap();
+        assert!((rate.some_percent - 10.0).abs() < 1e-9);
+        assert!((rate.full_percent - 2.0).abs() < 1e-9);
+
+        // Jitter cannot push the stall past the window
+        let rate =
+            sampler.sample_with_interval(3_600_000, 500_000, Duration::from_secs(1)).unwrap();
+        assert_eq!(rate.some_percent, 100.0);
+        assert_eq!(rate.full_percent, 0.0);
+
+        // Totals went backwards: new baseline
+        assert_eq!(
+            sampler.sample_with_interval(10, 0, Duration::from_secs(1)),
+            Some(PressureRate::default())
+        );
+        assert_eq!(
+            sampler.sample_with_interval(10, 0, Duration::ZERO),
+            Some(PressureRate::default())
+        );
+
+        sampler.reset();
+        assert_eq!(sampler.sample(0, 0), None);
+    }
+
+    #[test]
+    fn test_major_fault_rate_from_two_snapshots() {
+        let mut sampler = PageFaultRateSampler::new();
+        assert_eq!(sampler.sample_with_interval(1_000, 40, Duration::from_secs(1)), None);
+
+        let rate = sampler.sample_with_interval(3_000, 100, Duration::from_secs(4)).unwrap();
+        assert_eq!(rate.minor_per_sec, 500.0);
+        assert_eq!(rate.major_per_sec, 15.0);
+
+        // Counters went backwards (PID reuse): no negative rate, new baseline
+        let rate = sampler.sample_with_interval(10, 2, Duration::from_secs(1)).unwrap();
+        assert_eq!(rate, PageFaultRate::default());
+        assert_eq!(
+            sampler.sample_with_interval(10, 2, Duration::ZERO),
+            Some(PageFaultRate::default())
+        );
+    }
+
+    #[test]
+    fn test_samplers_reset_on_pid_reuse() {
+        let original = ProcessIdentityKey::new(42, 1_000);
+        let reused = ProcessIdentityKey::new(42, 9_000);
+        let second = Duration::from_secs(1);
+
+        let mut faults = PageFaultRateSampler::new();
+        assert!(faults.sample_process_with_interval(original, 5_000, 50, second).is_none());
+        let rate = faults.sample_process_with_interval(original, 5_100, 52, second).unwrap();
+        assert_eq!(rate.minor_per_sec, 100.0);
+        // The new process has far more faults: no delta against the old one
+        assert!(faults.sample_process_with_interval(reused, 900_000, 900, second).is_none());
+        let rate = faults.sample_process_with_interval(reused, 900_010, 901, second).unwrap();
+        assert_eq!(rate.minor_per_sec, 10.0);
+        assert_eq!(rate.major_per_sec, 1.0);
+
+        let thread = |tid, user_time_us| ThreadInfo { tid, user_time_us, ..Default::default() };
+        let mut cpu = ThreadCpuSampler::new();
+        cpu.sample_process_with_interval(original, &mut [thread(42, 0)], second);
+        // Same TID in the new process, with unrelated CPU time
+        let mut threads = [thread(42, 30_000_000)];
+        cpu.sample_process_with_interval(reused, &mut threads, second);
+        assert_eq!(threads[0].cpu_percent, 0.0);
+        let mut threads = [thread(42, 30_500_000)];
+        cpu.sample_process_with_interval(reused, &mut threads, second);
+        assert_eq!(threads[0].cpu_percent, 50.0);
+    }
+
+    #[test]
+    fn test_cpu_sampler_reports_interval_usage() {
+        let ticks = |user, system, idle, steal| CpuTicks {
+            user,
+            system,
+            idle,
+            steal,
+            ..Default::default()
+        };
+        let mut sampler = CpuSampler::new();
+
+        // Lifetime totals are mostly idle; only the interval counts
+        assert!(sampler.sample(ticks(1_000, 1_000, 98_000, 0)).is_none());
+        let usage = sampler.sample(ticks(1_060, 1_020, 98_010, 10)).unwrap();
+        assert_eq!(usage.user_percent, 60.0);
+        assert_eq!(usage.system_percent, 20.0);
+        assert_eq!(usage.idle_percent, 10.0);
+        assert_eq!(usage.steal_percent, 10.0);
+
+        // No tick elapsed: no delta rather than a lifetime average
+        assert!(sampler.sample(ticks(1_060, 1_020, 98_010, 10)).is_none());
+
+        // A 64-bit counter going backwards is not a wraparound
+        let mut sampler = CpuSampler::new();
+        sampler.sample(CpuTicks { user: 1_000, idle: 8_000, iowait: 500, ..Default::default() });
+        let usage = sampler
+            .sample(CpuTicks { user: 1_010, idle: 8_010, iowait: 490, ..Default::default() })
+            .unwrap();
+        assert_eq!(usage.user_percent, 50.0);
+        assert_eq!(usage.idle_percent, 50.0);
+        assert_eq!(usage.iowait_percent, 0.0);
+    }
+
+    #[test]
+    fn test_thread_cpu_follows_threads_by_tid() {
+        let thread = |tid, user_time_us| ThreadInfo { tid, user_time_us, ..Default::default() };
+        let mut sampler = ThreadCpuSampler::new();
+
+        let mut threads = vec![thread(10, 1_000_000), thread(11, 0)];
+        sampler.sample_with_interval(&mut threads, Duration::from_secs(1));
+        assert!(threads.iter().all(|t| t.cpu_percent == 0.0));
+
+        // Thread 11 exited and 12 appeared
+        let mut threads = vec![thread(10, 1_900_000), thread(12, 500_000)];
+        sampler.sample_with_interval(&mut threads, Duration::from_secs(1));
+        assert_eq!(threads[0].cpu_percent, 90.0);
+        assert_eq!(threads[1].cpu_percent, 0.0);
+
+        let mut threads = vec![thread(12, 750_000)];
+        sampler.sample_with_interval(&mut threads, Duration::from_millis(500));
+        assert_eq!(threads[0].cpu_percent, 50.0);
+    }
+
+    #[test]
+    fn test_metrics_delta_from_two_snapshots() {
+        let metrics = |timestamp_us: u64, scale: u64| AllMetrics {
+            io_stats: IOStats {
+                read_ops: 100 * scale,
+                read_bytes: 4096 * scale,
+                write_ops: 10 * scale,
+                write_bytes: 1024 * scale,
+            },
+            disk_io: vec![snapshot(100 * scale, 0, 0, 0, 0)],
+            net_stats: vec![NetStats {
+                interface: "eth0".to_string(),
+                rx_bytes: 1000 * scale,
+                tx_packets: 3 * scale,
+                ..Default::default()
+            }],
+            timestamp_us,
+            ..Default::default()
+        };
+
+        let mut sampler = MetricsDeltaSampler::new();
+        assert!(sampler.sample(&metrics(1_000_000, 1)).is_none());
+
+        let delta = sampler.sample(&metrics(3_000_000, 3)).unwrap();
+        assert_eq!(delta.interval, Duration::from_secs(2));
+        assert_eq!(delta.read_ops_per_sec, 100.0);
+        assert_eq!(delta.write_bytes_per_sec, 1024.0);
+        assert_eq!(delta.disk_io[0].reads_per_sec, 100.0);
+        assert_eq!(delta.net[0].rx_bytes_per_sec, 1000.0);
+        assert_eq!(delta.net[0].tx_packets_per_sec, 3.0);
+
+        // Counters going backwards and a new interface
+        let mut next = metrics(4_000_000, 0);
+        next.net_stats.push(NetStats { interface: "eth1".to_string(), ..Default::default() });
+        let delta = sampler.sample(&next).unwrap();
+        assert_eq!(delta.read_ops_per_sec, 0.0);
+        assert_eq!(delta.net.len(), 1);
+    }
+
+    #[test]
+    fn test_reclaim_rate_from_two_vmstat_snapshots() {
+        let vmstat = |scan: u64, steal: u64, refill: u64| -> HashMap<String, u64> {
+            [
+                ("pgscan_kswapd", scan / 2),
+                ("pgscan_direct", scan / 2),
+                ("pgscan_direct_throttle", 7),
+                ("pgsteal_kswapd", steal),
+                ("pgsteal_direct", 0),
+                ("pgrefill", refill),
+                ("pgfault", 1_000_000),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect()
+        };
+
+        let mut sampler = ReclaimRateSampler::new();
+        assert_eq!(
+            sampler.sample_with_interval(&vmstat(1_000, 900, 50), Duration::ZERO).unwrap(),
+            None
+        );
+
+        let rate = sampler
+            .sample_with_interval(&vmstat(5_000, 2_900, 250), Duration::from_secs(2))
+            .unwrap()
+            .unwrap();
+        assert_eq!(rate.pgscan_per_sec, 2_000.0);
+        assert_eq!(rate.pgsteal_per_sec, 1_000.0);
+        assert_eq!(rate.pgrefill_per_sec, 100.0);
+        assert_eq!(rate.efficiency, Some(0.5));
+
+        // No scanning: no efficiency
+        let rate = sampler
+            .sample_with_interval(&vmstat(5_000, 2_900, 250), Duration::from_secs(1))
+            .unwrap()
+            .unwrap();
+        assert_eq!(rate.efficiency, None);
+
+        let no_reclaim: HashMap<String, u64> = [("pgfault".to_string(), 1)].into();
+        assert!(matches!(sampler.sample(&no_reclaim), Err(Error::NotSupported)));
+    }
+}
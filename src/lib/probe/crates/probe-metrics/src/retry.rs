@@ -0,0 +1,339 @@
+//! Collector wrapper retrying transient failures.
+
+use crate::{
+    AllPressure, BlockDevice, CPUCollector, CPUPressure, Capabilities, CollectorDecorator,
+    ConntrackStats, CoreUsage, CpuCache, CpuTicks, CpuVuln, DeviceMapping, DiskCollector,
+    DiskIOStats, DiskQueueConfig, DiskUsage, Error, IOCollector, IOPressure, IOStats, IcmpStats,
+    InotifyStats, KernelLogEntry, KernelModule, KernelStats, ListenQueueStats, LoadAverage,
+    LoadCollector, MemoryBreakdown, MemoryCollector, MemoryPressure, MemoryZone, NamespaceIds,
+    NetInterface, NetStats, NetworkCollector, OomInfo, Partition, ProcessCapabilities,
+    ProcessCollector, ProcessIdentity, ProcessMetrics, RawSource, Result, RunQueueStat, SchedStats,
+    SocketMemStats, SwapDevice, SystemCPU, SystemCollector, SystemLimits, SystemMemory, ThpStatus,
+    ThreadInfo, TimeInfo, UdpProtoStats, UnitStatus, ZfsArcStats,
+};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Retries collector calls failing with a transient error.
+///
+/// I/O errors and timeouts are retried up to `max_attempts` calls in total,
+/// sleeping `backoff` between attempts. Other errors (not supported,
+/// permission, not found) are returned immediately.
+pub struct RetryCollector<T: SystemCollector> {
+    inner: T,
+    max_attempts: u32,
+    backoff: Duration,
+}
+
+impl<T: SystemCollector> RetryCollector<T> {
+    /// Wrap `inner`, making at most `max_attempts` calls (at least one).
+    pub fn new(inner: T, max_attempts: u32) -> Self {
+        Self { inner, max_attempts: max_attempts.max(1), backoff: Duration::ZERO }
+    }
+
+    /// Sleep `backoff` between attempts.
+    pub fn with_backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    fn retry<V>(&self, mut call: impl FnMut() -> Result<V>) -> Result<V> {
+        let mut attempt = 1;
+        loop {
+            match call() {
+                Err(Error::Io(_) | Error::Timeout) if attempt < self.max_attempts => {
+                    attempt += 1;
+                    if !self.backoff.is_zero() {
+                        std::thread::sleep(self.backoff);
+                    }
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<T: SystemCollector + 'static> CollectorDecorator for RetryCollector<T> {
+    type Inner = T;
+
+    fn inner(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T: SystemCollector + 'static> SystemCollector for RetryCollector<T> {
+    fn cpu(&self) -> &dyn CPUCollector {
+        self
+    }
+
+    fn memory(&self) -> &dyn MemoryCollector {
+        self
+    }
+
+    fn load(&self) -> &dyn LoadCollector {
+        self
+    }
+
+    fn process(&self) -> &dyn ProcessCollector {
+        self
+    }
+
+    fn disk(&self) -> &dyn DiskCollector {
+        self
+    }
+
+    fn network(&self) -> &dyn NetworkCollector {
+        self
+    }
+
+    fn io(&self) -> &dyn IOCollector {
+        self
+    }
+
+    fn collect_cgroup_pressure(&self) -> Result<AllPressure> {
+        self.retry(|| self.inner.collect_cgroup_pressure())
+    }
+
+    fn collect_kernel_stats(&self) -> Result<KernelStats> {
+        self.retry(|| self.inner.collect_kernel_stats())
+    }
+
+    fn recent_kernel_errors(&self, since: Option<Duration>) -> Result<Vec<KernelLogEntry>> {
+        self.retry(|| self.inner.recent_kernel_errors(since))
+    }
+
+    fn time_info(&self) -> Result<TimeInfo> {
+        self.retry(|| self.inner.time_info())
+    }
+
+    fn inotify_stats(&self) -> Result<InotifyStats> {
+        self.retry(|| self.inner.inotify_stats())
+    }
+
+    fn list_modules(&self) -> Result<Vec<KernelModule>> {
+        self.retry(|| self.inner.list_modules())
+    }
+
+    fn namespace_ids(&self) -> Result<NamespaceIds> {
+        self.retry(|| self.inner.namespace_ids())
+    }
+
+    fn system_limits(&self) -> Result<SystemLimits> {
+        self.retry(|| self.inner.system_limits())
+    }
+
+    fn unit_status(&self, unit: &str) -> Result<UnitStatus> {
+        self.retry(|| self.inner.unit_status(unit))
+    }
+
+    fn read_raw(&self, source: RawSource) -> Result<String> {
+        self.retry(|| self.inner.read_raw(source))
+    }
+
+    fn pressure_supported(&self) -> bool {
+        self.inner.pressure_supported()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+}
+
+impl<T: SystemCollector + 'static> CPUCollector for RetryCollector<T> {
+    fn collect_system(&self) -> Result<SystemCPU> {
+        self.retry(|| self.inner.cpu().collect_system())
+    }
+
+    fn collect_pressure(&self) -> Result<CPUPressure> {
+        self.retry(|| self.inner.cpu().collect_pressure())
+    }
+
+    fn cpu_ticks(&self) -> Result<CpuTicks> {
+        self.retry(|| self.inner.cpu().cpu_ticks())
+    }
+
+    fn cpu_vulnerabilities(&self) -> Result<Vec<CpuVuln>> {
+        self.retry(|| self.inner.cpu().cpu_vulnerabilities())
+    }
+
+    fn per_cpu_runqueue(&self) -> Result<Vec<RunQueueStat>> {
+        self.retry(|| self.inner.cpu().per_cpu_runqueue())
+    }
+
+    fn cpu_caches(&self) -> Result<Vec<CpuCache>> {
+        self.retry(|| self.inner.cpu().cpu_caches())
+    }
+
+    fn collect_per_core(&self) -> Result<Vec<CoreUsage>> {
+        self.retry(|| self.inner.cpu().collect_per_core())
+    }
+}
+
+impl<T: SystemCollector + 'static> MemoryCollector for RetryCollector<T> {
+    fn collect_system(&self) -> Result<SystemMemory> {
+        self.retry(|| self.inner.memory().collect_system())
+    }
+
+    fn collect_pressure(&self) -> Result<MemoryPressure> {
+        self.retry(|| self.inner.memory().collect_pressure())
+    }
+
+    fn thp_status(&self) -> Result<ThpStatus> {
+        self.retry(|| self.inner.memory().thp_status())
+    }
+
+    fn zone_info(&self) -> Result<Vec<MemoryZone>> {
+        self.retry(|| self.inner.memory().zone_info())
+    }
+
+    fn swap_devices(&self) -> Result<Vec<SwapDevice>> {
+        self.retry(|| self.inner.memory().swap_devices())
+    }
+
+    fn zfs_arc(&self) -> Result<ZfsArcStats> {
+        self.retry(|| self.inner.memory().zfs_arc())
+    }
+
+    fn vmstat(&self) -> Result<HashMap<String, u64>> {
+        self.retry(|| self.inner.memory().vmstat())
+    }
+}
+
+impl<T: SystemCollector + 'static> LoadCollector for RetryCollector<T> {
+    fn collect(&self) -> Result<LoadAverage> {
+        self.retry(|| self.inner.load().collect())
+    }
+
+    fn runnable_tasks(&self) -> Result<u32> {
+        self.retry(|| self.inner.load().runnable_tasks())
+    }
+
+    fn blocked_tasks(&self) -> Result<u32> {
+        self.retry(|| self.inner.load().blocked_tasks())
+    }
+}
+
+impl<T: SystemCollector + 'static> ProcessCollector for RetryCollector<T> {
+    fn collect(&self, pid: i32) -> Result<ProcessMetrics> {
+        self.retry(|| self.inner.process().collect(pid))
+    }
+
+    fn collect_all(&self) -> Result<Vec<ProcessMetrics>> {
+        self.retry(|| self.inner.process().collect_all())
+    }
+
+    fn memory_breakdown(&self, pid: i32) -> Result<MemoryBreakdown> {
+        self.retry(|| self.inner.process().memory_breakdown(pid))
+    }
+
+    fn sched_latency(&self, pid: i32) -> Result<SchedStats> {
+        self.retry(|| self.inner.process().sched_latency(pid))
+    }
+
+    fn oom_info(&self, pid: i32) -> Result<OomInfo> {
+        self.retry(|| self.inner.process().oom_info(pid))
+    }
+
+    fn list_threads(&self, pid: i32) -> Result<Vec<ThreadInfo>> {
+        self.retry(|| self.inner.process().list_threads(pid))
+    }
+
+    fn capability_sets(&self, pid: i32) -> Result<ProcessCapabilities> {
+        self.retry(|| self.inner.process().capability_sets(pid))
+    }
+
+    fn list_identities(&self) -> Result<Vec<ProcessIdentity>> {
+        self.retry(|| self.inner.process().list_identities())
+    }
+
+    fn identity(&self, pid: i32) -> Result<ProcessIdentity> {
+        self.retry(|| self.inner.process().identity(pid))
+    }
+}
+
+impl<T: SystemCollector + 'static> DiskCollector for RetryCollector<T> {
+    fn list_partitions(&self) -> Result<Vec<Partition>> {
+        self.retry(|| self.inner.disk().list_partitions())
+    }
+
+    fn collect_usage(&self, path: &str) -> Result<DiskUsage> {
+        self.retry(|| self.inner.disk().collect_usage(path))
+    }
+
+    fn collect_all_usage(&self) -> Result<Vec<DiskUsage>> {
+        self.retry(|| self.inner.disk().collect_all_usage())
+    }
+
+    fn collect_io(&self) -> Result<Vec<DiskIOStats>> {
+        self.retry(|| self.inner.disk().collect_io())
+    }
+
+    fn collect_device_io(&self, device: &str) -> Result<DiskIOStats> {
+        self.retry(|| self.inner.disk().collect_device_io(device))
+    }
+
+    fn block_devices(&self) -> Result<Vec<BlockDevice>> {
+        self.retry(|| self.inner.disk().block_devices())
+    }
+
+    fn probe_mount_latency(&self, path: &str) -> Result<Duration> {
+        // Retrying would hide the slowness being measured
+        self.inner.disk().probe_mount_latency(path)
+    }
+
+    fn disk_queue_config(&self, device: &str) -> Result<DiskQueueConfig> {
+        self.retry(|| self.inner.disk().disk_queue_config(device))
+    }
+
+    fn device_topology(&self) -> Result<Vec<DeviceMapping>> {
+        self.retry(|| self.inner.disk().device_topology())
+    }
+}
+
+impl<T: SystemCollector + 'static> NetworkCollector for RetryCollector<T> {
+    fn list_interfaces(&self) -> Result<Vec<NetInterface>> {
+        self.retry(|| self.inner.network().list_interfaces())
+    }
+
+    fn collect_stats(&self, interface: &str) -> Result<NetStats> {
+        self.retry(|| self.inner.network().collect_stats(interface))
+    }
+
+    fn collect_all_stats(&self) -> Result<Vec<NetStats>> {
+        self.retry(|| self.inner.network().collect_all_stats())
+    }
+
+    fn collect_stats_for_pid_netns(&self, pid: i32) -> Result<Vec<NetStats>> {
+        self.retry(|| self.inner.network().collect_stats_for_pid_netns(pid))
+    }
+
+    fn conntrack_stats(&self) -> Result<ConntrackStats> {
+        self.retry(|| self.inner.network().conntrack_stats())
+    }
+
+    fn socket_memory(&self) -> Result<SocketMemStats> {
+        self.retry(|| self.inner.network().socket_memory())
+    }
+
+    fn listen_queue_stats(&self) -> Result<ListenQueueStats> {
+        self.retry(|| self.inner.network().listen_queue_stats())
+    }
+
+    fn udp_stats(&self) -> Result<UdpProtoStats> {
+        self.retry(|| self.inner.network().udp_stats())
+    }
+
+    fn icmp_stats(&self) -> Result<IcmpStats> {
+        self.retry(|| self.inner.network().icmp_stats())
+    }
+}
+
+impl<T: SystemCollector + 'static> IOCollector for RetryCollector<T> {
+    fn collect_stats(&self) -> Result<IOStats> {
+        self.retry(|| self.inner.io().collect_stats())
+    }
+
+    fn collect_pressure(&self) -> Result<IOPressure> {
+        self.retry(|| self.inner.io().collect_pressure())
+    }
+}
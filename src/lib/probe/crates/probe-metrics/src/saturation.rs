@@ -0,0 +1,200 @@
+//! Single "how stressed is this host" score blended from system metrics.
+
+use crate::{IOPressure, LoadAverage, SystemCPU, SystemMemory};
+use std::time::Duration;
+
+/// Window over which `SystemCollector::saturation_score` measures swap
+/// activity and CPU usage.
+pub const SATURATION_SAMPLE_WINDOW: Duration = Duration::from_millis(250);
+
+/// Relative weights of the saturation score components.
+///
+/// Weights need not sum to 1: the score is divided by the total weight of
+/// the components that were available.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SaturationWeights {
+    /// Weight of CPU busy time.
+    pub cpu: f64,
+    /// Weight of memory usage.
+    pub memory: f64,
+    /// Weight of load per core.
+    pub load: f64,
+    /// Weight of I/O pressure (ignored when PSI is unavailable).
+    pub io_pressure: f64,
+    /// Weight of swap activity (ignored without swap counters).
+    pub swap: f64,
+    /// Pages swapped in and out per second that count as 100% swap activity.
+    pub swap_full_scale_pages_per_sec: f64,
+}
+
+impl Default for SaturationWeights {
+    fn default() -> Self {
+        Self {
+            cpu: 0.30,
+            memory: 0.25,
+            load: 0.20,
+            io_pressure: 0.15,
+            swap: 0.10,
+            // About 4 MiB/s with 4 KiB pages
+            swap_full_scale_pages_per_sec: 1000.0,
+        }
+    }
+}
+
+/// Host saturation score with the components it was computed from.
+///
+/// Every component is a percentage in 0-100:
+/// - `cpu`: `100 - idle_percent`
+/// - `memory`: `used_bytes / total_bytes`
+/// - `load`: 1-minute load average per core, where one runnable task per
+///   core is 100 (capped)
+/// - `io_pressure`: PSI `some` 10s average for I/O, `None` without PSI
+/// - `swap`: pages swapped in and out per second relative to
+///   `swap_full_scale_pages_per_sec` (capped), `None` without swap counters.
+///   Swapped-out pages that stay idle do not count.
+///
+/// `score` is the weighted mean of the available components.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SaturationScore {
+    /// Weighted score (0-100).
+    pub score: f64,
+    /// CPU busy percentage.
+    pub cpu: f64,
+    /// Memory usage percentage.
+    pub memory: f64,
+    /// Load per core as a percentage.
+    pub load: f64,
+    /// I/O pressure percentage, `None` when PSI is unavailable.
+    pub io_pressure: Option<f64>,
+    /// Swap activity percentage, `None` without swap counters.
+    pub swap: Option<f64>,
+}
+
+impl SaturationScore {
+    /// Blend already collected metrics into a score.
+    ///
+    /// `swap_pages_per_sec` is the rate of `pswpin` plus `pswpout`.
+    pub fn compute(
+        cpu: &SystemCPU,
+        memory: &SystemMemory,
+        load: &LoadAverage,
+        io_pressure: Option<&IOPressure>,
+        swap_pages_per_sec: Option<f64>,
+        weights: &SaturationWeights,
+    ) -> Self {
+        let cores = cpu.cores.max(1) as f64;
+        let mut components = Self {
+            score: 0.0,
+            cpu: clamp_percent(100.0 - cpu.idle_percent),
+            memory: ratio_percent(memory.used_bytes, memory.total_bytes),
+            load: clamp_percent(load.load_1min / cores * 100.0),
+            io_pressure: io_pressure.map(|p| clamp_percent(p.some_avg10)),
+            swap: swap_pages_per_sec.map(|rate| {
+                if weights.swap_full_scale_pages_per_sec > 0.0 {
+                    clamp_percent(rate / weights.swap_full_scale_pages_per_sec * 100.0)
+                } else {
+                    0.0
+                }
+            }),
+        };
+
+        let weighted = [
+            Some((weights.cpu, components.cpu)),
+            Some((weights.memory, components.memory)),
+            Some((weights.load, components.load)),
+            components.io_pressure.map(|io| (weights.io_pressure, io)),
+            components.swap.map(|swap| (weights.swap, swap)),
+        ];
+
+        let total_weight: f64 = weighted.iter().flatten().map(|(w, _)| w.max(0.0)).sum();
+        if total_weight > 0.0 {
+            let sum: f64 = weighted.iter().flatten().map(|(w, v)| w.max(0.0) * v).sum();
+            components.score = sum / total_weight;
+        }
+        components
+    }
+}
+
+fn clamp_percent(value: f64) -> f64 {
+    if value.is_nan() { 0.0 } else { value.clamp(0.0, 100.0) }
+}
+
+fn ratio_percent(used: u64, total: u64) -> f64 {
+    if total == 0 { 0.0 } else { clamp_percent(used as f64 / total as f64 * 100.0) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs() -> (SystemCPU, SystemMemory, LoadAverage) {
+        let cpu = SystemCPU { idle_percent: 5.0, cores: 4, ..Default::default() };
+        let memory = SystemMemory {
+            total_bytes: 1000,
+            used_bytes: 900,
+            swap_total_bytes: 0,
+            ..Default::default()
+        };
+        let load = LoadAverage { load_1min: 6.0, ..Default::default() };
+        (cpu, memory, load)
+    }
+
+    #[test]
+    fn test_high_cpu_and_pressure_scores_high() {
+        let (cpu, memory, load) = inputs();
+        let io = IOPressure { some_avg10: 80.0, ..Default::default() };
+
+        let score = SaturationScore::compute(
+            &cpu,
+            &memory,
+            &load,
+            Some(&io),
+            Some(0.0),
+            &SaturationWeights::default(),
+        );
+
+        assert_eq!(score.cpu, 95.0);
+        assert_eq!(score.memory, 90.0);
+        assert_eq!(score.load, 100.0);
+        assert_eq!(score.io_pressure, Some(80.0));
+        assert_eq!(score.swap, Some(0.0));
+        // 0.30*95 + 0.25*90 + 0.20*100 + 0.15*80 + 0.10*0
+        assert!((score.score - 83.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_missing_pressure_is_excluded_from_weights() {
+        let (cpu, memory, load) = inputs();
+        let weights = SaturationWeights { memory: 0.0, load: 0.0, swap: 0.0, ..Default::default() };
+
+        let score = SaturationScore::compute(&cpu, &memory, &load, None, None, &weights);
+
+        assert_eq!(score.io_pressure, None);
+        assert_eq!(score.swap, None);
+        assert_eq!(score.score, 95.0);
+    }
+
+    #[test]
+    fn test_swap_scores_activity_not_usage() {
+        let (cpu, _, load) = inputs();
+        let weights = SaturationWeights { cpu: 0.0, memory: 0.0, load: 0.0, ..Default::default() };
+
+        // Mostly swapped out, but the pages stay put
+        let idle = SystemMemory {
+            total_bytes: 1000,
+            swap_total_bytes: 1000,
+            swap_used_bytes: 950,
+            ..Default::default()
+        };
+        let score = SaturationScore::compute(&cpu, &idle, &load, None, Some(0.0), &weights);
+        assert_eq!(score.swap, Some(0.0));
+        assert_eq!(score.score, 0.0);
+
+        // Little swap in use, but pages move constantly
+        let thrashing = SystemMemory { swap_used_bytes: 10, ..idle };
+        let score = SaturationScore::compute(&cpu, &thrashing, &load, None, Some(500.0), &weights);
+        assert_eq!(score.swap, Some(50.0));
+        let score = SaturationScore::compute(&cpu, &thrashing, &load, None, Some(5000.0), &weights);
+        assert_eq!(score.score, 100.0);
+    }
+}
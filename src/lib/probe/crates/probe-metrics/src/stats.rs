@@ -0,0 +1,154 @@
+//! Rolling-window statistics for sampled metrics.
+
+use std::collections::VecDeque;
+
+/// Bounded rolling window of `f64` samples with summary statistics.
+///
+/// Samples are kept in a ring buffer; once the window is full the oldest
+/// sample is evicted. Works for any sampled value (CPU %, load, latency...).
+///
+/// Percentiles use the nearest-rank method on a sorted copy of the window.
+/// This is O(n log n) per query, which is fine for the small windows this
+/// type is intended for (seconds to minutes of samples).
+#[derive(Debug, Clone)]
+pub struct RollingStats {
+    samples: VecDeque<f64>,
+    window: usize,
+}
+
+impl RollingStats {
+    /// Create a new accumulator keeping at most `window` samples (minimum 1).
+    pub fn new(window: usize) -> Self {
+        let window = window.max(1);
+        Self { samples: VecDeque::with_capacity(window), window }
+    }
+
+    /// Add a sample, evicting the oldest one if the window is full.
+    ///
+    /// NaN samples are ignored.
+    pub fn push(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        if self.samples.len() == self.window {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(value);
+    }
+
+    /// Number of samples currently in the window.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Check if the window holds no samples.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Maximum number of samples kept.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Remove all samples.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Smallest sample in the window.
+    pub fn min(&self) -> Option<f64> {
+        self.samples.iter().copied().reduce(f64::min)
+    }
+
+    /// Largest sample in the window.
+    pub fn max(&self) -> Option<f64> {
+        self.samples.iter().copied().reduce(f64::max)
+    }
+
+    /// Arithmetic mean of the window.
+    pub fn mean(&self) -> Option<f64> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        Some(self.samples.iter().sum::<f64>() / self.samples.len() as f64)
+    }
+
+    /// Percentile (0-100) using the nearest-rank method.
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        if self.samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted: Vec<f64> = self.samples.iter().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+
+        let p = p.clamp(0.0, 100.0);
+        let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+        Some(sorted[rank.saturating_sub(1)])
+    }
+
+    /// Median (50th percentile).
+    pub fn p50(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    /// 95th percentile.
+    pub fn p95(&self) -> Option<f64> {
+        self.percentile(95.0)
+    }
+
+    /// 99th percentile.
+    pub fn p99(&self) -> Option<f64> {
+        self.percentile(99.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentiles_known_distribution() {
+        let mut stats = RollingStats::new(100);
+        // Insert 1..=100 in reverse order to exercise sorting
+        for v in (1..=100).rev() {
+            stats.push(v as f64);
+        }
+
+        assert_eq!(stats.len(), 100);
+        assert_eq!(stats.min(), Some(1.0));
+        assert_eq!(stats.max(), Some(100.0));
+        assert_eq!(stats.mean(), Some(50.5));
+        assert_eq!(stats.p50(), Some(50.0));
+        assert_eq!(stats.p95(), Some(95.0));
+        assert_eq!(stats.p99(), Some(99.0));
+        assert_eq!(stats.percentile(100.0), Some(100.0));
+        assert_eq!(stats.percentile(0.0), Some(1.0));
+    }
+
+    #[test]
+    fn test_window_eviction() {
+        let mut stats = RollingStats::new(3);
+        for v in [1.0, 2.0, 3.0, 4.0, 5.0] {
+            stats.push(v);
+        }
+
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats.min(), Some(3.0));
+        assert_eq!(stats.max(), Some(5.0));
+        assert_eq!(stats.mean(), Some(4.0));
+    }
+
+    #[test]
+    fn test_empty_and_nan() {
+        let mut stats = RollingStats::new(0);
+        assert_eq!(stats.window(), 1);
+        assert!(stats.is_empty());
+        assert_eq!(stats.p50(), None);
+        assert_eq!(stats.mean(), None);
+
+        stats.push(f64::NAN);
+        assert!(stats.is_empty());
+    }
+}
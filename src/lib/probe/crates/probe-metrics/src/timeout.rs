@@ -0,0 +1,121 @@
+//! Bounding blocking calls in time.
+
+use crate::{Error, Result};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, mpsc};
+use std::time::Duration;
+
+/// Default time [`DiskCollector::probe_mount_latency`](crate::DiskCollector::probe_mount_latency)
+/// waits before reporting a mount as hung.
+pub const DEFAULT_MOUNT_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Run `work` on a worker thread and wait for it at most `timeout`.
+///
+/// Returns `Error::Timeout` if `work` does not finish in time. The thread
+/// cannot be cancelled: it keeps running and its result is discarded, so
+/// `work` must not hold locks a caller may need afterwards.
+pub fn run_with_timeout<T: Send + 'static>(
+    timeout: Duration,
+    work: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        // The receiver is gone if the timeout passed; nothing to report then
+        let _ = tx.send(work());
+    });
+    rx.recv_timeout(timeout).map_err(|_| Error::Timeout)?
+}
+
+/// Keys (e.g. mount paths) with a [`run_with_timeout`] worker still running.
+///
+/// A worker stuck on a hung mount never returns, so probing the same key
+/// again would leak one more thread per call. While a key's previous worker
+/// is still in flight, [`run`](Self::run) fails with `Error::Timeout` at once.
+#[derive(Debug, Clone, Default)]
+pub struct InFlight {
+    keys: Arc<Mutex<HashSet<String>>>,
+}
+
+impl InFlight {
+    /// Create an empty in-flight set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `work` for `key` with [`run_with_timeout`], unless a previous
+    /// worker for `key` has not finished yet.
+    pub fn run<T: Send + 'static>(
+        &self,
+        key: &str,
+        timeout: Duration,
+        work: impl FnOnce() -> Result<T> + Send + 'static,
+    ) -> Result<T> {
+        if !self.lock().insert(key.to_string()) {
+            return Err(Error::Timeout);
+        }
+
+        let this = self.clone();
+        let key = key.to_string();
+        run_with_timeout(timeout, move || {
+            let result = work();
+            this.lock().remove(&key);
+            result
+        })
+    }
+
+    /// Whether a worker for `key` is still running.
+    pub fn contains(&self, key: &str) -> bool {
+        self.lock().contains(key)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        // The set stays consistent even if a holder panicked
+        self.keys.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_with_timeout() {
+        assert_eq!(run_with_timeout(Duration::from_secs(5), || Ok(42)).unwrap(), 42);
+
+        let result = run_with_timeout(Duration::from_millis(10), || {
+            std::thread::sleep(Duration::from_secs(1));
+            Ok(())
+        });
+        assert!(matches!(result, Err(Error::Timeout)));
+    }
+
+    #[test]
+    fn test_in_flight_skips_key_until_worker_finishes() {
+        let in_flight = InFlight::new();
+        let (release, wait) = mpsc::channel::<()>();
+
+        let result = in_flight.run("/mnt/hung", Duration::from_millis(10), move || {
+            let _ = wait.recv();
+            Ok(())
+        });
+        assert!(matches!(result, Err(Error::Timeout)));
+        assert!(in_flight.contains("/mnt/hung"));
+
+        // No new worker is started while the first one is stuck
+        let spawned = Arc::new(Mutex::new(false));
+        let flag = Arc::clone(&spawned);
+        let result = in_flight.run("/mnt/hung", Duration::from_secs(5), move || {
+            *flag.lock().unwrap() = true;
+            Ok(())
+        });
+        assert!(matches!(result, Err(Error::Timeout)));
+        assert!(!*spawned.lock().unwrap());
+        assert_eq!(in_flight.run("/mnt/ok", Duration::from_secs(5), || Ok(1)).unwrap(), 1);
+
+        drop(release);
+        while in_flight.contains("/mnt/hung") {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(in_flight.run("/mnt/hung", Duration::from_secs(5), || Ok(2)).unwrap(), 2);
+    }
+}
@@ -0,0 +1,188 @@
+//! Disk fill-rate tracking and time-to-full projection.
+
+use crate::DiskUsage;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    at: Instant,
+    used_bytes: u64,
+    free_bytes: u64,
+}
+
+/// Tracks timestamped `DiskUsage` samples per path and projects when each
+/// path will run out of space.
+///
+/// The projection is a least-squares linear fit of `used_bytes` over time,
+/// extrapolated until the latest `free_bytes` are consumed.
+#[derive(Debug, Clone)]
+pub struct DiskTrend {
+    samples: HashMap<String, VecDeque<Sample>>,
+    window: usize,
+    min_samples: usize,
+}
+
+impl Default for DiskTrend {
+    /// Keep 60 samples per path and require at least 3 to project.
+    fn default() -> Self {
+        Self::new(60, 3)
+    }
+}
+
+impl DiskTrend {
+    /// Create a tracker keeping at most `window` samples per path and
+    /// requiring `min_samples` before projecting (both at least 2).
+    pub fn new(window: usize, min_samples: usize) -> Self {
+        let window = window.max(2);
+        Self { samples: HashMap::new(), window, min_samples: min_samples.clamp(2, window) }
+    }
+
+    /// Record a usage sample taken now.
+    pub fn record(&mut self, usage: &DiskUsage) {
+        self.record_at(usage, Instant::now());
+    }
+
+    /// Record a usage sample taken at `at`.
+    ///
+    /// Samples older than the latest one for the same path are ignored.
+    pub fn record_at(&mut self, usage: &DiskUsage, at: Instant) {
+        let samples = self.samples.entry(usage.path.clone()).or_default();
+        if samples.back().is_some_and(|last| at < last.at) {
+            return;
+        }
+        if samples.len() == self.window {
+            samples.pop_front();
+        }
+        samples.push_back(Sample {
+            at,
+            used_bytes: usage.used_bytes,
+            free_bytes: usage.free_bytes,
+        });
+    }
+
+    /// Number of samples currently held for `path`.
+    pub fn len(&self, path: &str) -> usize {
+        self.samples.get(path).map_or(0, VecDeque::len)
+    }
+
+    /// Forget all samples for `path`.
+    pub fn remove(&mut self, path: &str) {
+        self.samples.remove(path);
+    }
+
+    /// Forget all samples.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Estimated time from the latest sample until `path` is full.
+    ///
+    /// Returns `None` with fewer than `min_samples` samples, when all samples
+    /// share a timestamp, or when usage is flat or shrinking.
+    pub fn project_full(&self, path: &str) -> Option<Duration> {
+        let samples = self.samples.get(path)?;
+        if samples.len() < self.min_samples {
+            return None;
+        }
+
+        let first = samples.front()?.at;
+        let points: Vec<(f64, f64)> = samples
+            .iter()
+            .map(|s| (s.at.duration_since(first).as_secs_f64(), s.used_bytes as f64))
+            .collect();
+
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let var_x: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        if var_x <= 0.0 {
+            return None;
+        }
+        let cov: f64 = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+
+        // Bytes per second
+        let slope = cov / var_x;
+        if !slope.is_finite() || slope <= 0.0 {
+            return None;
+        }
+
+        let free = samples.back()?.free_bytes as f64;
+        Duration::try_from_secs_f64(free / slope).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1 << 30;
+
+    fn usage(used_bytes: u64, total_bytes: u64) -> DiskUsage {
+        DiskUsage {
+            path: "/data".to_string(),
+            total_bytes,
+            used_bytes,
+            free_bytes: total_bytes - used_bytes,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_linear_fill_projects_time_to_full() {
+        let mut trend = DiskTrend::new(10, 3);
+        let start = Instant::now();
+
+        // 1 GiB per hour, 10 GiB used of 100 GiB after the last sample
+        for hour in 0..=5u64 {
+            trend.record_at(
+                &usage((5 + hour) * GIB, 100 * GIB),
+                start + Duration::from_secs(hour * 3600),
+            );
+        }
+
+        let projected = trend.project_full("/data").unwrap();
+        let expected = Duration::from_secs(90 * 3600);
+        assert!(projected.abs_diff(expected) < Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_flat_or_shrinking_usage_has_no_projection() {
+        let mut trend = DiskTrend::default();
+        let start = Instant::now();
+
+        for step in 0..5u64 {
+            trend.record_at(&usage(50 * GIB, 100 * GIB), start + Duration::from_secs(step * 60));
+        }
+        assert_eq!(trend.project_full("/data"), None);
+
+        trend.clear();
+        for step in 0..5u64 {
+            trend.record_at(
+                &usage((50 - step) * GIB, 100 * GIB),
+                start + Duration::from_secs(step * 60),
+            );
+        }
+        assert_eq!(trend.project_full("/data"), None);
+    }
+
+    #[test]
+    fn test_requires_min_samples_and_bounds_window() {
+        let mut trend = DiskTrend::new(4, 3);
+        let start = Instant::now();
+
+        trend.record_at(&usage(GIB, 100 * GIB), start);
+        trend.record_at(&usage(2 * GIB, 100 * GIB), start + Duration::from_secs(60));
+        assert_eq!(trend.project_full("/data"), None);
+        assert_eq!(trend.project_full("/other"), None);
+
+        for step in 2..10u64 {
+            trend.record_at(
+                &usage((step + 1) * GIB, 100 * GIB),
+                start + Duration::from_secs(step * 60),
+            );
+        }
+        assert_eq!(trend.len("/data"), 4);
+        assert!(trend.project_full("/data").is_some());
+    }
+}
@@ -6,18 +6,22 @@ mod sysctl;
 pub mod thermal;
 
 pub use sysctl::{
-    ConnectionProtocol, ConnectionState, ContextSwitches, NetworkConnection,
+    ConnectionProtocol, ConnectionState, ContextSwitches, NetworkConnection, get_host_uuid,
     list_network_connections, read_process_context_switches, read_self_context_switches,
     read_system_context_switches,
 };
 
+#[cfg(target_os = "openbsd")]
+pub(crate) use sysctl::sysctlbyname;
+
 pub use thermal::{deci_kelvin_to_celsius, is_thermal_supported, read_thermal_zones};
 
 use crate::{
-    CPUCollector, CPUPressure, DiskCollector, DiskIOStats, DiskUsage, Error, IOCollector,
-    IOPressure, IOStats, LoadAverage, LoadCollector, MemoryCollector, MemoryPressure, NetInterface,
-    NetStats, NetworkCollector, Partition, ProcessCollector, ProcessMetrics, ProcessState, Result,
-    SystemCPU, SystemCollector, SystemMemory,
+    CPUCollector, CPUPressure, Capabilities, CpuTicks, DiskCollector, DiskIOStats, DiskUsage,
+    Error, IOCollector, IOPressure, IOStats, LoadAverage, LoadCollector, MemoryCollector,
+    MemoryPressure, NetInterface, NetStats, NetworkCollector, Partition, ProcessCollector,
+    ProcessMetrics, ProcessState, Result, SystemCPU, SystemCollector, SystemLimits, SystemMemory,
+    read_sysctl_u64,
 };
 
 /// BSD system collector implementation.
@@ -81,6 +85,35 @@ impl SystemCollector for BsdCollector {
     fn io(&self) -> &dyn IOCollector {
         &self.io
     }
+
+    fn system_limits(&self) -> Result<SystemLimits> {
+        // Sysctls missing on this BSD (or unknown to the OpenBSD shim)
+        // leave the limit at 0
+        let read = |name: &str| read_sysctl_u64(name).unwrap_or(0);
+        Ok(SystemLimits {
+            file_max: read("kern.maxfiles"),
+            pid_max: read("kern.pid_max"),
+            threads_max: read("kern.maxthread"),
+            max_map_count: 0,
+            aio_max_nr: read("vfs.aio.max_aio_queue"),
+        })
+    }
+
+    fn pressure_supported(&self) -> bool {
+        // PSI is Linux-only
+        false
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            has_pressure: false,
+            has_thermal: is_thermal_supported(),
+            // kern.cp_times is FreeBSD-only
+            has_per_core: cfg!(target_os = "freebsd"),
+            has_connections: true,
+            has_disk_io: true,
+        }
+    }
 }
 
 // ============================================================================
@@ -91,7 +124,9 @@ struct BsdCPUCollector;
 
 impl CPUCollector for BsdCPUCollector {
     fn collect_system(&self) -> Result<SystemCPU> {
-        let cpu_times = sysctl::get_cpu_times()?;
+        // Averages since boot; interval usage comes from cpu_ticks and a
+        // caller-owned CpuSampler
+        let cpu_times = sysctl::get_cpu_ticks()?.times();
         let cpu_info = sysctl::get_cpu_info()?;
 
         Ok(SystemCPU {
@@ -101,10 +136,19 @@ impl CPUCollector for BsdCPUCollector {
             iowait_percent: 0.0, // Not always available on BSD
             steal_percent: 0.0,  // Not available on BSD
             cores: cpu_info.cores,
+            cores_present: cpu_info.cores_present,
+            cores_online: cpu_info.cores,
+            physical_cores: cpu_info.physical_cores,
             frequency_mhz: cpu_info.frequency_mhz,
+            guest_percent: 0.0,
+            guest_nice_percent: 0.0,
         })
     }
 
+    fn cpu_ticks(&self) -> Result<CpuTicks> {
+        Ok(sysctl::get_cpu_ticks()?.ticks())
+    }
+
     fn collect_pressure(&self) -> Result<CPUPressure> {
         // PSI not available on BSD
         Err(Error::NotSupported)
@@ -127,6 +171,7 @@ impl MemoryCollector for BsdMemoryCollector {
             used_bytes: mem_info.total.saturating_sub(mem_info.available),
             cached_bytes: mem_info.cached,
             buffers_bytes: mem_info.buffers,
+            slab_reclaimable_bytes: 0, // Not available on BSD
             swap_total_bytes: mem_info.swap_total,
             swap_used_bytes: mem_info.swap_used,
         })
@@ -136,6 +181,17 @@ impl MemoryCollector for BsdMemoryCollector {
         // PSI not available on BSD
         Err(Error::NotSupported)
     }
+
+    #[cfg(target_os = "freebsd")]
+    fn zfs_arc(&self) -> Result<crate::ZfsArcStats> {
+        // The arcstats sysctls only exist once zfs.ko is loaded
+        let size_bytes =
+            read_sysctl_u64("kstat.zfs.misc.arcstats.size").map_err(|_| Error::NotSupported)?;
+        Ok(crate::ZfsArcStats {
+            size_bytes,
+            min_bytes: read_sysctl_u64("kstat.zfs.misc.arcstats.c_min").unwrap_or(0),
+        })
+    }
 }
 
 // ============================================================================
@@ -176,6 +232,8 @@ impl ProcessCollector for BsdProcessCollector {
             num_fds: proc_info.num_fds,
             read_bytes_per_sec: 0,
             write_bytes_per_sec: 0,
+            minor_faults: proc_info.minor_faults,
+            major_faults: proc_info.major_faults,
             state: match proc_info.state {
                 1 => ProcessState::Running,
                 2 => ProcessState::Sleeping,
@@ -184,6 +242,9 @@ impl ProcessCollector for BsdProcessCollector {
                 5 => ProcessState::Stopped,
                 _ => ProcessState::Unknown,
             },
+            nice: proc_info.nice,
+            // Scheduling policy and real-time priority are not reported
+            ..Default::default()
         })
     }
 
@@ -270,16 +331,7 @@ impl IOCollector for BsdIOCollector {
     fn collect_stats(&self) -> Result<IOStats> {
         // Aggregate from disk I/O
         let disk_stats = sysctl::get_disk_io_stats()?;
-
-        let mut total = IOStats::default();
-        for stat in disk_stats {
-            total.read_ops += stat.reads_completed;
-            total.read_bytes += stat.read_bytes;
-            total.write_ops += stat.writes_completed;
-            total.write_bytes += stat.write_bytes;
-        }
-
-        Ok(total)
+        Ok(IOStats::from_disks(&disk_stats))
     }
 
     fn collect_pressure(&self) -> Result<IOPressure> {
@@ -287,3 +339,54 @@ impl IOCollector for BsdIOCollector {
         Err(Error::NotSupported)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_sysctl_matches_cpu_collector() {
+        let ncpu = read_sysctl_u64("hw.ncpu").unwrap();
+        let cpu = BsdCollector::new().cpu().collect_system().unwrap();
+
+        assert_eq!(ncpu, cpu.cores_present as u64);
+    }
+
+    #[test]
+    fn test_cpu_percentages_cover_last_interval() {
+        let collector = BsdCollector::new();
+        let mut sampler = probe_metrics::CpuSampler::new();
+        assert!(sampler.sample(collector.cpu().cpu_ticks().unwrap()).is_none());
+
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let deadline = std::time::Instant::now() + std::time::Duration::from_millis(500);
+        let busy: Vec<_> = (0..threads)
+            .map(|_| {
+                std::thread::spawn(move || {
+                    while std::time::Instant::now() < deadline {
+                        std::hint::spin_loop();
+                    }
+                })
+            })
+            .collect();
+        busy.into_iter().for_each(|t| t.join().unwrap());
+
+        let usage = sampler.sample(collector.cpu().cpu_ticks().unwrap()).unwrap();
+        assert!(usage.user_percent > 50.0, "user% {} after a busy loop", usage.user_percent);
+    }
+
+    #[test]
+    fn test_cpu_sampler_uses_tick_deltas() {
+        let ticks = |user, system, idle| sysctl::CpuTicks { user, system, idle }.ticks();
+        let mut sampler = probe_metrics::CpuSampler::new();
+
+        // No delta yet on the first reading, nor when no tick elapsed
+        assert!(sampler.sample(ticks(50, 100, 1000)).is_none());
+        assert!(sampler.sample(ticks(50, 100, 1000)).is_none());
+
+        let usage = sampler.sample(ticks(125, 110, 1015)).unwrap();
+        assert_eq!(usage.user_percent, 75.0);
+        assert_eq!(usage.system_percent, 10.0);
+        assert_eq!(usage.idle_percent, 15.0);
+    }
+}
@@ -1,5 +1,6 @@
 //! BSD sysctl wrappers
 
+use crate::sysctl::sysctl_error;
 use crate::{DiskIOStats, DiskUsage, Error, NetInterface, NetStats, Partition, Result};
 use std::ffi::CString;
 use std::mem;
@@ -24,6 +25,9 @@ pub unsafe extern "C" fn sysctlbyname(
     const HW_PHYSMEM64: libc::c_int = 19;
     const HW_CPUSPEED: libc::c_int = 12;
     const HW_DISKSTATS: libc::c_int = 9;
+    const HW_UUID: libc::c_int = 18;
+    const HW_NCPUFOUND: libc::c_int = 21;
+    const HW_NCPUONLINE: libc::c_int = 25;
 
     let name_cstr = unsafe { std::ffi::CStr::from_ptr(name) };
     let name_bytes = name_cstr.to_bytes();
@@ -32,6 +36,9 @@ pub unsafe extern "C" fn sysctlbyname(
     let mib: [libc::c_int; 2] = match name_bytes {
         b"kern.cp_time" => [CTL_KERN, KERN_CPTIME],
         b"hw.ncpu" => [CTL_HW, HW_NCPU],
+        b"hw.ncpufound" => [CTL_HW, HW_NCPUFOUND],
+        b"hw.uuid" => [CTL_HW, HW_UUID],
+        b"hw.ncpuonline" => [CTL_HW, HW_NCPUONLINE],
         b"hw.cpuspeed" => [CTL_HW, HW_CPUSPEED],
         b"hw.physmem" => [CTL_HW, HW_PHYSMEM64],
         b"hw.diskstats" => [CTL_HW, HW_DISKSTATS],
@@ -77,11 +84,57 @@ pub struct CpuTimes {
 }
 
 pub struct CpuInfo {
+    /// Online cores.
     pub cores: u32,
+    /// Cores present in the machine, including offline ones.
+    pub cores_present: u32,
+    /// Physical cores (FreeBSD only, `cores` elsewhere).
+    pub physical_cores: u32,
     pub frequency_mhz: u64,
 }
 
-pub fn get_cpu_times() -> Result<CpuTimes> {
+/// Cumulative CPU ticks of all cores, from `kern.cp_time`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CpuTicks {
+    /// User and nice ticks.
+    pub user: u64,
+    /// System and interrupt ticks.
+    pub system: u64,
+    pub idle: u64,
+}
+
+impl CpuTicks {
+    /// These counters as [`crate::CpuTicks`].
+    pub fn ticks(&self) -> crate::CpuTicks {
+        crate::CpuTicks {
+            user: self.user,
+            system: self.system,
+            idle: self.idle,
+            ..Default::default()
+        }
+    }
+
+    pub fn total(&self) -> u64 {
+        self.user + self.system + self.idle
+    }
+
+    /// Share of each state in these ticks.
+    pub fn times(&self) -> CpuTimes {
+        let total = self.total();
+        if total == 0 {
+            return CpuTimes { user_percent: 0.0, system_percent: 0.0, idle_percent: 100.0 };
+        }
+        let percent = |ticks: u64| ticks as f64 / total as f64 * 100.0;
+        CpuTimes {
+            user_percent: percent(self.user),
+            system_percent: percent(self.system),
+            idle_percent: percent(self.idle),
+        }
+    }
+}
+
+/// Read the cumulative CPU ticks since boot.
+pub fn get_cpu_ticks() -> Result<CpuTicks> {
     unsafe {
         // kern.cp_time on FreeBSD/OpenBSD/NetBSD
         let name = CString::new("kern.cp_time")
@@ -99,22 +152,13 @@ pub fn get_cpu_times() -> Result<CpuTimes> {
         );
 
         if result != 0 {
-            return Ok(CpuTimes { user_percent: 0.0, system_percent: 0.0, idle_percent: 100.0 });
-        }
-
-        let user = cp_time[0] + cp_time[1]; // user + nice
-        let system = cp_time[2] + cp_time[3]; // sys + intr
-        let idle = cp_time[4];
-        let total = user + system + idle;
-
-        if total == 0 {
-            return Ok(CpuTimes { user_percent: 0.0, system_percent: 0.0, idle_percent: 100.0 });
+            return Err(sysctl_error("kern.cp_time"));
         }
 
-        Ok(CpuTimes {
-            user_percent: (user as f64 / total as f64) * 100.0,
-            system_percent: (system as f64 / total as f64) * 100.0,
-            idle_percent: (idle as f64 / total as f64) * 100.0,
+        Ok(CpuTicks {
+            user: cp_time[0] + cp_time[1],   // user + nice
+            system: cp_time[2] + cp_time[3], // sys + intr
+            idle: cp_time[4],
         })
     }
 }
@@ -157,8 +201,86 @@ pub fn get_cpu_info() -> Result<CpuInfo> {
             0,
         );
 
-        Ok(CpuInfo { cores: ncpu as u32, frequency_mhz: freq as u64 })
+        // FreeBSD has no CPU offlining: hw.ncpu is both online and present.
+        // OpenBSD's hw.ncpu counts online CPUs and hw.ncpufound all of them;
+        // NetBSD's hw.ncpu counts all of them and hw.ncpuonline the online ones.
+        #[cfg(target_os = "freebsd")]
+        let (online, present) = (ncpu, ncpu);
+        #[cfg(target_os = "openbsd")]
+        let (online, present) = (ncpu, sysctl_int("hw.ncpufound").unwrap_or(ncpu));
+        #[cfg(target_os = "netbsd")]
+        let (online, present) = (sysctl_int("hw.ncpuonline").unwrap_or(ncpu), ncpu);
+
+        // Only FreeBSD exposes the physical core count (kern.smp.cores)
+        #[cfg(target_os = "freebsd")]
+        let physical = sysctl_int("kern.smp.cores").unwrap_or(online);
+        #[cfg(not(target_os = "freebsd"))]
+        let physical = online;
+
+        Ok(CpuInfo {
+            cores: online as u32,
+            cores_present: present as u32,
+            physical_cores: physical as u32,
+            frequency_mhz: freq as u64,
+        })
+    }
+}
+
+/// Read an integer sysctl, returning `None` when it is missing or not positive.
+fn sysctl_int(name: &str) -> Option<libc::c_int> {
+    let name = CString::new(name).ok()?;
+    let mut value: libc::c_int = 0;
+    let mut len = mem::size_of::<libc::c_int>();
+
+    let result = unsafe {
+        do_sysctlbyname(
+            name.as_ptr(),
+            &mut value as *mut _ as *mut libc::c_void,
+            &mut len,
+            ptr::null_mut(),
+            0,
+        )
+    };
+    (result == 0 && value > 0).then_some(value)
+}
+
+// ============================================================================
+// HOST IDENTITY
+// ============================================================================
+
+/// Read the host UUID.
+///
+/// Uses `kern.hostuuid` on FreeBSD, `hw.uuid` on OpenBSD and
+/// `machdep.dmi.system-uuid` on NetBSD. Returns an empty string if unset.
+pub fn get_host_uuid() -> Result<String> {
+    #[cfg(target_os = "freebsd")]
+    let name = "kern.hostuuid";
+    #[cfg(target_os = "openbsd")]
+    let name = "hw.uuid";
+    #[cfg(target_os = "netbsd")]
+    let name = "machdep.dmi.system-uuid";
+
+    let name =
+        CString::new(name).map_err(|e| Error::Platform(format!("invalid sysctl name: {}", e)))?;
+    let mut buf = [0u8; 64];
+    let mut len = buf.len();
+
+    let result = unsafe {
+        do_sysctlbyname(
+            name.as_ptr(),
+            buf.as_mut_ptr() as *mut libc::c_void,
+            &mut len,
+            ptr::null_mut(),
+            0,
+        )
+    };
+    if result != 0 {
+        return Ok(String::new());
     }
+
+    let len = len.min(buf.len());
+    let end = buf[..len].iter().position(|&b| b == 0).unwrap_or(len);
+    Ok(String::from_utf8_lossy(&buf[..end]).trim().to_string())
 }
 
 // ============================================================================
@@ -273,7 +395,8 @@ fn get_buffers_bytes(page_size: u64) -> u64 {
     {
         // vfs.bufspace is CTLTYPE_LONG (signed long) per sys/kern/vfs_bio.c.
         // Use c_long for correct size on both 32-bit and 64-bit architectures.
-        // On ZFS systems this may return 0 (ZFS uses its own ARC cache).
+        // On ZFS systems this may return 0 (ZFS uses its own ARC cache,
+        // reported by `MemoryCollector::zfs_arc`).
         let name = match CString::new("vfs.bufspace") {
             Ok(n) => n,
             Err(_) => return 0,
@@ -624,11 +747,18 @@ pub fn get_loadavg() -> Result<LoadAvg> {
 // PROCESS
 // ============================================================================
 
+/// Offset added to nice values stored in `kinfo_proc`.
+#[cfg(any(target_os = "openbsd", target_os = "netbsd"))]
+const NZERO: i8 = 20;
+
 pub struct ProcessInfo {
     pub rss: u64,
     pub vsize: u64,
     pub num_threads: u32,
     pub num_fds: u32,
+    pub minor_faults: u64,
+    pub major_faults: u64,
+    pub nice: i8,
     pub state: u8,
 }
 
@@ -660,6 +790,9 @@ pub fn get_process_info(pid: i32) -> Result<ProcessInfo> {
                 vsize: kinfo.ki_size as u64,
                 num_threads: kinfo.ki_numthreads as u32,
                 num_fds: 0, // Fallback: libc crate may not expose fd_nfiles field
+                minor_faults: kinfo.ki_rusage.ru_minflt.max(0) as u64,
+                major_faults: kinfo.ki_rusage.ru_majflt.max(0) as u64,
+                nice: kinfo.ki_nice,
                 state: match kinfo.ki_stat as i32 {
                     SRUN => 1,
                     SSLEEP => 2,
@@ -811,6 +944,10 @@ fn get_process_info_openbsd(pid: i32) -> Result<ProcessInfo> {
                 .saturating_mul(page_size),
             num_threads: 1, // OpenBSD doesn't expose thread count easily
             num_fds: 0,     // Would need KERN_FILE sysctl
+            minor_faults: kinfo.p_uru_minflt,
+            major_faults: kinfo.p_uru_majflt,
+            // p_nice is offset by NZERO (20)
+            nice: kinfo.p_nice.wrapping_sub(NZERO),
             state: match kinfo.p_stat {
                 1 => 1, // SIDL -> Running (idle)
                 2 => 1, // SRUN -> Running
@@ -945,6 +1082,10 @@ fn get_process_info_netbsd(pid: i32) -> Result<ProcessInfo> {
             vsize: kinfo.p_vm_vsize as u64,
             num_threads: kinfo.p_nlwps as u32,
             num_fds: 0, // Would need KERN_FILE sysctl
+            minor_faults: kinfo.p_uru_minflt,
+            major_faults: kinfo.p_uru_majflt,
+            // p_nice is offset by NZERO (20)
+            nice: kinfo.p_nice.wrapping_sub(NZERO),
             state: match kinfo.p_stat {
                 1 => 1, // SIDL -> Running
                 2 => 1, // SRUN -> Running
@@ -1132,6 +1273,31 @@ const SSTOP: i32 = 6;
 // DISK
 // ============================================================================
 
+/// Render statfs mount flags as a `mount(8)`-style option string.
+fn mount_flags_to_options(flags: u64) -> String {
+    let has = |flag: libc::c_int| flags & flag as u64 != 0;
+
+    let mut options = vec![if has(libc::MNT_RDONLY) { "ro" } else { "rw" }];
+    if has(libc::MNT_NOEXEC) {
+        options.push("noexec");
+    }
+    if has(libc::MNT_NOSUID) {
+        options.push("nosuid");
+    }
+    #[cfg(any(target_os = "openbsd", target_os = "netbsd"))]
+    if has(libc::MNT_NODEV) {
+        options.push("nodev");
+    }
+    if has(libc::MNT_NOATIME) {
+        options.push("noatime");
+    }
+    #[cfg(target_os = "netbsd")]
+    if has(libc::MNT_RELATIME) {
+        options.push("relatime");
+    }
+    options.join(",")
+}
+
 pub fn get_mounts() -> Result<Vec<Partition>> {
     unsafe {
         #[cfg(target_os = "freebsd")]
@@ -1155,7 +1321,8 @@ pub fn get_mounts() -> Result<Vec<Partition>> {
                     continue;
                 }
 
-                partitions.push(Partition { device, mount_point, fs_type, options: String::new() });
+                let options = mount_flags_to_options(fs.f_flags as u64);
+                partitions.push(Partition { device, mount_point, fs_type, options });
             }
 
             Ok(partitions)
@@ -1196,7 +1363,8 @@ fn get_mounts_openbsd() -> Result<Vec<Partition>> {
                 continue;
             }
 
-            partitions.push(Partition { device, mount_point, fs_type, options: String::new() });
+            let options = mount_flags_to_options(fs.f_flags as u64);
+            partitions.push(Partition { device, mount_point, fs_type, options });
         }
 
         Ok(partitions)
@@ -1235,7 +1403,8 @@ fn get_mounts_netbsd() -> Result<Vec<Partition>> {
                 continue;
             }
 
-            partitions.push(Partition { device, mount_point, fs_type, options: String::new() });
+            let options = mount_flags_to_options(fs.f_flag as u64);
+            partitions.push(Partition { device, mount_point, fs_type, options });
         }
 
         Ok(partitions)
@@ -1924,10 +2093,14 @@ pub fn get_network_stats() -> Result<Vec<NetStats>> {
                         rx_packets: data.ifi_ipackets,
                         rx_errors: data.ifi_ierrors,
                         rx_drops: data.ifi_iqdrops,
+                        rx_multicast: data.ifi_imcasts,
                         tx_bytes: data.ifi_obytes,
                         tx_packets: data.ifi_opackets,
                         tx_errors: data.ifi_oerrors,
                         tx_drops: 0, // Not all BSDs expose this
+                        tx_collisions: data.ifi_collisions,
+                        // FIFO and compression counters are Linux-specific
+                        ..Default::default()
                     });
                 }
             }
@@ -3148,11 +3321,11 @@ mod tests {
     }
 
     #[test]
-    fn test_get_cpu_times_does_not_panic() {
-        let result = get_cpu_times();
-        assert!(result.is_ok(), "get_cpu_times() should succeed on BSD");
+    fn test_get_cpu_ticks_does_not_panic() {
+        let result = get_cpu_ticks();
+        assert!(result.is_ok(), "get_cpu_ticks() should succeed on BSD");
 
-        let times = result.unwrap();
+        let times = result.unwrap().times();
         let total = times.user_percent + times.system_percent + times.idle_percent;
         assert!((total - 100.0).abs() < 1.0, "CPU percentages should sum to ~100%, got {total}");
     }
@@ -5,17 +5,19 @@
 mod sysctl;
 
 pub use sysctl::{
-    ConnectionProtocol, ConnectionState, ContextSwitches, NetworkConnection, is_thermal_supported,
-    list_network_connections, read_process_context_switches, read_self_context_switches,
-    read_system_context_switches, read_thermal_zones,
+    ConnectionProtocol, ConnectionState, ContextSwitches, NetworkConnection, get_platform_uuid,
+    is_thermal_supported, list_network_connections, read_process_context_switches,
+    read_self_context_switches, read_system_context_switches, read_thermal_zones,
 };
 
 use crate::{
-    CPUCollector, CPUPressure, DiskCollector, DiskIOStats, DiskUsage, Error, IOCollector,
-    IOPressure, IOStats, LoadAverage, LoadCollector, MemoryCollector, MemoryPressure, NetInterface,
-    NetStats, NetworkCollector, Partition, ProcessCollector, ProcessMetrics, ProcessState, Result,
-    SystemCPU, SystemCollector, SystemMemory,
+    CPUCollector, CPUPressure, CacheKind, Capabilities, CpuCache, CpuTicks, DiskCollector,
+    DiskIOStats, DiskUsage, Error, IOCollector, IOPressure, IOStats, LoadAverage, LoadCollector,
+    MemoryCollector, MemoryPressure, NetInterface, NetStats, NetworkCollector, Partition,
+    ProcessCollector, ProcessMetrics, ProcessState, Result, SystemCPU, SystemCollector,
+    SystemLimits, SystemMemory, read_sysctl_u64,
 };
+use std::sync::Mutex;
 
 /// macOS system collector implementation.
 pub struct DarwinCollector {
@@ -32,7 +34,7 @@ impl DarwinCollector {
     /// Create a new Darwin collector.
     pub fn new() -> Self {
         Self {
-            cpu: DarwinCPUCollector,
+            cpu: DarwinCPUCollector::default(),
             memory: DarwinMemoryCollector,
             load: DarwinLoadCollector,
             process: DarwinProcessCollector,
@@ -77,17 +79,53 @@ impl SystemCollector for DarwinCollector {
     fn io(&self) -> &dyn IOCollector {
         &self.io
     }
+
+    fn system_limits(&self) -> Result<SystemLimits> {
+        // Missing sysctls leave the limit at 0
+        let read = |name: &str| read_sysctl_u64(name).unwrap_or(0);
+        Ok(SystemLimits {
+            file_max: read("kern.maxfiles"),
+            // PID_MAX is a compile-time constant on macOS
+            pid_max: 0,
+            threads_max: read("kern.num_threads"),
+            max_map_count: 0,
+            aio_max_nr: read("kern.aiomax"),
+        })
+    }
+
+    fn pressure_supported(&self) -> bool {
+        // PSI is Linux-only
+        false
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            has_pressure: false,
+            has_thermal: is_thermal_supported(),
+            // host_processor_info reports per-CPU ticks
+            has_per_core: true,
+            has_connections: true,
+            has_disk_io: true,
+        }
+    }
 }
 
 // ============================================================================
 // CPU COLLECTOR
 // ============================================================================
 
-struct DarwinCPUCollector;
+/// CPU collector; keeps the latest ticks extended to 64 bits so that
+/// 32-bit Mach counters never appear to go backwards.
+#[derive(Default)]
+struct DarwinCPUCollector {
+    ticks: Mutex<CpuTicks>,
+}
 
 impl CPUCollector for DarwinCPUCollector {
     fn collect_system(&self) -> Result<SystemCPU> {
-        let cpu_times = sysctl::get_cpu_times()?;
+        // Averages since boot; interval usage comes from cpu_ticks and a
+        // caller-owned CpuSampler
+        let cpu_times = sysctl::get_cpu_ticks()?.times();
         let cpu_info = sysctl::get_cpu_info()?;
 
         Ok(SystemCPU {
@@ -97,14 +135,44 @@ impl CPUCollector for DarwinCPUCollector {
             iowait_percent: 0.0, // Not available on macOS
             steal_percent: 0.0,  // Not available on macOS
             cores: cpu_info.cores,
+            cores_present: cpu_info.cores_present,
+            cores_online: cpu_info.cores,
+            physical_cores: cpu_info.physical_cores,
             frequency_mhz: cpu_info.frequency_mhz,
+            guest_percent: 0.0,
+            guest_nice_percent: 0.0,
         })
     }
 
+    fn cpu_ticks(&self) -> Result<CpuTicks> {
+        let raw = sysctl::get_cpu_ticks()?;
+        let mut ticks = self.ticks.lock().unwrap_or_else(|e| e.into_inner());
+        *ticks = raw.extend(&ticks);
+        Ok(*ticks)
+    }
+
     fn collect_pressure(&self) -> Result<CPUPressure> {
         // PSI not available on macOS
         Err(Error::NotSupported)
     }
+
+    fn cpu_caches(&self) -> Result<Vec<CpuCache>> {
+        // Apple Silicon has no L3; missing or zero sysctls are skipped
+        let caches: Vec<CpuCache> = [
+            (1, CacheKind::Data, "hw.l1dcachesize"),
+            (1, CacheKind::Instruction, "hw.l1icachesize"),
+            (2, CacheKind::Unified, "hw.l2cachesize"),
+            (3, CacheKind::Unified, "hw.l3cachesize"),
+        ]
+        .into_iter()
+        .filter_map(|(level, kind, name)| {
+            let size_bytes = read_sysctl_u64(name).ok().filter(|&n| n > 0)?;
+            Some(CpuCache { level, kind, size_bytes, shared_cpu_list: String::new() })
+        })
+        .collect();
+
+        if caches.is_empty() { Err(Error::NotSupported) } else { Ok(caches) }
+    }
 }
 
 // ============================================================================
@@ -122,7 +190,8 @@ impl MemoryCollector for DarwinMemoryCollector {
             available_bytes: mem_info.available,
             used_bytes: mem_info.total.saturating_sub(mem_info.available),
             cached_bytes: mem_info.cached,
-            buffers_bytes: 0, // Not available on macOS
+            buffers_bytes: 0,          // Not available on macOS
+            slab_reclaimable_bytes: 0, // Not available on macOS
             swap_total_bytes: mem_info.swap_total,
             swap_used_bytes: mem_info.swap_used,
         })
@@ -172,6 +241,8 @@ impl ProcessCollector for DarwinProcessCollector {
             num_fds: proc_info.num_fds,
             read_bytes_per_sec: 0,
             write_bytes_per_sec: 0,
+            minor_faults: proc_info.minor_faults,
+            major_faults: proc_info.major_faults,
             state: match proc_info.state {
                 1 => ProcessState::Running,
                 2 => ProcessState::Sleeping,
@@ -180,6 +251,9 @@ impl ProcessCollector for DarwinProcessCollector {
                 5 => ProcessState::Stopped,
                 _ => ProcessState::Unknown,
             },
+            nice: proc_info.nice,
+            // Scheduling policy and real-time priority are not reported
+            ..Default::default()
         })
     }
 
@@ -266,16 +340,7 @@ impl IOCollector for DarwinIOCollector {
     fn collect_stats(&self) -> Result<IOStats> {
         // Aggregate from disk I/O
         let disk_stats = sysctl::get_disk_io_stats()?;
-
-        let mut total = IOStats::default();
-        for stat in disk_stats {
-            total.read_ops += stat.reads_completed;
-            total.read_bytes += stat.read_bytes;
-            total.write_ops += stat.writes_completed;
-            total.write_bytes += stat.write_bytes;
-        }
-
-        Ok(total)
+        Ok(IOStats::from_disks(&disk_stats))
     }
 
     fn collect_pressure(&self) -> Result<IOPressure> {
@@ -283,3 +348,87 @@ impl IOCollector for DarwinIOCollector {
         Err(Error::NotSupported)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_sysctl_string;
+
+    #[test]
+    fn test_capabilities_report_no_pressure() {
+        let caps = DarwinCollector::new().capabilities();
+
+        assert!(!caps.has_pressure);
+        assert!(caps.has_disk_io);
+    }
+
+    #[test]
+    fn test_read_sysctl_matches_cpu_collector() {
+        let ncpu = read_sysctl_u64("hw.ncpu").unwrap();
+        let cpu = DarwinCollector::new().cpu().collect_system().unwrap();
+
+        assert_eq!(ncpu, cpu.cores_present as u64);
+        assert_eq!(read_sysctl_string("kern.ostype").unwrap(), "Darwin");
+        assert!(matches!(read_sysctl_u64("probe.missing"), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_cpu_caches_from_sysctl() {
+        let caches = DarwinCollector::new().cpu().cpu_caches().unwrap();
+
+        assert_eq!(caches[0].level, 1);
+        assert!(caches.iter().all(|c| c.size_bytes > 0));
+    }
+
+    #[test]
+    fn test_system_limits_from_sysctl() {
+        let limits = DarwinCollector::new().system_limits().unwrap();
+
+        assert!(limits.file_max > 0);
+        assert!(limits.threads_max > 0);
+        assert_eq!(limits.max_map_count, 0);
+    }
+
+    #[test]
+    fn test_cpu_percentages_cover_last_interval() {
+        let collector = DarwinCollector::new();
+        let mut sampler = probe_metrics::CpuSampler::new();
+        assert!(sampler.sample(collector.cpu().cpu_ticks().unwrap()).is_none());
+
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let deadline = std::time::Instant::now() + std::time::Duration::from_millis(500);
+        let busy: Vec<_> = (0..threads)
+            .map(|_| {
+                std::thread::spawn(move || {
+                    while std::time::Instant::now() < deadline {
+                        std::hint::spin_loop();
+                    }
+                })
+            })
+            .collect();
+        busy.into_iter().for_each(|t| t.join().unwrap());
+
+        let usage = sampler.sample(collector.cpu().cpu_ticks().unwrap()).unwrap();
+        assert!(usage.user_percent > 50.0, "user% {} after a busy loop", usage.user_percent);
+    }
+
+    #[test]
+    fn test_cpu_sampler_unwraps_mach_ticks() {
+        let mut extended = CpuTicks::default();
+        let mut ticks = |user, system, idle| {
+            extended = sysctl::CpuTicks { user, nice: 0, system, idle }.extend(&extended);
+            extended
+        };
+        let mut sampler = probe_metrics::CpuSampler::new();
+
+        // No delta yet on the first reading, nor when no tick elapsed
+        assert!(sampler.sample(ticks(u32::MAX - 49, 100, 1000)).is_none());
+        assert!(sampler.sample(ticks(u32::MAX - 49, 100, 1000)).is_none());
+
+        // The 32-bit user counter wrapped: 50 + 25 ticks
+        let usage = sampler.sample(ticks(25, 110, 1015)).unwrap();
+        assert_eq!(usage.user_percent, 75.0);
+        assert_eq!(usage.system_percent, 10.0);
+        assert_eq!(usage.idle_percent, 15.0);
+    }
+}
@@ -16,61 +16,85 @@ pub struct CpuTimes {
 }
 
 pub struct CpuInfo {
+    /// Online cores (hw.activecpu).
     pub cores: u32,
+    /// Cores present in the machine (hw.ncpu).
+    pub cores_present: u32,
+    /// Physical cores (hw.physicalcpu).
+    pub physical_cores: u32,
     pub frequency_mhz: u64,
 }
 
-pub fn get_cpu_times() -> Result<CpuTimes> {
-    // Use host_processor_info for CPU times
-    // This is a simplified implementation
-    unsafe {
-        let mut count: libc::c_uint = 0;
-        let mut info: *mut libc::c_int = ptr::null_mut();
-        let mut msg_count: libc::c_uint = 0;
-
-        let host = libc::mach_host_self();
-        let result = host_processor_info(
-            host,
-            PROCESSOR_CPU_LOAD_INFO,
-            &mut count,
-            &mut info,
-            &mut msg_count,
-        );
-
-        if result != 0 || info.is_null() {
-            return Ok(CpuTimes { user_percent: 0.0, system_percent: 0.0, idle_percent: 100.0 });
+/// Cumulative CPU ticks of all cores, from `HOST_CPU_LOAD_INFO`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CpuTicks {
+    pub user: u32,
+    pub nice: u32,
+    pub system: u32,
+    pub idle: u32,
+}
+
+impl CpuTicks {
+    /// These counters as [`crate::CpuTicks`], extended to 64 bits against
+    /// `prev`, the previous extended reading.
+    ///
+    /// Mach counters are 32-bit: each one advances `prev` by its wrapping
+    /// delta, so a single wraparound between two readings is absorbed.
+    pub fn extend(&self, prev: &crate::CpuTicks) -> crate::CpuTicks {
+        let extend = |curr: u32, prev: u64| prev + u64::from(curr.wrapping_sub(prev as u32));
+        crate::CpuTicks {
+            user: extend(self.user, prev.user),
+            nice: extend(self.nice, prev.nice),
+            system: extend(self.system, prev.system),
+            idle: extend(self.idle, prev.idle),
+            ..Default::default()
         }
+    }
 
-        // Calculate aggregate CPU times
-        let mut total_user: u64 = 0;
-        let mut total_system: u64 = 0;
-        let mut total_idle: u64 = 0;
-
-        for i in 0..count as usize {
-            let base = i * CPU_STATE_MAX;
-            total_user += (*info.add(base + CPU_STATE_USER as usize)) as u64;
-            total_system += (*info.add(base + CPU_STATE_SYSTEM as usize)) as u64;
-            total_idle += (*info.add(base + CPU_STATE_IDLE as usize)) as u64;
-        }
+    pub fn total(&self) -> u64 {
+        u64::from(self.user) + u64::from(self.nice) + u64::from(self.system) + u64::from(self.idle)
+    }
 
-        let total = total_user + total_system + total_idle;
+    /// Share of each state in these ticks; user time includes nice.
+    pub fn times(&self) -> CpuTimes {
+        let total = self.total();
         if total == 0 {
-            return Ok(CpuTimes { user_percent: 0.0, system_percent: 0.0, idle_percent: 100.0 });
+            return CpuTimes { user_percent: 0.0, system_percent: 0.0, idle_percent: 100.0 };
         }
+        let percent = |ticks: u64| ticks as f64 / total as f64 * 100.0;
+        CpuTimes {
+            user_percent: percent(u64::from(self.user) + u64::from(self.nice)),
+            system_percent: percent(u64::from(self.system)),
+            idle_percent: percent(u64::from(self.idle)),
+        }
+    }
+}
 
-        // Deallocate
-        libc::vm_deallocate(
-            libc::mach_task_self(),
-            info as libc::vm_address_t,
-            (msg_count as usize) * mem::size_of::<libc::c_int>(),
-        );
-
-        Ok(CpuTimes {
-            user_percent: (total_user as f64 / total as f64) * 100.0,
-            system_percent: (total_system as f64 / total as f64) * 100.0,
-            idle_percent: (total_idle as f64 / total as f64) * 100.0,
-        })
+/// Read the cumulative CPU ticks since boot.
+pub fn get_cpu_ticks() -> Result<CpuTicks> {
+    let mut load = [0u32; CPU_STATE_MAX];
+    let mut count = CPU_STATE_MAX as u32;
+    let result = unsafe {
+        host_statistics(
+            libc::mach_host_self(),
+            HOST_CPU_LOAD_INFO,
+            load.as_mut_ptr() as *mut libc::c_int,
+            &mut count,
+        )
+    };
+    if result != 0 {
+        return Err(Error::Platform(format!(
+            "host_statistics(HOST_CPU_LOAD_INFO) failed: {}",
+            result
+        )));
     }
+
+    Ok(CpuTicks {
+        user: load[CPU_STATE_USER],
+        nice: load[CPU_STATE_NICE],
+        system: load[CPU_STATE_SYSTEM],
+        idle: load[CPU_STATE_IDLE],
+    })
 }
 
 pub fn get_cpu_info() -> Result<CpuInfo> {
@@ -102,7 +126,40 @@ pub fn get_cpu_info() -> Result<CpuInfo> {
             0,
         );
 
-        Ok(CpuInfo { cores: ncpu as u32, frequency_mhz: freq / 1_000_000 })
+        // Get online CPUs (hw.activecpu); hw.ncpu counts all present CPUs
+        let name = CString::new("hw.activecpu").unwrap();
+        let mut active: libc::c_int = 0;
+        let mut active_len = mem::size_of::<libc::c_int>();
+
+        let result = libc::sysctlbyname(
+            name.as_ptr(),
+            &mut active as *mut _ as *mut libc::c_void,
+            &mut active_len,
+            ptr::null_mut(),
+            0,
+        );
+        let online = if result == 0 && active > 0 { active } else { ncpu };
+
+        // Get physical cores (hw.physicalcpu); hw.logicalcpu counts SMT threads
+        let name = CString::new("hw.physicalcpu").unwrap();
+        let mut physical: libc::c_int = 0;
+        let mut physical_len = mem::size_of::<libc::c_int>();
+
+        let result = libc::sysctlbyname(
+            name.as_ptr(),
+            &mut physical as *mut _ as *mut libc::c_void,
+            &mut physical_len,
+            ptr::null_mut(),
+            0,
+        );
+        let physical = if result == 0 && physical > 0 { physical } else { online };
+
+        Ok(CpuInfo {
+            cores: online as u32,
+            cores_present: ncpu as u32,
+            physical_cores: physical as u32,
+            frequency_mhz: freq / 1_000_000,
+        })
     }
 }
 
@@ -226,6 +283,9 @@ pub struct ProcessInfo {
     pub vsize: u64,
     pub num_threads: u32,
     pub num_fds: u32,
+    pub minor_faults: u64,
+    pub major_faults: u64,
+    pub nice: i8,
     pub state: u8,
 }
 
@@ -265,6 +325,15 @@ pub fn get_process_info(pid: i32) -> Result<ProcessInfo> {
             (0, 0, 1)
         };
 
+        // pti_faults counts all faults; page-ins are the ones that hit disk
+        let (minor_faults, major_faults) = if task_size > 0 {
+            let faults = task_info.pti_faults.max(0) as u64;
+            let pageins = task_info.pti_pageins.max(0) as u64;
+            (faults.saturating_sub(pageins), pageins)
+        } else {
+            (0, 0)
+        };
+
         // Count file descriptors using proc_pidinfo
         let num_fds = proc_pidinfo_fdcount(pid);
 
@@ -273,6 +342,9 @@ pub fn get_process_info(pid: i32) -> Result<ProcessInfo> {
             vsize,
             num_threads,
             num_fds,
+            minor_faults,
+            major_faults,
+            nice: bsd_info.pbi_nice.clamp(-20, 20) as i8,
             state: match bsd_info.pbi_status {
                 SIDL => 1,
                 SRUN => 1,
@@ -543,7 +615,7 @@ unsafe fn parse_iokit_disk_stats(stats_dict: *const libc::c_void, device: &str)
     stats.write_time_us /= 1_000;
 
     // Calculate total IO time
-    stats.io_time_us = stats.read_time_us + stats.write_time_us;
+    stats.io_time_us = stats.read_time_us.saturating_add(stats.write_time_us);
     stats.weighted_io_time_us = stats.io_time_us;
 
     stats
@@ -661,10 +733,14 @@ pub fn get_network_stats() -> Result<Vec<NetStats>> {
                         rx_packets: data.ifi_ipackets,
                         rx_errors: data.ifi_ierrors,
                         rx_drops: data.ifi_iqdrops,
+                        rx_multicast: data.ifi_imcasts,
                         tx_bytes: data.ifi_obytes,
                         tx_packets: data.ifi_opackets,
                         tx_errors: data.ifi_oerrors,
                         tx_drops: 0,
+                        tx_collisions: data.ifi_collisions,
+                        // FIFO and compression counters are Linux-specific
+                        ..Default::default()
                     });
                 }
             }
@@ -1162,13 +1238,14 @@ unsafe fn cstr_to_string(ptr: *const libc::c_char) -> String {
 // MACH AND SYSTEM TYPES
 // ============================================================================
 
-const PROCESSOR_CPU_LOAD_INFO: libc::c_int = 2;
-const CPU_STATE_USER: libc::c_int = 0;
-const CPU_STATE_SYSTEM: libc::c_int = 1;
-const CPU_STATE_IDLE: libc::c_int = 2;
+const CPU_STATE_USER: usize = 0;
+const CPU_STATE_SYSTEM: usize = 1;
+const CPU_STATE_IDLE: usize = 2;
+const CPU_STATE_NICE: usize = 3;
 const CPU_STATE_MAX: usize = 4;
 
 const HOST_VM_INFO: libc::c_int = 2;
+const HOST_CPU_LOAD_INFO: libc::c_int = 3;
 
 const SIDL: u32 = 1;
 const SRUN: u32 = 2;
@@ -1549,14 +1626,6 @@ struct RusageInfoV4 {
 
 // External Mach functions
 unsafe extern "C" {
-    fn host_processor_info(
-        host: libc::mach_port_t,
-        flavor: libc::c_int,
-        out_processor_count: *mut libc::c_uint,
-        out_processor_info: *mut *mut libc::c_int,
-        out_processor_infoCnt: *mut libc::c_uint,
-    ) -> libc::c_int;
-
     fn host_statistics(
         host: libc::mach_port_t,
         flavor: libc::c_int,
@@ -1623,6 +1692,18 @@ unsafe extern "C" {
         allocator: *const libc::c_void,
         options: u32,
     ) -> libc::c_int;
+
+    fn IOServiceGetMatchingService(
+        master_port: libc::mach_port_t,
+        matching: *mut libc::c_void,
+    ) -> u32;
+
+    fn IORegistryEntryCreateCFProperty(
+        entry: u32,
+        key: *const libc::c_void,
+        allocator: *const libc::c_void,
+        options: u32,
+    ) -> *const libc::c_void;
 }
 
 // CoreFoundation functions for dictionary access
@@ -1646,6 +1727,70 @@ unsafe extern "C" {
         number_type: libc::c_int,
         value_ptr: *mut libc::c_void,
     ) -> bool;
+
+    fn CFStringGetCString(
+        string: *const libc::c_void,
+        buffer: *mut libc::c_char,
+        buffer_size: isize,
+        encoding: u32,
+    ) -> bool;
+}
+
+// ============================================================================
+// HOST IDENTITY
+// ============================================================================
+
+/// Read the hardware UUID (`IOPlatformUUID`) from the IOKit registry.
+///
+/// Returns an empty string if the registry entry cannot be read.
+pub fn get_platform_uuid() -> Result<String> {
+    const KCF_STRING_ENCODING_UTF8: u32 = 0x08000100;
+
+    // kIOMainPortDefault
+    const IO_MAIN_PORT_DEFAULT: libc::mach_port_t = 0;
+
+    unsafe {
+        // IOServiceGetMatchingService consumes the matching dictionary
+        let matching =
+            IOServiceMatching(b"IOPlatformExpertDevice\0".as_ptr() as *const libc::c_char);
+        if matching.is_null() {
+            return Ok(String::new());
+        }
+        let service = IOServiceGetMatchingService(IO_MAIN_PORT_DEFAULT, matching);
+        if service == 0 {
+            return Ok(String::new());
+        }
+
+        let key = CFStringCreateWithCString(
+            ptr::null(),
+            b"IOPlatformUUID\0".as_ptr() as *const libc::c_char,
+            KCF_STRING_ENCODING_UTF8,
+        );
+        let value = if key.is_null() {
+            ptr::null()
+        } else {
+            let value = IORegistryEntryCreateCFProperty(service, key, ptr::null(), 0);
+            CFRelease(key);
+            value
+        };
+        IOObjectRelease(service);
+        if value.is_null() {
+            return Ok(String::new());
+        }
+
+        let mut buf = [0 as libc::c_char; 64];
+        let ok = CFStringGetCString(
+            value,
+            buf.as_mut_ptr(),
+            buf.len() as isize,
+            KCF_STRING_ENCODING_UTF8,
+        );
+        CFRelease(value);
+        if !ok {
+            return Ok(String::new());
+        }
+        Ok(std::ffi::CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned())
+    }
 }
 
 // ============================================================================
@@ -0,0 +1,92 @@
+//! Host identity: hostname and a machine identifier stable across reboots.
+
+use crate::{Error, Result};
+
+/// Return the system hostname as reported by `gethostname(2)`.
+#[cfg(unix)]
+pub fn hostname() -> Result<String> {
+    let mut buf = [0u8; 256];
+    // SAFETY: buf is valid for buf.len() bytes
+    let result = unsafe { libc::gethostname(buf.as_mut_ptr() as *mut libc::c_char, buf.len()) };
+    if result != 0 {
+        return Err(Error::Io(std::io::Error::last_os_error()));
+    }
+    let end = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    Ok(String::from_utf8_lossy(&buf[..end]).into_owned())
+}
+
+/// Return the system hostname as reported by `gethostname(2)`.
+#[cfg(not(unix))]
+pub fn hostname() -> Result<String> {
+    Err(Error::NotSupported)
+}
+
+/// Return the machine identifier, or an empty string if the host has none.
+///
+/// - Linux: `/etc/machine-id`, falling back to `/var/lib/dbus/machine-id`
+/// - macOS: `IOPlatformUUID` from the IOKit registry
+/// - FreeBSD: `kern.hostuuid`; OpenBSD: `hw.uuid`; NetBSD: `machdep.dmi.system-uuid`
+pub fn machine_id() -> Result<String> {
+    #[cfg(target_os = "linux")]
+    {
+        Ok(read_machine_id_from(&["/etc/machine-id", "/var/lib/dbus/machine-id"]))
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        crate::darwin::get_platform_uuid()
+    }
+
+    #[cfg(any(target_os = "freebsd", target_os = "openbsd", target_os = "netbsd"))]
+    {
+        crate::bsd::get_host_uuid()
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd"
+    )))]
+    {
+        Ok(String::new())
+    }
+}
+
+/// Return the first non-empty machine-id found in `paths`, trimmed.
+#[cfg(target_os = "linux")]
+fn read_machine_id_from(paths: &[impl AsRef<std::path::Path>]) -> String {
+    paths
+        .iter()
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .map(|content| content.trim().to_string())
+        .find(|id| !id.is_empty())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hostname_is_not_empty() {
+        assert!(!hostname().unwrap().is_empty());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_machine_id_fallback_and_absence() {
+        let dir = tempfile::tempdir().unwrap();
+        let etc = dir.path().join("etc-machine-id");
+        let dbus = dir.path().join("dbus-machine-id");
+
+        assert_eq!(read_machine_id_from(&[&etc, &dbus]), "");
+
+        std::fs::write(&dbus, "0123456789abcdef0123456789abcdef\n").unwrap();
+        assert_eq!(read_machine_id_from(&[&etc, &dbus]), "0123456789abcdef0123456789abcdef");
+
+        std::fs::write(&etc, "fedcba9876543210fedcba9876543210\n").unwrap();
+        assert_eq!(read_machine_id_from(&[&etc, &dbus]), "fedcba9876543210fedcba9876543210");
+    }
+}
@@ -6,14 +6,28 @@
 //! - BSD (FreeBSD, OpenBSD, NetBSD): via sysctl and kvm
 
 pub use probe_metrics::{
-    AddressFamily, CPUCollector, CPUPressure, ConnectionCollector, ContextSwitches, DiskCollector,
-    DiskIOStats, DiskUsage, Error, IOCollector, IOPressure, IOStats, LoadAverage, LoadCollector,
-    MemoryCollector, MemoryPressure, NetInterface, NetStats, NetworkCollector, Partition,
-    ProcessCollector, ProcessMetrics, ProcessState, Result, SocketState, SystemCPU,
-    SystemCollector, SystemMemory, TcpConnection, TcpStats, ThermalCollector, ThermalZone,
-    UdpConnection, UnixSocket,
+    AddressFamily, AllPressure, BackgroundThread, BlockDevice, BlockDeviceKind, CAPABILITY_NAMES,
+    CPU_THERMAL_SENSORS, CPUCollector, CPUPressure, CacheKind, Capabilities, CollectorDecorator,
+    ConnectionCollector, ConntrackStats, ContextSwitches, CoreUsage, CpuCache, CpuTemp, CpuTicks,
+    CpuUsage, CpuVuln, CpuVulnStatus, DEFAULT_MOUNT_PROBE_TIMEOUT, DEFAULT_PROCESS_POLL_INTERVAL,
+    DeviceMapping, DiskCollector, DiskIOStats, DiskQueueConfig, DiskUsage, Error, IOCollector,
+    IOPressure, IOStats, IcmpStats, InFlight, InotifyStats, KernelLogEntry, KernelModule,
+    KernelStats, LinkEvent, ListenQueueStats, ListeningPort, LoadAverage, LoadCollector,
+    LogSeverity, MemoryBreakdown, MemoryCollector, MemoryPressure, MemoryZone, ModuleState,
+    MountOptions, NamespaceIds, NetInterface, NetInterfaceFilter, NetStats, NetworkCollector,
+    OomInfo, Partition, PressureLevel, ProcessCapabilities, ProcessCollector, ProcessFilter,
+    ProcessIdentity, ProcessIdentityKey, ProcessMetrics, ProcessState, ProcessWatcher, RawSource,
+    Result, RetryCollector, RunQueueStat, SATURATION_SAMPLE_WINDOW, SaturationScore,
+    SaturationWeights, SchedPolicy, SchedStats, SelfTestReport, SocketMemStats, SocketState,
+    SubsystemStatus, SwapDevice, SwapKind, SystemCPU, SystemCollector, SystemLimits, SystemMemory,
+    TcpConnection, TcpStats, ThermalCollector, ThermalZone, ThpDefrag, ThpMode, ThpStatus,
+    ThreadCpuSampler, ThreadInfo, TimeInfo, TransportProtocol, UdpConnection, UdpProtoStats,
+    UnitStatus, UnixSocket, WirelessCollector, WirelessInfo, ZfsArcStats, capability_names,
+    run_with_timeout, shutdown_background, spawn_background,
 };
 
+pub mod host;
+
 // Platform-specific modules
 #[cfg(target_os = "linux")]
 pub mod linux;
@@ -34,6 +48,23 @@ pub use darwin::DarwinCollector as PlatformCollector;
 #[cfg(any(target_os = "freebsd", target_os = "openbsd", target_os = "netbsd"))]
 pub use bsd::BsdCollector as PlatformCollector;
 
+// Generic sysctl readers
+#[cfg(any(
+    target_os = "macos",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd"
+))]
+mod sysctl;
+
+#[cfg(any(
+    target_os = "macos",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd"
+))]
+pub use sysctl::{read_sysctl_string, read_sysctl_u64};
+
 // Fallback for unsupported platforms
 #[cfg(not(any(
     target_os = "linux",
@@ -0,0 +1,78 @@
+//! Local timezone and clock synchronization state.
+
+use crate::{Error, Result, TimeInfo};
+use std::path::Path;
+
+/// Read the local timezone, UTC offset and NTP synchronization state.
+pub fn read_time_info() -> Result<TimeInfo> {
+    let (utc_offset_secs, abbreviation) = local_offset()?;
+    let timezone = configured_timezone().unwrap_or(abbreviation);
+    Ok(TimeInfo { timezone, utc_offset_secs, ntp_synchronized: ntp_synchronized() })
+}
+
+/// Timezone name from `TZ`, `/etc/timezone` or the `/etc/localtime` symlink.
+fn configured_timezone() -> Option<String> {
+    let from_env = std::env::var("TZ").ok().map(|tz| tz.trim_start_matches(':').to_string());
+    if let Some(tz) = from_env.filter(|tz| !tz.is_empty() && !tz.starts_with('/')) {
+        return Some(tz);
+    }
+
+    let from_file = std::fs::read_to_string("/etc/timezone").ok();
+    if let Some(tz) = from_file.map(|tz| tz.trim().to_string()).filter(|tz| !tz.is_empty()) {
+        return Some(tz);
+    }
+
+    zone_from_localtime_link(&std::fs::read_link("/etc/localtime").ok()?)
+}
+
+/// Zone name from a link such as `/usr/share/zoneinfo/Europe/Paris`.
+fn zone_from_localtime_link(target: &Path) -> Option<String> {
+    let target = target.to_str()?;
+    let (_, zone) = target.split_once("zoneinfo/")?;
+    (!zone.is_empty()).then(|| zone.to_string())
+}
+
+/// Current UTC offset and zone abbreviation (e.g. `CET`).
+#[allow(clippy::unnecessary_cast)] // tm_gmtoff is i32 on some targets
+fn local_offset() -> Result<(i32, String)> {
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    let now = unsafe { libc::time(std::ptr::null_mut()) };
+    if unsafe { libc::localtime_r(&now, &mut tm) }.is_null() {
+        return Err(Error::Io(std::io::Error::last_os_error()));
+    }
+
+    let abbreviation = if tm.tm_zone.is_null() {
+        String::new()
+    } else {
+        unsafe { std::ffi::CStr::from_ptr(tm.tm_zone) }.to_string_lossy().into_owned()
+    };
+    Ok((tm.tm_gmtoff as i32, abbreviation))
+}
+
+/// Whether the kernel clock is NTP-synchronized (`STA_UNSYNC` cleared).
+fn ntp_synchronized() -> Option<bool> {
+    // modes = 0 only reads the kernel clock state and needs no privileges
+    let mut tx: libc::timex = unsafe { std::mem::zeroed() };
+    if unsafe { libc::adjtimex(&mut tx) } < 0 {
+        return None;
+    }
+    Some(tx.status & libc::STA_UNSYNC == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zone_from_localtime_link() {
+        assert_eq!(
+            zone_from_localtime_link(Path::new("/usr/share/zoneinfo/Europe/Paris")).as_deref(),
+            Some("Europe/Paris")
+        );
+        assert_eq!(
+            zone_from_localtime_link(Path::new("../usr/share/zoneinfo/UTC")).as_deref(),
+            Some("UTC")
+        );
+        assert_eq!(zone_from_localtime_link(Path::new("/etc/localtime.bak")), None);
+    }
+}
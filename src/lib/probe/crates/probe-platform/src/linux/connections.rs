@@ -1,13 +1,21 @@
 //! Network connection parsing for Linux.
 //!
 //! Parses /proc/net/tcp, /proc/net/tcp6, /proc/net/udp, /proc/net/udp6
-//! and resolves process ownership via /proc/[pid]/fd.
+//! and resolves process ownership via /proc/[pid]/fd. Unix socket peers
+//! and the maximum backlog of TCP listeners are resolved via
+//! NETLINK_SOCK_DIAG.
 
+use super::ProcfsRoot;
+use super::procfs::read_file;
 use crate::{
-    AddressFamily, Error, Result, SocketState, TcpConnection, TcpStats, UdpConnection, UnixSocket,
+    AddressFamily, Error, ListeningPort, Result, SocketState, TcpConnection, TcpStats,
+    UdpConnection, UnixSocket,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::io::{BufRead, BufReader};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::os::unix::fs::MetadataExt;
 use std::path::Path;
 
 /// Parse an IPv4 address from hex format (little-endian).
@@ -61,9 +69,27 @@ fn parse_addr_port(addr_port: &str, ipv6: bool) -> (String, u16) {
 
 /// Build a map of socket inode -> (pid, process_name) for all processes.
 pub fn build_socket_pid_map() -> HashMap<u64, (i32, String)> {
+    build_socket_pid_map_at(&ProcfsRoot::default())
+}
+
+/// Build the socket inode -> (pid, process_name) map under the given procfs root.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+pub fn build_socket_pid_map_at(root: &ProcfsRoot) -> HashMap<u64, (i32, String)> {
+    scan_socket_owners(root, None)
+}
+
+/// Scan /proc/[pid]/fd for socket links, keeping only the `wanted` inodes
+/// when given. The scan ends as soon as every wanted inode is found.
+fn scan_socket_owners(
+    root: &ProcfsRoot,
+    wanted: Option<&HashSet<u64>>,
+) -> HashMap<u64, (i32, String)> {
     let mut map = HashMap::new();
+    if wanted.is_some_and(HashSet::is_empty) {
+        return map;
+    }
 
-    let proc_path = Path::new("/proc");
+    let proc_path = root.proc();
     let entries = match fs::read_dir(proc_path) {
         Ok(e) => e,
         Err(_) => return map,
@@ -79,9 +105,8 @@ pub fn build_socket_pid_map() -> HashMap<u64, (i32, String)> {
             Err(_) => continue,
         };
 
-        // Read process name
-        let comm_path = proc_path.join(&name).join("comm");
-        let process_name = fs::read_to_string(&comm_path).unwrap_or_default().trim().to_string();
+        // Process name, read on the first matching socket
+        let mut process_name: Option<String> = None;
 
         // Scan fd directory for socket links
         let fd_path = proc_path.join(&name).join("fd");
@@ -101,10 +126,19 @@ pub fn build_socket_pid_map() -> HashMap<u64, (i32, String)> {
             if let Some(inode_str) =
                 link_str.strip_prefix("socket:[").and_then(|s| s.strip_suffix(']'))
                 && let Ok(inode) = inode_str.parse::<u64>()
+                && wanted.is_none_or(|wanted| wanted.contains(&inode))
             {
+                let process_name = process_name.get_or_insert_with(|| {
+                    let comm_path = proc_path.join(&name).join("comm");
+                    read_file(&comm_path).unwrap_or_default().trim().to_string()
+                });
                 map.insert(inode, (pid, process_name.clone()));
             }
         }
+
+        if wanted.is_some_and(|wanted| map.len() == wanted.len()) {
+            break;
+        }
     }
 
     map
@@ -112,63 +146,100 @@ pub fn build_socket_pid_map() -> HashMap<u64, (i32, String)> {
 
 /// Parse /proc/net/tcp or /proc/net/tcp6 file.
 fn parse_tcp_file(
-    path: &str,
+    path: &Path,
     ipv6: bool,
     socket_map: &HashMap<u64, (i32, String)>,
 ) -> Result<Vec<TcpConnection>> {
-    let content = fs::read_to_string(path)?;
+    let content = read_file(path)?;
     let mut connections = Vec::new();
 
+    // Skip header
     for line in content.lines().skip(1) {
-        // Skip header
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() < 10 {
-            continue;
+        if let Some(mut conn) = parse_tcp_line(line, ipv6) {
+            if let Some((pid, process_name)) = socket_map.get(&conn.inode) {
+                conn.pid = *pid;
+                conn.process_name = process_name.clone();
+            }
+            connections.push(conn);
         }
+    }
 
-        // Format: sl local_address rem_address st tx_queue:rx_queue tr:tm->when retrnsmt uid timeout inode
-        let (local_addr, local_port) = parse_addr_port(parts[1], ipv6);
-        let (remote_addr, remote_port) = parse_addr_port(parts[2], ipv6);
-
-        let state_hex = u8::from_str_radix(parts[3], 16).unwrap_or(0);
-        let state = SocketState::from_linux_state(state_hex);
+    Ok(connections)
+}
 
-        // Parse tx_queue:rx_queue
-        let queue_parts: Vec<&str> = parts[4].split(':').collect();
-        let tx_queue =
-            queue_parts.first().and_then(|s| u32::from_str_radix(s, 16).ok()).unwrap_or(0);
-        let rx_queue =
-            queue_parts.get(1).and_then(|s| u32::from_str_radix(s, 16).ok()).unwrap_or(0);
+/// Read at most `limit` entries of a /proc/net/tcp or /proc/net/tcp6 file,
+/// without process ownership.
+///
+/// The table is streamed; reading stops at the first entry past the limit,
+/// and the flag reports whether there was one.
+fn parse_tcp_file_limited(
+    path: &Path,
+    ipv6: bool,
+    limit: usize,
+) -> Result<(Vec<TcpConnection>, bool)> {
+    let reader = BufReader::new(fs::File::open(path)?);
+    let mut connections = Vec::new();
 
-        let inode = parts.get(9).and_then(|s| s.parse::<u64>().ok()).unwrap_or(0);
+    // Skip header
+    for line in reader.lines().skip(1) {
+        let line = line?;
+        if connections.len() == limit {
+            if line.trim().is_empty() {
+                continue;
+            }
+            return Ok((connections, true));
+        }
+        connections.extend(parse_tcp_line(&line, ipv6));
+    }
 
-        let (pid, process_name) = socket_map.get(&inode).cloned().unwrap_or((-1, String::new()));
+    Ok((connections, false))
+}
 
-        connections.push(TcpConnection {
-            family: if ipv6 { AddressFamily::IPv6 } else { AddressFamily::IPv4 },
-            local_addr,
-            local_port,
-            remote_addr,
-            remote_port,
-            state,
-            pid,
-            process_name,
-            inode,
-            rx_queue,
-            tx_queue,
-        });
+/// Parse one entry of a /proc/net/tcp or /proc/net/tcp6 table.
+///
+/// The owning process is left unknown.
+fn parse_tcp_line(line: &str, ipv6: bool) -> Option<TcpConnection> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() < 10 {
+        return None;
     }
 
-    Ok(connections)
+    // Format: sl local_address rem_address st tx_queue:rx_queue tr:tm->when retrnsmt uid timeout inode
+    let (local_addr, local_port) = parse_addr_port(parts[1], ipv6);
+    let (remote_addr, remote_port) = parse_addr_port(parts[2], ipv6);
+
+    let state_hex = u8::from_str_radix(parts[3], 16).unwrap_or(0);
+    let state = SocketState::from_linux_state(state_hex);
+
+    // Parse tx_queue:rx_queue
+    let queue_parts: Vec<&str> = parts[4].split(':').collect();
+    let tx_queue = queue_parts.first().and_then(|s| u32::from_str_radix(s, 16).ok()).unwrap_or(0);
+    let rx_queue = queue_parts.get(1).and_then(|s| u32::from_str_radix(s, 16).ok()).unwrap_or(0);
+
+    let inode = parts.get(9).and_then(|s| s.parse::<u64>().ok()).unwrap_or(0);
+
+    Some(TcpConnection {
+        family: if ipv6 { AddressFamily::IPv6 } else { AddressFamily::IPv4 },
+        local_addr,
+        local_port,
+        remote_addr,
+        remote_port,
+        state,
+        pid: -1,
+        process_name: String::new(),
+        inode,
+        rx_queue,
+        tx_queue,
+    })
 }
 
 /// Parse /proc/net/udp or /proc/net/udp6 file.
 fn parse_udp_file(
-    path: &str,
+    path: &Path,
     ipv6: bool,
     socket_map: &HashMap<u64, (i32, String)>,
 ) -> Result<Vec<UdpConnection>> {
-    let content = fs::read_to_string(path)?;
+    let content = read_file(path)?;
     let mut connections = Vec::new();
 
     for line in content.lines().skip(1) {
@@ -211,11 +282,278 @@ fn parse_udp_file(
     Ok(connections)
 }
 
+// sock_diag constants and structures (linux/sock_diag.h, linux/unix_diag.h,
+// linux/inet_diag.h).
+const SOCK_DIAG_BY_FAMILY: u16 = 20;
+const UDIAG_SHOW_PEER: u32 = 0x0000_0004;
+const UDIAG_SHOW_UID: u32 = 0x0000_0040;
+const UNIX_DIAG_PEER: u16 = 2;
+const UNIX_DIAG_UID: u16 = 7;
+/// `TCP_LISTEN` in the kernel's TCP state numbering.
+const TCP_LISTEN: u32 = 10;
+
+/// Size of `struct unix_diag_msg`.
+const UNIX_DIAG_MSG_LEN: usize = 16;
+/// Offsets of `idiag_wqueue` and `idiag_inode` in `struct inet_diag_msg`.
+const INET_DIAG_WQUEUE_OFFSET: usize = 60;
+const INET_DIAG_INODE_OFFSET: usize = 68;
+/// Size of `struct nlmsghdr`.
+const NLMSG_HDR_LEN: usize = 16;
+
+#[repr(C)]
+struct UnixDiagReq {
+    sdiag_family: u8,
+    sdiag_protocol: u8,
+    pad: u16,
+    udiag_states: u32,
+    udiag_ino: u32,
+    udiag_show: u32,
+    udiag_cookie: [u32; 2],
+}
+
+#[repr(C)]
+struct UnixDiagRequest {
+    header: libc::nlmsghdr,
+    req: UnixDiagReq,
+}
+
+/// `struct inet_diag_req_v2` with an empty socket ID (dump everything).
+#[repr(C)]
+struct InetDiagReq {
+    sdiag_family: u8,
+    sdiag_protocol: u8,
+    idiag_ext: u8,
+    pad: u8,
+    idiag_states: u32,
+    /// `struct inet_diag_sockid`: ports, addresses, interface and cookie.
+    id: [u32; 12],
+}
+
+#[repr(C)]
+struct InetDiagRequest {
+    header: libc::nlmsghdr,
+    req: InetDiagReq,
+}
+
+/// Peer information for a Unix socket as reported by sock_diag.
+#[derive(Debug, Clone, Copy, Default)]
+struct UnixDiagEntry {
+    /// Inode of the connected peer socket (0 if unconnected).
+    peer_inode: u64,
+    /// UID owning the socket (kernel 5.3+).
+    uid: Option<u32>,
+}
+
+const fn nlmsg_align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+fn read_u16(buf: &[u8], offset: usize) -> Option<u16> {
+    buf.get(offset..offset + 2).map(|b| u16::from_ne_bytes([b[0], b[1]]))
+}
+
+fn read_u32(buf: &[u8], offset: usize) -> Option<u32> {
+    buf.get(offset..offset + 4).map(|b| u32::from_ne_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// Parse a `unix_diag_msg` payload followed by its attributes.
+fn parse_unix_diag_msg(payload: &[u8]) -> Option<(u64, UnixDiagEntry)> {
+    let inode = read_u32(payload, 4)? as u64;
+    let mut entry = UnixDiagEntry::default();
+
+    let mut offset = UNIX_DIAG_MSG_LEN;
+    while let (Some(rta_len), Some(rta_type)) =
+        (read_u16(payload, offset), read_u16(payload, offset + 2))
+    {
+        let rta_len = rta_len as usize;
+        if rta_len < 4 || offset + rta_len > payload.len() {
+            break;
+        }
+        match rta_type {
+            UNIX_DIAG_PEER => entry.peer_inode = read_u32(payload, offset + 4).unwrap_or(0) as u64,
+            UNIX_DIAG_UID => entry.uid = read_u32(payload, offset + 4),
+            _ => {}
+        }
+        offset += nlmsg_align(rta_len);
+    }
+
+    Some((inode, entry))
+}
+
+/// Parse an `inet_diag_msg` payload into the socket inode and `idiag_wqueue`,
+/// which holds the maximum backlog for listening sockets.
+fn parse_inet_diag_msg(payload: &[u8]) -> Option<(u64, u32)> {
+    let backlog = read_u32(payload, INET_DIAG_WQUEUE_OFFSET)?;
+    let inode = read_u32(payload, INET_DIAG_INODE_OFFSET)? as u64;
+    Some((inode, backlog))
+}
+
+/// Pass the payload of each message in a buffer of netlink messages to
+/// `on_msg`.
+///
+/// Returns `Ok(true)` once the dump is complete.
+fn parse_diag_buffer(buf: &[u8], on_msg: &mut impl FnMut(&[u8])) -> Result<bool> {
+    let mut offset = 0;
+    while offset + NLMSG_HDR_LEN <= buf.len() {
+        let msg_len = read_u32(buf, offset).unwrap_or(0) as usize;
+        let msg_type = read_u16(buf, offset + 4).unwrap_or(0);
+        if msg_len < NLMSG_HDR_LEN || offset + msg_len > buf.len() {
+            break;
+        }
+
+        match msg_type as libc::c_int {
+            libc::NLMSG_DONE => return Ok(true),
+            libc::NLMSG_ERROR => {
+                return Err(Error::Platform("sock_diag request failed".into()));
+            }
+            _ => on_msg(&buf[offset + NLMSG_HDR_LEN..offset + msg_len]),
+        }
+        offset += nlmsg_align(msg_len);
+    }
+    Ok(false)
+}
+
+/// Send a NETLINK_SOCK_DIAG dump `request` and pass the payload of each
+/// reply message to `on_msg`.
+fn sock_diag_dump<R>(request: &R, mut on_msg: impl FnMut(&[u8])) -> Result<()> {
+    let fd = unsafe {
+        libc::socket(
+            libc::AF_NETLINK,
+            libc::SOCK_DGRAM | libc::SOCK_CLOEXEC,
+            libc::NETLINK_SOCK_DIAG,
+        )
+    };
+    if fd < 0 {
+        return Err(Error::Io(std::io::Error::last_os_error()));
+    }
+    // Take ownership so the socket is closed on every return path
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    let sent = unsafe {
+        libc::send(fd.as_raw_fd(), (request as *const R).cast(), std::mem::size_of::<R>(), 0)
+    };
+    if sent < 0 {
+        return Err(Error::Io(std::io::Error::last_os_error()));
+    }
+
+    let mut buf = vec![0u8; 32 * 1024];
+    loop {
+        let received = unsafe { libc::recv(fd.as_raw_fd(), buf.as_mut_ptr().cast(), buf.len(), 0) };
+        if received < 0 {
+            return Err(Error::Io(std::io::Error::last_os_error()));
+        }
+        if received == 0 || parse_diag_buffer(&buf[..received as usize], &mut on_msg)? {
+            return Ok(());
+        }
+    }
+}
+
+/// Dump all Unix sockets via NETLINK_SOCK_DIAG with peer and owner info.
+fn read_unix_diag() -> Result<HashMap<u64, UnixDiagEntry>> {
+    let request = UnixDiagRequest {
+        header: libc::nlmsghdr {
+            nlmsg_len: std::mem::size_of::<UnixDiagRequest>() as u32,
+            nlmsg_type: SOCK_DIAG_BY_FAMILY,
+            nlmsg_flags: (libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16,
+            nlmsg_seq: 1,
+            nlmsg_pid: 0,
+        },
+        req: UnixDiagReq {
+            sdiag_family: libc::AF_UNIX as u8,
+            sdiag_protocol: 0,
+            pad: 0,
+            udiag_states: u32::MAX,
+            udiag_ino: 0,
+            udiag_show: UDIAG_SHOW_PEER | UDIAG_SHOW_UID,
+            udiag_cookie: [u32::MAX; 2],
+        },
+    };
+
+    let mut entries = HashMap::new();
+    sock_diag_dump(&request, |payload| {
+        if let Some((inode, entry)) = parse_unix_diag_msg(payload) {
+            entries.insert(inode, entry);
+        }
+    })?;
+    Ok(entries)
+}
+
+/// Dump the maximum backlog of every listening TCP socket, keyed by inode,
+/// via NETLINK_SOCK_DIAG.
+///
+/// procfs reports 0 as the transmit queue of listeners; `inet_diag`
+/// reports their `sk_max_ack_backlog` there. A failed IPv6 dump (IPv6
+/// disabled) leaves IPv4 listeners only.
+fn read_tcp_listen_backlogs() -> Result<HashMap<u64, u32>> {
+    let request = |family: libc::c_int| InetDiagRequest {
+        header: libc::nlmsghdr {
+            nlmsg_len: std::mem::size_of::<InetDiagRequest>() as u32,
+            nlmsg_type: SOCK_DIAG_BY_FAMILY,
+            nlmsg_flags: (libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16,
+            nlmsg_seq: 1,
+            nlmsg_pid: 0,
+        },
+        req: InetDiagReq {
+            sdiag_family: family as u8,
+            sdiag_protocol: libc::IPPROTO_TCP as u8,
+            idiag_ext: 0,
+            pad: 0,
+            idiag_states: 1 << TCP_LISTEN,
+            id: [0; 12],
+        },
+    };
+
+    let mut backlogs = HashMap::new();
+    let mut collect = |payload: &[u8]| {
+        if let Some((inode, backlog)) = parse_inet_diag_msg(payload) {
+            backlogs.insert(inode, backlog);
+        }
+    };
+    sock_diag_dump(&request(libc::AF_INET), &mut collect)?;
+    let _ = sock_diag_dump(&request(libc::AF_INET6), &mut collect);
+    Ok(backlogs)
+}
+
+/// Report the maximum backlog of listeners as their `tx_queue`.
+///
+/// Best-effort: without sock_diag the procfs value (0) is kept. sock_diag
+/// only sees the caller's network namespace, so it is skipped for any other
+/// procfs root.
+fn fill_listen_backlogs(root: &ProcfsRoot, connections: &mut [TcpConnection]) {
+    if *root != ProcfsRoot::default() {
+        return;
+    }
+    let mut listeners =
+        connections.iter_mut().filter(|conn| conn.state == SocketState::Listen).peekable();
+    if listeners.peek().is_none() {
+        return;
+    }
+    let Ok(backlogs) = read_tcp_listen_backlogs() else {
+        return;
+    };
+    for conn in listeners {
+        if let Some(&backlog) = backlogs.get(&conn.inode) {
+            conn.tx_queue = backlog;
+        }
+    }
+}
+
+/// Resolve the UID owning a process from its /proc directory.
+fn process_uid(root: &ProcfsRoot, pid: i32) -> Option<u32> {
+    fs::metadata(root.proc_path(pid.to_string())).ok().map(|m| m.uid())
+}
+
 /// Parse /proc/net/unix file.
-fn parse_unix_file(socket_map: &HashMap<u64, (i32, String)>) -> Result<Vec<UnixSocket>> {
-    let content = fs::read_to_string("/proc/net/unix")?;
+fn parse_unix_file(
+    root: &ProcfsRoot,
+    socket_map: &HashMap<u64, (i32, String)>,
+) -> Result<Vec<UnixSocket>> {
+    let content = read_file(root.proc_path("net/unix"))?;
     let mut sockets = Vec::new();
 
+    // Peer resolution is best-effort; without sock_diag peers stay unknown
+    let diag = read_unix_diag().unwrap_or_default();
+
     for line in content.lines().skip(1) {
         let parts: Vec<&str> = line.split_whitespace().collect();
         if parts.len() < 7 {
@@ -244,57 +582,168 @@ fn parse_unix_file(socket_map: &HashMap<u64, (i32, String)>) -> Result<Vec<UnixS
 
         let (pid, process_name) = socket_map.get(&inode).cloned().unwrap_or((-1, String::new()));
 
-        sockets.push(UnixSocket { path, socket_type, state, pid, process_name, inode });
+        let peer_inode = diag.get(&inode).map(|e| e.peer_inode).unwrap_or(0);
+        let peer_pid = socket_map.get(&peer_inode).map(|(p, _)| *p).unwrap_or(-1);
+        let peer_uid = diag
+            .get(&peer_inode)
+            .and_then(|e| e.uid)
+            .or_else(|| (peer_pid > 0).then(|| process_uid(root, peer_pid)).flatten())
+            .unwrap_or(0);
+
+        sockets.push(UnixSocket {
+            path,
+            socket_type,
+            state,
+            pid,
+            process_name,
+            inode,
+            peer_pid,
+            peer_uid,
+        });
     }
 
     Ok(sockets)
 }
 
-/// Collect all TCP connections (IPv4 and IPv6).
-pub fn collect_tcp_connections() -> Result<Vec<TcpConnection>> {
-    let socket_map = build_socket_pid_map();
-    let mut connections = Vec::new();
+/// Check if IPv6 is enabled on this host.
+///
+/// Uses the `disable_ipv6` sysctl when present, otherwise whether the
+/// kernel exposes IPv6 socket tables at all.
+pub fn ipv6_enabled() -> bool {
+    ipv6_enabled_at(&ProcfsRoot::default())
+}
+
+/// Check if IPv6 is enabled according to the given procfs root.
+pub fn ipv6_enabled_at(root: &ProcfsRoot) -> bool {
+    match read_file(root.proc_path("sys/net/ipv6/conf/all/disable_ipv6")) {
+        Ok(content) => content.trim() != "1",
+        Err(_) => root.proc_path("net/tcp6").exists(),
+    }
+}
 
-    // IPv4
-    if let Ok(mut tcp4) = parse_tcp_file("/proc/net/tcp", false, &socket_map) {
-        connections.append(&mut tcp4);
+/// Parse the IPv4 and IPv6 variants of a /proc/net socket table.
+///
+/// Errors reading the IPv4 table are returned. A missing or unreadable
+/// IPv6 table (IPv6 disabled) means no IPv6 sockets, not an error.
+fn parse_dual_stack<T>(
+    net_dir: &Path,
+    name: &str,
+    parse: impl Fn(&Path, bool) -> Result<Vec<T>>,
+) -> Result<Vec<T>> {
+    let mut entries = parse(&net_dir.join(name), false)?;
+    if let Ok(mut v6) = parse(&net_dir.join(format!("{}6", name)), true) {
+        entries.append(&mut v6);
     }
+    Ok(entries)
+}
+
+/// Collect TCP connections from a /proc/net-style directory.
+fn collect_tcp_from(
+    net_dir: &Path,
+    socket_map: &HashMap<u64, (i32, String)>,
+) -> Result<Vec<TcpConnection>> {
+    parse_dual_stack(net_dir, "tcp", |path, ipv6| parse_tcp_file(path, ipv6, socket_map))
+}
 
-    // IPv6
-    if let Ok(mut tcp6) = parse_tcp_file("/proc/net/tcp6", true, &socket_map) {
-        connections.append(&mut tcp6);
+/// Collect at most `limit` TCP connections from a /proc/net-style directory.
+///
+/// The IPv6 table is only read while the limit has not been reached, and
+/// errors reading it are ignored as in [`parse_dual_stack`]. Owning
+/// processes are left unknown.
+fn collect_tcp_limited_from(net_dir: &Path, limit: usize) -> Result<(Vec<TcpConnection>, bool)> {
+    let (mut connections, truncated) = parse_tcp_file_limited(&net_dir.join("tcp"), false, limit)?;
+    if truncated {
+        return Ok((connections, true));
+    }
+    let remaining = limit - connections.len();
+    match parse_tcp_file_limited(&net_dir.join("tcp6"), true, remaining) {
+        Ok((mut v6, truncated)) => {
+            connections.append(&mut v6);
+            Ok((connections, truncated))
+        }
+        Err(_) => Ok((connections, false)),
     }
+}
 
+/// Collect UDP sockets from a /proc/net-style directory.
+fn collect_udp_from(
+    net_dir: &Path,
+    socket_map: &HashMap<u64, (i32, String)>,
+) -> Result<Vec<UdpConnection>> {
+    parse_dual_stack(net_dir, "udp", |path, ipv6| parse_udp_file(path, ipv6, socket_map))
+}
+
+/// Collect all TCP connections (IPv4 and IPv6).
+pub fn collect_tcp_connections() -> Result<Vec<TcpConnection>> {
+    collect_tcp_connections_at(&ProcfsRoot::default())
+}
+
+/// Collect all TCP connections under the given procfs root.
+pub fn collect_tcp_connections_at(root: &ProcfsRoot) -> Result<Vec<TcpConnection>> {
+    let socket_map = build_socket_pid_map_at(root);
+    let mut connections = collect_tcp_from(&root.proc_path("net"), &socket_map)?;
+    fill_listen_backlogs(root, &mut connections);
     Ok(connections)
 }
 
-/// Collect all UDP sockets (IPv4 and IPv6).
-pub fn collect_udp_connections() -> Result<Vec<UdpConnection>> {
-    let socket_map = build_socket_pid_map();
-    let mut connections = Vec::new();
+/// Collect at most `limit` TCP connections (IPv4 first, then IPv6).
+///
+/// Returns the connections and whether more were left unread.
+pub fn collect_tcp_connections_limited(limit: usize) -> Result<(Vec<TcpConnection>, bool)> {
+    collect_tcp_connections_limited_at(&ProcfsRoot::default(), limit)
+}
 
-    // IPv4
-    if let Ok(mut udp4) = parse_udp_file("/proc/net/udp", false, &socket_map) {
-        connections.append(&mut udp4);
+/// Collect at most `limit` TCP connections under the given procfs root.
+///
+/// Process ownership is only resolved for the connections kept.
+pub fn collect_tcp_connections_limited_at(
+    root: &ProcfsRoot,
+    limit: usize,
+) -> Result<(Vec<TcpConnection>, bool)> {
+    let (mut connections, truncated) = collect_tcp_limited_from(&root.proc_path("net"), limit)?;
+    let inodes: HashSet<u64> =
+        connections.iter().map(|conn| conn.inode).filter(|&inode| inode != 0).collect();
+    let socket_map = scan_socket_owners(root, Some(&inodes));
+    for conn in &mut connections {
+        if let Some((pid, process_name)) = socket_map.get(&conn.inode) {
+            conn.pid = *pid;
+            conn.process_name = process_name.clone();
+        }
     }
+    fill_listen_backlogs(root, &mut connections);
+    Ok((connections, truncated))
+}
 
-    // IPv6
-    if let Ok(mut udp6) = parse_udp_file("/proc/net/udp6", true, &socket_map) {
-        connections.append(&mut udp6);
-    }
+/// Collect all UDP sockets (IPv4 and IPv6).
+pub fn collect_udp_connections() -> Result<Vec<UdpConnection>> {
+    collect_udp_connections_at(&ProcfsRoot::default())
+}
 
-    Ok(connections)
+/// Collect all UDP sockets under the given procfs root.
+pub fn collect_udp_connections_at(root: &ProcfsRoot) -> Result<Vec<UdpConnection>> {
+    let socket_map = build_socket_pid_map_at(root);
+    collect_udp_from(&root.proc_path("net"), &socket_map)
 }
 
 /// Collect all Unix domain sockets.
 pub fn collect_unix_sockets() -> Result<Vec<UnixSocket>> {
-    let socket_map = build_socket_pid_map();
-    parse_unix_file(&socket_map)
+    collect_unix_sockets_at(&ProcfsRoot::default())
+}
+
+/// Collect all Unix domain sockets under the given procfs root.
+pub fn collect_unix_sockets_at(root: &ProcfsRoot) -> Result<Vec<UnixSocket>> {
+    let socket_map = build_socket_pid_map_at(root);
+    parse_unix_file(root, &socket_map)
 }
 
 /// Calculate TCP connection statistics.
 pub fn collect_tcp_stats() -> Result<TcpStats> {
-    let connections = collect_tcp_connections()?;
+    collect_tcp_stats_at(&ProcfsRoot::default())
+}
+
+/// Calculate TCP connection statistics under the given procfs root.
+pub fn collect_tcp_stats_at(root: &ProcfsRoot) -> Result<TcpStats> {
+    let connections = collect_tcp_connections_at(root)?;
     let mut stats = TcpStats::default();
 
     for conn in connections {
@@ -319,16 +768,24 @@ pub fn collect_tcp_stats() -> Result<TcpStats> {
 
 /// Collect connections for a specific process.
 pub fn collect_process_connections(pid: i32) -> Result<(Vec<TcpConnection>, Vec<UdpConnection>)> {
+    collect_process_connections_at(&ProcfsRoot::default(), pid)
+}
+
+/// Collect connections for a specific process under the given procfs root.
+pub fn collect_process_connections_at(
+    root: &ProcfsRoot,
+    pid: i32,
+) -> Result<(Vec<TcpConnection>, Vec<UdpConnection>)> {
     // Build socket map for just this process
     let mut socket_map = HashMap::new();
 
-    let proc_path = Path::new("/proc").join(pid.to_string());
+    let proc_path = root.proc_path(pid.to_string());
     if !proc_path.exists() {
         return Err(Error::NotFound(format!("process {} not found", pid)));
     }
 
     let comm_path = proc_path.join("comm");
-    let process_name = fs::read_to_string(&comm_path).unwrap_or_default().trim().to_string();
+    let process_name = read_file(&comm_path).unwrap_or_default().trim().to_string();
 
     let fd_path = proc_path.join("fd");
     if let Ok(entries) = fs::read_dir(&fd_path) {
@@ -346,37 +803,47 @@ pub fn collect_process_connections(pid: i32) -> Result<(Vec<TcpConnection>, Vec<
     }
 
     // Parse TCP connections and filter by this process's sockets
-    let mut tcp_conns = Vec::new();
-    if let Ok(tcp4) = parse_tcp_file("/proc/net/tcp", false, &socket_map) {
-        tcp_conns.extend(tcp4.into_iter().filter(|c| c.pid == pid));
-    }
-    if let Ok(tcp6) = parse_tcp_file("/proc/net/tcp6", true, &socket_map) {
-        tcp_conns.extend(tcp6.into_iter().filter(|c| c.pid == pid));
-    }
+    let net_dir = root.proc_path("net");
+    let mut tcp_conns = collect_tcp_from(&net_dir, &socket_map).unwrap_or_default();
+    tcp_conns.retain(|c| c.pid == pid);
 
     // Parse UDP connections and filter
-    let mut udp_conns = Vec::new();
-    if let Ok(udp4) = parse_udp_file("/proc/net/udp", false, &socket_map) {
-        udp_conns.extend(udp4.into_iter().filter(|c| c.pid == pid));
-    }
-    if let Ok(udp6) = parse_udp_file("/proc/net/udp6", true, &socket_map) {
-        udp_conns.extend(udp6.into_iter().filter(|c| c.pid == pid));
-    }
+    let mut udp_conns = collect_udp_from(&net_dir, &socket_map).unwrap_or_default();
+    udp_conns.retain(|c| c.pid == pid);
 
     Ok((tcp_conns, udp_conns))
 }
 
+/// Summarize listening TCP ports and bound UDP sockets.
+pub fn collect_listening_ports() -> Result<Vec<ListeningPort>> {
+    collect_listening_ports_at(&ProcfsRoot::default())
+}
+
+/// Summarize listening TCP ports and bound UDP sockets under the given procfs root.
+pub fn collect_listening_ports_at(root: &ProcfsRoot) -> Result<Vec<ListeningPort>> {
+    let socket_map = build_socket_pid_map_at(root);
+    let net = root.proc_path("net");
+    let tcp = collect_tcp_from(&net, &socket_map)?;
+    let udp = collect_udp_from(&net, &socket_map)?;
+    Ok(ListeningPort::summarize(&tcp, &udp))
+}
+
 /// Find which process owns a specific port.
 pub fn find_process_by_port(port: u16, tcp: bool) -> Result<Option<i32>> {
+    find_process_by_port_at(&ProcfsRoot::default(), port, tcp)
+}
+
+/// Find which process owns a specific port under the given procfs root.
+pub fn find_process_by_port_at(root: &ProcfsRoot, port: u16, tcp: bool) -> Result<Option<i32>> {
     if tcp {
-        let connections = collect_tcp_connections()?;
+        let connections = collect_tcp_connections_at(root)?;
         for conn in connections {
             if conn.local_port == port && conn.pid > 0 {
                 return Ok(Some(conn.pid));
             }
         }
     } else {
-        let connections = collect_udp_connections()?;
+        let connections = collect_udp_connections_at(root)?;
         for conn in connections {
             if conn.local_port == port && conn.pid > 0 {
                 return Ok(Some(conn.pid));
@@ -418,4 +885,198 @@ mod tests {
         let result = collect_tcp_stats();
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_missing_ipv6_tables() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("tcp"),
+            "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n   \
+             0: 0100007F:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 4242 1\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("udp"),
+            "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops\n",
+        )
+        .unwrap();
+
+        let socket_map = HashMap::from([(4242, (1, "init".to_string()))]);
+
+        // No tcp6/udp6 files: IPv4 results are still returned
+        let tcp = collect_tcp_from(dir.path(), &socket_map).unwrap();
+        assert_eq!(tcp.len(), 1);
+        assert_eq!(tcp[0].local_addr, "127.0.0.1");
+        assert_eq!(tcp[0].local_port, 8080);
+        assert_eq!(tcp[0].pid, 1);
+        assert!(collect_udp_from(dir.path(), &socket_map).unwrap().is_empty());
+
+        // A missing IPv4 table is still an error
+        fs::remove_file(dir.path().join("tcp")).unwrap();
+        assert!(collect_tcp_from(dir.path(), &socket_map).is_err());
+    }
+
+    #[test]
+    fn test_collect_tcp_limited_truncates() {
+        let dir = tempfile::tempdir().unwrap();
+        let header = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n";
+        let table = |count: u32| {
+            let mut content = header.to_string();
+            for i in 0..count {
+                content.push_str(&format!(
+                    "{i:4}: 0100007F:{:04X} 0200007F:0050 01 00000000:00000000 00:00000000 00000000     0        0 {} 1\n",
+                    10000 + i,
+                    5000 + i
+                ));
+            }
+            content
+        };
+        fs::write(dir.path().join("tcp"), table(500)).unwrap();
+
+        let (tcp, truncated) = collect_tcp_limited_from(dir.path(), 100).unwrap();
+        assert_eq!(tcp.len(), 100);
+        assert!(truncated);
+        assert_eq!(tcp[99].local_port, 10099);
+
+        // Exactly as many entries as the limit is not a truncation
+        let (tcp, truncated) = collect_tcp_limited_from(dir.path(), 500).unwrap();
+        assert_eq!(tcp.len(), 500);
+        assert!(!truncated);
+
+        // The limit spans the IPv4 and IPv6 tables
+        fs::write(dir.path().join("tcp6"), header).unwrap();
+        fs::write(dir.path().join("tcp"), table(3)).unwrap();
+        let (tcp, truncated) = collect_tcp_limited_from(dir.path(), 3).unwrap();
+        assert_eq!(tcp.len(), 3);
+        assert!(!truncated);
+    }
+
+    #[test]
+    fn test_collect_tcp_limited_resolves_kept_sockets_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let proc = dir.path().join("proc");
+        fs::create_dir_all(proc.join("net")).unwrap();
+        let mut table = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n".to_string();
+        for i in 0..3 {
+            table.push_str(&format!(
+                "{i:4}: 0100007F:{:04X} 0200007F:0050 01 00000000:00000000 00:00000000 00000000     0        0 {} 1\n",
+                10000 + i,
+                5000 + i
+            ));
+        }
+        fs::write(proc.join("net/tcp"), table).unwrap();
+        for (pid, comm, inode) in [(10, "web", 5000), (11, "worker", 5001)] {
+            let fd_dir = proc.join(format!("{}/fd", pid));
+            fs::create_dir_all(&fd_dir).unwrap();
+            fs::write(proc.join(format!("{}/comm", pid)), format!("{}\n", comm)).unwrap();
+            std::os::unix::fs::symlink(format!("socket:[{}]", inode), fd_dir.join("3")).unwrap();
+        }
+        let root = ProcfsRoot::new(&proc, dir.path().join("sys"));
+
+        let (tcp, truncated) = collect_tcp_connections_limited_at(&root, 1).unwrap();
+        assert!(truncated);
+        assert_eq!(tcp.len(), 1);
+        assert_eq!((tcp[0].pid, tcp[0].process_name.as_str()), (10, "web"));
+
+        let owners = scan_socket_owners(&root, Some(&HashSet::from([5000])));
+        assert_eq!(owners.len(), 1);
+        assert!(scan_socket_owners(&root, Some(&HashSet::new())).is_empty());
+        assert_eq!(build_socket_pid_map_at(&root).len(), 2);
+    }
+
+    #[test]
+    fn test_unix_socket_peer_credentials() {
+        use std::os::unix::net::UnixStream;
+
+        let (a, b) = UnixStream::pair().unwrap();
+        let inode_of = |s: &UnixStream| {
+            let link = fs::read_link(format!("/proc/self/fd/{}", s.as_raw_fd())).unwrap();
+            let link = link.to_string_lossy().into_owned();
+            link.trim_start_matches("socket:[").trim_end_matches(']').parse::<u64>().unwrap()
+        };
+        let (inode_a, inode_b) = (inode_of(&a), inode_of(&b));
+
+        // sock_diag may be unavailable in restricted sandboxes
+        if read_unix_diag().is_err() {
+            return;
+        }
+
+        let sockets = collect_unix_sockets().unwrap();
+        let me = std::process::id() as i32;
+        let uid = unsafe { libc::getuid() };
+
+        let sock_a = sockets.iter().find(|s| s.inode == inode_a).expect("socket a listed");
+        assert_eq!(sock_a.pid, me);
+        assert_eq!(sock_a.peer_pid, me);
+        assert_eq!(sock_a.peer_uid, uid);
+
+        let sock_b = sockets.iter().find(|s| s.inode == inode_b).expect("socket b listed");
+        assert_eq!(sock_b.peer_pid, me);
+    }
+
+    #[test]
+    fn test_listener_reports_max_backlog() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // Listening again only updates the backlog
+        assert_eq!(unsafe { libc::listen(listener.as_raw_fd(), 7) }, 0);
+
+        // sock_diag may be unavailable in restricted sandboxes
+        if read_tcp_listen_backlogs().is_err() {
+            return;
+        }
+
+        let tcp = collect_tcp_connections().unwrap();
+        let entry = tcp
+            .iter()
+            .find(|c| c.state == SocketState::Listen && c.local_port == port)
+            .expect("listener listed");
+        assert_eq!(entry.tx_queue, 7);
+        assert_eq!(entry.rx_queue, 0);
+
+        // Another root may be another netns: the same inode is not looked up
+        let dir = tempfile::tempdir().unwrap();
+        let proc = dir.path().join("proc");
+        fs::create_dir_all(proc.join("net")).unwrap();
+        fs::write(
+            proc.join("net/tcp"),
+            format!(
+                "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n   0: 0100007F:{:04X} 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 {} 1\n",
+                port, entry.inode
+            ),
+        )
+        .unwrap();
+        let root = ProcfsRoot::new(&proc, dir.path().join("sys"));
+        let tcp = collect_tcp_connections_at(&root).unwrap();
+        assert_eq!(tcp.len(), 1);
+        assert_eq!(tcp[0].tx_queue, 0);
+    }
+
+    #[test]
+    fn test_parse_inet_diag_msg() {
+        let mut payload = vec![0u8; 72];
+        payload[INET_DIAG_WQUEUE_OFFSET..INET_DIAG_WQUEUE_OFFSET + 4]
+            .copy_from_slice(&4096u32.to_ne_bytes());
+        payload[INET_DIAG_INODE_OFFSET..INET_DIAG_INODE_OFFSET + 4]
+            .copy_from_slice(&31337u32.to_ne_bytes());
+
+        assert_eq!(parse_inet_diag_msg(&payload), Some((31337, 4096)));
+        assert_eq!(parse_inet_diag_msg(&payload[..64]), None);
+    }
+
+    #[test]
+    fn test_listening_ports_include_tcp_listener() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let ports = collect_listening_ports().unwrap();
+        let matches: Vec<_> = ports
+            .iter()
+            .filter(|p| p.protocol == crate::TransportProtocol::Tcp && p.port == port)
+            .collect();
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].address, "127.0.0.1");
+        assert_eq!(matches[0].pid, std::process::id() as i32);
+    }
 }
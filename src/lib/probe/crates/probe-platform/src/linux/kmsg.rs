@@ -0,0 +1,166 @@
+//! Kernel log access for Linux via /dev/kmsg
+//!
+//! Each read of /dev/kmsg returns one structured record:
+//! `prio,seq,timestamp_us,flags[,...];message`, followed by optional
+//! continuation lines (` KEY=value`) carrying device metadata.
+
+use crate::{Error, KernelLogEntry, LogSeverity, Result};
+use std::collections::VecDeque;
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Read};
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+use std::time::Duration;
+
+/// Maximum number of entries returned by [`read_kernel_errors`].
+pub const MAX_KERNEL_LOG_ENTRIES: usize = 256;
+
+/// Read buffer size; larger than the kernel's longest record.
+const RECORD_BUF_SIZE: usize = 8192;
+
+/// Parse one /dev/kmsg record, ignoring continuation lines.
+pub fn parse_kmsg_record(record: &str) -> Option<KernelLogEntry> {
+    let (header, message) = record.split_once(';')?;
+    let mut fields = header.split(',');
+    let prio: u32 = fields.next()?.parse().ok()?;
+    let _seq = fields.next()?;
+    let timestamp_us: u64 = fields.next()?.parse().ok()?;
+
+    Some(KernelLogEntry {
+        timestamp: Duration::from_micros(timestamp_us),
+        severity: LogSeverity::from_level((prio & 7) as u8),
+        facility: u8::try_from(prio >> 3).unwrap_or(u8::MAX),
+        message: message.lines().next().unwrap_or_default().to_string(),
+    })
+}
+
+/// Read recent error-or-worse records from /dev/kmsg.
+pub fn read_kernel_errors(since: Option<Duration>) -> Result<Vec<KernelLogEntry>> {
+    read_kernel_errors_at(Path::new("/dev/kmsg"), since)
+}
+
+/// Read recent error-or-worse records from a kmsg-formatted file.
+///
+/// Keeps at most [`MAX_KERNEL_LOG_ENTRIES`], dropping the oldest first.
+pub fn read_kernel_errors_at(path: &Path, since: Option<Duration>) -> Result<Vec<KernelLogEntry>> {
+    // Non-blocking so the read loop stops at the end of the ring buffer
+    let mut file =
+        OpenOptions::new().read(true).custom_flags(libc::O_NONBLOCK).open(path).map_err(
+            |e| match e.kind() {
+                ErrorKind::NotFound => Error::NotSupported,
+                ErrorKind::PermissionDenied => {
+                    Error::Permission(format!("cannot read {}", path.display()))
+                }
+                _ => Error::Io(e),
+            },
+        )?;
+    let cutoff = since.map(|s| monotonic_now().saturating_sub(s)).unwrap_or_default();
+
+    let mut entries = VecDeque::new();
+    let mut buf = vec![0u8; RECORD_BUF_SIZE];
+    loop {
+        let n = match file.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+            // Records were overwritten while reading; the next read resumes
+            Err(e) if e.raw_os_error() == Some(libc::EPIPE) => continue,
+            Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+                return Err(Error::Permission(format!("cannot read {}", path.display())));
+            }
+            Err(e) => return Err(Error::Io(e)),
+        };
+
+        // One record per read on /dev/kmsg; regular files may return several
+        let chunk = String::from_utf8_lossy(&buf[..n]);
+        for line in chunk.lines().filter(|l| !l.starts_with(' ')) {
+            let Some(entry) = parse_kmsg_record(line) else { continue };
+            if entry.severity > LogSeverity::Error || entry.timestamp < cutoff {
+                continue;
+            }
+            if entries.len() == MAX_KERNEL_LOG_ENTRIES {
+                entries.pop_front();
+            }
+            entries.push_back(entry);
+        }
+    }
+
+    Ok(entries.into())
+}
+
+/// Time since boot on the clock used for kmsg timestamps.
+fn monotonic_now() -> Duration {
+    let mut ts = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    // SAFETY: ts is a valid, writable timespec
+    unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut ts) };
+    Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const SAMPLE: &str = "\
+6,1203,5123456,-;eth0: link up
+3,1204,5200000,-;blk_update_request: I/O error, dev sda, sector 2048 op 0x0:(READ)
+ SUBSYSTEM=block
+ DEVICE=b8:0
+4,1205,5300000,-;nvme nvme0: I/O 12 QID 3 timeout, aborting
+3,1206,6000000,c;Out of memory: Killed process 4242 (java) total-vm:8123456kB
+2,1207,6100000,-;EXT4-fs error (device sda1): ext4_find_entry: reading directory
+12,1208,6200000,-;systemd[1]: user message at warning
+";
+
+    #[test]
+    fn test_parse_kmsg_record() {
+        let entry =
+            parse_kmsg_record("3,1206,6000000,c;Out of memory: Killed process 4242").unwrap();
+        assert_eq!(entry.timestamp, Duration::from_secs(6));
+        assert_eq!(entry.severity, LogSeverity::Error);
+        assert_eq!(entry.facility, 0);
+        assert!(entry.is_oom_kill());
+
+        let entry = parse_kmsg_record("30,7,100,-;systemd[1]: started\n SUBSYSTEM=x").unwrap();
+        assert_eq!(entry.severity, LogSeverity::Info);
+        assert_eq!(entry.facility, 3);
+        assert_eq!(entry.message, "systemd[1]: started");
+
+        assert!(parse_kmsg_record("not a record").is_none());
+        assert!(parse_kmsg_record("x,1,2,-;msg").is_none());
+    }
+
+    #[test]
+    fn test_read_kernel_errors_filters_severity() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("kmsg");
+        fs::write(&path, SAMPLE).unwrap();
+
+        let entries = read_kernel_errors_at(&path, None).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert!(entries[0].is_io_error());
+        assert!(entries[1].is_oom_kill());
+        assert_eq!(entries[2].severity, LogSeverity::Critical);
+
+        // Everything in the sample predates "now"
+        assert!(read_kernel_errors_at(&path, Some(Duration::ZERO)).unwrap().is_empty());
+        assert!(matches!(
+            read_kernel_errors_at(&dir.path().join("missing"), None),
+            Err(Error::NotSupported)
+        ));
+    }
+
+    #[test]
+    fn test_read_kernel_errors_is_bounded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("kmsg");
+        let content: String = (0..MAX_KERNEL_LOG_ENTRIES + 10)
+            .map(|i| format!("3,{},{},-;error {}\n", i, i, i))
+            .collect();
+        fs::write(&path, content).unwrap();
+
+        let entries = read_kernel_errors_at(&path, None).unwrap();
+        assert_eq!(entries.len(), MAX_KERNEL_LOG_ENTRIES);
+        assert_eq!(entries[0].message, "error 10");
+    }
+}
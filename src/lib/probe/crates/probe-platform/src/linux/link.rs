@@ -0,0 +1,398 @@
+//! Network interface up/down notifications for Linux
+//!
+//! [`LinkWatcher`] listens on a netlink `RTMGRP_LINK` socket from a background
+//! thread. Where netlink is unavailable (restricted sandboxes, seccomp) it
+//! falls back to polling the `carrier` files under /sys/class/net.
+//!
+//! "Up" means operationally up (`IFF_RUNNING`: administratively up with
+//! carrier), so unplugging a cable reports a down event.
+
+use super::ProcfsRoot;
+use super::procfs::read_file;
+use crate::{BackgroundThread, LinkEvent, Result, spawn_background};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, TryRecvError};
+use std::time::Duration;
+
+/// Polling interval used when netlink is unavailable.
+pub const DEFAULT_LINK_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long a netlink `recv` blocks before checking for shutdown.
+const NETLINK_RECV_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Watches network interfaces and reports up/down transitions to a callback.
+///
+/// The callback runs on the watcher thread. Dropping the watcher, or
+/// [`shutdown_background`](crate::shutdown_background), stops the thread and
+/// waits for it, so the callback is never invoked afterwards.
+pub struct LinkWatcher {
+    _thread: BackgroundThread,
+    event_driven: bool,
+}
+
+impl LinkWatcher {
+    /// Start watching via netlink, falling back to polling /sys every
+    /// [`DEFAULT_LINK_POLL_INTERVAL`].
+    pub fn start<F>(callback: F) -> Result<Self>
+    where
+        F: FnMut(LinkEvent) + Send + 'static,
+    {
+        let root = ProcfsRoot::default();
+        match netlink::open() {
+            Ok(fd) => {
+                let states = LinkStates::snapshot(&root);
+                Self::spawn(true, move |stop| netlink_loop(fd, root, states, stop, callback))
+            }
+            Err(_) => Self::start_polling(root, DEFAULT_LINK_POLL_INTERVAL, callback),
+        }
+    }
+
+    /// Start watching by polling `class/net/*/carrier` under the given sysfs root.
+    pub fn start_polling<F>(root: ProcfsRoot, interval: Duration, callback: F) -> Result<Self>
+    where
+        F: FnMut(LinkEvent) + Send + 'static,
+    {
+        let states = LinkStates::snapshot(&root);
+        Self::spawn(false, move |stop| poll_loop(&root, interval, states, &stop, callback))
+    }
+
+    /// Whether events come from netlink rather than polling.
+    pub fn is_event_driven(&self) -> bool {
+        self.event_driven
+    }
+
+    fn spawn(event_driven: bool, run: impl FnOnce(Receiver<()>) + Send + 'static) -> Result<Self> {
+        let thread = spawn_background("probe-link-watch", run)?;
+        Ok(Self { _thread: thread, event_driven })
+    }
+}
+
+/// Last known up/down state per interface.
+#[derive(Debug, Default)]
+struct LinkStates(BTreeMap<String, bool>);
+
+impl LinkStates {
+    /// Current states from sysfs; empty if unreadable.
+    fn snapshot(root: &ProcfsRoot) -> Self {
+        Self(read_link_states(root).unwrap_or_default())
+    }
+
+    /// Record the state of `interface` (`None` once removed), returning an
+    /// event if it differs from the last known one.
+    ///
+    /// Interfaces not seen before count as down, so a new interface that
+    /// comes up reports an event and one that appears down does not.
+    fn apply(&mut self, interface: &str, up: Option<bool>) -> Option<LinkEvent> {
+        let was_up = match up {
+            Some(up) => self.0.insert(interface.to_string(), up),
+            None => self.0.remove(interface),
+        }
+        .unwrap_or(false);
+        let is_up = up.unwrap_or(false);
+
+        (was_up != is_up).then(|| LinkEvent { interface: interface.to_string(), up: is_up })
+    }
+
+    /// Replace all states with `current`, returning the transitions.
+    fn sync(&mut self, current: BTreeMap<String, bool>) -> Vec<LinkEvent> {
+        let removed: Vec<String> =
+            self.0.keys().filter(|name| !current.contains_key(*name)).cloned().collect();
+
+        let mut events: Vec<LinkEvent> =
+            removed.iter().filter_map(|name| self.apply(name, None)).collect();
+        events.extend(current.into_iter().filter_map(|(name, up)| self.apply(&name, Some(up))));
+        events
+    }
+}
+
+/// Read the up/down state of every interface from /sys/class/net.
+///
+/// `carrier` cannot be read while an interface is administratively down,
+/// which counts as down too.
+fn read_link_states(root: &ProcfsRoot) -> Result<BTreeMap<String, bool>> {
+    let mut states = BTreeMap::new();
+    for entry in fs::read_dir(root.sys_path("class/net"))? {
+        let entry = entry?;
+        let up = read_file(entry.path().join("carrier")).is_ok_and(|s| s.trim() == "1");
+        states.insert(entry.file_name().to_string_lossy().into_owned(), up);
+    }
+    Ok(states)
+}
+
+fn poll_loop<F: FnMut(LinkEvent)>(
+    root: &ProcfsRoot,
+    interval: Duration,
+    mut states: LinkStates,
+    stop: &Receiver<()>,
+    mut callback: F,
+) {
+    while let Err(RecvTimeoutError::Timeout) = stop.recv_timeout(interval) {
+        if let Ok(current) = read_link_states(root) {
+            states.sync(current).into_iter().for_each(&mut callback);
+        }
+    }
+}
+
+fn netlink_loop<F: FnMut(LinkEvent)>(
+    fd: OwnedFd,
+    root: ProcfsRoot,
+    mut states: LinkStates,
+    stop: Receiver<()>,
+    mut callback: F,
+) {
+    let mut buf = vec![0u8; 16 * 1024];
+
+    while let Err(TryRecvError::Empty) = stop.try_recv() {
+        let n = unsafe {
+            libc::recv(fd.as_raw_fd(), buf.as_mut_ptr() as *mut libc::c_void, buf.len(), 0)
+        };
+        if n < 0 {
+            let err = io::Error::last_os_error();
+            match err.raw_os_error() {
+                Some(libc::EAGAIN | libc::EINTR) => {}
+                // Receive queue overflowed and events were lost: resync from sysfs
+                Some(libc::ENOBUFS) => {
+                    if let Ok(current) = read_link_states(&root) {
+                        states.sync(current).into_iter().for_each(&mut callback);
+                    }
+                }
+                _ => {
+                    drop(fd);
+                    return poll_loop(&root, DEFAULT_LINK_POLL_INTERVAL, states, &stop, callback);
+                }
+            }
+            continue;
+        }
+
+        for msg in netlink::parse_link_messages(&buf[..n as usize]) {
+            let up = (!msg.removed).then_some(msg.up);
+            if let Some(event) = states.apply(&msg.interface, up) {
+                callback(event);
+            }
+        }
+    }
+}
+
+/// rtnetlink socket and message parsing (linux/rtnetlink.h).
+mod netlink {
+    use super::*;
+
+    const NLMSG_HDRLEN: usize = 16;
+    const IFINFOMSG_LEN: usize = 16;
+    const RTA_HDRLEN: usize = 4;
+    const IFLA_IFNAME: u16 = 3;
+
+    /// A parsed `RTM_NEWLINK`/`RTM_DELLINK` message.
+    #[derive(Debug, PartialEq, Eq)]
+    pub(super) struct LinkMessage {
+        pub interface: String,
+        pub up: bool,
+        pub removed: bool,
+    }
+
+    /// Open a netlink route socket subscribed to link notifications.
+    pub(super) fn open() -> io::Result<OwnedFd> {
+        let raw = unsafe {
+            libc::socket(libc::AF_NETLINK, libc::SOCK_RAW | libc::SOCK_CLOEXEC, libc::NETLINK_ROUTE)
+        };
+        if raw < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: raw is a freshly opened descriptor owned by nobody else
+        let fd = unsafe { OwnedFd::from_raw_fd(raw) };
+
+        let mut addr: libc::sockaddr_nl = unsafe { std::mem::zeroed() };
+        addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+        addr.nl_groups = libc::RTMGRP_LINK as u32;
+        let bound = unsafe {
+            libc::bind(
+                fd.as_raw_fd(),
+                &addr as *const libc::sockaddr_nl as *const libc::sockaddr,
+                std::mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t,
+            )
+        };
+        if bound < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let timeout = libc::timeval {
+            tv_sec: 0,
+            tv_usec: NETLINK_RECV_TIMEOUT.as_micros() as libc::suseconds_t,
+        };
+        let set = unsafe {
+            libc::setsockopt(
+                fd.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_RCVTIMEO,
+                &timeout as *const libc::timeval as *const libc::c_void,
+                std::mem::size_of::<libc::timeval>() as libc::socklen_t,
+            )
+        };
+        if set < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(fd)
+    }
+
+    fn align(len: usize) -> usize {
+        (len + 3) & !3
+    }
+
+    fn read_u16(buf: &[u8], at: usize) -> u16 {
+        u16::from_ne_bytes([buf[at], buf[at + 1]])
+    }
+
+    fn read_u32(buf: &[u8], at: usize) -> u32 {
+        u32::from_ne_bytes([buf[at], buf[at + 1], buf[at + 2], buf[at + 3]])
+    }
+
+    /// Extract link messages from a netlink datagram, skipping everything else.
+    ///
+    /// Layout: `nlmsghdr` (16 bytes), `ifinfomsg` (16 bytes, `ifi_flags` at
+    /// offset 8), then `rtattr`s; the name is the `IFLA_IFNAME` attribute.
+    pub(super) fn parse_link_messages(buf: &[u8]) -> Vec<LinkMessage> {
+        let mut messages = Vec::new();
+        let mut offset = 0;
+
+        while offset + NLMSG_HDRLEN <= buf.len() {
+            let len = read_u32(buf, offset) as usize;
+            if len < NLMSG_HDRLEN || offset + len > buf.len() {
+                break;
+            }
+            let msg = &buf[offset..offset + len];
+            let kind = read_u16(msg, 4);
+
+            if (kind == libc::RTM_NEWLINK || kind == libc::RTM_DELLINK)
+                && len >= NLMSG_HDRLEN + IFINFOMSG_LEN
+            {
+                let flags = read_u32(msg, NLMSG_HDRLEN + 8);
+                if let Some(interface) = find_ifname(&msg[NLMSG_HDRLEN + IFINFOMSG_LEN..]) {
+                    messages.push(LinkMessage {
+                        interface,
+                        up: flags & libc::IFF_RUNNING as u32 != 0,
+                        removed: kind == libc::RTM_DELLINK,
+                    });
+                }
+            }
+            offset += align(len);
+        }
+
+        messages
+    }
+
+    fn find_ifname(mut attrs: &[u8]) -> Option<String> {
+        while attrs.len() >= RTA_HDRLEN {
+            let len = read_u16(attrs, 0) as usize;
+            if len < RTA_HDRLEN || len > attrs.len() {
+                return None;
+            }
+            if read_u16(attrs, 2) == IFLA_IFNAME {
+                let name = &attrs[RTA_HDRLEN..len];
+                let end = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+                return Some(String::from_utf8_lossy(&name[..end]).into_owned());
+            }
+            attrs = &attrs[align(len).min(attrs.len())..];
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::netlink::{LinkMessage, parse_link_messages};
+    use super::*;
+    use std::sync::mpsc;
+
+    /// Build a netlink link message with an `IFLA_IFNAME` attribute.
+    fn link_message(kind: u16, flags: u32, name: &str) -> Vec<u8> {
+        let mut attr = Vec::new();
+        attr.extend_from_slice(&((4 + name.len() + 1) as u16).to_ne_bytes());
+        attr.extend_from_slice(&3u16.to_ne_bytes());
+        attr.extend_from_slice(name.as_bytes());
+        attr.push(0);
+        attr.resize((attr.len() + 3) & !3, 0);
+
+        let mut ifinfo = vec![0u8; 16];
+        ifinfo[8..12].copy_from_slice(&flags.to_ne_bytes());
+
+        let len = 16 + ifinfo.len() + attr.len();
+        let mut msg = Vec::new();
+        msg.extend_from_slice(&(len as u32).to_ne_bytes());
+        msg.extend_from_slice(&kind.to_ne_bytes());
+        msg.extend_from_slice(&[0u8; 10]);
+        msg.extend(ifinfo);
+        msg.extend(attr);
+        msg
+    }
+
+    #[test]
+    fn test_parse_link_messages() {
+        let mut buf = link_message(libc::RTM_NEWLINK, 0x11043, "eth0");
+        buf.extend(link_message(libc::NLMSG_DONE as u16, 0, "ignored"));
+        // Administratively up without carrier (cable unplugged)
+        buf.extend(link_message(libc::RTM_NEWLINK, 0x1003, "eth1"));
+        buf.extend(link_message(libc::RTM_DELLINK, 0x1002, "dummy0"));
+
+        assert_eq!(
+            parse_link_messages(&buf),
+            vec![
+                LinkMessage { interface: "eth0".to_string(), up: true, removed: false },
+                LinkMessage { interface: "eth1".to_string(), up: false, removed: false },
+                LinkMessage { interface: "dummy0".to_string(), up: false, removed: true },
+            ]
+        );
+        assert!(parse_link_messages(&buf[..20]).is_empty());
+    }
+
+    #[test]
+    fn test_link_states_report_transitions_only() {
+        let mut states = LinkStates::default();
+
+        assert_eq!(states.apply("dummy0", Some(false)), None);
+        assert_eq!(
+            states.apply("dummy0", Some(true)),
+            Some(LinkEvent { interface: "dummy0".to_string(), up: true })
+        );
+        assert_eq!(states.apply("dummy0", Some(true)), None);
+        assert_eq!(
+            states.sync(BTreeMap::from([("eth0".to_string(), true)])),
+            vec![
+                LinkEvent { interface: "dummy0".to_string(), up: false },
+                LinkEvent { interface: "eth0".to_string(), up: true },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_polling_watcher_reports_carrier_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let iface = dir.path().join("sys/class/net/dummy0");
+        std::fs::create_dir_all(&iface).unwrap();
+        std::fs::write(iface.join("carrier"), "0\n").unwrap();
+
+        let root = ProcfsRoot::new(dir.path().join("proc"), dir.path().join("sys"));
+        let (tx, rx) = mpsc::channel();
+        let watcher = LinkWatcher::start_polling(root, Duration::from_millis(10), move |event| {
+            let _ = tx.send(event);
+        })
+        .unwrap();
+        assert!(!watcher.is_event_driven());
+
+        std::fs::write(iface.join("carrier"), "1\n").unwrap();
+        let event = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(event, LinkEvent { interface: "dummy0".to_string(), up: true });
+
+        std::fs::remove_file(iface.join("carrier")).unwrap();
+        let event = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(event, LinkEvent { interface: "dummy0".to_string(), up: false });
+
+        drop(watcher);
+        assert!(matches!(
+            rx.recv_timeout(Duration::from_secs(1)),
+            Err(RecvTimeoutError::Disconnected)
+        ));
+    }
+}
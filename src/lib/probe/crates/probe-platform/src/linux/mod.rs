@@ -2,26 +2,48 @@
 //!
 //! Collects system metrics via the /proc and /sys filesystems.
 
+mod clock;
 mod connections;
+mod kmsg;
+mod link;
 mod procfs;
+mod root;
+mod systemd;
 mod thermal;
+mod wireless;
 
 pub use connections::{
-    build_socket_pid_map, collect_process_connections, collect_tcp_connections, collect_tcp_stats,
-    collect_udp_connections, collect_unix_sockets, find_process_by_port,
+    build_socket_pid_map, collect_listening_ports, collect_process_connections,
+    collect_tcp_connections, collect_tcp_connections_limited, collect_tcp_stats,
+    collect_udp_connections, collect_unix_sockets, find_process_by_port, ipv6_enabled,
 };
+pub use kmsg::{MAX_KERNEL_LOG_ENTRIES, read_kernel_errors};
+pub use link::{DEFAULT_LINK_POLL_INTERVAL, LinkWatcher};
 pub use procfs::{
     read_process_context_switches, read_self_context_switches, read_system_context_switches,
 };
+pub use root::ProcfsRoot;
 pub use thermal::{is_thermal_supported, read_thermal_zones};
+pub use wireless::{list_wireless_interfaces, read_wireless_info};
 
 use crate::{
-    CPUCollector, CPUPressure, ConnectionCollector, DiskCollector, DiskIOStats, DiskUsage, Error,
-    IOCollector, IOPressure, IOStats, LoadAverage, LoadCollector, MemoryCollector, MemoryPressure,
-    NetInterface, NetStats, NetworkCollector, Partition, ProcessCollector, ProcessMetrics,
-    ProcessState, Result, SystemCPU, SystemCollector, SystemMemory, TcpConnection, TcpStats,
-    ThermalCollector, ThermalZone, UdpConnection, UnixSocket,
+    AllPressure, BlockDevice, CPUCollector, CPUPressure, Capabilities, ConnectionCollector,
+    ConntrackStats, CoreUsage, CpuCache, CpuTicks, CpuVuln, DEFAULT_MOUNT_PROBE_TIMEOUT,
+    DeviceMapping, DiskCollector, DiskIOStats, DiskQueueConfig, DiskUsage, Error, IOCollector,
+    IOPressure, IOStats, IcmpStats, InFlight, InotifyStats, KernelLogEntry, KernelModule,
+    KernelStats, ListenQueueStats, ListeningPort, LoadAverage, LoadCollector, MemoryBreakdown,
+    MemoryCollector, MemoryPressure, MemoryZone, NamespaceIds, NetInterface, NetStats,
+    NetworkCollector, OomInfo, Partition, ProcessCapabilities, ProcessCollector, ProcessIdentity,
+    ProcessMetrics, ProcessState, RawSource, Result, RunQueueStat, SchedPolicy, SchedStats,
+    SocketMemStats, SwapDevice, SystemCPU, SystemCollector, SystemLimits, SystemMemory,
+    TcpConnection, TcpStats, ThermalCollector, ThermalZone, ThpStatus, ThreadInfo, TimeInfo,
+    UdpConnection, UdpProtoStats, UnitStatus, UnixSocket, WirelessCollector, WirelessInfo,
+    ZfsArcStats,
 };
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
 
 /// Linux system collector implementation.
 pub struct LinuxCollector {
@@ -32,21 +54,54 @@ pub struct LinuxCollector {
     disk: LinuxDiskCollector,
     network: LinuxNetworkCollector,
     io: LinuxIOCollector,
+    root: ProcfsRoot,
+    /// Whether /proc/pressure exists, checked on first use.
+    pressure_supported: OnceLock<bool>,
 }
 
 impl LinuxCollector {
-    /// Create a new Linux collector.
+    /// Create a new Linux collector reading `/proc` and `/sys`.
     pub fn new() -> Self {
+        Self::with_root(ProcfsRoot::default())
+    }
+
+    /// Create a collector reading procfs and sysfs from custom locations.
+    ///
+    /// Useful for fixture-based tests or for reading another mount
+    /// namespace's procfs (e.g. `/proc/<pid>/root/proc`).
+    pub fn new_with_roots(proc_root: impl Into<PathBuf>, sys_root: impl Into<PathBuf>) -> Self {
+        Self::with_root(ProcfsRoot::new(proc_root, sys_root))
+    }
+
+    /// Create a collector reading the procfs and sysfs seen by `host_pid`.
+    ///
+    /// See [`ProcfsRoot::for_container_pid`].
+    pub fn for_container_pid(host_pid: i32) -> Self {
+        Self::with_root(ProcfsRoot::for_container_pid(host_pid))
+    }
+
+    /// Create a collector from a root configuration.
+    pub fn with_root(root: ProcfsRoot) -> Self {
         Self {
-            cpu: LinuxCPUCollector,
-            memory: LinuxMemoryCollector,
-            load: LinuxLoadCollector,
-            process: LinuxProcessCollector,
-            disk: LinuxDiskCollector,
-            network: LinuxNetworkCollector,
-            io: LinuxIOCollector,
+            cpu: LinuxCPUCollector {
+                root: root.clone(),
+                previous_cores: Mutex::new(BTreeMap::new()),
+            },
+            memory: LinuxMemoryCollector { root: root.clone() },
+            load: LinuxLoadCollector { root: root.clone() },
+            process: LinuxProcessCollector { root: root.clone() },
+            disk: LinuxDiskCollector { root: root.clone(), mount_probes: InFlight::new() },
+            network: LinuxNetworkCollector { root: root.clone() },
+            io: LinuxIOCollector { root: root.clone() },
+            root,
+            pressure_supported: OnceLock::new(),
         }
     }
+
+    /// Procfs/sysfs roots this collector reads from.
+    pub fn root(&self) -> &ProcfsRoot {
+        &self.root
+    }
 }
 
 impl Default for LinuxCollector {
@@ -83,18 +138,138 @@ impl SystemCollector for LinuxCollector {
     fn io(&self) -> &dyn IOCollector {
         &self.io
     }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "system.collect_cgroup_pressure", level = "debug", skip(self))
+    )]
+    fn collect_cgroup_pressure(&self) -> Result<AllPressure> {
+        // The cgroup of this process is only meaningful for the host procfs
+        let reader = probe_quota::LinuxQuotaReader::new();
+        if self.root == ProcfsRoot::default()
+            && let Ok(cgroup_path) = reader.cgroup_v2_path(std::process::id() as i32)
+            && let Ok(pressure) = procfs::read_cgroup_pressure(&cgroup_path)
+        {
+            return Ok(pressure);
+        }
+
+        // Fall back to system-wide PSI
+        Ok(AllPressure {
+            cpu: procfs::read_cpu_pressure(&self.root)?,
+            memory: procfs::read_memory_pressure(&self.root)?,
+            io: procfs::read_io_pressure(&self.root)?,
+        })
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "system.collect_kernel_stats", level = "debug", skip(self))
+    )]
+    fn collect_kernel_stats(&self) -> Result<KernelStats> {
+        Ok(procfs::ProcStat::read(&self.root)?.kernel_stats())
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "system.recent_kernel_errors", level = "debug", skip(self))
+    )]
+    fn recent_kernel_errors(&self, since: Option<Duration>) -> Result<Vec<KernelLogEntry>> {
+        kmsg::read_kernel_errors(since)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "system.time_info", level = "debug", skip(self))
+    )]
+    fn time_info(&self) -> Result<TimeInfo> {
+        clock::read_time_info()
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "system.inotify_stats", level = "debug", skip(self))
+    )]
+    fn inotify_stats(&self) -> Result<InotifyStats> {
+        procfs::read_inotify_stats(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "system.list_modules", level = "debug", skip(self))
+    )]
+    fn list_modules(&self) -> Result<Vec<KernelModule>> {
+        procfs::read_modules(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "system.namespace_ids", level = "debug", skip(self))
+    )]
+    fn namespace_ids(&self) -> Result<NamespaceIds> {
+        procfs::read_namespace_ids(&self.root, "self")
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "system.system_limits", level = "debug", skip(self))
+    )]
+    fn system_limits(&self) -> Result<SystemLimits> {
+        procfs::read_system_limits(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "system.unit_status", level = "debug", skip(self))
+    )]
+    fn unit_status(&self, unit: &str) -> Result<UnitStatus> {
+        systemd::read_unit_status(&self.root, unit)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "system.read_raw", level = "debug", skip(self))
+    )]
+    fn read_raw(&self, source: RawSource) -> Result<String> {
+        let path = self.root.proc_path(source.proc_path());
+        procfs::read_file(&path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => Error::NotFound(path.display().to_string()),
+            _ => Error::Io(e),
+        })
+    }
+
+    fn pressure_supported(&self) -> bool {
+        *self.pressure_supported.get_or_init(|| self.root.proc_path("pressure/cpu").exists())
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            has_pressure: self.pressure_supported(),
+            has_thermal: thermal::is_thermal_supported_at(&self.root),
+            has_per_core: self.root.proc_path("stat").exists(),
+            has_connections: self.root.proc_path("net/tcp").exists(),
+            has_disk_io: self.root.proc_path("diskstats").exists(),
+        }
+    }
 }
 
 // ============================================================================
 // CPU COLLECTOR
 // ============================================================================
 
-struct LinuxCPUCollector;
+struct LinuxCPUCollector {
+    root: ProcfsRoot,
+    /// Previous per-core samples, keyed by core ID.
+    previous_cores: Mutex<BTreeMap<u32, procfs::ProcStat>>,
+}
 
 impl CPUCollector for LinuxCPUCollector {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "cpu.collect_system", level = "debug", skip(self))
+    )]
     fn collect_system(&self) -> Result<SystemCPU> {
-        let stat = procfs::ProcStat::read()?;
-        let cpuinfo = procfs::CpuInfo::read()?;
+        let stat = procfs::ProcStat::read(&self.root)?;
+        let cpuinfo = procfs::CpuInfo::read(&self.root)?;
 
         Ok(SystemCPU {
             user_percent: stat.user_percent(),
@@ -103,12 +278,67 @@ impl CPUCollector for LinuxCPUCollector {
             iowait_percent: stat.iowait_percent(),
             steal_percent: stat.steal_percent(),
             cores: cpuinfo.num_cores,
+            physical_cores: cpuinfo.physical_cores,
             frequency_mhz: cpuinfo.frequency_mhz,
+            guest_percent: stat.guest_percent(),
+            guest_nice_percent: stat.guest_nice_percent(),
+            cores_present: procfs::read_cpu_count(&self.root, "present")
+                .unwrap_or(cpuinfo.num_cores),
+            cores_online: procfs::read_cpu_count(&self.root, "online").unwrap_or(cpuinfo.num_cores),
         })
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "cpu.cpu_ticks", level = "debug", skip(self))
+    )]
+    fn cpu_ticks(&self) -> Result<CpuTicks> {
+        Ok(procfs::ProcStat::read(&self.root)?.ticks())
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "cpu.collect_per_core", level = "debug", skip(self))
+    )]
+    fn collect_per_core(&self) -> Result<Vec<CoreUsage>> {
+        let current = procfs::ProcStat::read_per_core(&self.root)?;
+        let mut previous = self.previous_cores.lock().unwrap_or_else(|e| e.into_inner());
+        let usage = procfs::per_core_usage(&previous, &current);
+        // Replacing the map drops cores that went offline
+        *previous = current;
+        Ok(usage)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "cpu.collect_pressure", level = "debug", skip(self))
+    )]
     fn collect_pressure(&self) -> Result<CPUPressure> {
-        procfs::read_cpu_pressure()
+        procfs::read_cpu_pressure(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "cpu.cpu_vulnerabilities", level = "debug", skip(self))
+    )]
+    fn cpu_vulnerabilities(&self) -> Result<Vec<CpuVuln>> {
+        procfs::read_cpu_vulnerabilities(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "cpu.per_cpu_runqueue", level = "debug", skip(self))
+    )]
+    fn per_cpu_runqueue(&self) -> Result<Vec<RunQueueStat>> {
+        procfs::read_runqueue_stats(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "cpu.cpu_caches", level = "debug", skip(self))
+    )]
+    fn cpu_caches(&self) -> Result<Vec<CpuCache>> {
+        procfs::read_cpu_caches(&self.root)
     }
 }
 
@@ -116,11 +346,17 @@ impl CPUCollector for LinuxCPUCollector {
 // MEMORY COLLECTOR
 // ============================================================================
 
-struct LinuxMemoryCollector;
+struct LinuxMemoryCollector {
+    root: ProcfsRoot,
+}
 
 impl MemoryCollector for LinuxMemoryCollector {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "memory.collect_system", level = "debug", skip(self))
+    )]
     fn collect_system(&self) -> Result<SystemMemory> {
-        let meminfo = procfs::MemInfo::read()?;
+        let meminfo = procfs::MemInfo::read(&self.root)?;
 
         Ok(SystemMemory {
             total_bytes: meminfo.mem_total,
@@ -128,13 +364,58 @@ impl MemoryCollector for LinuxMemoryCollector {
             used_bytes: meminfo.mem_total.saturating_sub(meminfo.mem_available),
             cached_bytes: meminfo.cached,
             buffers_bytes: meminfo.buffers,
+            slab_reclaimable_bytes: meminfo.slab_reclaimable,
             swap_total_bytes: meminfo.swap_total,
             swap_used_bytes: meminfo.swap_total.saturating_sub(meminfo.swap_free),
         })
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "memory.collect_pressure", level = "debug", skip(self))
+    )]
     fn collect_pressure(&self) -> Result<MemoryPressure> {
-        procfs::read_memory_pressure()
+        procfs::read_memory_pressure(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "memory.thp_status", level = "debug", skip(self))
+    )]
+    fn thp_status(&self) -> Result<ThpStatus> {
+        procfs::read_thp_status(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "memory.zone_info", level = "debug", skip(self))
+    )]
+    fn zone_info(&self) -> Result<Vec<MemoryZone>> {
+        procfs::read_zone_info(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "memory.swap_devices", level = "debug", skip(self))
+    )]
+    fn swap_devices(&self) -> Result<Vec<SwapDevice>> {
+        procfs::read_swap_devices(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "memory.zfs_arc", level = "debug", skip(self))
+    )]
+    fn zfs_arc(&self) -> Result<ZfsArcStats> {
+        procfs::read_zfs_arc(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "memory.vmstat", level = "debug", skip(self))
+    )]
+    fn vmstat(&self) -> Result<HashMap<String, u64>> {
+        procfs::read_vmstat(&self.root)
     }
 }
 
@@ -142,11 +423,17 @@ impl MemoryCollector for LinuxMemoryCollector {
 // LOAD COLLECTOR
 // ============================================================================
 
-struct LinuxLoadCollector;
+struct LinuxLoadCollector {
+    root: ProcfsRoot,
+}
 
 impl LoadCollector for LinuxLoadCollector {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "load.collect", level = "debug", skip(self))
+    )]
     fn collect(&self) -> Result<LoadAverage> {
-        let loadavg = procfs::LoadAvg::read()?;
+        let loadavg = procfs::LoadAvg::read(&self.root)?;
 
         Ok(LoadAverage {
             load_1min: loadavg.load_1min,
@@ -154,18 +441,40 @@ impl LoadCollector for LinuxLoadCollector {
             load_15min: loadavg.load_15min,
         })
     }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "load.runnable_tasks", level = "debug", skip(self))
+    )]
+    fn runnable_tasks(&self) -> Result<u32> {
+        procfs::read_task_counts(&self.root).map(|(running, _)| running)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "load.blocked_tasks", level = "debug", skip(self))
+    )]
+    fn blocked_tasks(&self) -> Result<u32> {
+        procfs::read_task_counts(&self.root).map(|(_, blocked)| blocked)
+    }
 }
 
 // ============================================================================
 // PROCESS COLLECTOR
 // ============================================================================
 
-struct LinuxProcessCollector;
+struct LinuxProcessCollector {
+    root: ProcfsRoot,
+}
 
 impl ProcessCollector for LinuxProcessCollector {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "process.collect", level = "debug", skip(self))
+    )]
     fn collect(&self, pid: i32) -> Result<ProcessMetrics> {
-        let stat = procfs::ProcessStat::read(pid)?;
-        let status = procfs::ProcessStatus::read(pid)?;
+        let stat = procfs::ProcessStat::read(&self.root, pid)?;
+        let status = procfs::ProcessStatus::read(&self.root, pid)?;
 
         Ok(ProcessMetrics {
             pid,
@@ -174,27 +483,109 @@ impl ProcessCollector for LinuxProcessCollector {
             memory_vms_bytes: status.vm_size,
             memory_percent: 0.0,
             num_threads: stat.num_threads,
-            num_fds: procfs::count_fds(pid).unwrap_or(0),
+            num_fds: procfs::count_fds(&self.root, pid).unwrap_or(0),
             read_bytes_per_sec: 0,
             write_bytes_per_sec: 0,
-            state: match stat.state {
-                'R' => ProcessState::Running,
-                'S' => ProcessState::Sleeping,
-                'D' => ProcessState::Waiting,
-                'Z' => ProcessState::Zombie,
-                'T' => ProcessState::Stopped,
-                _ => ProcessState::Unknown,
-            },
+            minor_faults: stat.minflt,
+            major_faults: stat.majflt,
+            state: process_state(stat.state),
+            sched_policy: SchedPolicy::from_linux(stat.policy),
+            nice: stat.nice,
+            rt_priority: stat.rt_priority,
         })
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "process.collect_all", level = "debug", skip(self))
+    )]
     fn collect_all(&self) -> Result<Vec<ProcessMetrics>> {
-        procfs::list_processes()?
+        procfs::list_processes(&self.root)?
             .into_iter()
             .filter_map(|pid| self.collect(pid).ok())
             .collect::<Vec<_>>()
             .pipe(Ok)
     }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "process.memory_breakdown", level = "debug", skip(self))
+    )]
+    fn memory_breakdown(&self, pid: i32) -> Result<MemoryBreakdown> {
+        procfs::read_memory_breakdown(&self.root, pid)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "process.sched_latency", level = "debug", skip(self))
+    )]
+    fn sched_latency(&self, pid: i32) -> Result<SchedStats> {
+        procfs::read_sched_stats(&self.root, pid)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "process.oom_info", level = "debug", skip(self))
+    )]
+    fn oom_info(&self, pid: i32) -> Result<OomInfo> {
+        procfs::read_oom_info(&self.root, pid)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "process.list_threads", level = "debug", skip(self))
+    )]
+    fn list_threads(&self, pid: i32) -> Result<Vec<ThreadInfo>> {
+        let us_per_tick = 1_000_000 / procfs::clock_ticks_per_sec();
+        let threads = procfs::list_threads(&self.root, pid)?
+            .into_iter()
+            // Threads may exit between the listing and the read
+            .filter_map(|tid| procfs::ProcessStat::read_thread(&self.root, pid, tid).ok())
+            .map(|stat| ThreadInfo {
+                tid: stat.pid,
+                comm: stat.comm,
+                state: process_state(stat.state),
+                user_time_us: stat.utime * us_per_tick,
+                system_time_us: stat.stime * us_per_tick,
+                cpu_percent: 0.0,
+            })
+            .collect();
+        Ok(threads)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "process.capability_sets", level = "debug", skip(self))
+    )]
+    fn capability_sets(&self, pid: i32) -> Result<ProcessCapabilities> {
+        procfs::read_process_capabilities(&self.root, pid)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "process.list_identities", level = "debug", skip(self))
+    )]
+    fn list_identities(&self) -> Result<Vec<ProcessIdentity>> {
+        // Processes exiting between the listing and the read are skipped
+        Ok(procfs::list_processes(&self.root)?
+            .into_iter()
+            .filter_map(|pid| procfs::ProcessStat::read(&self.root, pid).ok())
+            .map(|stat| ProcessIdentity {
+                pid: stat.pid,
+                name: stat.comm,
+                start_time: stat.start_time,
+            })
+            .collect())
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "process.identity", level = "debug", skip(self))
+    )]
+    fn identity(&self, pid: i32) -> Result<ProcessIdentity> {
+        let stat = procfs::ProcessStat::read(&self.root, pid)?;
+        Ok(ProcessIdentity { pid: stat.pid, name: stat.comm, start_time: stat.start_time })
+    }
 }
 
 // Helper trait for functional style
@@ -209,21 +600,49 @@ trait Pipe: Sized {
 
 impl<T> Pipe for T {}
 
+/// Map a /proc/[pid]/stat state character to a process state.
+fn process_state(state: char) -> ProcessState {
+    match state {
+        'R' => ProcessState::Running,
+        'S' => ProcessState::Sleeping,
+        'D' => ProcessState::Waiting,
+        'Z' => ProcessState::Zombie,
+        'T' => ProcessState::Stopped,
+        _ => ProcessState::Unknown,
+    }
+}
+
 // ============================================================================
 // DISK COLLECTOR
 // ============================================================================
 
-struct LinuxDiskCollector;
+struct LinuxDiskCollector {
+    root: ProcfsRoot,
+    /// Mount paths whose latency probe is still blocked in `statvfs`.
+    mount_probes: InFlight,
+}
 
 impl DiskCollector for LinuxDiskCollector {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "disk.list_partitions", level = "debug", skip(self))
+    )]
     fn list_partitions(&self) -> Result<Vec<Partition>> {
-        procfs::read_mounts()
+        procfs::read_mounts(&self.root)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "disk.collect_usage", level = "debug", skip(self))
+    )]
     fn collect_usage(&self, path: &str) -> Result<DiskUsage> {
         procfs::read_disk_usage(path)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "disk.collect_all_usage", level = "debug", skip(self))
+    )]
     fn collect_all_usage(&self) -> Result<Vec<DiskUsage>> {
         let partitions = self.list_partitions()?;
         let mut usages = Vec::new();
@@ -237,10 +656,18 @@ impl DiskCollector for LinuxDiskCollector {
         Ok(usages)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "disk.collect_io", level = "debug", skip(self))
+    )]
     fn collect_io(&self) -> Result<Vec<DiskIOStats>> {
-        procfs::read_diskstats()
+        procfs::read_diskstats(&self.root)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "disk.collect_device_io", level = "debug", skip(self))
+    )]
     fn collect_device_io(&self, device: &str) -> Result<DiskIOStats> {
         let stats = self.collect_io()?;
         stats
@@ -248,19 +675,63 @@ impl DiskCollector for LinuxDiskCollector {
             .find(|s| s.device == device)
             .ok_or_else(|| Error::NotFound(format!("device {} not found", device)))
     }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "disk.block_devices", level = "debug", skip(self))
+    )]
+    fn block_devices(&self) -> Result<Vec<BlockDevice>> {
+        procfs::read_block_devices(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "disk.probe_mount_latency", level = "debug", skip(self))
+    )]
+    fn probe_mount_latency(&self, path: &str) -> Result<Duration> {
+        let owned = path.to_string();
+        self.mount_probes
+            .run(path, DEFAULT_MOUNT_PROBE_TIMEOUT, move || procfs::time_statvfs(&owned))
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "disk.disk_queue_config", level = "debug", skip(self))
+    )]
+    fn disk_queue_config(&self, device: &str) -> Result<DiskQueueConfig> {
+        procfs::read_disk_queue_config(&self.root, device)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "disk.device_topology", level = "debug", skip(self))
+    )]
+    fn device_topology(&self) -> Result<Vec<DeviceMapping>> {
+        procfs::read_device_topology(&self.root)
+    }
 }
 
 // ============================================================================
 // NETWORK COLLECTOR
 // ============================================================================
 
-struct LinuxNetworkCollector;
+struct LinuxNetworkCollector {
+    root: ProcfsRoot,
+}
 
 impl NetworkCollector for LinuxNetworkCollector {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "network.list_interfaces", level = "debug", skip(self))
+    )]
     fn list_interfaces(&self) -> Result<Vec<NetInterface>> {
-        procfs::read_net_interfaces()
+        procfs::read_net_interfaces(&self.root)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "network.collect_stats", level = "debug", skip(self))
+    )]
     fn collect_stats(&self, interface: &str) -> Result<NetStats> {
         let all_stats = self.collect_all_stats()?;
         all_stats
@@ -269,8 +740,64 @@ impl NetworkCollector for LinuxNetworkCollector {
             .ok_or_else(|| Error::NotFound(format!("interface {} not found", interface)))
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "network.collect_all_stats", level = "debug", skip(self))
+    )]
     fn collect_all_stats(&self) -> Result<Vec<NetStats>> {
-        procfs::read_net_dev()
+        procfs::read_net_dev(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "network.collect_stats_for_pid_netns",
+            level = "debug",
+            skip(self)
+        )
+    )]
+    fn collect_stats_for_pid_netns(&self, pid: i32) -> Result<Vec<NetStats>> {
+        procfs::read_pid_net_dev(&self.root, pid)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "network.conntrack_stats", level = "debug", skip(self))
+    )]
+    fn conntrack_stats(&self) -> Result<ConntrackStats> {
+        procfs::read_conntrack_stats(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "network.socket_memory", level = "debug", skip(self))
+    )]
+    fn socket_memory(&self) -> Result<SocketMemStats> {
+        procfs::read_socket_memory(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "network.listen_queue_stats", level = "debug", skip(self))
+    )]
+    fn listen_queue_stats(&self) -> Result<ListenQueueStats> {
+        procfs::read_listen_queue_stats(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "network.udp_stats", level = "debug", skip(self))
+    )]
+    fn udp_stats(&self) -> Result<UdpProtoStats> {
+        procfs::read_udp_stats(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "network.icmp_stats", level = "debug", skip(self))
+    )]
+    fn icmp_stats(&self) -> Result<IcmpStats> {
+        procfs::read_icmp_stats(&self.root)
     }
 }
 
@@ -278,15 +805,25 @@ impl NetworkCollector for LinuxNetworkCollector {
 // I/O COLLECTOR
 // ============================================================================
 
-struct LinuxIOCollector;
+struct LinuxIOCollector {
+    root: ProcfsRoot,
+}
 
 impl IOCollector for LinuxIOCollector {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "io.collect_stats", level = "debug", skip(self))
+    )]
     fn collect_stats(&self) -> Result<IOStats> {
-        procfs::read_io_stats()
+        procfs::read_io_stats(&self.root)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "io.collect_pressure", level = "debug", skip(self))
+    )]
     fn collect_pressure(&self) -> Result<IOPressure> {
-        procfs::read_io_pressure()
+        procfs::read_io_pressure(&self.root)
     }
 }
 
@@ -295,19 +832,82 @@ impl IOCollector for LinuxIOCollector {
 // ============================================================================
 
 /// Linux thermal collector using /sys/class/hwmon.
-pub struct LinuxThermalCollector;
+#[derive(Default)]
+pub struct LinuxThermalCollector {
+    root: ProcfsRoot,
+}
+
+impl LinuxThermalCollector {
+    /// Create a thermal collector reading `/sys`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a thermal collector reading from a custom root.
+    pub fn with_root(root: ProcfsRoot) -> Self {
+        Self { root }
+    }
+}
 
 impl ThermalCollector for LinuxThermalCollector {
     fn is_supported(&self) -> bool {
-        thermal::is_thermal_supported()
+        thermal::is_thermal_supported_at(&self.root)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "thermal.list_zones", level = "debug", skip(self))
+    )]
     fn list_zones(&self) -> Result<Vec<ThermalZone>> {
-        thermal::read_thermal_zones()
+        thermal::read_thermal_zones_at(&self.root)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "thermal.collect_temperatures", level = "debug", skip(self))
+    )]
     fn collect_temperatures(&self) -> Result<Vec<ThermalZone>> {
-        thermal::read_thermal_zones()
+        thermal::read_thermal_zones_at(&self.root)
+    }
+}
+
+// ============================================================================
+// WIRELESS COLLECTOR
+// ============================================================================
+
+/// Linux wireless collector using /proc/net/wireless and sysfs.
+#[derive(Default)]
+pub struct LinuxWirelessCollector {
+    root: ProcfsRoot,
+}
+
+impl LinuxWirelessCollector {
+    /// Create a wireless collector reading `/proc` and `/sys`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a wireless collector reading from a custom root.
+    pub fn with_root(root: ProcfsRoot) -> Self {
+        Self { root }
+    }
+}
+
+impl WirelessCollector for LinuxWirelessCollector {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "wireless.list_interfaces", level = "debug", skip(self))
+    )]
+    fn list_interfaces(&self) -> Result<Vec<String>> {
+        wireless::list_wireless_interfaces_at(&self.root)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "wireless.collect", level = "debug", skip(self))
+    )]
+    fn collect(&self, interface: &str) -> Result<WirelessInfo> {
+        wireless::read_wireless_info_at(&self.root, interface)
     }
 }
 
@@ -316,33 +916,981 @@ impl ThermalCollector for LinuxThermalCollector {
 // ============================================================================
 
 /// Linux connection collector using /proc/net.
-pub struct LinuxConnectionCollector;
+#[derive(Default)]
+pub struct LinuxConnectionCollector {
+    root: ProcfsRoot,
+}
+
+impl LinuxConnectionCollector {
+    /// Create a connection collector reading `/proc`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a connection collector reading from a custom root.
+    pub fn with_root(root: ProcfsRoot) -> Self {
+        Self { root }
+    }
+}
 
 impl ConnectionCollector for LinuxConnectionCollector {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "connections.collect_tcp", level = "debug", skip(self))
+    )]
     fn collect_tcp(&self) -> Result<Vec<TcpConnection>> {
-        connections::collect_tcp_connections()
+        connections::collect_tcp_connections_at(&self.root)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "connections.collect_tcp_limited", level = "debug", skip(self))
+    )]
+    fn collect_tcp_limited(&self, limit: usize) -> Result<(Vec<TcpConnection>, bool)> {
+        connections::collect_tcp_connections_limited_at(&self.root, limit)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "connections.collect_udp", level = "debug", skip(self))
+    )]
     fn collect_udp(&self) -> Result<Vec<UdpConnection>> {
-        connections::collect_udp_connections()
+        connections::collect_udp_connections_at(&self.root)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "connections.collect_unix", level = "debug", skip(self))
+    )]
     fn collect_unix(&self) -> Result<Vec<UnixSocket>> {
-        connections::collect_unix_sockets()
+        connections::collect_unix_sockets_at(&self.root)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "connections.collect_tcp_stats", level = "debug", skip(self))
+    )]
     fn collect_tcp_stats(&self) -> Result<TcpStats> {
-        connections::collect_tcp_stats()
+        connections::collect_tcp_stats_at(&self.root)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "connections.collect_process_connections",
+            level = "debug",
+            skip(self)
+        )
+    )]
     fn collect_process_connections(
         &self,
         pid: i32,
     ) -> Result<(Vec<TcpConnection>, Vec<UdpConnection>)> {
-        connections::collect_process_connections(pid)
+        connections::collect_process_connections_at(&self.root, pid)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "connections.find_process_by_port",
+            level = "debug",
+            skip(self)
+        )
+    )]
     fn find_process_by_port(&self, port: u16, tcp: bool) -> Result<Option<i32>> {
-        connections::find_process_by_port(port, tcp)
+        connections::find_process_by_port_at(&self.root, port, tcp)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "connections.listening_ports", level = "debug", skip(self))
+    )]
+    fn listening_ports(&self) -> Result<Vec<ListeningPort>> {
+        connections::collect_listening_ports_at(&self.root)
+    }
+
+    fn ipv6_enabled(&self) -> bool {
+        connections::ipv6_enabled_at(&self.root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CpuVulnStatus, ThreadCpuSampler};
+
+    fn fixture_collector() -> LinuxCollector {
+        let fixtures = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/linux");
+        LinuxCollector::new_with_roots(fixtures.join("proc"), fixtures.join("sys"))
+    }
+
+    #[test]
+    fn test_pressure_supported_is_checked_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let collector = LinuxCollector::new_with_roots(dir.path(), dir.path());
+        assert!(!collector.pressure_supported());
+        assert!(collector.collect_all().unwrap().pressure.is_none());
+
+        // PSI appearing later is not looked up again
+        std::fs::create_dir(dir.path().join("pressure")).unwrap();
+        std::fs::write(
+            dir.path().join("pressure/cpu"),
+            "some avg10=1.00 avg60=0.00 avg300=0.00 total=1\n",
+        )
+        .unwrap();
+        assert!(!collector.pressure_supported());
+        assert!(!collector.capabilities().has_pressure);
+        assert!(collector.collect_all().unwrap().pressure.is_none());
+
+        assert!(fixture_collector().pressure_supported());
+    }
+
+    #[test]
+    fn test_collectors_read_fixture_roots() {
+        let collector = fixture_collector();
+
+        let cpu = collector.cpu().collect_system().unwrap();
+        assert_eq!(cpu.cores, 2);
+        assert_eq!(cpu.cores_online, 2);
+        assert_eq!(cpu.cores_present, 4);
+        assert_eq!(cpu.physical_cores, 2);
+        assert_eq!(cpu.frequency_mhz, 2400);
+        assert_eq!(cpu.user_percent, 30.0);
+        assert_eq!(cpu.idle_percent, 50.0);
+
+        let memory = collector.memory().collect_system().unwrap();
+        assert_eq!(memory.total_bytes, 8_000_000 * 1024);
+        assert_eq!(memory.used_bytes, 3_000_000 * 1024);
+        assert_eq!(memory.swap_used_bytes, 250_000 * 1024);
+        assert_eq!(collector.memory().memory_truly_available().unwrap(), 5_150_000 * 1024);
+        assert_eq!(memory.effective_used_bytes(), 2_850_000 * 1024);
+
+        let load = collector.load().collect().unwrap();
+        assert_eq!(load.load_1min, 0.5);
+        assert_eq!(collector.load().runnable_tasks().unwrap(), 2);
+        assert_eq!(collector.load().blocked_tasks().unwrap(), 1);
+
+        let pressure = collector.collect_cgroup_pressure().unwrap();
+        assert_eq!(pressure.cpu.some_avg10, 1.5);
+        assert_eq!(pressure.io.full_total_us, 25000);
+
+        let kernel = collector.collect_kernel_stats().unwrap();
+        assert_eq!(kernel.context_switches_total, 987654);
+        assert_eq!(kernel.procs_running, 2);
+
+        let process = collector.process().collect(42).unwrap();
+        assert_eq!(process.num_threads, 3);
+        assert_eq!(process.memory_rss_bytes, 1024 * 1024);
+        assert_eq!(process.state, ProcessState::Sleeping);
+        assert_eq!(collector.process().collect_all().unwrap().len(), 1);
+
+        let partitions = collector.disk().list_partitions().unwrap();
+        assert_eq!(partitions.len(), 2);
+        let disk_io = collector.disk().collect_io().unwrap();
+        assert_eq!(disk_io.len(), 1);
+        assert_eq!(disk_io[0].read_bytes, 16000 * 512);
+
+        let io = collector.io().collect_stats().unwrap();
+        assert_eq!(io.write_ops, 2000);
+
+        let interfaces = collector.network().list_interfaces().unwrap();
+        let eth0 = interfaces.iter().find(|i| i.name == "eth0").unwrap();
+        assert!(eth0.is_up);
+        assert_eq!(eth0.mtu, 1500);
+        let stats = collector.network().collect_stats("eth0").unwrap();
+        assert_eq!(stats.rx_bytes, 500000);
+        assert_eq!(stats.tx_drops, 4);
+    }
+
+    #[test]
+    fn test_net_stats_parse_fifo_compressed_multicast_and_collisions() {
+        let collector = fixture_collector();
+        let network = collector.network();
+
+        // frame (6) and carrier (11) sit between these columns and must not shift them
+        let eth0 = network.collect_stats("eth0").unwrap();
+        assert_eq!((eth0.rx_fifo, eth0.rx_compressed, eth0.rx_multicast), (5, 7, 80));
+        assert_eq!((eth0.tx_fifo, eth0.tx_collisions, eth0.tx_compressed), (9, 10, 12));
+        assert_eq!((eth0.rx_errors, eth0.rx_drops, eth0.tx_errors), (1, 2, 3));
+
+        let lo = network.collect_stats("lo").unwrap();
+        assert_eq!((lo.rx_fifo, lo.rx_compressed, lo.rx_multicast), (0, 0, 0));
+        assert_eq!((lo.tx_fifo, lo.tx_collisions, lo.tx_compressed), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_capabilities_probe_fixture_root() {
+        let caps = fixture_collector().capabilities();
+
+        assert!(caps.has_pressure);
+        assert!(caps.has_thermal);
+        assert!(caps.has_per_core);
+        assert!(caps.has_connections);
+        assert!(caps.has_disk_io);
+
+        let empty = std::env::temp_dir().join("probe-capabilities-missing");
+        let caps =
+            LinuxCollector::new_with_roots(empty.join("proc"), empty.join("sys")).capabilities();
+        assert_eq!(caps, Capabilities::default());
+    }
+
+    #[test]
+    fn test_block_devices_read_fixture_root() {
+        let devices = fixture_collector().disk().block_devices().unwrap();
+
+        assert_eq!(
+            devices,
+            vec![
+                BlockDevice {
+                    name: "nvme0n1".to_string(),
+                    model: "Samsung SSD 980 PRO 1TB".to_string(),
+                    serial: "S5GXNF0R123456".to_string(),
+                    size_bytes: 2_000_409_264 * 512,
+                    rotational: false,
+                    removable: false,
+                },
+                BlockDevice {
+                    name: "sda".to_string(),
+                    model: "ST4000DM004-2CV1".to_string(),
+                    serial: String::new(),
+                    size_bytes: 7_814_037_168 * 512,
+                    rotational: true,
+                    removable: false,
+                },
+            ]
+        );
+
+        // A corrupt sector count saturates instead of overflowing
+        let dir = tempfile::tempdir().unwrap();
+        let sdz = dir.path().join("sys/block/sdz");
+        std::fs::create_dir_all(sdz.join("device")).unwrap();
+        std::fs::write(sdz.join("size"), format!("{}\n", u64::MAX)).unwrap();
+        let collector =
+            LinuxCollector::new_with_roots(dir.path().join("proc"), dir.path().join("sys"));
+        assert_eq!(collector.disk().block_devices().unwrap()[0].size_bytes, u64::MAX);
+    }
+
+    #[test]
+    fn test_collect_all_sampled_covers_window() {
+        let busy = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true));
+        let spinner = {
+            let busy = busy.clone();
+            std::thread::spawn(move || {
+                while busy.load(std::sync::atomic::Ordering::Relaxed) {
+                    std::hint::spin_loop();
+                }
+            })
+        };
+
+        let delta = LinuxCollector::new().collect_all_sampled(Duration::from_millis(200));
+        busy.store(false, std::sync::atomic::Ordering::Relaxed);
+        spinner.join().unwrap();
+
+        let delta = delta.unwrap();
+        assert!(delta.interval >= Duration::from_millis(200));
+        assert!(delta.cpu.user_percent > 0.0, "user% {}", delta.cpu.user_percent);
+    }
+
+    #[test]
+    fn test_root_filesystem_readonly() {
+        // The fixture mounts / read-write
+        assert!(!fixture_collector().disk().root_filesystem_readonly().unwrap());
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("proc")).unwrap();
+        std::fs::write(
+            dir.path().join("proc/mounts"),
+            "overlay / overlay ro,relatime,lowerdir=/l,upperdir=/u,workdir=/w 0 0\n\
+             tmpfs /tmp tmpfs rw,nosuid,nodev 0 0\n",
+        )
+        .unwrap();
+        let collector =
+            LinuxCollector::new_with_roots(dir.path().join("proc"), dir.path().join("sys"));
+        assert!(collector.disk().root_filesystem_readonly().unwrap());
+    }
+
+    #[test]
+    fn test_disk_queue_config_read_fixture_root() {
+        let disk = fixture_collector();
+        let disk = disk.disk();
+
+        assert_eq!(
+            disk.disk_queue_config("sda").unwrap(),
+            DiskQueueConfig {
+                scheduler: "mq-deadline".to_string(),
+                nr_requests: 64,
+                read_ahead_kb: 4096,
+                rotational: true,
+            }
+        );
+        let nvme = disk.disk_queue_config("nvme0n1").unwrap();
+        assert_eq!(nvme.scheduler, "none");
+        assert!(!nvme.rotational);
+
+        assert!(matches!(disk.disk_queue_config("sdz"), Err(Error::NotFound(_))));
+        assert!(matches!(disk.disk_queue_config("../sda"), Err(Error::Platform(_))));
+    }
+
+    #[test]
+    fn test_conntrack_stats_read_fixture_root() {
+        let stats = fixture_collector().network().conntrack_stats().unwrap();
+
+        assert_eq!(stats, ConntrackStats { count: 1843, max: 262144 });
+
+        let empty = std::env::temp_dir().join("probe-conntrack-missing");
+        let collector = LinuxCollector::new_with_roots(empty.join("proc"), empty.join("sys"));
+        assert!(matches!(collector.network().conntrack_stats(), Err(Error::NotSupported)));
+    }
+
+    #[test]
+    fn test_inotify_stats() {
+        let collector = fixture_collector();
+        let stats = collector.inotify_stats().unwrap();
+        assert_eq!(
+            (stats.max_user_instances, stats.max_user_watches, stats.max_queued_events),
+            (128, 65536, 16384)
+        );
+        // fds 3 and 5 of the fixture process (UID 1000) are the same instance
+        assert_eq!(procfs::count_user_inotify_instances(collector.root(), 1000).unwrap(), 1);
+        assert_eq!(procfs::count_user_inotify_instances(collector.root(), 1001).unwrap(), 0);
+
+        let live = LinuxCollector::new().inotify_stats().unwrap();
+        assert!(live.max_user_instances > 0);
+        assert!(live.max_user_watches >= live.max_user_instances);
+        assert!(live.max_queued_events > 0);
+    }
+
+    #[test]
+    fn test_list_modules_read_fixture_root() {
+        let modules = fixture_collector().list_modules().unwrap();
+        assert_eq!(modules.len(), 4);
+
+        assert_eq!(modules[0].name, "xt_conntrack");
+        assert!(modules[0].dependencies.is_empty());
+        assert_eq!(
+            modules[1],
+            KernelModule {
+                name: "nf_conntrack".into(),
+                size_bytes: 196608,
+                ref_count: 5,
+                state: crate::ModuleState::Live,
+                dependencies: vec!["xt_conntrack".into(), "nf_nat".into(), "xt_MASQUERADE".into()],
+            }
+        );
+        assert_eq!(modules[2].dependencies, ["nvidia_modeset", "nvidia_uvm"]);
+        assert_eq!(modules[3].state, crate::ModuleState::Loading);
+    }
+
+    #[test]
+    fn test_system_limits() {
+        // The fixture kernel is built without AIO
+        let limits = fixture_collector().system_limits().unwrap();
+        assert_eq!(
+            limits,
+            SystemLimits {
+                file_max: 9223372036854775807,
+                pid_max: 4194304,
+                threads_max: 126646,
+                max_map_count: 65530,
+                aio_max_nr: 0,
+            }
+        );
+
+        let live = LinuxCollector::new().system_limits().unwrap();
+        assert!(live.file_max > 0);
+        assert!(live.pid_max > 0);
+        assert!(live.threads_max > 0);
+        // Absent on kernels built without the feature
+        if std::path::Path::new("/proc/sys/vm/max_map_count").exists() {
+            assert!(live.max_map_count > 0);
+        }
+        if std::path::Path::new("/proc/sys/fs/aio-max-nr").exists() {
+            assert!(live.aio_max_nr > 0);
+        }
+    }
+
+    #[test]
+    fn test_namespace_ids_identify_namespaces() {
+        let ids = fixture_collector().namespace_ids().unwrap();
+        assert_eq!(
+            ids,
+            NamespaceIds {
+                net: 4026531840,
+                pid: 4026531836,
+                mnt: 4026531841,
+                ipc: 4026531839,
+                uts: 4026531838,
+                cgroup: 4026531835,
+            }
+        );
+
+        // Process 42 of the fixture runs in its own network namespace only
+        let fixtures = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/linux");
+        let root = ProcfsRoot::new(fixtures.join("proc"), fixtures.join("sys"));
+        let other = procfs::read_namespace_ids(&root, "42").unwrap();
+        assert_ne!(other.net, ids.net);
+        assert_eq!(other.pid, ids.pid);
+
+        // The link targets carry the inodes of the namespace files, so
+        // comparing them against init tells a container from the host
+        use std::os::unix::fs::MetadataExt;
+        let live = LinuxCollector::new().namespace_ids().unwrap();
+        assert_eq!(live.net, std::fs::metadata("/proc/self/ns/net").unwrap().ino());
+        if let Ok(init) = procfs::read_namespace_ids(&ProcfsRoot::default(), "1") {
+            assert_eq!(init.net, std::fs::metadata("/proc/1/ns/net").unwrap().ino());
+        }
+    }
+
+    #[test]
+    fn test_snmp_sections_read_fixture_root() {
+        let network = fixture_collector();
+        let udp = network.network().udp_stats().unwrap();
+        assert_eq!(
+            udp,
+            UdpProtoStats {
+                in_datagrams: 982341,
+                no_ports: 1203,
+                in_errors: 4588,
+                out_datagrams: 975002,
+                rcvbuf_errors: 4561,
+                sndbuf_errors: 12,
+                in_csum_errors: 27,
+            }
+        );
+
+        // IcmpMsg: and UdpLite: share a prefix with the sections read
+        let icmp = network.network().icmp_stats().unwrap();
+        assert_eq!(icmp.in_msgs, 4521);
+        assert_eq!(icmp.in_errors, 17);
+        assert_eq!(icmp.in_dest_unreachs, 3980);
+        assert_eq!(icmp.out_dest_unreachs, 3702);
+
+        let dir = tempfile::tempdir().unwrap();
+        let empty = LinuxCollector::new_with_roots(dir.path(), dir.path());
+        assert!(matches!(empty.network().udp_stats(), Err(Error::NotSupported)));
+    }
+
+    #[test]
+    fn test_listen_queue_stats_read_fixture_root() {
+        let stats = fixture_collector().network().listen_queue_stats().unwrap();
+        assert_eq!(stats, ListenQueueStats { listen_overflows: 1542, listen_drops: 1610 });
+
+        let ip_ext = procfs::parse_netstat_section(
+            &fixture_collector().read_raw(RawSource::NetNetstat).unwrap(),
+            "IpExt",
+        );
+        assert_eq!(ip_ext["InBcastPkts"], 3300);
+    }
+
+    #[test]
+    fn test_socket_memory_read_fixture_root() {
+        let stats = fixture_collector().network().socket_memory().unwrap();
+
+        assert_eq!(
+            stats,
+            SocketMemStats {
+                tcp_inuse: 48,
+                tcp_mem_pages: 27,
+                tcp_orphan: 3,
+                udp_inuse: 9,
+                udp_mem_pages: 6,
+                sockets_used: 1287,
+            }
+        );
+    }
+
+    #[test]
+    fn test_process_watcher_reports_child_start_and_exit() {
+        use std::sync::mpsc;
+
+        let collector = LinuxCollector::new();
+        let (started_tx, started_rx) = mpsc::channel();
+        let (exited_tx, exited_rx) = mpsc::channel();
+        let filter =
+            crate::ProcessFilter { name_contains: Some("sleep".to_string()), ..Default::default() };
+        let _watcher = crate::ProcessWatcher::start(
+            move || collector.process().list_identities(),
+            Duration::from_millis(20),
+            filter,
+            move |process| {
+                let _ = started_tx.send(process);
+            },
+            move |pid| {
+                let _ = exited_tx.send(pid);
+            },
+        )
+        .unwrap();
+
+        let mut child = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+        let pid = child.id() as i32;
+        let timeout = Duration::from_secs(5);
+        let started = std::iter::from_fn(|| started_rx.recv_timeout(timeout).ok())
+            .find(|process| process.pid == pid)
+            .expect("start not reported");
+        assert_eq!(started.name, "sleep");
+        assert!(started.start_time > 0);
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+        assert!(std::iter::from_fn(|| exited_rx.recv_timeout(timeout).ok()).any(|p| p == pid));
+    }
+
+    #[test]
+    fn test_mount_latency_of_local_mount() {
+        let collector = LinuxCollector::new();
+        let latency = collector.disk().probe_mount_latency("/").unwrap();
+        assert!(latency > Duration::ZERO);
+        assert!(latency < Duration::from_secs(1));
+
+        assert!(matches!(
+            collector.disk().probe_mount_latency("/nonexistent/mount"),
+            Err(Error::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_read_raw_fixture_root() {
+        let collector = fixture_collector();
+
+        let meminfo = collector.read_raw(RawSource::MemInfo).unwrap();
+        assert!(meminfo.starts_with("MemTotal:"), "{meminfo}");
+        let net_dev = collector.read_raw(RawSource::NetDev).unwrap();
+        assert!(net_dev.contains("eth0:"));
+
+        assert!(matches!(collector.read_raw(RawSource::ZoneInfo), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_zfs_arc_counts_as_available() {
+        let collector = fixture_collector();
+        let memory = collector.memory();
+        let arc = memory.zfs_arc().unwrap();
+        assert_eq!(arc, ZfsArcStats { size_bytes: 2_147_483_648, min_bytes: 536_870_912 });
+
+        let plain = memory.collect_system().unwrap();
+        let aware = memory.collect_system_zfs_aware().unwrap();
+        assert_eq!(aware.available_bytes, plain.available_bytes + arc.reclaimable_bytes());
+        assert_eq!(aware.used_bytes, plain.used_bytes - arc.reclaimable_bytes());
+        assert_eq!(aware.total_bytes, plain.total_bytes);
+    }
+
+    #[test]
+    fn test_swap_devices_read_fixture_root() {
+        let swaps = fixture_collector().memory().swap_devices().unwrap();
+        assert_eq!(swaps.len(), 3);
+
+        assert_eq!(
+            swaps[0],
+            SwapDevice {
+                path: "/dev/nvme0n1p3".into(),
+                kind: crate::SwapKind::Partition,
+                size_bytes: 8_388_604 * 1024,
+                used_bytes: 1_048_576 * 1024,
+                priority: -2,
+            }
+        );
+        assert_eq!(swaps[1].kind, crate::SwapKind::File);
+        assert_eq!(swaps[1].used_bytes, 0);
+        // zram reports as a partition and is told apart by its path
+        assert_eq!(swaps[2].path, "/dev/zram0");
+        assert_eq!(swaps[2].kind, crate::SwapKind::Zram);
+        assert_eq!(swaps[2].priority, 100);
+    }
+
+    #[test]
+    fn test_vmstat_read_fixture_root() {
+        let collector = fixture_collector();
+        let memory = collector.memory();
+
+        let vmstat = memory.vmstat().unwrap();
+        assert_eq!(vmstat.len(), 27);
+        assert_eq!(vmstat["numa_hit"], 912_345_678);
+        assert_eq!(vmstat["pswpout"], 4512);
+
+        assert_eq!(memory.page_faults().unwrap(), 734_512_098);
+        assert_eq!(memory.major_page_faults().unwrap(), 18234);
+        assert_eq!(memory.oom_kills().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_per_cpu_runqueue_read_fixture_root() {
+        let stats = fixture_collector().cpu().per_cpu_runqueue().unwrap();
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(
+            stats[0],
+            RunQueueStat {
+                cpu: 0,
+                yld_count: 12,
+                sched_count: 9876543,
+                run_time_ns: 1004586519123,
+                wait_time_ns: 76655588456,
+                timeslices: 1186823,
+            }
+        );
+        assert_eq!(stats[1].cpu, 1);
+        assert_eq!(stats[1].wait_time_ns, 81234567890);
+    }
+
+    #[test]
+    fn test_cpu_vulnerabilities_classify_fixture_root() {
+        let vulns = fixture_collector().cpu().cpu_vulnerabilities().unwrap();
+        let statuses: Vec<(&str, CpuVulnStatus)> =
+            vulns.iter().map(|v| (v.name.as_str(), v.status)).collect();
+
+        assert_eq!(
+            statuses,
+            vec![
+                ("itlb_multihit", CpuVulnStatus::Mitigated),
+                ("l1tf", CpuVulnStatus::NotAffected),
+                ("meltdown", CpuVulnStatus::Mitigated),
+                ("mmio_stale_data", CpuVulnStatus::Unknown),
+                ("spectre_v1", CpuVulnStatus::Mitigated),
+                ("spectre_v2", CpuVulnStatus::Vulnerable),
+                ("srbds", CpuVulnStatus::Vulnerable),
+            ]
+        );
+        assert_eq!(vulns[0].detail, "KVM: Mitigation: VMX disabled");
+        assert_eq!(vulns[2].detail, "Mitigation: PTI");
+
+        let empty = std::env::temp_dir().join("probe-vulnerabilities-missing");
+        let collector = LinuxCollector::new_with_roots(empty.join("proc"), empty.join("sys"));
+        assert!(matches!(collector.cpu().cpu_vulnerabilities(), Err(Error::NotSupported)));
+    }
+
+    #[test]
+    fn test_thermal_and_connections_read_fixture_roots() {
+        let root = fixture_collector().root().clone();
+
+        let thermal = LinuxThermalCollector::with_root(root.clone());
+        assert!(thermal.is_supported());
+        let zones = thermal.collect_temperatures().unwrap();
+        assert_eq!(zones.len(), 1);
+        assert_eq!(zones[0].label, "Package id 0");
+        assert_eq!(zones[0].temp_celsius, 45.0);
+        let cpu_temp = thermal.cpu_temperature().unwrap();
+        assert_eq!(cpu_temp.package_celsius, Some(45.0));
+        assert_eq!(cpu_temp.core_max_celsius, 45.0);
+
+        let wireless = LinuxWirelessCollector::with_root(root.clone());
+        assert!(wireless.list_interfaces().unwrap().is_empty());
+        assert!(matches!(wireless.collect("eth0"), Err(Error::NotSupported)));
+
+        let connections = LinuxConnectionCollector::with_root(root);
+        let tcp = connections.collect_tcp().unwrap();
+        assert_eq!(tcp.len(), 2);
+        let stats = connections.collect_tcp_stats().unwrap();
+        assert_eq!(stats.listen, 1);
+        assert_eq!(stats.established, 1);
+        assert_eq!(connections.collect_udp().unwrap().len(), 1);
+        let unix = connections.collect_unix().unwrap();
+        assert_eq!(unix.len(), 2);
+        assert_eq!(unix[0].path, "/run/fixture.sock");
+        assert!(!connections.ipv6_enabled());
+    }
+
+    #[test]
+    fn test_tcp_connections_by_remote_merges_ipv4_mapped_peers() {
+        let header = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n";
+        let dir = tempfile::tempdir().unwrap();
+        let net = dir.path().join("proc/net");
+        std::fs::create_dir_all(&net).unwrap();
+        // 10.0.0.1 connected over IPv4 and over a dual-stack IPv6 socket
+        std::fs::write(
+            net.join("tcp"),
+            format!(
+                "{header}   0: 0100007F:1F90 0100000A:C350 01 00000000:00000000 00:00000000 00000000     0        0 1001 1\n"
+            ),
+        )
+        .unwrap();
+        std::fs::write(
+            net.join("tcp6"),
+            format!(
+                "{header}   0: 00000000000000000000000000000000:1F90 0000000000000000FFFF00000100000A:C351 01 00000000:00000000 00:00000000 00000000     0        0 1002 1\n   \
+                 1: 00000000000000000000000000000000:1F90 B80D0120000000000000000002000000:C352 01 00000000:00000000 00:00000000 00000000     0        0 1003 1\n"
+            ),
+        )
+        .unwrap();
+
+        let root = ProcfsRoot::new(dir.path().join("proc"), dir.path().join("sys"));
+        let counts = LinuxConnectionCollector::with_root(root).tcp_connections_by_remote().unwrap();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["10.0.0.1"], 2);
+        assert_eq!(counts["2001:0db8:0000:0000:0000:0000:0000:0002"], 1);
+    }
+
+    #[test]
+    fn test_pid_netns_stats_match_own_namespace() {
+        let collector = LinuxCollector::new();
+        let pid = std::process::id() as i32;
+
+        let mut own: Vec<String> = collector
+            .network()
+            .collect_stats_for_pid_netns(pid)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.interface)
+            .collect();
+        let mut global: Vec<String> = collector
+            .network()
+            .collect_all_stats()
+            .unwrap()
+            .into_iter()
+            .map(|s| s.interface)
+            .collect();
+        own.sort();
+        global.sort();
+        assert_eq!(own, global);
+
+        assert!(matches!(
+            collector.network().collect_stats_for_pid_netns(i32::MAX),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_sched_policy_and_nice_of_current_process() {
+        let pid = std::process::id() as i32;
+        let metrics = LinuxCollector::new().process().collect(pid).unwrap();
+        let nice = unsafe { libc::getpriority(libc::PRIO_PROCESS, pid as libc::id_t) };
+
+        assert_eq!(metrics.sched_policy, SchedPolicy::Normal);
+        assert_eq!(i32::from(metrics.nice), nice);
+        assert_eq!(metrics.rt_priority, 0);
+    }
+
+    #[test]
+    fn test_list_threads_read_fixture_root() {
+        let threads = fixture_collector().process().list_threads(42).unwrap();
+        let us_per_tick = 1_000_000 / procfs::clock_ticks_per_sec();
+
+        assert_eq!(threads.iter().map(|t| t.tid).collect::<Vec<_>>(), [42, 43, 44]);
+        assert_eq!(threads[1].comm, "worker 1");
+        assert_eq!(threads[1].state, ProcessState::Running);
+        assert_eq!(threads[1].user_time_us, 900 * us_per_tick);
+        assert_eq!(threads[2].state, ProcessState::Waiting);
+        assert!(matches!(
+            fixture_collector().process().list_threads(i32::MAX),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_list_threads_of_multithreaded_process() {
+        let stop = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let worker = {
+            let stop = stop.clone();
+            std::thread::Builder::new()
+                .name("busy-worker".into())
+                .spawn(move || {
+                    while !stop.load(std::sync::atomic::Ordering::Relaxed) {
+                        std::hint::spin_loop();
+                    }
+                })
+                .unwrap()
+        };
+
+        let collector = LinuxCollector::new();
+        let pid = std::process::id() as i32;
+        let mut sampler = ThreadCpuSampler::new();
+        sampler.sample(&mut collector.process().list_threads(pid).unwrap());
+        std::thread::sleep(Duration::from_millis(200));
+        let mut threads = collector.process().list_threads(pid).unwrap();
+        sampler.sample(&mut threads);
+        stop.store(true, std::sync::atomic::Ordering::Relaxed);
+        worker.join().unwrap();
+
+        assert!(threads.len() > 1);
+        let busy = threads.iter().find(|t| t.comm == "busy-worker").unwrap();
+        assert!(busy.cpu_percent > 10.0, "{}", busy.cpu_percent);
+    }
+
+    #[test]
+    fn test_oom_info_of_current_process() {
+        let collector = LinuxCollector::new();
+        let info = collector.process().oom_info(std::process::id() as i32).unwrap();
+
+        assert!((0..=2000).contains(&info.oom_score));
+        assert!((-1000..=1000).contains(&info.oom_score_adj));
+        assert!(matches!(collector.process().oom_info(i32::MAX), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_oom_info_reads_procfs_root() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("42")).unwrap();
+        std::fs::write(dir.path().join("42/oom_score"), "667\n").unwrap();
+        std::fs::write(dir.path().join("42/oom_score_adj"), "-500\n").unwrap();
+        let collector = LinuxCollector::new_with_roots(dir.path(), dir.path());
+
+        let info = collector.process().oom_info(42).unwrap();
+        assert_eq!(info, OomInfo { oom_score: 667, oom_score_adj: -500 });
+        assert!(matches!(collector.process().oom_info(43), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_capabilities_read_fixture_root() {
+        let caps = fixture_collector().process().capability_sets(42).unwrap();
+
+        assert_eq!(caps.inheritable, 0);
+        assert_eq!(caps.permitted, 0x1ff_ffff_ffff);
+        assert_eq!(caps.effective_names(), ["CAP_NET_ADMIN", "CAP_NET_RAW"]);
+        // Full root caps decode to every known capability
+        assert_eq!(caps.permitted_names(), crate::CAPABILITY_NAMES);
+
+        let own =
+            LinuxCollector::new().process().capability_sets(std::process::id() as i32).unwrap();
+        assert_eq!(own.effective & !own.permitted, 0);
+        assert!(matches!(
+            fixture_collector().process().capability_sets(i32::MAX),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_identity_reads_start_time() {
+        let process = fixture_collector().process().identity(42).unwrap();
+        assert_eq!(process.name, "fixture proc");
+        // Field 22 of /proc/[pid]/stat
+        assert_eq!(process.start_time, 100);
+        assert!(matches!(
+            fixture_collector().process().identity(i32::MAX),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_container_root_matches_host_view() {
+        let pid = std::process::id() as i32;
+        // Same mount namespace: the host roots are used as-is
+        assert_eq!(LinuxCollector::for_container_pid(pid).root(), &ProcfsRoot::default());
+
+        let host = LinuxCollector::new();
+        let via_root =
+            LinuxCollector::new_with_roots("/proc/self/root/proc", "/proc/self/root/sys");
+        assert_eq!(
+            via_root.memory().collect_system().unwrap().total_bytes,
+            host.memory().collect_system().unwrap().total_bytes
+        );
+        assert_eq!(
+            via_root.cpu().collect_system().unwrap().cores,
+            host.cpu().collect_system().unwrap().cores
+        );
+        assert!(via_root.process().collect(pid).is_ok());
+    }
+
+    #[test]
+    fn test_unit_status_fixture_root() {
+        let collector = fixture_collector();
+        let state = |unit: &str| {
+            collector.unit_status(unit).map(|status| (status.active_state, status.sub_state))
+        };
+        let pair = |active: &str, sub: &str| (active.to_string(), sub.to_string());
+
+        assert_eq!(state("cron.service").unwrap(), pair("active", "running"));
+        assert_eq!(state("setup.service").unwrap(), pair("active", "exited"));
+        assert_eq!(state("backup.timer").unwrap(), pair("active", "waiting"));
+        assert_eq!(state("docker.socket").unwrap(), pair("active", "listening"));
+        // Without D-Bus a failed unit is indistinguishable from an inactive one
+        assert_eq!(state("failed-job.service").unwrap(), pair("inactive", "dead"));
+        assert!(matches!(state("probe-no-such-unit.service"), Err(Error::NotFound(_))));
+
+        // dbus.service is an alias of dbus-broker.service
+        assert_eq!(state("dbus.service").unwrap(), pair("active", "running"));
+        // Units in custom, nested slices
+        assert_eq!(state("batch-runner.service").unwrap(), pair("active", "running"));
+        // A masked unit links to /dev/null
+        assert_eq!(state("masked.service").unwrap(), pair("inactive", "dead"));
+    }
+
+    #[test]
+    fn test_unit_status_requires_systemd_boot() {
+        let empty = std::env::temp_dir().join("probe-systemd-missing");
+        let collector = LinuxCollector::new_with_roots(empty.join("proc"), empty.join("sys"));
+        assert!(matches!(collector.unit_status("cron.service"), Err(Error::NotSupported)));
+    }
+
+    #[test]
+    fn test_time_info_matches_localtime() {
+        let info = LinuxCollector::new().time_info().unwrap();
+
+        // `date +%z` prints the local offset as +HHMM or -HHMM
+        let output = std::process::Command::new("date").arg("+%z").output().unwrap();
+        let offset = String::from_utf8(output.stdout).unwrap();
+        let (sign, digits) = offset.trim().split_at(1);
+        let hours: i32 = digits[..2].parse().unwrap();
+        let minutes: i32 = digits[2..].parse().unwrap();
+        let expected = (hours * 3600 + minutes * 60) * if sign == "-" { -1 } else { 1 };
+
+        assert_eq!(info.utc_offset_secs, expected);
+        assert!(!info.timezone.is_empty());
+    }
+
+    #[test]
+    fn test_recent_kernel_errors_live() {
+        let collector = LinuxCollector::new();
+
+        // /dev/kmsg may be restricted (dmesg_restrict) or absent in containers
+        match collector.recent_kernel_errors(None) {
+            Ok(entries) => {
+                assert!(entries.len() <= MAX_KERNEL_LOG_ENTRIES);
+                assert!(entries.iter().all(|e| e.severity <= crate::LogSeverity::Error));
+            }
+            Err(e) => assert!(matches!(e, Error::Permission(_) | Error::NotSupported)),
+        }
+    }
+
+    #[test]
+    fn test_collect_all_records_collection_duration() {
+        let metrics = LinuxCollector::new().collect_all().unwrap();
+
+        assert!(metrics.timestamp_us > 0);
+        assert!(metrics.collection_duration_us > 0);
+    }
+
+    #[test]
+    fn test_runnable_tasks_reads_live_proc_stat() {
+        let collector = LinuxCollector::new();
+
+        // The test thread itself is running
+        assert!(collector.load().runnable_tasks().unwrap() >= 1);
+        assert!(collector.load().blocked_tasks().is_ok());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_collect_all_emits_timing_spans() {
+        use std::sync::Arc;
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        struct SpanNames(Arc<Mutex<Vec<&'static str>>>);
+
+        impl Subscriber for SpanNames {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut names = self.0.lock().unwrap();
+                names.push(span.metadata().name());
+                Id::from_u64(names.len() as u64)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let names = Arc::new(Mutex::new(Vec::new()));
+        tracing::subscriber::with_default(SpanNames(names.clone()), || {
+            let _ = SystemCollector::collect_all(&fixture_collector());
+        });
+
+        let names = names.lock().unwrap();
+        for expected in ["cpu.collect_system", "memory.collect_system", "load.collect", "read_file"]
+        {
+            assert!(names.contains(&expected), "missing span {expected}: {names:?}");
+        }
     }
 }
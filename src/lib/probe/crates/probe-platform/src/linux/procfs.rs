@@ -2,11 +2,33 @@
 //!
 //! Parses various files under /proc to collect system metrics.
 
-use crate::{Error, Result};
+use super::ProcfsRoot;
+use crate::{
+    BlockDevice, BlockDeviceKind, CacheKind, ConntrackStats, CoreUsage, CpuCache, CpuTicks,
+    CpuVuln, CpuVulnStatus, DeviceMapping, Error, IcmpStats, KernelModule, KernelStats,
+    ListenQueueStats, MemoryBreakdown, MemoryZone, ModuleState, NamespaceIds, OomInfo,
+    ProcessCapabilities, Result, RunQueueStat, SchedStats, SocketMemStats, SwapDevice, SwapKind,
+    SystemLimits, ThpDefrag, ThpMode, ThpStatus, UdpProtoStats, ZfsArcStats,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Read a procfs or sysfs file into a string.
+///
+/// With the `tracing` feature enabled each read is a `trace` span carrying
+/// the path, which makes slow mounts visible in collection timings.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip_all, fields(path = %path.as_ref().display()))
+)]
+pub(crate) fn read_file(path: impl AsRef<Path>) -> std::io::Result<String> {
+    fs::read_to_string(path)
+}
 
 /// CPU statistics from /proc/stat.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct ProcStat {
     user: u64,
     nice: u64,
@@ -16,13 +38,26 @@ pub struct ProcStat {
     irq: u64,
     softirq: u64,
     steal: u64,
+    guest: u64,
+    guest_nice: u64,
     total: u64,
+    ctxt: u64,
+    intr: u64,
+    processes: u64,
+    procs_running: u32,
+    procs_blocked: u32,
+    btime: u64,
 }
 
 impl ProcStat {
     /// Read and parse /proc/stat.
-    pub fn read() -> Result<Self> {
-        let content = fs::read_to_string("/proc/stat")?;
+    pub fn read(root: &ProcfsRoot) -> Result<Self> {
+        let content = read_file(root.proc_path("stat"))?;
+        Self::parse(&content)
+    }
+
+    /// Parse the contents of /proc/stat.
+    fn parse(content: &str) -> Result<Self> {
         let line =
             content.lines().next().ok_or_else(|| Error::Platform("empty /proc/stat".into()))?;
 
@@ -31,6 +66,50 @@ impl ProcStat {
             return Err(Error::Platform("invalid /proc/stat format".into()));
         }
 
+        let mut stat = Self::from_cpu_fields(&parts);
+
+        // Aggregate kernel counters; only the first value of "intr" is the total
+        for line in content.lines() {
+            let mut fields = line.split_whitespace();
+            let (Some(key), Some(value)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+            match key {
+                "ctxt" => stat.ctxt = value.parse().unwrap_or(0),
+                "intr" => stat.intr = value.parse().unwrap_or(0),
+                "processes" => stat.processes = value.parse().unwrap_or(0),
+                "procs_running" => stat.procs_running = value.parse().unwrap_or(0),
+                "procs_blocked" => stat.procs_blocked = value.parse().unwrap_or(0),
+                "btime" => stat.btime = value.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+
+        Ok(stat)
+    }
+
+    /// Read the per-core lines of /proc/stat, keyed by core ID.
+    pub fn read_per_core(root: &ProcfsRoot) -> Result<BTreeMap<u32, Self>> {
+        let content = read_file(root.proc_path("stat"))?;
+        Ok(Self::parse_per_core(&content))
+    }
+
+    /// Parse the `cpuN` lines of /proc/stat, keyed by N.
+    ///
+    /// Offline cores have no line, so IDs may have gaps.
+    fn parse_per_core(content: &str) -> BTreeMap<u32, Self> {
+        content
+            .lines()
+            .filter_map(|line| {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                let id = parts.first()?.strip_prefix("cpu")?.parse().ok()?;
+                (parts.len() >= 9).then(|| (id, Self::from_cpu_fields(&parts)))
+            })
+            .collect()
+    }
+
+    /// Build a sample from the fields of a `cpu` or `cpuN` line.
+    fn from_cpu_fields(parts: &[&str]) -> Self {
         let user: u64 = parts[1].parse().unwrap_or(0);
         let nice: u64 = parts[2].parse().unwrap_or(0);
         let system: u64 = parts[3].parse().unwrap_or(0);
@@ -39,10 +118,102 @@ impl ProcStat {
         let irq: u64 = parts[6].parse().unwrap_or(0);
         let softirq: u64 = parts[7].parse().unwrap_or(0);
         let steal: u64 = parts[8].parse().unwrap_or(0);
+        // Guest columns appeared in 2.6.24 and 2.6.33
+        let guest: u64 = parts.get(9).and_then(|v| v.parse().ok()).unwrap_or(0);
+        let guest_nice: u64 = parts.get(10).and_then(|v| v.parse().ok()).unwrap_or(0);
 
+        // Guest time is already accounted in user and nice, so it is not added again
         let total = user + nice + system + idle + iowait + irq + softirq + steal;
 
-        Ok(Self { user, nice, system, idle, iowait, irq, softirq, steal, total })
+        Self {
+            user,
+            nice,
+            system,
+            idle,
+            iowait,
+            irq,
+            softirq,
+            steal,
+            guest,
+            guest_nice,
+            total,
+            ..Default::default()
+        }
+    }
+
+    /// CPU time spent between `prev` and this sample.
+    ///
+    /// Percentages computed on the result cover only that interval. Kernel
+    /// counters are taken from this sample unchanged.
+    pub fn since(&self, prev: &Self) -> Self {
+        let user = self.user.saturating_sub(prev.user);
+        let nice = self.nice.saturating_sub(prev.nice);
+        let system = self.system.saturating_sub(prev.system);
+        let idle = self.idle.saturating_sub(prev.idle);
+        let iowait = self.iowait.saturating_sub(prev.iowait);
+        let irq = self.irq.saturating_sub(prev.irq);
+        let softirq = self.softirq.saturating_sub(prev.softirq);
+        let steal = self.steal.saturating_sub(prev.steal);
+
+        Self {
+            user,
+            nice,
+            system,
+            idle,
+            iowait,
+            irq,
+            softirq,
+            steal,
+            guest: self.guest.saturating_sub(prev.guest),
+            guest_nice: self.guest_nice.saturating_sub(prev.guest_nice),
+            total: user + nice + system + idle + iowait + irq + softirq + steal,
+            ..self.clone()
+        }
+    }
+
+    /// Usage of core `core_id` over this sample.
+    pub fn core_usage(&self, core_id: u32) -> CoreUsage {
+        CoreUsage {
+            core_id,
+            user_percent: self.user_percent(),
+            system_percent: self.system_percent(),
+            idle_percent: self.idle_percent(),
+            iowait_percent: self.iowait_percent(),
+            steal_percent: self.steal_percent(),
+        }
+    }
+
+    /// Whether any CPU time was accounted in this sample.
+    pub fn has_ticks(&self) -> bool {
+        self.total > 0
+    }
+
+    /// Cumulative CPU time counters of this sample.
+    pub fn ticks(&self) -> CpuTicks {
+        CpuTicks {
+            user: self.user,
+            nice: self.nice,
+            system: self.system,
+            idle: self.idle,
+            iowait: self.iowait,
+            irq: self.irq,
+            softirq: self.softirq,
+            steal: self.steal,
+            guest: self.guest,
+            guest_nice: self.guest_nice,
+        }
+    }
+
+    /// Kernel-wide activity counters captured alongside the CPU line.
+    pub fn kernel_stats(&self) -> KernelStats {
+        KernelStats {
+            context_switches_total: self.ctxt,
+            interrupts_total: self.intr,
+            processes_started_total: self.processes,
+            procs_running: self.procs_running,
+            procs_blocked: self.procs_blocked,
+            boot_time: self.btime,
+        }
     }
 
     /// User CPU percentage.
@@ -84,39 +255,210 @@ impl ProcStat {
         }
         self.steal as f64 / self.total as f64 * 100.0
     }
+
+    /// Guest percentage (time running virtual CPUs for guests).
+    pub fn guest_percent(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.guest as f64 / self.total as f64 * 100.0
+    }
+
+    /// Niced guest percentage.
+    pub fn guest_nice_percent(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.guest_nice as f64 / self.total as f64 * 100.0
+    }
+}
+
+/// Read only the `procs_running` and `procs_blocked` counters from /proc/stat.
+pub fn read_task_counts(root: &ProcfsRoot) -> Result<(u32, u32)> {
+    let content = read_file(root.proc_path("stat"))?;
+    let (mut running, mut blocked) = (None, None);
+
+    for line in content.lines() {
+        if let Some(value) = line.strip_prefix("procs_running ") {
+            running = value.trim().parse().ok();
+        } else if let Some(value) = line.strip_prefix("procs_blocked ") {
+            blocked = value.trim().parse().ok();
+        }
+        if running.is_some() && blocked.is_some() {
+            break;
+        }
+    }
+
+    match (running, blocked) {
+        (Some(running), Some(blocked)) => Ok((running, blocked)),
+        _ => Err(Error::Platform("procs_running/procs_blocked missing from /proc/stat".into())),
+    }
 }
 
 /// CPU information from /proc/cpuinfo.
 #[derive(Debug, Default)]
 pub struct CpuInfo {
-    /// Number of CPU cores.
+    /// Number of CPU cores (logical, one per `processor` entry).
     pub num_cores: u32,
+    /// Number of distinct (physical id, core id) pairs.
+    ///
+    /// Equals `num_cores` when the kernel does not report core ids.
+    pub physical_cores: u32,
     /// CPU frequency in MHz.
     pub frequency_mhz: u64,
 }
 
 impl CpuInfo {
     /// Read and parse /proc/cpuinfo.
-    pub fn read() -> Result<Self> {
-        let content = fs::read_to_string("/proc/cpuinfo")?;
+    pub fn read(root: &ProcfsRoot) -> Result<Self> {
+        Ok(Self::parse(&read_file(root.proc_path("cpuinfo"))?))
+    }
+
+    /// Parse the contents of /proc/cpuinfo.
+    pub fn parse(content: &str) -> Self {
         let mut num_cores = 0u32;
         let mut frequency_mhz = 0u64;
+        let mut package = 0u32;
+        let mut cores = std::collections::HashSet::new();
 
         for line in content.lines() {
+            let value = line.split(':').nth(1).map(str::trim);
             if line.starts_with("processor") {
                 num_cores += 1;
+                package = 0;
+            } else if line.starts_with("physical id")
+                && let Some(id) = value.and_then(|v| v.parse().ok())
+            {
+                package = id;
+            } else if line.starts_with("core id")
+                && let Some(id) = value.and_then(|v| v.parse::<u32>().ok())
+            {
+                cores.insert((package, id));
             } else if line.starts_with("cpu MHz")
-                && let Some(value) = line.split(':').nth(1)
-                && let Ok(freq) = value.trim().parse::<f64>()
+                && let Some(freq) = value.and_then(|v| v.parse::<f64>().ok())
             {
                 frequency_mhz = freq as u64;
             }
         }
 
-        Ok(Self { num_cores, frequency_mhz })
+        let physical_cores = if cores.is_empty() { num_cores } else { cores.len() as u32 };
+        Self { num_cores, physical_cores, frequency_mhz }
     }
 }
 
+/// Per-core usage between two per-core samples.
+///
+/// Cores are matched by ID, so cores going offline or online between the
+/// samples do not shift the others: a core missing from `previous` has no
+/// baseline and is skipped, and one missing from `current` is dropped.
+pub fn per_core_usage(
+    previous: &BTreeMap<u32, ProcStat>,
+    current: &BTreeMap<u32, ProcStat>,
+) -> Vec<CoreUsage> {
+    current
+        .iter()
+        .filter_map(|(&id, stat)| {
+            let delta = stat.since(previous.get(&id)?);
+            delta.has_ticks().then(|| delta.core_usage(id))
+        })
+        .collect()
+}
+
+/// Count the CPUs in a sysfs CPU list such as `0-3,6-7`.
+pub fn parse_cpu_range(list: &str) -> u32 {
+    list.trim()
+        .split(',')
+        .filter(|part| !part.is_empty())
+        .filter_map(|part| match part.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (start.parse::<u32>().ok()?, end.parse::<u32>().ok()?);
+                end.checked_sub(start).map(|n| n + 1)
+            }
+            None => part.parse::<u32>().ok().map(|_| 1),
+        })
+        .sum()
+}
+
+/// Read a CPU count from `/sys/devices/system/cpu/<name>` (`present` or `online`).
+pub fn read_cpu_count(root: &ProcfsRoot, name: &str) -> Option<u32> {
+    let content = read_file(root.sys_path(format!("devices/system/cpu/{name}"))).ok()?;
+    Some(parse_cpu_range(&content)).filter(|&n| n > 0)
+}
+
+/// Read CPU vulnerability reports from /sys/devices/system/cpu/vulnerabilities.
+///
+/// Entries are sorted by name. Returns `NotSupported` on kernels without the
+/// vulnerabilities directory (pre-4.15).
+pub fn read_cpu_vulnerabilities(root: &ProcfsRoot) -> Result<Vec<CpuVuln>> {
+    let entries =
+        fs::read_dir(root.sys_path("devices/system/cpu/vulnerabilities")).map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                Error::NotSupported
+            } else {
+                Error::Io(e)
+            }
+        })?;
+
+    let mut vulns: Vec<CpuVuln> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let detail = read_file(entry.path()).ok()?.trim().to_string();
+            Some(CpuVuln {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                status: CpuVulnStatus::classify(&detail),
+                detail,
+            })
+        })
+        .collect();
+    vulns.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(vulns)
+}
+
+/// Read the caches of cpu0 from /sys/devices/system/cpu/cpu0/cache/index*.
+///
+/// Returns `NotSupported` when the kernel exposes no cache information
+/// (e.g. some virtual machines and ARM boards).
+pub fn read_cpu_caches(root: &ProcfsRoot) -> Result<Vec<CpuCache>> {
+    let entries = fs::read_dir(root.sys_path("devices/system/cpu/cpu0/cache")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })?;
+
+    let mut caches: Vec<CpuCache> = entries
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("index"))
+        .filter_map(|entry| {
+            let path = entry.path();
+            let read = |name: &str| read_file(path.join(name)).ok().map(|s| s.trim().to_string());
+            let kind = match read("type")?.as_str() {
+                "Data" => CacheKind::Data,
+                "Instruction" => CacheKind::Instruction,
+                "Unified" => CacheKind::Unified,
+                _ => return None,
+            };
+            Some(CpuCache {
+                level: read("level")?.parse().ok()?,
+                kind,
+                size_bytes: parse_cache_size(&read("size")?)?,
+                shared_cpu_list: read("shared_cpu_list").unwrap_or_default(),
+            })
+        })
+        .collect();
+    caches.sort_by_key(|c| (c.level, c.kind as u8));
+    Ok(caches)
+}
+
+/// Parse a sysfs cache size such as `32K`, `1024K` or `16M` into bytes.
+fn parse_cache_size(size: &str) -> Option<u64> {
+    let size = size.trim();
+    let (digits, multiplier) = match size.as_bytes().last()? {
+        b'K' => (&size[..size.len() - 1], 1024),
+        b'M' => (&size[..size.len() - 1], 1024 * 1024),
+        b'G' => (&size[..size.len() - 1], 1024 * 1024 * 1024),
+        _ => (size, 1),
+    };
+    digits.parse::<u64>().ok().map(|n| n.saturating_mul(multiplier))
+}
+
 /// Memory information from /proc/meminfo.
 #[derive(Debug, Default)]
 pub struct MemInfo {
@@ -125,14 +467,16 @@ pub struct MemInfo {
     pub mem_available: u64,
     pub buffers: u64,
     pub cached: u64,
+    pub slab_reclaimable: u64,
     pub swap_total: u64,
     pub swap_free: u64,
+    pub anon_huge_pages: u64,
 }
 
 impl MemInfo {
     /// Read and parse /proc/meminfo.
-    pub fn read() -> Result<Self> {
-        let content = fs::read_to_string("/proc/meminfo")?;
+    pub fn read(root: &ProcfsRoot) -> Result<Self> {
+        let content = read_file(root.proc_path("meminfo"))?;
         let mut info = Self::default();
 
         for line in content.lines() {
@@ -150,8 +494,10 @@ impl MemInfo {
                 "MemAvailable:" => info.mem_available = value,
                 "Buffers:" => info.buffers = value,
                 "Cached:" => info.cached = value,
+                "SReclaimable:" => info.slab_reclaimable = value,
                 "SwapTotal:" => info.swap_total = value,
                 "SwapFree:" => info.swap_free = value,
+                "AnonHugePages:" => info.anon_huge_pages = value,
                 _ => {}
             }
         }
@@ -170,8 +516,8 @@ pub struct LoadAvg {
 
 impl LoadAvg {
     /// Read and parse /proc/loadavg.
-    pub fn read() -> Result<Self> {
-        let content = fs::read_to_string("/proc/loadavg")?;
+    pub fn read(root: &ProcfsRoot) -> Result<Self> {
+        let content = read_file(root.proc_path("loadavg"))?;
         let parts: Vec<&str> = content.split_whitespace().collect();
 
         if parts.len() < 3 {
@@ -186,39 +532,64 @@ impl LoadAvg {
     }
 }
 
+/// Map an I/O error reading `what` of process `pid`: a missing file means
+/// the process is gone.
+fn map_proc_io_err(e: std::io::Error, pid: i32, what: &str) -> Error {
+    match e.kind() {
+        std::io::ErrorKind::NotFound => Error::NotFound(format!("process {} not found", pid)),
+        std::io::ErrorKind::PermissionDenied => {
+            Error::Permission(format!("cannot read {} for pid {}", what, pid))
+        }
+        _ => Error::Io(e),
+    }
+}
+
 /// Process statistics from /proc/[pid]/stat.
 #[derive(Debug, Default)]
 pub struct ProcessStat {
-    /// Process ID (used for debugging/logging).
-    #[allow(dead_code)]
+    /// Process ID.
     pub pid: i32,
+    /// Command name (without parentheses).
+    pub comm: String,
     /// Process state character.
     pub state: char,
     /// Number of threads.
     pub num_threads: u32,
+    /// Cumulative minor page faults.
+    pub minflt: u64,
+    /// Cumulative major page faults.
+    pub majflt: u64,
     /// User time ticks (used for CPU percentage calculation).
-    #[allow(dead_code)]
     pub utime: u64,
     /// System time ticks (used for CPU percentage calculation).
-    #[allow(dead_code)]
     pub stime: u64,
+    /// Start time in clock ticks since boot.
+    pub start_time: u64,
+    /// Nice value.
+    pub nice: i8,
+    /// Real-time priority.
+    pub rt_priority: u32,
+    /// Scheduling policy (`SCHED_*` value).
+    pub policy: u32,
 }
 
 impl ProcessStat {
     /// Read and parse /proc/[pid]/stat.
-    pub fn read(pid: i32) -> Result<Self> {
-        let path = format!("/proc/{}/stat", pid);
-        let content = fs::read_to_string(&path).map_err(|e| {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                Error::NotFound(format!("process {} not found", pid))
-            } else {
-                Error::Io(e)
-            }
-        })?;
+    pub fn read(root: &ProcfsRoot, pid: i32) -> Result<Self> {
+        Self::read_path(&root.proc_path(format!("{}/stat", pid)), pid)
+    }
+
+    /// Read and parse /proc/[pid]/task/[tid]/stat; `pid` of the result is the TID.
+    pub fn read_thread(root: &ProcfsRoot, pid: i32, tid: i32) -> Result<Self> {
+        Self::read_path(&root.proc_path(format!("{}/task/{}/stat", pid, tid)), tid)
+    }
+
+    fn read_path(path: &Path, pid: i32) -> Result<Self> {
+        let content = read_file(path).map_err(|e| map_proc_io_err(e, pid, "stat"))?;
 
         // Format: pid (comm) state ...
         // Find the closing paren to handle commands with spaces
-        let _start = content
+        let start = content
             .find('(')
             .ok_or_else(|| Error::Platform(format!("invalid stat format for pid {}", pid)))?;
         let end = content
@@ -233,11 +604,49 @@ impl ProcessStat {
         }
 
         let state = fields[0].chars().next().unwrap_or('?');
+        let minflt: u64 = fields.get(7).and_then(|s| s.parse().ok()).unwrap_or(0);
+        let majflt: u64 = fields.get(9).and_then(|s| s.parse().ok()).unwrap_or(0);
         let utime: u64 = fields.get(11).and_then(|s| s.parse().ok()).unwrap_or(0);
         let stime: u64 = fields.get(12).and_then(|s| s.parse().ok()).unwrap_or(0);
         let num_threads: u32 = fields.get(17).and_then(|s| s.parse().ok()).unwrap_or(0);
+        let start_time: u64 = fields.get(19).and_then(|s| s.parse().ok()).unwrap_or(0);
+        let nice: i8 = fields.get(16).and_then(|s| s.parse().ok()).unwrap_or(0);
+        let rt_priority: u32 = fields.get(37).and_then(|s| s.parse().ok()).unwrap_or(0);
+        let policy: u32 = fields.get(38).and_then(|s| s.parse().ok()).unwrap_or(0);
+        let comm = content[start + 1..end].to_string();
 
-        Ok(Self { pid, state, num_threads, utime, stime })
+        Ok(Self {
+            pid,
+            comm,
+            state,
+            num_threads,
+            minflt,
+            majflt,
+            utime,
+            stime,
+            start_time,
+            nice,
+            rt_priority,
+            policy,
+        })
+    }
+}
+
+/// List the thread IDs of a process from /proc/[pid]/task.
+pub fn list_threads(root: &ProcfsRoot, pid: i32) -> Result<Vec<i32>> {
+    let entries = fs::read_dir(root.proc_path(format!("{}/task", pid)))
+        .map_err(|e| map_proc_io_err(e, pid, "threads"))?;
+    let mut tids: Vec<i32> =
+        entries.flatten().filter_map(|entry| entry.file_name().to_str()?.parse().ok()).collect();
+    tids.sort_unstable();
+    Ok(tids)
+}
+
+/// Clock ticks per second used by the tick counters of /proc/[pid]/stat.
+pub fn clock_ticks_per_sec() -> u64 {
+    match unsafe { libc::sysconf(libc::_SC_CLK_TCK) } {
+        ticks if ticks > 0 => ticks as u64,
+        _ => 100,
     }
 }
 
@@ -250,15 +659,9 @@ pub struct ProcessStatus {
 
 impl ProcessStatus {
     /// Read and parse /proc/[pid]/status.
-    pub fn read(pid: i32) -> Result<Self> {
-        let path = format!("/proc/{}/status", pid);
-        let content = fs::read_to_string(&path).map_err(|e| {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                Error::NotFound(format!("process {} not found", pid))
-            } else {
-                Error::Io(e)
-            }
-        })?;
+    pub fn read(root: &ProcfsRoot, pid: i32) -> Result<Self> {
+        let path = root.proc_path(format!("{}/status", pid));
+        let content = read_file(&path).map_err(|e| map_proc_io_err(e, pid, "status"))?;
 
         let mut status = Self::default();
 
@@ -283,19 +686,159 @@ impl ProcessStatus {
 }
 
 /// Count open file descriptors for a process.
-pub fn count_fds(pid: i32) -> Result<u32> {
-    let path = format!("/proc/{}/fd", pid);
-    let entries = fs::read_dir(&path).map_err(|e| {
-        if e.kind() == std::io::ErrorKind::NotFound {
-            Error::NotFound(format!("process {} not found", pid))
-        } else if e.kind() == std::io::ErrorKind::PermissionDenied {
-            Error::Permission(format!("cannot read fds for pid {}", pid))
-        } else {
+pub fn count_fds(root: &ProcfsRoot, pid: i32) -> Result<u32> {
+    let path = root.proc_path(format!("{}/fd", pid));
+    let entries = fs::read_dir(&path).map_err(|e| map_proc_io_err(e, pid, "fds"))?;
+
+    Ok(entries.count() as u32)
+}
+
+/// Sum the memory fields of /proc/[pid]/smaps or smaps_rollup content.
+///
+/// Both files use the same `Key: value kB` lines; smaps repeats them per
+/// mapping while smaps_rollup holds a single pre-summed block.
+fn parse_smaps(content: &str) -> MemoryBreakdown {
+    let mut breakdown = MemoryBreakdown::default();
+
+    for line in content.lines() {
+        let mut parts = line.split_whitespace();
+        let (Some(key), Some(value)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let field = match key {
+            "Rss:" => &mut breakdown.rss,
+            "Pss:" => &mut breakdown.pss,
+            "Shared_Clean:" => &mut breakdown.shared_clean,
+            "Shared_Dirty:" => &mut breakdown.shared_dirty,
+            "Private_Clean:" => &mut breakdown.private_clean,
+            "Private_Dirty:" => &mut breakdown.private_dirty,
+            "Swap:" => &mut breakdown.swap,
+            _ => continue,
+        };
+        // Values are in kB
+        *field += value.parse::<u64>().unwrap_or(0) * 1024;
+    }
+
+    breakdown
+}
+
+/// Read the memory breakdown of a process.
+///
+/// Uses /proc/[pid]/smaps_rollup (Linux 4.14+) and falls back to summing
+/// /proc/[pid]/smaps on older kernels.
+pub fn read_memory_breakdown(root: &ProcfsRoot, pid: i32) -> Result<MemoryBreakdown> {
+    let map_err = |e| map_proc_io_err(e, pid, "memory maps");
+
+    let content = match read_file(root.proc_path(format!("{}/smaps_rollup", pid))) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            read_file(root.proc_path(format!("{}/smaps", pid))).map_err(map_err)?
+        }
+        Err(e) => return Err(map_err(e)),
+    };
+
+    Ok(parse_smaps(&content))
+}
+
+/// Parse /proc/[pid]/schedstat: run time, run-queue wait time, timeslices.
+fn parse_schedstat(content: &str) -> Option<SchedStats> {
+    let mut values = content.split_whitespace().map(|v| v.parse::<u64>().ok());
+    Some(SchedStats {
+        run_ticks: values.next()??,
+        wait_ticks: values.next()??,
+        timeslices: values.next()??,
+    })
+}
+
+/// Read scheduler statistics from /proc/[pid]/schedstat.
+///
+/// Returns `NotSupported` if the process exists but the kernel was built
+/// without `CONFIG_SCHEDSTATS`.
+pub fn read_sched_stats(root: &ProcfsRoot, pid: i32) -> Result<SchedStats> {
+    let content = read_file(root.proc_path(format!("{}/schedstat", pid))).map_err(|e| {
+        if e.kind() != std::io::ErrorKind::NotFound {
             Error::Io(e)
+        } else if root.proc_path(pid.to_string()).exists() {
+            Error::NotSupported
+        } else {
+            Error::NotFound(format!("process {} not found", pid))
         }
     })?;
+    parse_schedstat(&content)
+        .ok_or_else(|| Error::Platform(format!("invalid schedstat format for pid {}", pid)))
+}
 
-    Ok(entries.count() as u32)
+/// Read per-CPU run queue statistics from /proc/schedstat.
+///
+/// Returns `NotSupported` if the kernel was built without `CONFIG_SCHEDSTATS`.
+pub fn read_runqueue_stats(root: &ProcfsRoot) -> Result<Vec<RunQueueStat>> {
+    let content = read_file(root.proc_path("schedstat")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })?;
+    Ok(content.lines().filter_map(parse_runqueue_line).collect())
+}
+
+/// Parse a per-CPU line of /proc/schedstat (version 15):
+/// "cpu0 yld_count 0 sched_count sched_goidle ttwu_count ttwu_local run_time wait_time timeslices"
+///
+/// Returns `None` for the version, timestamp and domain lines.
+fn parse_runqueue_line(line: &str) -> Option<RunQueueStat> {
+    let mut fields = line.split_whitespace();
+    let cpu = fields.next()?.strip_prefix("cpu")?.parse().ok()?;
+    let values: Vec<u64> = fields.map(|v| v.parse().ok()).collect::<Option<_>>()?;
+    if values.len() < 9 {
+        return None;
+    }
+    Some(RunQueueStat {
+        cpu,
+        yld_count: values[0],
+        sched_count: values[2],
+        run_time_ns: values[6],
+        wait_time_ns: values[7],
+        timeslices: values[8],
+    })
+}
+
+/// Read a single integer from /proc/[pid]/<name>.
+fn read_pid_i32(root: &ProcfsRoot, pid: i32, name: &str) -> Result<i32> {
+    let content = read_file(root.proc_path(format!("{}/{}", pid, name)))
+        .map_err(|e| map_proc_io_err(e, pid, name))?;
+    content
+        .trim()
+        .parse()
+        .map_err(|_| Error::Platform(format!("invalid {} format for pid {}", name, pid)))
+}
+
+/// Read the OOM killer score and adjustment of a process.
+pub fn read_oom_info(root: &ProcfsRoot, pid: i32) -> Result<OomInfo> {
+    Ok(OomInfo {
+        oom_score: read_pid_i32(root, pid, "oom_score")?,
+        oom_score_adj: read_pid_i32(root, pid, "oom_score_adj")?,
+    })
+}
+
+/// Read the capability sets of a process from /proc/[pid]/status.
+pub fn read_process_capabilities(root: &ProcfsRoot, pid: i32) -> Result<ProcessCapabilities> {
+    let content = read_file(root.proc_path(format!("{}/status", pid)))
+        .map_err(|e| map_proc_io_err(e, pid, "status"))?;
+    parse_capabilities(&content)
+        .ok_or_else(|| Error::Platform(format!("missing capability sets for pid {}", pid)))
+}
+
+/// Parse the `Cap*` hex masks of a /proc/[pid]/status file.
+fn parse_capabilities(content: &str) -> Option<ProcessCapabilities> {
+    let mask = |key: &str| {
+        content
+            .lines()
+            .find_map(|line| line.strip_prefix(key)?.strip_prefix(':'))
+            .and_then(|value| u64::from_str_radix(value.trim(), 16).ok())
+    };
+    Some(ProcessCapabilities {
+        inheritable: mask("CapInh")?,
+        permitted: mask("CapPrm")?,
+        effective: mask("CapEff")?,
+        bounding: mask("CapBnd")?,
+    })
 }
 
 // ============================================================================
@@ -303,8 +846,8 @@ pub fn count_fds(pid: i32) -> Result<u32> {
 // ============================================================================
 
 use crate::{
-    CPUPressure, DiskIOStats, DiskUsage, IOPressure, IOStats, MemoryPressure, NetInterface,
-    NetStats, Partition,
+    AllPressure, CPUPressure, DiskIOStats, DiskQueueConfig, DiskUsage, IOPressure, IOStats,
+    InotifyStats, MemoryPressure, NetInterface, NetStats, Partition,
 };
 
 /// Parse PSI line: "some avg10=0.00 avg60=0.00 avg300=0.00 total=0"
@@ -329,33 +872,25 @@ fn parse_psi_line(line: &str) -> (f64, f64, f64, u64) {
     (avg10, avg60, avg300, total)
 }
 
-/// Read CPU pressure from /proc/pressure/cpu.
-pub fn read_cpu_pressure() -> Result<CPUPressure> {
-    let content = fs::read_to_string("/proc/pressure/cpu").map_err(|e| {
-        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
-    })?;
-
+/// Parse CPU pressure file contents (only the "some" line is reported).
+fn parse_cpu_pressure(content: &str) -> CPUPressure {
     for line in content.lines() {
         if line.starts_with("some") {
             let (avg10, avg60, avg300, total) = parse_psi_line(line);
-            return Ok(CPUPressure {
+            return CPUPressure {
                 some_avg10: avg10,
                 some_avg60: avg60,
                 some_avg300: avg300,
                 some_total_us: total,
-            });
+            };
         }
     }
 
-    Ok(CPUPressure::default())
+    CPUPressure::default()
 }
 
-/// Read memory pressure from /proc/pressure/memory.
-pub fn read_memory_pressure() -> Result<MemoryPressure> {
-    let content = fs::read_to_string("/proc/pressure/memory").map_err(|e| {
-        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
-    })?;
-
+/// Parse memory pressure file contents.
+fn parse_memory_pressure(content: &str) -> MemoryPressure {
     let mut pressure = MemoryPressure::default();
 
     for line in content.lines() {
@@ -374,15 +909,11 @@ pub fn read_memory_pressure() -> Result<MemoryPressure> {
         }
     }
 
-    Ok(pressure)
+    pressure
 }
 
-/// Read I/O pressure from /proc/pressure/io.
-pub fn read_io_pressure() -> Result<IOPressure> {
-    let content = fs::read_to_string("/proc/pressure/io").map_err(|e| {
-        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
-    })?;
-
+/// Parse I/O pressure file contents.
+fn parse_io_pressure(content: &str) -> IOPressure {
     let mut pressure = IOPressure::default();
 
     for line in content.lines() {
@@ -401,7 +932,203 @@ pub fn read_io_pressure() -> Result<IOPressure> {
         }
     }
 
-    Ok(pressure)
+    pressure
+}
+
+/// Read a PSI file, mapping a missing file to `NotSupported`.
+fn read_pressure_file(path: &Path) -> Result<String> {
+    read_file(path).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })
+}
+
+/// Read CPU pressure from /proc/pressure/cpu.
+pub fn read_cpu_pressure(root: &ProcfsRoot) -> Result<CPUPressure> {
+    let content = read_pressure_file(&root.proc_path("pressure/cpu"))?;
+    Ok(parse_cpu_pressure(&content))
+}
+
+/// Read memory pressure from /proc/pressure/memory.
+pub fn read_memory_pressure(root: &ProcfsRoot) -> Result<MemoryPressure> {
+    let content = read_pressure_file(&root.proc_path("pressure/memory"))?;
+    Ok(parse_memory_pressure(&content))
+}
+
+/// Read I/O pressure from /proc/pressure/io.
+pub fn read_io_pressure(root: &ProcfsRoot) -> Result<IOPressure> {
+    let content = read_pressure_file(&root.proc_path("pressure/io"))?;
+    Ok(parse_io_pressure(&content))
+}
+
+/// Read pressure metrics from a cgroup v2 directory.
+///
+/// Reads `cpu.pressure`, `memory.pressure` and `io.pressure`. Returns
+/// `NotSupported` if any of them is absent (e.g. the root cgroup or a
+/// kernel built without PSI).
+pub fn read_cgroup_pressure(cgroup_dir: &Path) -> Result<AllPressure> {
+    let cpu = read_pressure_file(&cgroup_dir.join("cpu.pressure"))?;
+    let memory = read_pressure_file(&cgroup_dir.join("memory.pressure"))?;
+    let io = read_pressure_file(&cgroup_dir.join("io.pressure"))?;
+
+    Ok(AllPressure {
+        cpu: parse_cpu_pressure(&cpu),
+        memory: parse_memory_pressure(&memory),
+        io: parse_io_pressure(&io),
+    })
+}
+
+// ============================================================================
+// TRANSPARENT HUGE PAGES
+// ============================================================================
+
+/// Extract the selected value from a sysfs choice file.
+///
+/// Format: "always [madvise] never" (the active choice is bracketed).
+fn parse_sysfs_choice(content: &str) -> Option<&str> {
+    content
+        .split_whitespace()
+        .find_map(|word| word.strip_prefix('[').and_then(|w| w.strip_suffix(']')))
+}
+
+/// Read transparent huge page status from sysfs and /proc/meminfo.
+///
+/// Returns `NotSupported` if the kernel was built without THP.
+pub fn read_thp_status(root: &ProcfsRoot) -> Result<ThpStatus> {
+    let thp_dir = root.sys_path("kernel/mm/transparent_hugepage");
+    let enabled = read_file(thp_dir.join("enabled")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })?;
+    let defrag = read_file(thp_dir.join("defrag")).unwrap_or_default();
+
+    Ok(ThpStatus {
+        enabled: parse_sysfs_choice(&enabled).map(ThpMode::from_kernel_name).unwrap_or_default(),
+        defrag: parse_sysfs_choice(&defrag).map(ThpDefrag::from_kernel_name).unwrap_or_default(),
+        anon_hugepages_bytes: MemInfo::read(root).map(|m| m.anon_huge_pages).unwrap_or(0),
+    })
+}
+
+/// Parse /proc/zoneinfo into per-zone watermarks.
+///
+/// Each zone starts with a `Node N, zone NAME` header. Only the first
+/// `min`/`low`/`high` lines after it are used; newer kernels indent them
+/// under `pages free`, older ones list them at the same level.
+pub fn parse_zoneinfo(content: &str) -> Vec<MemoryZone> {
+    let mut zones: Vec<MemoryZone> = Vec::new();
+    let mut seen = [false; 3];
+
+    for line in content.lines() {
+        if let Some(rest) = line.strip_prefix("Node ") {
+            let Some((node, zone)) = rest.split_once(',') else { continue };
+            let Some(zone_name) = zone.trim().strip_prefix("zone") else { continue };
+            zones.push(MemoryZone {
+                node: node.trim().parse().unwrap_or(0),
+                zone_name: zone_name.trim().to_string(),
+                ..Default::default()
+            });
+            seen = [false; 3];
+            continue;
+        }
+
+        let Some(zone) = zones.last_mut() else { continue };
+        let mut parts = line.split_whitespace();
+        let (field, value) = match (parts.next(), parts.next(), parts.next()) {
+            (Some("pages"), Some("free"), Some(v)) => ("free", v),
+            (Some(k), Some(v), None) => (k, v),
+            _ => continue,
+        };
+        let Ok(value) = value.parse::<u64>() else { continue };
+
+        match field {
+            "free" => zone.free_pages = value,
+            "min" if !seen[0] => (zone.min_watermark, seen[0]) = (value, true),
+            "low" if !seen[1] => (zone.low_watermark, seen[1]) = (value, true),
+            "high" if !seen[2] => (zone.high_watermark, seen[2]) = (value, true),
+            _ => {}
+        }
+    }
+
+    zones
+}
+
+/// Read per-zone free pages and watermarks from /proc/zoneinfo.
+pub fn read_zone_info(root: &ProcfsRoot) -> Result<Vec<MemoryZone>> {
+    let content = read_file(root.proc_path("zoneinfo")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })?;
+    Ok(parse_zoneinfo(&content))
+}
+
+/// Read active swap areas from /proc/swaps.
+pub fn read_swap_devices(root: &ProcfsRoot) -> Result<Vec<SwapDevice>> {
+    let content = read_file(root.proc_path("swaps")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })?;
+    Ok(content.lines().skip(1).filter_map(parse_swap_line).collect())
+}
+
+/// Read the ZFS ARC size from /proc/spl/kstat/zfs/arcstats.
+///
+/// Returns `NotSupported` when the ZFS module is not loaded.
+pub fn read_zfs_arc(root: &ProcfsRoot) -> Result<ZfsArcStats> {
+    let content = read_file(root.proc_path("spl/kstat/zfs/arcstats")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })?;
+    Ok(parse_arcstats(&content))
+}
+
+/// Parse kstat "name type data" rows, skipping the two header lines.
+fn parse_arcstats(content: &str) -> ZfsArcStats {
+    let mut arc = ZfsArcStats::default();
+    for line in content.lines().skip(2) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [name, _, value] = fields[..] else { continue };
+        let Ok(value) = value.parse::<u64>() else { continue };
+        match name {
+            "size" => arc.size_bytes = value,
+            "c_min" => arc.min_bytes = value,
+            _ => {}
+        }
+    }
+    arc
+}
+
+/// Parse a /proc/swaps line (sizes in KiB):
+/// "/dev/zram0   partition   4046844   524288   100"
+fn parse_swap_line(line: &str) -> Option<SwapDevice> {
+    let mut fields = line.split_whitespace();
+    let path = fields.next()?.to_string();
+    let kind = match fields.next()? {
+        // zram devices report as partitions
+        _ if path.starts_with("/dev/zram") => SwapKind::Zram,
+        "file" => SwapKind::File,
+        _ => SwapKind::Partition,
+    };
+    Some(SwapDevice {
+        path,
+        kind,
+        size_bytes: fields.next()?.parse::<u64>().ok()? * 1024,
+        used_bytes: fields.next()?.parse::<u64>().ok()? * 1024,
+        priority: fields.next()?.parse().ok()?,
+    })
+}
+
+/// Parse /proc/vmstat `name value` lines.
+pub fn parse_vmstat(content: &str) -> HashMap<String, u64> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (name, value) = line.split_once(' ')?;
+            Some((name.to_string(), value.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+/// Read virtual memory counters from /proc/vmstat.
+pub fn read_vmstat(root: &ProcfsRoot) -> Result<HashMap<String, u64>> {
+    let content = read_file(root.proc_path("vmstat")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })?;
+    Ok(parse_vmstat(&content))
 }
 
 // ============================================================================
@@ -409,10 +1136,10 @@ pub fn read_io_pressure() -> Result<IOPressure> {
 // ============================================================================
 
 /// List all process IDs from /proc.
-pub fn list_processes() -> Result<Vec<i32>> {
+pub fn list_processes(root: &ProcfsRoot) -> Result<Vec<i32>> {
     let mut pids = Vec::new();
 
-    for entry in fs::read_dir("/proc")? {
+    for entry in fs::read_dir(root.proc())? {
         let entry = entry?;
         if let Some(name) = entry.file_name().to_str()
             && let Ok(pid) = name.parse::<i32>()
@@ -429,8 +1156,8 @@ pub fn list_processes() -> Result<Vec<i32>> {
 // ============================================================================
 
 /// Read mounted partitions from /proc/mounts.
-pub fn read_mounts() -> Result<Vec<Partition>> {
-    let content = fs::read_to_string("/proc/mounts")?;
+pub fn read_mounts(root: &ProcfsRoot) -> Result<Vec<Partition>> {
+    let content = read_file(root.proc_path("mounts"))?;
     let mut partitions = Vec::new();
 
     for line in content.lines() {
@@ -476,6 +1203,24 @@ pub fn read_mounts() -> Result<Vec<Partition>> {
     Ok(partitions)
 }
 
+/// Time a statvfs call on a path.
+pub fn time_statvfs(path: &str) -> Result<Duration> {
+    use std::ffi::CString;
+    use std::mem::MaybeUninit;
+
+    let c_path = CString::new(path).map_err(|_| Error::Platform("invalid path".into()))?;
+    let mut stat: MaybeUninit<libc::statvfs> = MaybeUninit::uninit();
+
+    let start = Instant::now();
+    let ret = unsafe { libc::statvfs(c_path.as_ptr(), stat.as_mut_ptr()) };
+    let elapsed = start.elapsed();
+
+    if ret != 0 {
+        return Err(Error::Io(std::io::Error::last_os_error()));
+    }
+    Ok(elapsed)
+}
+
 /// Read disk usage for a path using statvfs.
 pub fn read_disk_usage(path: &str) -> Result<DiskUsage> {
     use std::ffi::CString;
@@ -525,8 +1270,8 @@ pub fn read_disk_usage(path: &str) -> Result<DiskUsage> {
 }
 
 /// Read disk I/O statistics from /proc/diskstats.
-pub fn read_diskstats() -> Result<Vec<DiskIOStats>> {
-    let content = fs::read_to_string("/proc/diskstats")?;
+pub fn read_diskstats(root: &ProcfsRoot) -> Result<Vec<DiskIOStats>> {
+    let content = read_file(root.proc_path("diskstats"))?;
     let mut stats = Vec::new();
 
     for line in content.lines() {
@@ -566,32 +1311,155 @@ pub fn read_diskstats() -> Result<Vec<DiskIOStats>> {
     Ok(stats)
 }
 
+/// Read the physical block device inventory from /sys/block.
+///
+/// Virtual devices (loop, ram, device-mapper, zram) have no `device` link
+/// and are skipped. Sizes in /sys/block are in 512-byte sectors.
+pub fn read_block_devices(root: &ProcfsRoot) -> Result<Vec<BlockDevice>> {
+    let mut devices = Vec::new();
+
+    for entry in fs::read_dir(root.sys_path("block"))? {
+        let path = entry?.path();
+        if !path.join("device").exists() {
+            continue;
+        }
+
+        let read_trimmed =
+            |rel: &str| read_file(path.join(rel)).map(|s| s.trim().to_string()).unwrap_or_default();
+        let read_flag = |rel: &str| read_trimmed(rel) == "1";
+
+        devices.push(BlockDevice {
+            name: path.file_name().unwrap_or_default().to_string_lossy().into_owned(),
+            model: read_trimmed("device/model"),
+            serial: read_trimmed("device/serial"),
+            size_bytes: read_trimmed("size").parse::<u64>().unwrap_or(0).saturating_mul(512),
+            rotational: read_flag("queue/rotational"),
+            removable: read_flag("removable"),
+        });
+    }
+
+    devices.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(devices)
+}
+
+/// Read the request queue settings of a block device from
+/// /sys/block/<device>/queue.
+pub fn read_disk_queue_config(root: &ProcfsRoot, device: &str) -> Result<DiskQueueConfig> {
+    if device.is_empty() || device.contains('/') {
+        return Err(Error::Platform(format!("invalid block device name: {:?}", device)));
+    }
+    let queue = root.sys_path(format!("block/{}/queue", device));
+    let scheduler = read_file(queue.join("scheduler")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            Error::NotFound(format!("block device {} not found", device))
+        } else {
+            Error::Io(e)
+        }
+    })?;
+
+    let read_u32 = |name: &str| {
+        read_file(queue.join(name)).ok().and_then(|s| s.trim().parse().ok()).unwrap_or(0)
+    };
+    Ok(DiskQueueConfig {
+        // Devices with a single choice list it without brackets
+        scheduler: parse_sysfs_choice(&scheduler).unwrap_or(scheduler.trim()).to_string(),
+        nr_requests: read_u32("nr_requests"),
+        read_ahead_kb: read_u32("read_ahead_kb"),
+        rotational: read_u32("rotational") == 1,
+    })
+}
+
+/// Read the holder/slave graph of block devices and their partitions from
+/// /sys/block/*/{holders,slaves} and /sys/block/dm-*/dm/name.
+///
+/// Returns `Error::NotSupported` without /sys/block. Devices removed during
+/// the scan are left out.
+pub fn read_device_topology(root: &ProcfsRoot) -> Result<Vec<DeviceMapping>> {
+    let mut mappings = Vec::new();
+
+    let entries = fs::read_dir(root.sys_path("block")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })?;
+    for entry in entries {
+        let path = entry?.path();
+        let children = match fs::read_dir(&path) {
+            Ok(children) => children,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+        let name = path.file_name().unwrap_or_default().to_string_lossy().into_owned();
+        let kind = if name.starts_with("dm-") {
+            BlockDeviceKind::Dm
+        } else if name.starts_with("md") {
+            BlockDeviceKind::Md
+        } else {
+            BlockDeviceKind::Disk
+        };
+        mappings.push(read_device_mapping(&path, name, kind));
+
+        // Partitions are subdirectories with a `partition` attribute
+        for child in children.flatten() {
+            let child_path = child.path();
+            if child_path.join("partition").exists() {
+                let child_name = child.file_name().to_string_lossy().into_owned();
+                mappings.push(read_device_mapping(
+                    &child_path,
+                    child_name,
+                    BlockDeviceKind::Partition,
+                ));
+            }
+        }
+    }
+
+    mappings.sort_by(|a, b| a.device.cmp(&b.device));
+    Ok(mappings)
+}
+
+/// Read the holders, slaves and device-mapper name of one sysfs block device.
+fn read_device_mapping(path: &Path, device: String, kind: BlockDeviceKind) -> DeviceMapping {
+    let list = |rel: &str| -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(path.join(rel))
+            .map(|entries| {
+                entries.flatten().map(|e| e.file_name().to_string_lossy().into_owned()).collect()
+            })
+            .unwrap_or_default();
+        names.sort();
+        names
+    };
+    let dm_name = match kind {
+        BlockDeviceKind::Dm => read_file(path.join("dm/name"))
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty()),
+        _ => None,
+    };
+
+    DeviceMapping { device, kind, holders: list("holders"), slaves: list("slaves"), dm_name }
+}
+
 // ============================================================================
 // NETWORK METRICS
 // ============================================================================
 
 /// Read network interfaces from /sys/class/net.
-pub fn read_net_interfaces() -> Result<Vec<NetInterface>> {
+pub fn read_net_interfaces(root: &ProcfsRoot) -> Result<Vec<NetInterface>> {
     let mut interfaces = Vec::new();
 
-    for entry in fs::read_dir("/sys/class/net")? {
+    for entry in fs::read_dir(root.sys_path("class/net"))? {
         let entry = entry?;
         let name = entry.file_name().to_string_lossy().to_string();
         let iface_path = entry.path();
 
         // Read MAC address
-        let mac_address = fs::read_to_string(iface_path.join("address"))
-            .map(|s| s.trim().to_string())
-            .unwrap_or_default();
+        let mac_address =
+            read_file(iface_path.join("address")).map(|s| s.trim().to_string()).unwrap_or_default();
 
         // Read MTU
-        let mtu: u32 = fs::read_to_string(iface_path.join("mtu"))
-            .ok()
-            .and_then(|s| s.trim().parse().ok())
-            .unwrap_or(0);
+        let mtu: u32 =
+            read_file(iface_path.join("mtu")).ok().and_then(|s| s.trim().parse().ok()).unwrap_or(0);
 
         // Read flags to check if up
-        let flags: u32 = fs::read_to_string(iface_path.join("flags"))
+        let flags: u32 = read_file(iface_path.join("flags"))
             .ok()
             .and_then(|s| {
                 let s = s.trim().trim_start_matches("0x");
@@ -617,8 +1485,143 @@ pub fn read_net_interfaces() -> Result<Vec<NetInterface>> {
 }
 
 /// Read network statistics from /proc/net/dev.
-pub fn read_net_dev() -> Result<Vec<NetStats>> {
-    let content = fs::read_to_string("/proc/net/dev")?;
+pub fn read_net_dev(root: &ProcfsRoot) -> Result<Vec<NetStats>> {
+    Ok(parse_net_dev(&read_file(root.proc_path("net/dev"))?))
+}
+
+/// Read network statistics of the namespace `pid` lives in, from /proc/[pid]/net/dev.
+pub fn read_pid_net_dev(root: &ProcfsRoot, pid: i32) -> Result<Vec<NetStats>> {
+    let content = read_file(root.proc_path(format!("{}/net/dev", pid)))
+        .map_err(|e| map_proc_io_err(e, pid, "network namespace"))?;
+    Ok(parse_net_dev(&content))
+}
+
+/// Read conntrack table usage from /proc/sys/net/netfilter.
+///
+/// Returns `NotSupported` when the nf_conntrack module is not loaded.
+pub fn read_conntrack_stats(root: &ProcfsRoot) -> Result<ConntrackStats> {
+    let read = |name: &str| -> Result<u64> {
+        let content =
+            read_file(root.proc_path(format!("sys/net/netfilter/{}", name))).map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    Error::NotSupported
+                } else {
+                    Error::Io(e)
+                }
+            })?;
+        content.trim().parse().map_err(|_| Error::Platform(format!("invalid {} value", name)))
+    };
+
+    Ok(ConntrackStats { count: read("nf_conntrack_count")?, max: read("nf_conntrack_max")? })
+}
+
+/// Read socket usage from /proc/net/sockstat.
+pub fn read_socket_memory(root: &ProcfsRoot) -> Result<SocketMemStats> {
+    let content = read_file(root.proc_path("net/sockstat")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })?;
+    Ok(parse_sockstat(&content))
+}
+
+/// Parse the contents of /proc/net/sockstat.
+///
+/// Each line is a protocol label followed by `key value` pairs, e.g.
+/// `TCP: inuse 48 orphan 3 tw 112 alloc 61 mem 27`.
+fn parse_sockstat(content: &str) -> SocketMemStats {
+    let mut stats = SocketMemStats::default();
+
+    for line in content.lines() {
+        let Some((protocol, rest)) = line.split_once(':') else { continue };
+        let fields: Vec<&str> = rest.split_whitespace().collect();
+        for pair in fields.chunks_exact(2) {
+            let value = pair[1].parse().unwrap_or(0);
+            match (protocol, pair[0]) {
+                ("sockets", "used") => stats.sockets_used = value,
+                ("TCP", "inuse") => stats.tcp_inuse = value,
+                ("TCP", "orphan") => stats.tcp_orphan = value,
+                ("TCP", "mem") => stats.tcp_mem_pages = value,
+                ("UDP", "inuse") => stats.udp_inuse = value,
+                ("UDP", "mem") => stats.udp_mem_pages = value,
+                _ => {}
+            }
+        }
+    }
+
+    stats
+}
+
+/// Read TCP accept queue overflow counters from /proc/net/netstat.
+pub fn read_listen_queue_stats(root: &ProcfsRoot) -> Result<ListenQueueStats> {
+    let content = read_file(root.proc_path("net/netstat")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })?;
+    let tcp_ext = parse_netstat_section(&content, "TcpExt");
+    Ok(ListenQueueStats {
+        listen_overflows: tcp_ext.get("ListenOverflows").copied().unwrap_or(0),
+        listen_drops: tcp_ext.get("ListenDrops").copied().unwrap_or(0),
+    })
+}
+
+/// Read UDP counters from the `Udp:` section of /proc/net/snmp.
+pub fn read_udp_stats(root: &ProcfsRoot) -> Result<UdpProtoStats> {
+    let udp = parse_netstat_section(&read_snmp(root)?, "Udp");
+    let get = |name: &str| udp.get(name).copied().unwrap_or(0);
+    Ok(UdpProtoStats {
+        in_datagrams: get("InDatagrams"),
+        no_ports: get("NoPorts"),
+        in_errors: get("InErrors"),
+        out_datagrams: get("OutDatagrams"),
+        rcvbuf_errors: get("RcvbufErrors"),
+        sndbuf_errors: get("SndbufErrors"),
+        in_csum_errors: get("InCsumErrors"),
+    })
+}
+
+/// Read ICMP counters from the `Icmp:` section of /proc/net/snmp.
+pub fn read_icmp_stats(root: &ProcfsRoot) -> Result<IcmpStats> {
+    let icmp = parse_netstat_section(&read_snmp(root)?, "Icmp");
+    let get = |name: &str| icmp.get(name).copied().unwrap_or(0);
+    Ok(IcmpStats {
+        in_msgs: get("InMsgs"),
+        in_errors: get("InErrors"),
+        in_csum_errors: get("InCsumErrors"),
+        in_dest_unreachs: get("InDestUnreachs"),
+        in_time_excds: get("InTimeExcds"),
+        out_msgs: get("OutMsgs"),
+        out_errors: get("OutErrors"),
+        out_dest_unreachs: get("OutDestUnreachs"),
+    })
+}
+
+fn read_snmp(root: &ProcfsRoot) -> Result<String> {
+    read_file(root.proc_path("net/snmp")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })
+}
+
+/// Parse one section of /proc/net/netstat or /proc/net/snmp.
+///
+/// Each section is a header line of counter names followed by a line of
+/// values, both prefixed by the section name (e.g. `TcpExt:`).
+pub fn parse_netstat_section(content: &str, section: &str) -> HashMap<String, u64> {
+    let prefix = format!("{}:", section);
+    let mut lines = content.lines().filter_map(|line| line.strip_prefix(prefix.as_str()));
+    let (Some(names), Some(values)) = (lines.next(), lines.next()) else {
+        return HashMap::new();
+    };
+    names
+        .split_whitespace()
+        .zip(values.split_whitespace())
+        .filter_map(|(name, value)| Some((name.to_string(), value.parse().ok()?)))
+        .collect()
+}
+
+/// Parse the contents of a net/dev file.
+///
+/// Each line holds 8 receive columns (bytes, packets, errs, drop, fifo,
+/// frame, compressed, multicast) then 8 transmit columns (bytes, packets,
+/// errs, drop, fifo, colls, carrier, compressed).
+fn parse_net_dev(content: &str) -> Vec<NetStats> {
     let mut stats = Vec::new();
 
     for line in content.lines().skip(2) {
@@ -636,14 +1639,20 @@ pub fn read_net_dev() -> Result<Vec<NetStats>> {
             rx_packets: parts[2].parse().unwrap_or(0),
             rx_errors: parts[3].parse().unwrap_or(0),
             rx_drops: parts[4].parse().unwrap_or(0),
+            rx_fifo: parts[5].parse().unwrap_or(0),
+            rx_compressed: parts[7].parse().unwrap_or(0),
+            rx_multicast: parts[8].parse().unwrap_or(0),
             tx_bytes: parts[9].parse().unwrap_or(0),
             tx_packets: parts[10].parse().unwrap_or(0),
             tx_errors: parts[11].parse().unwrap_or(0),
             tx_drops: parts[12].parse().unwrap_or(0),
+            tx_fifo: parts[13].parse().unwrap_or(0),
+            tx_collisions: parts[14].parse().unwrap_or(0),
+            tx_compressed: parts[16].parse().unwrap_or(0),
         });
     }
 
-    Ok(stats)
+    stats
 }
 
 // ============================================================================
@@ -658,7 +1667,7 @@ use crate::ContextSwitches;
 
 /// Read system-wide context switch count from /proc/stat.
 pub fn read_system_context_switches() -> Result<u64> {
-    let content = fs::read_to_string("/proc/stat")?;
+    let content = read_file("/proc/stat")?;
 
     for line in content.lines() {
         if line.starts_with("ctxt ") {
@@ -675,13 +1684,7 @@ pub fn read_system_context_switches() -> Result<u64> {
 /// Read per-process context switches from /proc/[pid]/status.
 pub fn read_process_context_switches(pid: i32) -> Result<ContextSwitches> {
     let path = format!("/proc/{}/status", pid);
-    let content = fs::read_to_string(&path).map_err(|e| {
-        if e.kind() == std::io::ErrorKind::NotFound {
-            Error::NotFound(format!("process {} not found", pid))
-        } else {
-            Error::Io(e)
-        }
-    })?;
+    let content = read_file(&path).map_err(|e| map_proc_io_err(e, pid, "status"))?;
 
     let mut switches = ContextSwitches::default();
 
@@ -707,7 +1710,7 @@ pub fn read_process_context_switches(pid: i32) -> Result<ContextSwitches> {
 
 /// Read context switches for the current process.
 pub fn read_self_context_switches() -> Result<ContextSwitches> {
-    let content = fs::read_to_string("/proc/self/status")?;
+    let content = read_file("/proc/self/status")?;
 
     let mut switches = ContextSwitches::default();
 
@@ -730,20 +1733,181 @@ pub fn read_self_context_switches() -> Result<ContextSwitches> {
     Ok(switches)
 }
 
-/// Read system-wide I/O statistics (aggregated from diskstats).
-pub fn read_io_stats() -> Result<IOStats> {
-    let diskstats = read_diskstats()?;
+// ============================================================================
+// INOTIFY
+// ============================================================================
 
-    let mut stats = IOStats::default();
+/// Read inotify limits from /proc/sys/fs/inotify and count the inotify
+/// instances held by processes of the current user.
+pub fn read_inotify_stats(root: &ProcfsRoot) -> Result<InotifyStats> {
+    let read = |name: &str| -> Result<u64> {
+        let content =
+            read_file(root.proc_path(format!("sys/fs/inotify/{}", name))).map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    Error::NotSupported
+                } else {
+                    Error::Io(e)
+                }
+            })?;
+        content.trim().parse().map_err(|_| Error::Platform(format!("invalid {} value", name)))
+    };
+
+    Ok(InotifyStats {
+        max_user_instances: read("max_user_instances")?,
+        max_user_watches: read("max_user_watches")?,
+        max_queued_events: read("max_queued_events")?,
+        user_instances: count_user_inotify_instances(root, unsafe { libc::getuid() })?,
+    })
+}
 
-    for disk in diskstats {
-        stats.read_ops += disk.reads_completed;
-        stats.read_bytes += disk.read_bytes;
-        stats.write_ops += disk.writes_completed;
-        stats.write_bytes += disk.write_bytes;
+/// Count the inotify instances held by processes whose real UID is `uid`.
+///
+/// The kernel charges instances to the real UID, read from the `Uid:` line
+/// of /proc/[pid]/status. All inotify descriptors share one anonymous inode,
+/// so inherited and dup'ed descriptors are told apart by the watch list in
+/// /proc/[pid]/fdinfo; descriptors without watches are counted one by one.
+/// Processes that exit or whose descriptors are unreadable are skipped.
+pub fn count_user_inotify_instances(root: &ProcfsRoot, uid: u32) -> Result<u64> {
+    let mut watched = HashSet::new();
+    let mut unwatched = 0;
+    for pid in list_processes(root)? {
+        let pid_dir = root.proc_path(pid.to_string());
+        if read_real_uid(&pid_dir.join("status")) != Some(uid) {
+            continue;
+        }
+        let Ok(fds) = fs::read_dir(pid_dir.join("fd")) else {
+            continue;
+        };
+        for fd in fds.flatten() {
+            if !fs::read_link(fd.path())
+                .is_ok_and(|target| target.as_os_str() == "anon_inode:inotify")
+            {
+                continue;
+            }
+            let fdinfo = read_file(pid_dir.join("fdinfo").join(fd.file_name())).unwrap_or_default();
+            let watches: Vec<&str> =
+                fdinfo.lines().filter(|line| line.starts_with("inotify ")).collect();
+            if watches.is_empty() {
+                unwatched += 1;
+            } else {
+                watched.insert(watches.join("\n"));
+            }
+        }
     }
+    Ok(watched.len() as u64 + unwatched)
+}
 
-    Ok(stats)
+/// Real UID from the `Uid:` line of a /proc/[pid]/status file.
+fn read_real_uid(status: &Path) -> Option<u32> {
+    let content = read_file(status).ok()?;
+    let line = content.lines().find_map(|line| line.strip_prefix("Uid:"))?;
+    line.split_whitespace().next()?.parse().ok()
+}
+
+// ============================================================================
+// KERNEL MODULES
+// ============================================================================
+
+/// Read loaded kernel modules from /proc/modules.
+pub fn read_modules(root: &ProcfsRoot) -> Result<Vec<KernelModule>> {
+    let content = read_file(root.proc_path("modules")).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound { Error::NotSupported } else { Error::Io(e) }
+    })?;
+    Ok(content.lines().filter_map(parse_module_line).collect())
+}
+
+/// Parse a /proc/modules line:
+/// "nf_conntrack 196608 5 xt_conntrack,nf_nat, Live 0x0000000000000000 (E)"
+fn parse_module_line(line: &str) -> Option<KernelModule> {
+    let mut fields = line.split_whitespace();
+    let name = fields.next()?.to_string();
+    let size_bytes = fields.next()?.parse().ok()?;
+    // "-" when the kernel cannot unload modules
+    let ref_count = fields.next()?.parse().unwrap_or(0);
+    let dependencies = match fields.next()? {
+        "-" => Vec::new(),
+        list => list.split(',').filter(|d| !d.is_empty()).map(String::from).collect(),
+    };
+    let state = match fields.next()? {
+        "Loading" => ModuleState::Loading,
+        "Unloading" => ModuleState::Unloading,
+        _ => ModuleState::Live,
+    };
+    Some(KernelModule { name, size_bytes, ref_count, state, dependencies })
+}
+
+// ============================================================================
+// SYSTEM LIMITS
+// ============================================================================
+
+/// Read key system-wide limits from /proc/sys.
+///
+/// Limits the kernel was built without (e.g. aio-max-nr without
+/// CONFIG_AIO) are reported as 0.
+pub fn read_system_limits(root: &ProcfsRoot) -> Result<SystemLimits> {
+    let read = |name: &str| -> Result<u64> {
+        match read_file(root.proc_path(format!("sys/{}", name))) {
+            Ok(content) => content
+                .trim()
+                .parse()
+                .map_err(|_| Error::Platform(format!("invalid {} value", name))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+            Err(e) => Err(Error::Io(e)),
+        }
+    };
+
+    Ok(SystemLimits {
+        file_max: read("fs/file-max")?,
+        pid_max: read("kernel/pid_max")?,
+        threads_max: read("kernel/threads-max")?,
+        max_map_count: read("vm/max_map_count")?,
+        aio_max_nr: read("fs/aio-max-nr")?,
+    })
+}
+
+// ============================================================================
+// NAMESPACES
+// ============================================================================
+
+/// Read the namespace inodes of a process from /proc/[pid]/ns.
+///
+/// `pid` is a process ID or `self`. Namespace types the kernel does not
+/// provide (e.g. cgroup before 4.6) are reported as 0.
+pub fn read_namespace_ids(root: &ProcfsRoot, pid: &str) -> Result<NamespaceIds> {
+    let ns_dir = root.proc_path(format!("{}/ns", pid));
+    if !ns_dir.is_dir() {
+        return Err(Error::NotSupported);
+    }
+
+    let read = |name: &str| -> Result<u64> {
+        match fs::read_link(ns_dir.join(name)) {
+            Ok(target) => parse_namespace_link(&target.to_string_lossy()).ok_or_else(|| {
+                Error::Platform(format!("invalid {} namespace link: {}", name, target.display()))
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+            Err(e) => Err(Error::Io(e)),
+        }
+    };
+
+    Ok(NamespaceIds {
+        net: read("net")?,
+        pid: read("pid")?,
+        mnt: read("mnt")?,
+        ipc: read("ipc")?,
+        uts: read("uts")?,
+        cgroup: read("cgroup")?,
+    })
+}
+
+/// Parse a namespace link target: "net:[4026531840]" -> 4026531840
+fn parse_namespace_link(target: &str) -> Option<u64> {
+    let (_, rest) = target.split_once(":[")?;
+    rest.strip_suffix(']')?.parse().ok()
+}
+
+/// Read system-wide I/O statistics (aggregated from diskstats).
+pub fn read_io_stats(root: &ProcfsRoot) -> Result<IOStats> {
+    Ok(IOStats::from_disks(&read_diskstats(root)?))
 }
 
 #[cfg(test)]
@@ -776,4 +1940,494 @@ mod context_switch_tests {
             assert!(switches.system_total > 0);
         }
     }
+
+    #[test]
+    fn test_read_sched_stats() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("42")).unwrap();
+        fs::create_dir_all(dir.path().join("43")).unwrap();
+        fs::write(dir.path().join("42/schedstat"), "2338425317 81295047 4012\n").unwrap();
+        let root = ProcfsRoot::new(dir.path(), dir.path());
+
+        let stats = read_sched_stats(&root, 42).unwrap();
+        assert_eq!(
+            stats,
+            SchedStats { run_ticks: 2_338_425_317, wait_ticks: 81_295_047, timeslices: 4012 }
+        );
+        assert_eq!(stats.avg_wait_per_timeslice().round(), 20263.0);
+
+        assert!(matches!(read_sched_stats(&root, 43), Err(Error::NotSupported)));
+        assert!(matches!(read_sched_stats(&root, 44), Err(Error::NotFound(_))));
+        assert!(parse_schedstat("123 456").is_none());
+    }
+}
+
+#[cfg(test)]
+mod cache_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cache_size() {
+        assert_eq!(parse_cache_size("48K"), Some(48 * 1024));
+        assert_eq!(parse_cache_size("1280K\n"), Some(1280 * 1024));
+        assert_eq!(parse_cache_size("30M"), Some(30 * 1024 * 1024));
+        assert_eq!(parse_cache_size("512"), Some(512));
+        assert_eq!(parse_cache_size(&format!("{}G", u64::MAX)), Some(u64::MAX));
+        assert_eq!(parse_cache_size("K"), None);
+        assert_eq!(parse_cache_size(""), None);
+    }
+
+    #[test]
+    fn test_read_cpu_caches() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join("devices/system/cpu/cpu0/cache");
+        for (index, level, kind, size, shared) in [
+            ("index0", "1", "Data", "48K", "0,8"),
+            ("index1", "1", "Instruction", "32K", "0,8"),
+            ("index2", "2", "Unified", "1280K", "0,8"),
+            ("index3", "3", "Unified", "30M", "0-15"),
+        ] {
+            let path = cache.join(index);
+            fs::create_dir_all(&path).unwrap();
+            fs::write(path.join("level"), format!("{level}\n")).unwrap();
+            fs::write(path.join("type"), format!("{kind}\n")).unwrap();
+            fs::write(path.join("size"), format!("{size}\n")).unwrap();
+            fs::write(path.join("shared_cpu_list"), format!("{shared}\n")).unwrap();
+        }
+        // Not a cache index
+        fs::write(cache.join("uevent"), "").unwrap();
+
+        let root = ProcfsRoot::new("/proc", dir.path());
+        let caches = read_cpu_caches(&root).unwrap();
+        assert_eq!(caches.len(), 4);
+        assert_eq!(
+            caches[0],
+            CpuCache {
+                level: 1,
+                kind: CacheKind::Data,
+                size_bytes: 48 * 1024,
+                shared_cpu_list: "0,8".into(),
+            }
+        );
+        assert_eq!(caches[1].kind, CacheKind::Instruction);
+        assert_eq!(caches[2].size_bytes, 1280 * 1024);
+        assert_eq!(caches[3].level, 3);
+        assert_eq!(caches[3].size_bytes, 30 * 1024 * 1024);
+        assert_eq!(caches[3].shared_cpu_list, "0-15");
+
+        let missing = ProcfsRoot::new("/proc", dir.path().join("missing"));
+        assert!(matches!(read_cpu_caches(&missing), Err(Error::NotSupported)));
+    }
+}
+
+#[cfg(test)]
+mod topology_tests {
+    use super::*;
+
+    #[test]
+    fn test_read_device_topology_lvm_on_raid() {
+        let dir = tempfile::tempdir().unwrap();
+        let block = dir.path().join("block");
+        let mkdirs = |rel: &str| fs::create_dir_all(block.join(rel)).unwrap();
+
+        // sda1 + sdb1 -> md0 -> dm-0 (vg0-root)
+        for disk in ["sda", "sdb"] {
+            mkdirs(&format!("{disk}/holders"));
+            mkdirs(&format!("{disk}/slaves"));
+            mkdirs(&format!("{disk}/{disk}1/holders/md0"));
+            fs::write(block.join(format!("{disk}/{disk}1/partition")), "1\n").unwrap();
+            mkdirs(&format!("md0/slaves/{disk}1"));
+        }
+        mkdirs("md0/holders/dm-0");
+        mkdirs("dm-0/holders");
+        mkdirs("dm-0/slaves/md0");
+        mkdirs("dm-0/dm");
+        fs::write(block.join("dm-0/dm/name"), "vg0-root\n").unwrap();
+
+        let root = ProcfsRoot::new("/proc", dir.path());
+        let topology = read_device_topology(&root).unwrap();
+        let names: Vec<&str> = topology.iter().map(|m| m.device.as_str()).collect();
+        assert_eq!(names, ["dm-0", "md0", "sda", "sda1", "sdb", "sdb1"]);
+
+        assert_eq!(
+            topology[0],
+            DeviceMapping {
+                device: "dm-0".into(),
+                kind: BlockDeviceKind::Dm,
+                holders: vec![],
+                slaves: vec!["md0".into()],
+                dm_name: Some("vg0-root".into()),
+            }
+        );
+        assert_eq!(topology[1].kind, BlockDeviceKind::Md);
+        assert_eq!(topology[1].holders, ["dm-0"]);
+        assert_eq!(topology[1].slaves, ["sda1", "sdb1"]);
+        assert_eq!(topology[1].dm_name, None);
+        assert_eq!(topology[2].kind, BlockDeviceKind::Disk);
+        assert!(topology[2].holders.is_empty());
+        assert_eq!(topology[3].kind, BlockDeviceKind::Partition);
+        assert_eq!(topology[3].holders, ["md0"]);
+
+        // A device unplugged mid-scan leaves a dangling /sys/block link
+        std::os::unix::fs::symlink("../devices/gone/sdz", block.join("sdz")).unwrap();
+        assert_eq!(read_device_topology(&root).unwrap().len(), 6);
+
+        let missing = ProcfsRoot::new("/proc", dir.path().join("missing"));
+        assert!(matches!(read_device_topology(&missing), Err(Error::NotSupported)));
+    }
+}
+
+#[cfg(test)]
+mod pressure_tests {
+    use super::*;
+
+    #[test]
+    fn test_read_cgroup_pressure() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("cpu.pressure"),
+            "some avg10=1.50 avg60=0.75 avg300=0.25 total=123456\n\
+             full avg10=0.00 avg60=0.00 avg300=0.00 total=0\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("memory.pressure"),
+            "some avg10=2.00 avg60=1.00 avg300=0.50 total=1000\n\
+             full avg10=1.00 avg60=0.50 avg300=0.10 total=500\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("io.pressure"),
+            "some avg10=3.00 avg60=2.00 avg300=1.00 total=42\n\
+             full avg10=2.50 avg60=1.50 avg300=0.50 total=21\n",
+        )
+        .unwrap();
+
+        let pressure = read_cgroup_pressure(dir.path()).unwrap();
+        assert_eq!(pressure.cpu.some_avg10, 1.5);
+        assert_eq!(pressure.cpu.some_total_us, 123456);
+        assert_eq!(pressure.memory.full_avg60, 0.5);
+        assert_eq!(pressure.memory.full_total_us, 500);
+        assert_eq!(pressure.io.some_avg300, 1.0);
+        assert_eq!(pressure.io.full_total_us, 21);
+    }
+
+    #[test]
+    fn test_read_cgroup_pressure_missing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(matches!(read_cgroup_pressure(dir.path()), Err(Error::NotSupported)));
+    }
+}
+
+#[cfg(test)]
+mod proc_stat_tests {
+    use super::*;
+
+    const PROC_STAT: &str = "\
+cpu  10132153 290696 3084719 46828483 16683 0 25195 0 0 0
+cpu0 1393280 32966 572056 13343292 6130 0 17875 0 0 0
+intr 199292440 12 0 0 0 0 0 0 0 1 0 0 0
+ctxt 362915373
+btime 1700000000
+processes 401234
+procs_running 3
+procs_blocked 1
+softirq 12345 0 1 2 3 4 5 6 7 8 9
+";
+
+    #[test]
+    fn test_parse_kernel_stats() {
+        let stat = ProcStat::parse(PROC_STAT).unwrap();
+        let ks = stat.kernel_stats();
+
+        assert_eq!(ks.context_switches_total, 362915373);
+        assert_eq!(ks.interrupts_total, 199292440);
+        assert_eq!(ks.processes_started_total, 401234);
+        assert_eq!(ks.procs_running, 3);
+        assert_eq!(ks.procs_blocked, 1);
+        assert_eq!(ks.boot_time, 1700000000);
+        assert!(stat.idle_percent() > 0.0);
+    }
+
+    #[test]
+    fn test_parse_steal_and_guest() {
+        let stat = ProcStat::parse("cpu  400 100 200 200 0 0 0 100 50 25\n").unwrap();
+
+        assert_eq!(stat.steal_percent(), 10.0);
+        assert_eq!(stat.guest_percent(), 5.0);
+        assert_eq!(stat.guest_nice_percent(), 2.5);
+    }
+
+    #[test]
+    fn test_steal_from_interval_delta() {
+        let prev = ProcStat::parse("cpu  400 100 200 200 0 0 0 100 50 25\n").unwrap();
+        let curr = ProcStat::parse("cpu  460 100 200 220 0 0 0 120 70 25\n").unwrap();
+        let delta = curr.since(&prev);
+
+        assert_eq!(delta.steal_percent(), 20.0);
+        assert_eq!(delta.guest_percent(), 20.0);
+        assert_eq!(delta.guest_nice_percent(), 0.0);
+        assert_eq!(delta.idle_percent(), 20.0);
+    }
+
+    #[test]
+    fn test_per_core_usage_across_hotplug() {
+        let previous = ProcStat::parse_per_core(
+            "cpu  600 0 0 600 0 0 0 0 0 0\n\
+             cpu0 100 0 0 100 0 0 0 0 0 0\n\
+             cpu1 200 0 0 200 0 0 0 0 0 0\n\
+             cpu2 300 0 0 300 0 0 0 0 0 0\n",
+        );
+        // cpu1 went offline and cpu3 came online
+        let current = ProcStat::parse_per_core(
+            "cpu  900 0 0 900 0 0 0 0 0 0\n\
+             cpu0 150 0 0 150 0 0 0 0 0 0\n\
+             cpu2 375 0 0 325 0 0 0 0 0 0\n\
+             cpu3 10 0 0 10 0 0 0 0 0 0\n\
+             intr 1 0\n",
+        );
+        assert_eq!(current.keys().copied().collect::<Vec<_>>(), vec![0, 2, 3]);
+
+        let usage = per_core_usage(&previous, &current);
+        assert_eq!(usage.iter().map(|u| u.core_id).collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(usage[0].user_percent, 50.0);
+        assert_eq!(usage[1].user_percent, 75.0);
+
+        // cpu3 gets a delta once it has a baseline
+        let next = ProcStat::parse_per_core("cpu3 30 0 0 20 0 0 0 0 0 0\n");
+        let usage = per_core_usage(&current, &next);
+        assert_eq!(usage.len(), 1);
+        assert_eq!((usage[0].core_id, usage[0].user_percent), (3, 66.66666666666666));
+        assert!(per_core_usage(&BTreeMap::new(), &next).is_empty());
+    }
+
+    #[test]
+    fn test_parse_cpu_range() {
+        assert_eq!(parse_cpu_range("0-3,6-7\n"), 6);
+        assert_eq!(parse_cpu_range("0"), 1);
+        assert_eq!(parse_cpu_range("0,2,4-5"), 4);
+        assert_eq!(parse_cpu_range(""), 0);
+    }
+
+    #[test]
+    fn test_physical_cores_dedup_smt_siblings() {
+        let cpuinfo = (0..8)
+            .map(|cpu| {
+                format!(
+                    "processor\t: {cpu}\nphysical id\t: {}\ncore id\t\t: {}\ncpu MHz\t\t: 3000.000\n\n",
+                    cpu / 4,
+                    cpu % 2
+                )
+            })
+            .collect::<String>();
+        let info = CpuInfo::parse(&cpuinfo);
+
+        assert_eq!(info.num_cores, 8);
+        assert_eq!(info.physical_cores, 4);
+        assert_eq!(info.frequency_mhz, 3000);
+    }
+
+    #[test]
+    fn test_physical_cores_without_core_ids() {
+        let info = CpuInfo::parse("processor\t: 0\n\nprocessor\t: 1\n");
+        assert_eq!(info.physical_cores, 2);
+    }
+
+    #[test]
+    fn test_read_kernel_stats() {
+        let ks = ProcStat::read(&ProcfsRoot::default()).unwrap().kernel_stats();
+        assert!(ks.boot_time > 0);
+        assert!(ks.context_switches_total > 0);
+    }
+}
+
+#[cfg(test)]
+mod smaps_tests {
+    use super::*;
+
+    const SMAPS_ROLLUP: &str = "\
+55d0c8a4e000-7ffd5e1f9000 ---p 00000000 00:00 0                          [rollup]
+Rss:               16480 kB
+Pss:                6213 kB
+Pss_Anon:           3708 kB
+Pss_File:           2505 kB
+Pss_Shmem:             0 kB
+Shared_Clean:      11328 kB
+Shared_Dirty:          0 kB
+Private_Clean:      1444 kB
+Private_Dirty:      3708 kB
+Referenced:        16480 kB
+Anonymous:          3708 kB
+LazyFree:              0 kB
+AnonHugePages:         0 kB
+ShmemPmdMapped:        0 kB
+FilePmdMapped:         0 kB
+Shared_Hugetlb:        0 kB
+Private_Hugetlb:       0 kB
+Swap:                 64 kB
+SwapPss:              32 kB
+Locked:                0 kB
+";
+
+    #[test]
+    fn test_parse_smaps_rollup() {
+        let breakdown = parse_smaps(SMAPS_ROLLUP);
+
+        assert_eq!(breakdown.rss, 16480 * 1024);
+        assert_eq!(breakdown.pss, 6213 * 1024);
+        assert_eq!(breakdown.shared_clean, 11328 * 1024);
+        assert_eq!(breakdown.shared_dirty, 0);
+        assert_eq!(breakdown.private_clean, 1444 * 1024);
+        assert_eq!(breakdown.private_dirty, 3708 * 1024);
+        assert_eq!(breakdown.swap, 64 * 1024);
+    }
+
+    #[test]
+    fn test_fallback_sums_smaps() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("42")).unwrap();
+        fs::write(
+            dir.path().join("42/smaps"),
+            "Rss: 100 kB\nPss: 50 kB\nSwap: 0 kB\nRss: 20 kB\nPss: 20 kB\nSwap: 8 kB\n",
+        )
+        .unwrap();
+        let root = ProcfsRoot::new(dir.path(), dir.path());
+
+        let breakdown = read_memory_breakdown(&root, 42).unwrap();
+        assert_eq!(breakdown.rss, 120 * 1024);
+        assert_eq!(breakdown.pss, 70 * 1024);
+        assert_eq!(breakdown.swap, 8 * 1024);
+
+        assert!(matches!(read_memory_breakdown(&root, 43), Err(Error::NotFound(_))));
+    }
+}
+
+#[cfg(test)]
+mod thp_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sysfs_choice() {
+        assert_eq!(parse_sysfs_choice("[always] madvise never\n"), Some("always"));
+        assert_eq!(
+            parse_sysfs_choice("always defer [defer+madvise] madvise never"),
+            Some("defer+madvise")
+        );
+        assert_eq!(parse_sysfs_choice("always madvise never"), None);
+    }
+
+    #[test]
+    fn test_read_thp_status() {
+        let dir = tempfile::tempdir().unwrap();
+        let thp_dir = dir.path().join("sys/kernel/mm/transparent_hugepage");
+        fs::create_dir_all(&thp_dir).unwrap();
+        fs::create_dir_all(dir.path().join("proc")).unwrap();
+        fs::write(thp_dir.join("enabled"), "always [madvise] never\n").unwrap();
+        fs::write(thp_dir.join("defrag"), "always defer defer+madvise madvise [never]\n").unwrap();
+        fs::write(dir.path().join("proc/meminfo"), "MemTotal: 1000 kB\nAnonHugePages: 4096 kB\n")
+            .unwrap();
+
+        let root = ProcfsRoot::new(dir.path().join("proc"), dir.path().join("sys"));
+        let status = read_thp_status(&root).unwrap();
+        assert_eq!(status.enabled, ThpMode::MadviseOnly);
+        assert_eq!(status.defrag, ThpDefrag::Never);
+        assert_eq!(status.anon_hugepages_bytes, 4096 * 1024);
+
+        fs::remove_file(thp_dir.join("enabled")).unwrap();
+        assert!(matches!(read_thp_status(&root), Err(Error::NotSupported)));
+    }
+}
+
+#[cfg(test)]
+mod zoneinfo_tests {
+    use super::*;
+
+    // Trimmed capture from a two-node machine (kernel 6.x layout).
+    const ZONEINFO: &str = "\
+Node 0, zone      DMA
+  per-node stats
+      nr_inactive_anon 47207
+      nr_active_anon 3
+  pages free     3840
+        boost    0
+        min      42
+        low      52
+        high     62
+        promo    72
+        spanned  4095
+        present  3998
+        managed  3840
+        cma      0
+        protection: (0, 2910, 15851, 15851, 15851)
+      nr_free_pages 3840
+Node 0, zone    DMA32
+  pages free     712034
+        boost    0
+        min      7983
+        low      9978
+        high     11973
+        spanned  1044480
+        protection: (0, 0, 12940, 12940, 12940)
+Node 0, zone   Normal
+  pages free     1104
+        boost    0
+        min      35509
+        low      44386
+        high     53263
+        protection: (0, 0, 0, 0, 0)
+Node 0, zone  Movable
+  pages free     0
+        boost    0
+        min      0
+        low      0
+        high     0
+Node 1, zone   Normal
+  pages free     2013443
+        min      36012
+        low      45015
+        high     54018
+";
+
+    #[test]
+    fn test_parse_zoneinfo() {
+        let zones = parse_zoneinfo(ZONEINFO);
+        assert_eq!(zones.len(), 5);
+
+        assert_eq!(
+            zones[0],
+            MemoryZone {
+                node: 0,
+                zone_name: "DMA".to_string(),
+                free_pages: 3840,
+                min_watermark: 42,
+                low_watermark: 52,
+                high_watermark: 62,
+            }
+        );
+        assert_eq!(zones[1].zone_name, "DMA32");
+        assert_eq!(zones[1].free_pages, 712034);
+        assert!(zones[2].below_low());
+        assert_eq!(zones[3].high_watermark, 0);
+        assert_eq!(zones[4].node, 1);
+        assert_eq!(zones[4].high_watermark, 54018);
+        assert!(!zones[4].below_low());
+    }
+
+    #[test]
+    fn test_parse_zoneinfo_legacy_layout() {
+        let zones = parse_zoneinfo(
+            "Node 0, zone   Normal\n  pages free     500\n  min      10\n  low      20\n  high     30\n  scanned  0\n",
+        );
+        assert_eq!(zones.len(), 1);
+        assert_eq!(zones[0].free_pages, 500);
+        assert_eq!(zones[0].low_watermark, 20);
+        assert_eq!(zones[0].high_watermark, 30);
+    }
+
+    #[test]
+    fn test_read_zone_info_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = ProcfsRoot::new(dir.path(), dir.path());
+        assert!(matches!(read_zone_info(&root), Err(Error::NotSupported)));
+    }
 }
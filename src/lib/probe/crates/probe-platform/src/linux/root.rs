@@ -0,0 +1,67 @@
+//! Filesystem roots for procfs and sysfs.
+//!
+//! Allows pointing the Linux backend at fixture trees in tests, or at
+//! another mount namespace's procfs (e.g. `/proc/<pid>/root/proc`).
+
+use std::path::{Path, PathBuf};
+
+/// Location of the procfs and sysfs mounts read by the Linux collectors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcfsRoot {
+    proc: PathBuf,
+    sys: PathBuf,
+}
+
+impl ProcfsRoot {
+    /// Create a root configuration from explicit procfs and sysfs paths.
+    pub fn new(proc_root: impl Into<PathBuf>, sys_root: impl Into<PathBuf>) -> Self {
+        Self { proc: proc_root.into(), sys: sys_root.into() }
+    }
+
+    /// Roots of the filesystem seen by process `host_pid`, e.g. a container.
+    ///
+    /// Reads go through `/proc/<pid>/root/proc` and `/proc/<pid>/root/sys`.
+    /// When the process shares this process's mount namespace, the default
+    /// roots are returned since both views are the same.
+    pub fn for_container_pid(host_pid: i32) -> Self {
+        let ns = |pid: &str| std::fs::read_link(format!("/proc/{}/ns/mnt", pid)).ok();
+        if ns(&host_pid.to_string()).is_some_and(|target| Some(target) == ns("self")) {
+            return Self::default();
+        }
+
+        let base = PathBuf::from(format!("/proc/{}/root", host_pid));
+        Self::new(base.join("proc"), base.join("sys"))
+    }
+
+    /// Procfs mount point.
+    pub fn proc(&self) -> &Path {
+        &self.proc
+    }
+
+    /// Sysfs mount point.
+    pub fn sys(&self) -> &Path {
+        &self.sys
+    }
+
+    /// Path of `rel` under the procfs root (e.g. `"stat"` -> `/proc/stat`).
+    pub fn proc_path(&self, rel: impl AsRef<Path>) -> PathBuf {
+        self.proc.join(rel)
+    }
+
+    /// Path of `rel` under the sysfs root (e.g. `"class/net"` -> `/sys/class/net`).
+    pub fn sys_path(&self, rel: impl AsRef<Path>) -> PathBuf {
+        self.sys.join(rel)
+    }
+
+    /// Path of `rel` in the filesystem procfs is mounted in (e.g.
+    /// `"run/systemd"` -> `/run/systemd`), for files outside procfs and sysfs.
+    pub fn fs_path(&self, rel: impl AsRef<Path>) -> PathBuf {
+        self.proc.parent().unwrap_or(Path::new("/")).join(rel)
+    }
+}
+
+impl Default for ProcfsRoot {
+    fn default() -> Self {
+        Self::new("/proc", "/sys")
+    }
+}
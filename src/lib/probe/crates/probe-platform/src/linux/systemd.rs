@@ -0,0 +1,175 @@
+//! systemd unit status.
+//!
+//! With the `systemd` feature, states of the host's units are queried from
+//! systemd over D-Bus. Otherwise, and for any other [`ProcfsRoot`], they are
+//! inferred from `/run/systemd/units` and the unit's cgroup, which cannot
+//! tell a failed unit from an inactive one nor report the main PID.
+//!
+//! All paths are resolved through [`ProcfsRoot`], so a container's or a
+//! fixture's view of `/run` and the unit directories is used.
+
+use super::ProcfsRoot;
+use crate::{Error, Result, UnitStatus};
+use std::path::{Path, PathBuf};
+
+/// System unit directories, by decreasing precedence.
+const UNIT_DIRS: [&str; 4] =
+    ["etc/systemd/system", "run/systemd/system", "usr/lib/systemd/system", "lib/systemd/system"];
+
+/// Whether the host was booted with systemd (as `sd_booted()` checks).
+fn is_systemd_booted(root: &ProcfsRoot) -> bool {
+    root.fs_path("run/systemd/system").is_dir()
+}
+
+/// Read the status of a systemd unit (e.g. `dbus.service`).
+pub fn read_unit_status(root: &ProcfsRoot, unit: &str) -> Result<UnitStatus> {
+    if !is_systemd_booted(root) {
+        return Err(Error::NotSupported);
+    }
+    if unit.is_empty() || unit.contains('/') {
+        return Err(Error::Platform(format!("invalid unit name: {:?}", unit)));
+    }
+
+    // The system bus only reaches the host's systemd
+    #[cfg(feature = "systemd")]
+    if *root == ProcfsRoot::default() {
+        return dbus::unit_status(unit);
+    }
+
+    unit_status_from_runtime(root, unit)
+}
+
+fn unit_status_from_runtime(root: &ProcfsRoot, unit: &str) -> Result<UnitStatus> {
+    // Runtime state is recorded under the unit's own name, not its aliases
+    let unit = resolve_alias(root, unit);
+    let unit = unit.as_str();
+
+    // systemd links an invocation ID while the unit is active or activating
+    let invocation = root.fs_path(format!("run/systemd/units/invocation:{}", unit));
+    let active = invocation.symlink_metadata().is_ok();
+    let running = find_unit_cgroup(&root.sys_path("fs/cgroup"), unit).is_some_and(|cgroup| {
+        std::fs::read_to_string(cgroup.join("cgroup.procs"))
+            .is_ok_and(|pids| !pids.trim().is_empty())
+    });
+
+    if !active && !running && !unit_file_exists(root, unit) {
+        return Err(Error::NotFound(format!("unit {} not found", unit)));
+    }
+
+    let (active_state, sub_state) = match (active, running) {
+        (_, true) => ("active", "running"),
+        (true, false) => ("active", idle_sub_state(unit)),
+        (false, false) => ("inactive", "dead"),
+    };
+    Ok(UnitStatus {
+        active_state: active_state.to_string(),
+        sub_state: sub_state.to_string(),
+        main_pid: 0,
+    })
+}
+
+/// Name of the unit `unit` is an alias of, or `unit` itself.
+///
+/// Aliases are symlinks in the unit directories to the unit file (e.g.
+/// `dbus.service` -> `dbus-broker.service`). Links to `/dev/null` mask the
+/// unit and are not aliases.
+fn resolve_alias(root: &ProcfsRoot, unit: &str) -> String {
+    let mut name = unit.to_string();
+    // Bounded in case of a symlink loop
+    for _ in 0..UNIT_DIRS.len() {
+        let target = UNIT_DIRS.iter().find_map(|dir| {
+            let target = std::fs::read_link(root.fs_path(dir).join(&name)).ok()?;
+            target.file_name()?.to_str().map(str::to_string)
+        });
+        match target {
+            Some(target) if target != name && target != "null" => name = target,
+            _ => break,
+        }
+    }
+    name
+}
+
+/// Cgroup of `unit` under a cgroup v2 mount: either at the top level (e.g.
+/// `init.scope`) or in any slice, nested slices included.
+fn find_unit_cgroup(dir: &Path, unit: &str) -> Option<PathBuf> {
+    let cgroup = dir.join(unit);
+    if cgroup.is_dir() {
+        return Some(cgroup);
+    }
+    std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .filter(|entry| entry.file_name().to_str().is_some_and(|name| name.ends_with(".slice")))
+        .find_map(|slice| find_unit_cgroup(&slice.path(), unit))
+}
+
+/// Sub-state of an active unit without running processes, by unit type.
+fn idle_sub_state(unit: &str) -> &'static str {
+    match unit.rsplit_once('.').map(|(_, kind)| kind) {
+        Some("timer" | "path" | "automount") => "waiting",
+        Some("socket") => "listening",
+        Some("mount") => "mounted",
+        Some("device") => "plugged",
+        Some("target" | "slice" | "swap") => "active",
+        // Oneshot services with RemainAfterExit=yes
+        _ => "exited",
+    }
+}
+
+/// Whether a unit file exists in one of the system unit directories.
+fn unit_file_exists(root: &ProcfsRoot, unit: &str) -> bool {
+    UNIT_DIRS.iter().any(|dir| root.fs_path(dir).join(unit).exists())
+}
+
+#[cfg(feature = "systemd")]
+mod dbus {
+    use crate::{Error, Result, UnitStatus};
+    use zbus::blocking::{Connection, Proxy};
+    use zbus::zvariant::OwnedObjectPath;
+
+    const DESTINATION: &str = "org.freedesktop.systemd1";
+
+    fn dbus_error(e: zbus::Error) -> Error {
+        match e {
+            zbus::Error::MethodError(ref name, _, _)
+                if name.as_str() == "org.freedesktop.DBus.Error.AccessDenied" =>
+            {
+                Error::Permission(format!("D-Bus: {}", e))
+            }
+            _ => Error::Platform(format!("D-Bus: {}", e)),
+        }
+    }
+
+    pub fn unit_status(unit: &str) -> Result<UnitStatus> {
+        let conn = Connection::system().map_err(dbus_error)?;
+        let manager = Proxy::new(
+            &conn,
+            DESTINATION,
+            "/org/freedesktop/systemd1",
+            "org.freedesktop.systemd1.Manager",
+        )
+        .map_err(dbus_error)?;
+        let path: OwnedObjectPath = manager.call("LoadUnit", &(unit,)).map_err(dbus_error)?;
+
+        let unit_proxy = Proxy::new(&conn, DESTINATION, &path, "org.freedesktop.systemd1.Unit")
+            .map_err(dbus_error)?;
+        let load_state: String = unit_proxy.get_property("LoadState").map_err(dbus_error)?;
+        if load_state == "not-found" {
+            return Err(Error::NotFound(format!("unit {} not found", unit)));
+        }
+
+        let main_pid = if unit.ends_with(".service") {
+            Proxy::new(&conn, DESTINATION, &path, "org.freedesktop.systemd1.Service")
+                .and_then(|service| service.get_property("MainPID"))
+                .map_err(dbus_error)?
+        } else {
+            0
+        };
+
+        Ok(UnitStatus {
+            active_state: unit_proxy.get_property("ActiveState").map_err(dbus_error)?,
+            sub_state: unit_proxy.get_property("SubState").map_err(dbus_error)?,
+            main_pid,
+        })
+    }
+}
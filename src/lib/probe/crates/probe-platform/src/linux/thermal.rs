@@ -2,9 +2,10 @@
 //!
 //! Reads temperature sensors from hwmon interface.
 
+use super::ProcfsRoot;
+use super::procfs::read_file;
 use crate::{Error, Result, ThermalZone};
 use std::fs;
-use std::path::Path;
 
 /// Read temperature sensors from /sys/class/hwmon.
 ///
@@ -16,14 +17,19 @@ use std::path::Path;
 /// - /sys/class/hwmon/hwmon*/temp*_max - Max safe temp (optional)
 /// - /sys/class/hwmon/hwmon*/temp*_crit - Critical temp (optional)
 pub fn read_thermal_zones() -> Result<Vec<ThermalZone>> {
-    let hwmon_path = Path::new("/sys/class/hwmon");
+    read_thermal_zones_at(&ProcfsRoot::default())
+}
+
+/// Read temperature sensors from `class/hwmon` under the given sysfs root.
+pub fn read_thermal_zones_at(root: &ProcfsRoot) -> Result<Vec<ThermalZone>> {
+    let hwmon_path = root.sys_path("class/hwmon");
     if !hwmon_path.exists() {
         return Err(Error::NotSupported);
     }
 
     let mut zones = Vec::new();
 
-    let entries = fs::read_dir(hwmon_path)?;
+    let entries = fs::read_dir(&hwmon_path)?;
     for entry in entries.flatten() {
         let hwmon_dir = entry.path();
         if !hwmon_dir.is_dir() {
@@ -31,9 +37,8 @@ pub fn read_thermal_zones() -> Result<Vec<ThermalZone>> {
         }
 
         // Read device name
-        let name = fs::read_to_string(hwmon_dir.join("name"))
-            .map(|s| s.trim().to_string())
-            .unwrap_or_default();
+        let name =
+            read_file(hwmon_dir.join("name")).map(|s| s.trim().to_string()).unwrap_or_default();
 
         // Find all temp*_input files
         if let Ok(files) = fs::read_dir(&hwmon_dir) {
@@ -44,7 +49,7 @@ pub fn read_thermal_zones() -> Result<Vec<ThermalZone>> {
                     let prefix = file_name.trim_end_matches("_input");
 
                     // Read temperature (in millidegrees Celsius)
-                    let temp_millidegrees: i64 = fs::read_to_string(file.path())
+                    let temp_millidegrees: i64 = read_file(file.path())
                         .ok()
                         .and_then(|s| s.trim().parse().ok())
                         .unwrap_or(0);
@@ -52,18 +57,18 @@ pub fn read_thermal_zones() -> Result<Vec<ThermalZone>> {
                     let temp_celsius = temp_millidegrees as f64 / 1000.0;
 
                     // Read label (optional)
-                    let label = fs::read_to_string(hwmon_dir.join(format!("{}_label", prefix)))
+                    let label = read_file(hwmon_dir.join(format!("{}_label", prefix)))
                         .map(|s| s.trim().to_string())
                         .unwrap_or_default();
 
                     // Read max temperature (optional)
-                    let temp_max = fs::read_to_string(hwmon_dir.join(format!("{}_max", prefix)))
+                    let temp_max = read_file(hwmon_dir.join(format!("{}_max", prefix)))
                         .ok()
                         .and_then(|s| s.trim().parse::<i64>().ok())
                         .map(|t| t as f64 / 1000.0);
 
                     // Read critical temperature (optional)
-                    let temp_crit = fs::read_to_string(hwmon_dir.join(format!("{}_crit", prefix)))
+                    let temp_crit = read_file(hwmon_dir.join(format!("{}_crit", prefix)))
                         .ok()
                         .and_then(|s| s.trim().parse::<i64>().ok())
                         .map(|t| t as f64 / 1000.0);
@@ -85,7 +90,12 @@ pub fn read_thermal_zones() -> Result<Vec<ThermalZone>> {
 
 /// Check if thermal monitoring is supported on this system.
 pub fn is_thermal_supported() -> bool {
-    Path::new("/sys/class/hwmon").exists()
+    is_thermal_supported_at(&ProcfsRoot::default())
+}
+
+/// Check if `class/hwmon` exists under the given sysfs root.
+pub fn is_thermal_supported_at(root: &ProcfsRoot) -> bool {
+    root.sys_path("class/hwmon").exists()
 }
 
 #[cfg(test)]
@@ -0,0 +1,263 @@
+//! Wireless interface metrics for Linux
+//!
+//! Signal level and link quality come from /proc/net/wireless. SSID, bitrate
+//! and frequency are queried with Wireless Extensions ioctls, which cfg80211
+//! drivers still answer through their compatibility layer; they are left
+//! empty when unavailable.
+
+use super::ProcfsRoot;
+use super::procfs::read_file;
+use crate::{Error, Result, WirelessInfo};
+
+/// Per-interface values from /proc/net/wireless.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct WirelessStatus {
+    pub interface: String,
+    pub link_quality: f64,
+    pub signal_dbm: f64,
+}
+
+/// Parse /proc/net/wireless.
+///
+/// Format (two header lines, then one line per interface):
+/// ```text
+///  face | tus | link level noise |  nwid  crypt   frag  retry   misc | beacon | 22
+/// wlan0: 0000   54.  -56.  -256        0      0      0      0      0        0
+/// ```
+pub(crate) fn parse_proc_net_wireless(content: &str) -> Vec<WirelessStatus> {
+    content
+        .lines()
+        .skip(2)
+        .filter_map(|line| {
+            let (interface, rest) = line.split_once(':')?;
+            let mut fields = rest.split_whitespace().skip(1);
+            let mut value = || fields.next()?.trim_end_matches('.').parse::<f64>().ok();
+            Some(WirelessStatus {
+                interface: interface.trim().to_string(),
+                link_quality: value()?,
+                signal_dbm: value()?,
+            })
+        })
+        .collect()
+}
+
+/// List wireless interfaces (those with a `wireless` directory in sysfs).
+pub fn list_wireless_interfaces() -> Result<Vec<String>> {
+    list_wireless_interfaces_at(&ProcfsRoot::default())
+}
+
+/// List wireless interfaces under the given sysfs root.
+pub fn list_wireless_interfaces_at(root: &ProcfsRoot) -> Result<Vec<String>> {
+    let entries = match std::fs::read_dir(root.sys_path("class/net")) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut interfaces: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.path().join("wireless").is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+    interfaces.sort();
+    Ok(interfaces)
+}
+
+/// Collect signal metrics for a wireless interface.
+pub fn read_wireless_info(interface: &str) -> Result<WirelessInfo> {
+    read_wireless_info_at(&ProcfsRoot::default(), interface)
+}
+
+/// Collect signal metrics for a wireless interface under the given roots.
+///
+/// Returns `Error::NotFound` for unknown interfaces and `Error::NotSupported`
+/// for wired ones.
+pub fn read_wireless_info_at(root: &ProcfsRoot, interface: &str) -> Result<WirelessInfo> {
+    let iface_dir = root.sys_path(format!("class/net/{}", interface));
+    if !iface_dir.exists() {
+        return Err(Error::NotFound(format!("interface {} not found", interface)));
+    }
+    if !iface_dir.join("wireless").is_dir() {
+        return Err(Error::NotSupported);
+    }
+
+    let status = read_file(root.proc_path("net/wireless")).ok().and_then(|content| {
+        parse_proc_net_wireless(&content).into_iter().find(|s| s.interface == interface)
+    });
+
+    let mut info = WirelessInfo { interface: interface.to_string(), ..Default::default() };
+    if let Some(status) = status {
+        info.link_quality = status.link_quality;
+        info.signal_dbm = status.signal_dbm;
+    }
+    if let Some(ext) = wext::query(interface) {
+        info.ssid = ext.ssid;
+        info.bitrate_mbps = ext.bitrate_mbps;
+        info.frequency_mhz = ext.frequency_mhz;
+    }
+    Ok(info)
+}
+
+/// Wireless Extensions ioctls (linux/wireless.h).
+mod wext {
+    use std::mem;
+
+    const SIOCGIWFREQ: libc::c_ulong = 0x8B05;
+    const SIOCGIWESSID: libc::c_ulong = 0x8B1B;
+    const SIOCGIWRATE: libc::c_ulong = 0x8B21;
+    const IW_ESSID_MAX_SIZE: usize = 32;
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct IwPoint {
+        pointer: *mut libc::c_void,
+        length: u16,
+        flags: u16,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct IwParam {
+        value: i32,
+        fixed: u8,
+        disabled: u8,
+        flags: u16,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct IwFreq {
+        m: i32,
+        e: i16,
+        i: u8,
+        flags: u8,
+    }
+
+    /// `union iwreq_data`; `addr` pins its size to 16 bytes on every target.
+    #[repr(C)]
+    union IwReqData {
+        essid: IwPoint,
+        bitrate: IwParam,
+        freq: IwFreq,
+        addr: libc::sockaddr,
+    }
+
+    #[repr(C)]
+    struct IwReq {
+        ifr_name: [libc::c_char; libc::IFNAMSIZ],
+        u: IwReqData,
+    }
+
+    pub(super) struct ExtInfo {
+        pub ssid: String,
+        pub bitrate_mbps: f64,
+        pub frequency_mhz: u32,
+    }
+
+    /// Query SSID, bitrate and frequency; `None` if no socket can be opened.
+    pub(super) fn query(interface: &str) -> Option<ExtInfo> {
+        if interface.len() >= libc::IFNAMSIZ {
+            return None;
+        }
+        let fd = unsafe { libc::socket(libc::AF_INET, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, 0) };
+        if fd < 0 {
+            return None;
+        }
+
+        let request = || {
+            let mut req: IwReq = unsafe { mem::zeroed() };
+            for (dst, src) in req.ifr_name.iter_mut().zip(interface.bytes()) {
+                *dst = src as libc::c_char;
+            }
+            req
+        };
+
+        let mut essid = [0u8; IW_ESSID_MAX_SIZE + 1];
+        let mut req = request();
+        req.u.essid = IwPoint {
+            pointer: essid.as_mut_ptr() as *mut libc::c_void,
+            length: essid.len() as u16,
+            flags: 0,
+        };
+        let ssid = if unsafe { libc::ioctl(fd, SIOCGIWESSID as _, &mut req) } == 0 {
+            let len = (unsafe { req.u.essid.length } as usize).min(IW_ESSID_MAX_SIZE);
+            String::from_utf8_lossy(&essid[..len]).trim_end_matches('\0').to_string()
+        } else {
+            String::new()
+        };
+
+        let mut req = request();
+        let bitrate_mbps = if unsafe { libc::ioctl(fd, SIOCGIWRATE as _, &mut req) } == 0 {
+            unsafe { req.u.bitrate.value }.max(0) as f64 / 1_000_000.0
+        } else {
+            0.0
+        };
+
+        let mut req = request();
+        let frequency_mhz = if unsafe { libc::ioctl(fd, SIOCGIWFREQ as _, &mut req) } == 0 {
+            let freq = unsafe { req.u.freq };
+            freq_to_mhz(freq.m, freq.e)
+        } else {
+            0
+        };
+
+        unsafe { libc::close(fd) };
+        Some(ExtInfo { ssid, bitrate_mbps, frequency_mhz })
+    }
+
+    /// Convert an `iw_freq` mantissa/exponent to MHz.
+    ///
+    /// Small values with a zero exponent are channel numbers, not Hz.
+    pub(super) fn freq_to_mhz(m: i32, e: i16) -> u32 {
+        if e == 0 && m < 1000 {
+            return 0;
+        }
+        let hz = m as f64 * 10f64.powi(e as i32);
+        (hz / 1_000_000.0).round() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROC_NET_WIRELESS: &str = "\
+Inter-| sta-|   Quality        |   Discarded packets               | Missed | WE
+ face | tus | link level noise |  nwid  crypt   frag  retry   misc | beacon | 22
+ wlan0: 0000   54.  -56.  -256        0      0      0      0      0        0
+";
+
+    #[test]
+    fn test_parse_proc_net_wireless() {
+        let status = parse_proc_net_wireless(PROC_NET_WIRELESS);
+
+        assert_eq!(
+            status,
+            vec![WirelessStatus {
+                interface: "wlan0".to_string(),
+                link_quality: 54.0,
+                signal_dbm: -56.0,
+            }]
+        );
+        let header_only: String = PROC_NET_WIRELESS.lines().take(2).collect::<Vec<_>>().join("\n");
+        assert!(parse_proc_net_wireless(&header_only).is_empty());
+    }
+
+    #[test]
+    fn test_freq_to_mhz() {
+        assert_eq!(wext::freq_to_mhz(2_437, 6), 2437);
+        assert_eq!(wext::freq_to_mhz(518, 7), 5180);
+        assert_eq!(wext::freq_to_mhz(6, 0), 0);
+    }
+
+    #[test]
+    fn test_wired_and_missing_interfaces() {
+        let root = ProcfsRoot::default();
+
+        assert!(matches!(read_wireless_info_at(&root, "lo"), Err(Error::NotSupported)));
+        assert!(matches!(read_wireless_info_at(&root, "probe-missing0"), Err(Error::NotFound(_))));
+        for interface in list_wireless_interfaces_at(&root).unwrap() {
+            assert!(read_wireless_info_at(&root, &interface).is_ok());
+        }
+    }
+}
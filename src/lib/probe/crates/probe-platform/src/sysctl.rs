@@ -0,0 +1,84 @@
+//! Generic sysctl readers shared by macOS and the BSDs
+
+use crate::{Error, Result};
+use std::ffi::CString;
+use std::ptr;
+
+#[cfg(target_os = "openbsd")]
+use crate::bsd::sysctlbyname;
+#[cfg(not(target_os = "openbsd"))]
+use libc::sysctlbyname;
+
+/// Read an integer sysctl by name (e.g. `kern.ipc.somaxconn`).
+///
+/// Accepts 32- and 64-bit values; negative 32-bit values are rejected.
+///
+/// On OpenBSD only the names known to the `sysctlbyname` shim are available.
+pub fn read_sysctl_u64(name: &str) -> Result<u64> {
+    let raw = read_sysctl_raw(name)?;
+    let not_integer = || Error::Platform(format!("sysctl {} is not an unsigned integer", name));
+    match raw.len() {
+        4 => {
+            let value = i32::from_ne_bytes(raw.try_into().map_err(|_| not_integer())?);
+            u64::try_from(value).map_err(|_| not_integer())
+        }
+        8 => Ok(u64::from_ne_bytes(raw.try_into().map_err(|_| not_integer())?)),
+        _ => Err(not_integer()),
+    }
+}
+
+/// Read a string sysctl by name (e.g. `kern.ostype`).
+///
+/// On OpenBSD only the names known to the `sysctlbyname` shim are available.
+pub fn read_sysctl_string(name: &str) -> Result<String> {
+    let raw = read_sysctl_raw(name)?;
+    let end = raw.iter().position(|&b| b == 0).unwrap_or(raw.len());
+    Ok(String::from_utf8_lossy(&raw[..end]).into_owned())
+}
+
+/// Read the raw bytes of a sysctl, sizing the buffer from the kernel.
+fn read_sysctl_raw(name: &str) -> Result<Vec<u8>> {
+    let cname =
+        CString::new(name).map_err(|e| Error::Platform(format!("invalid sysctl name: {}", e)))?;
+
+    // The value can grow between the size query and the read; retry a few times
+    for _ in 0..3 {
+        let mut len = 0;
+        let result =
+            unsafe { sysctlbyname(cname.as_ptr(), ptr::null_mut(), &mut len, ptr::null_mut(), 0) };
+        if result != 0 {
+            return Err(sysctl_error(name));
+        }
+
+        let mut buf = vec![0u8; len];
+        let result = unsafe {
+            sysctlbyname(
+                cname.as_ptr(),
+                buf.as_mut_ptr() as *mut libc::c_void,
+                &mut len,
+                ptr::null_mut(),
+                0,
+            )
+        };
+        if result == 0 {
+            buf.truncate(len);
+            return Ok(buf);
+        }
+        if std::io::Error::last_os_error().raw_os_error() != Some(libc::ENOMEM) {
+            return Err(sysctl_error(name));
+        }
+    }
+    Err(Error::Platform(format!("sysctl {} kept changing size", name)))
+}
+
+/// Map the last sysctl failure to an error.
+pub(crate) fn sysctl_error(name: &str) -> Error {
+    let err = std::io::Error::last_os_error();
+    match err.raw_os_error() {
+        Some(libc::ENOENT) => Error::NotFound(format!("sysctl {} not found", name)),
+        Some(libc::EPERM | libc::EACCES) => {
+            Error::Permission(format!("cannot read sysctl {}", name))
+        }
+        _ => Error::Io(err),
+    }
+}
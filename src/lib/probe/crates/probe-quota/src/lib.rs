@@ -46,6 +46,14 @@ pub enum Error {
     #[error("process not found: {0}")]
     NotFound(i32),
 
+    /// Cgroup or container not found.
+    #[error("cgroup not found: {0}")]
+    CgroupNotFound(String),
+
+    /// Container ID prefix matches more than one container.
+    #[error("ambiguous container ID: {0}")]
+    AmbiguousContainer(String),
+
     /// I/O error.
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
@@ -64,7 +72,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// - `None` means the limit could not be determined or is not applicable
 /// - `Some(u64::MAX)` means unlimited
 /// - `Some(value)` is the actual limit
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct QuotaLimits {
     /// CPU quota in microseconds per period (cgroups).
     /// `None` if not in a cgroup or no CPU limit set.
@@ -129,9 +137,15 @@ impl QuotaLimits {
 /// Current resource usage for a process.
 #[derive(Debug, Clone, Default)]
 pub struct QuotaUsage {
-    /// Current memory usage in bytes.
+    /// Current memory usage in bytes, page cache included.
     pub memory_bytes: u64,
 
+    /// Working set in bytes: `memory_bytes` minus inactive file cache.
+    ///
+    /// The figure kubelet evicts on and the OOM killer effectively acts on.
+    /// Equals `memory_bytes` when `memory.stat` is not readable.
+    pub memory_working_set_bytes: u64,
+
     /// Memory limit in bytes (if any).
     pub memory_limit_bytes: Option<u64>,
 
@@ -146,6 +160,18 @@ pub struct QuotaUsage {
 
     /// CPU limit percentage (if any).
     pub cpu_limit_percent: Option<f64>,
+
+    /// Whether swap accounting is available for this process's cgroup.
+    /// `false` means swap figures are unknown, not that no swap is used.
+    pub swap_accounting_enabled: bool,
+
+    /// Swap limit in bytes (cgroup v2 `memory.swap.max`).
+    /// `None` if swap accounting is disabled, `Some(u64::MAX)` if unlimited.
+    pub memory_swap_limit_bytes: Option<u64>,
+
+    /// Current swap usage in bytes (cgroup v2 `memory.swap.current`).
+    /// `None` if swap accounting is disabled.
+    pub memory_swap_current_bytes: Option<u64>,
 }
 
 impl QuotaUsage {
@@ -186,6 +212,17 @@ pub trait QuotaReader: Send + Sync {
     ///
     /// Returns usage metrics that can be compared against limits.
     fn read_usage(&self, pid: i32) -> Result<QuotaUsage>;
+
+    /// Read the usage of the calling process's own cgroup, i.e. of its
+    /// container when running in one.
+    ///
+    /// Usage and limits come from cgroup v2 files only, without rlimit
+    /// fallbacks. `cpu_percent` covers the interval since the previous call
+    /// (0 on the first one), 100% being one CPU.
+    /// Returns `Error::NotSupported` without the cgroup v2 hierarchy.
+    fn self_cgroup_usage(&self) -> Result<QuotaUsage> {
+        Err(Error::NotSupported)
+    }
 }
 
 /// Container runtime detection.
@@ -330,3 +367,41 @@ pub fn detect_container() -> ContainerInfo {
         ContainerInfo::default()
     }
 }
+
+/// Read the calling process's `RLIMIT_NOFILE` as `(soft, hard)`.
+///
+/// Uses `getrlimit` directly, so it needs neither a PID nor /proc.
+/// Unlimited values are reported as `u64::MAX`.
+#[cfg(unix)]
+pub fn self_nofile_limit() -> Result<(u64, u64)> {
+    let mut rl = libc::rlimit { rlim_cur: 0, rlim_max: 0 };
+    if unsafe { libc::getrlimit(libc::RLIMIT_NOFILE, &mut rl) } != 0 {
+        return Err(Error::Io(std::io::Error::last_os_error()));
+    }
+    Ok((rlimit_to_u64(rl.rlim_cur), rlimit_to_u64(rl.rlim_max)))
+}
+
+/// Convert an rlimit value to u64, mapping RLIM_INFINITY to `u64::MAX`.
+#[cfg(unix)]
+#[allow(clippy::unnecessary_cast)] // rlim_t is u32 on 32-bit, u64 on 64-bit
+pub(crate) fn rlimit_to_u64(val: libc::rlim_t) -> u64 {
+    if val == libc::RLIM_INFINITY { u64::MAX } else { val as u64 }
+}
+
+/// Read the calling process's `RLIMIT_NOFILE` as `(soft, hard)`.
+#[cfg(not(unix))]
+pub fn self_nofile_limit() -> Result<(u64, u64)> {
+    Err(Error::NotSupported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_nofile_limit() {
+        let (soft, hard) = self_nofile_limit().unwrap();
+        assert!(soft > 0);
+        assert!(soft <= hard);
+    }
+}
@@ -2,14 +2,25 @@
 //!
 //! Reads resource limits from cgroups filesystem without applying them.
 
-use crate::{ContainerInfo, ContainerRuntime, Error, QuotaLimits, QuotaReader, QuotaUsage, Result};
+use crate::{
+    ContainerInfo, ContainerRuntime, Error, QuotaLimits, QuotaReader, QuotaUsage, Result,
+    rlimit_to_u64,
+};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Instant;
 
 /// Linux quota reader using cgroups.
 pub struct LinuxQuotaReader {
     /// Detected cgroups version (1 or 2).
     cgroup_version: CgroupVersion,
+    /// Procfs mount point used to resolve `/proc/[pid]/cgroup`.
+    proc_root: PathBuf,
+    /// Cgroup filesystem mount point.
+    cgroup_root: PathBuf,
+    /// Previous `usage_usec` of the own cgroup, for `self_cgroup_usage`.
+    previous_self_cpu: Mutex<Option<(u64, Instant)>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,29 +30,129 @@ enum CgroupVersion {
     Unknown,
 }
 
+/// Per-controller directories of a process in the cgroup v1 hierarchies.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct CgroupV1Paths {
+    cpu: Option<PathBuf>,
+    memory: Option<PathBuf>,
+    pids: Option<PathBuf>,
+    blkio: Option<PathBuf>,
+}
+
+/// Cgroup v1 reports "no limit" as the largest page-aligned i64.
+const CGROUP_V1_UNLIMITED_THRESHOLD: u64 = 0x7FFF_FFFF_FFFF_F000;
+
 impl LinuxQuotaReader {
     /// Create a new Linux quota reader.
     pub fn new() -> Self {
-        let cgroup_version = detect_cgroup_version();
-        Self { cgroup_version }
+        Self::with_roots("/proc", "/sys/fs/cgroup")
+    }
+
+    /// Create a reader using custom procfs and cgroup mount points.
+    ///
+    /// The cgroup version is detected from the layout under `cgroup_root`.
+    pub fn with_roots(proc_root: impl Into<PathBuf>, cgroup_root: impl Into<PathBuf>) -> Self {
+        let cgroup_root = cgroup_root.into();
+        let cgroup_version = detect_cgroup_version(&cgroup_root);
+        Self {
+            cgroup_version,
+            proc_root: proc_root.into(),
+            cgroup_root,
+            previous_self_cpu: Mutex::new(None),
+        }
     }
 
-    /// Get the cgroup path for a process.
-    fn get_cgroup_path(&self, pid: i32) -> Result<PathBuf> {
-        let cgroup_file = format!("/proc/{}/cgroup", pid);
-        let content = fs::read_to_string(&cgroup_file).map_err(|e| {
+    /// Read `/proc/[pid]/cgroup`.
+    fn read_proc_cgroup(&self, pid: i32) -> Result<String> {
+        let cgroup_file = self.proc_root.join(format!("{}/cgroup", pid));
+        fs::read_to_string(&cgroup_file).map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 Error::NotFound(pid)
             } else {
                 Error::Io(e)
             }
+        })
+    }
+
+    /// Resolve the cgroup v2 directory for a process.
+    ///
+    /// Returns `Error::NotSupported` when the unified hierarchy is not mounted.
+    pub fn cgroup_v2_path(&self, pid: i32) -> Result<PathBuf> {
+        if self.cgroup_version != CgroupVersion::V2 {
+            return Err(Error::NotSupported);
+        }
+        Ok(parse_cgroup_v2_path(&self.read_proc_cgroup(pid)?, &self.cgroup_root))
+    }
+
+    /// List the PIDs of the processes in a cgroup v2 group.
+    ///
+    /// `cgroup_path` is relative to the cgroup mount, as in `/proc/[pid]/cgroup`
+    /// (e.g. `/system.slice/docker-<id>.scope`). An empty cgroup yields an
+    /// empty list; a missing one `Error::CgroupNotFound`.
+    pub fn cgroup_pids(&self, cgroup_path: &str) -> Result<Vec<i32>> {
+        if self.cgroup_version != CgroupVersion::V2 {
+            return Err(Error::NotSupported);
+        }
+
+        let procs = self.cgroup_root.join(cgroup_path.trim_start_matches('/')).join("cgroup.procs");
+        let content = fs::read_to_string(&procs).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => Error::CgroupNotFound(cgroup_path.to_string()),
+            std::io::ErrorKind::PermissionDenied => Error::Permission(procs.display().to_string()),
+            _ => Error::Io(e),
         })?;
+        content
+            .lines()
+            .map(|line| {
+                line.trim().parse().map_err(|_| Error::Parse(format!("invalid PID {:?}", line)))
+            })
+            .collect()
+    }
 
-        match self.cgroup_version {
-            CgroupVersion::V2 => parse_cgroup_v2_path(&content),
-            CgroupVersion::V1 => parse_cgroup_v1_path(&content),
-            CgroupVersion::Unknown => Err(Error::NotSupported),
+    /// Resolve a container ID (full or a unique prefix) to its cgroup v2 path.
+    ///
+    /// Finds the scope created by the runtime, such as
+    /// `system.slice/docker-<id>.scope`, `machine.slice/libpod-<id>.scope`,
+    /// `kubepods.slice/.../cri-containerd-<id>.scope`,
+    /// `kubepods.slice/.../crio-<id>.scope` or `docker/<id>`.
+    /// A prefix must be at least 4 hex digits; one matching several
+    /// containers fails with `Error::AmbiguousContainer`.
+    pub fn container_cgroup(&self, container_id: &str) -> Result<String> {
+        if self.cgroup_version != CgroupVersion::V2 {
+            return Err(Error::NotSupported);
         }
+        if container_id.len() < MIN_CONTAINER_ID_PREFIX
+            || !container_id.bytes().all(|b| b.is_ascii_hexdigit())
+        {
+            return Err(Error::Parse(format!(
+                "container ID {:?} must be at least {} hex digits",
+                container_id, MIN_CONTAINER_ID_PREFIX
+            )));
+        }
+
+        let mut found = Vec::new();
+        find_container_cgroups(
+            &self.cgroup_root,
+            container_id,
+            CONTAINER_CGROUP_MAX_DEPTH,
+            &mut found,
+        );
+        let dir = match found.as_slice() {
+            [dir] => dir,
+            [] => return Err(Error::CgroupNotFound(container_id.to_string())),
+            _ => return Err(Error::AmbiguousContainer(container_id.to_string())),
+        };
+        let relative = dir.strip_prefix(&self.cgroup_root).unwrap_or(dir);
+        Ok(format!("/{}", relative.display()))
+    }
+
+    /// List the PIDs of the processes of a container.
+    pub fn container_pids(&self, container_id: &str) -> Result<Vec<i32>> {
+        self.cgroup_pids(&self.container_cgroup(container_id)?)
+    }
+
+    /// Resolve the cgroup v1 controller directories for a process.
+    fn cgroup_v1_paths(&self, pid: i32) -> Result<CgroupV1Paths> {
+        Ok(parse_cgroup_v1_paths(&self.read_proc_cgroup(pid)?, &self.cgroup_root))
     }
 
     fn read_cgroup_v2_limits(&self, cgroup_path: &Path) -> QuotaLimits {
@@ -72,48 +183,65 @@ impl LinuxQuotaReader {
             limits.io_write_bps = wbps;
         }
 
-        // Also read rlimits for nofile, cpu time, data
-        read_rlimits_into(&mut limits);
-
         limits
     }
 
-    fn read_cgroup_v1_limits(&self, _cgroup_path: &Path) -> QuotaLimits {
+    fn read_cgroup_v1_limits(&self, paths: &CgroupV1Paths) -> QuotaLimits {
         let mut limits = QuotaLimits::default();
 
-        // In cgroups v1, different controllers are in different paths
-        // cpu controller: /sys/fs/cgroup/cpu/...
-        // memory controller: /sys/fs/cgroup/memory/...
-
-        // CPU quota from cpu.cfs_quota_us and cpu.cfs_period_us
-        if let Ok(quota) = fs::read_to_string("/sys/fs/cgroup/cpu/cpu.cfs_quota_us")
-            && let Ok(val) = quota.trim().parse::<i64>()
-            && val > 0
-        {
-            limits.cpu_quota_us = Some(val as u64);
-        }
-        if let Ok(period) = fs::read_to_string("/sys/fs/cgroup/cpu/cpu.cfs_period_us")
-            && let Ok(val) = period.trim().parse::<u64>()
-        {
-            limits.cpu_period_us = Some(val);
+        // CPU quota from cpu.cfs_quota_us ("-1" when unlimited) and cpu.cfs_period_us
+        if let Some(cpu) = &paths.cpu {
+            if let Ok(quota) = fs::read_to_string(cpu.join("cpu.cfs_quota_us"))
+                && let Ok(val) = quota.trim().parse::<i64>()
+            {
+                limits.cpu_quota_us = Some(if val < 0 { u64::MAX } else { val as u64 });
+            }
+            if let Ok(period) = fs::read_to_string(cpu.join("cpu.cfs_period_us"))
+                && let Ok(val) = period.trim().parse::<u64>()
+            {
+                limits.cpu_period_us = Some(val);
+            }
         }
 
-        // Memory limit
-        if let Ok(content) = fs::read_to_string("/sys/fs/cgroup/memory/memory.limit_in_bytes") {
-            limits.memory_limit_bytes = parse_cgroup_value(&content);
+        // Memory limit from memory.limit_in_bytes
+        if let Some(memory) = &paths.memory
+            && let Ok(content) = fs::read_to_string(memory.join("memory.limit_in_bytes"))
+        {
+            limits.memory_limit_bytes = parse_cgroup_v1_value(&content);
         }
 
-        // PIDs limit
-        if let Ok(content) = fs::read_to_string("/sys/fs/cgroup/pids/pids.max") {
+        // PIDs limit from pids.max (same format as v2)
+        if let Some(pids) = &paths.pids
+            && let Ok(content) = fs::read_to_string(pids.join("pids.max"))
+        {
             limits.pids_limit = parse_cgroup_value(&content);
         }
 
-        // Also read rlimits
-        read_rlimits_into(&mut limits);
+        // I/O limits from blkio throttle files (format: "MAJ:MIN bps")
+        if let Some(blkio) = &paths.blkio {
+            limits.io_read_bps = fs::read_to_string(blkio.join("blkio.throttle.read_bps_device"))
+                .ok()
+                .and_then(|content| parse_blkio_throttle(&content));
+            limits.io_write_bps = fs::read_to_string(blkio.join("blkio.throttle.write_bps_device"))
+                .ok()
+                .and_then(|content| parse_blkio_throttle(&content));
+        }
 
         limits
     }
 
+    /// Fill rlimit-based fields from `/proc/[pid]/limits`.
+    ///
+    /// Falls back to `getrlimit` for the current process when the file is not
+    /// readable; other processes keep `None` in that case.
+    fn read_process_rlimits(&self, pid: i32, limits: &mut QuotaLimits) {
+        match fs::read_to_string(self.proc_root.join(format!("{}/limits", pid))) {
+            Ok(content) => apply_proc_limits(&content, limits),
+            Err(_) if pid == std::process::id() as i32 => read_rlimits_into(limits),
+            Err(_) => {}
+        }
+    }
+
     fn read_cgroup_v2_usage(&self, cgroup_path: &Path, limits: &QuotaLimits) -> QuotaUsage {
         let mut usage = QuotaUsage::default();
 
@@ -123,6 +251,11 @@ impl LinuxQuotaReader {
         {
             usage.memory_bytes = val;
         }
+        usage.memory_working_set_bytes = working_set_bytes(
+            usage.memory_bytes,
+            fs::read_to_string(cgroup_path.join("memory.stat")).ok().as_deref(),
+            "inactive_file",
+        );
         usage.memory_limit_bytes = limits.memory_limit_bytes;
 
         // PIDs current from pids.current
@@ -136,6 +269,49 @@ impl LinuxQuotaReader {
         // CPU limit percentage
         usage.cpu_limit_percent = limits.cpu_limit_percent();
 
+        // Swap accounting from memory.swap.*
+        let (enabled, swap_limit, swap_current) = read_swap_accounting(cgroup_path);
+        usage.swap_accounting_enabled = enabled;
+        usage.memory_swap_limit_bytes = swap_limit;
+        usage.memory_swap_current_bytes = swap_current;
+
+        usage
+    }
+
+    fn read_cgroup_v1_usage(&self, paths: &CgroupV1Paths, limits: &QuotaLimits) -> QuotaUsage {
+        let mut usage = QuotaUsage::default();
+
+        // Memory usage from memory.usage_in_bytes
+        if let Some(memory) = &paths.memory
+            && let Ok(content) = fs::read_to_string(memory.join("memory.usage_in_bytes"))
+            && let Ok(val) = content.trim().parse::<u64>()
+        {
+            usage.memory_bytes = val;
+        }
+        // Hierarchical counter, matching usage_in_bytes which covers children
+        usage.memory_working_set_bytes = working_set_bytes(
+            usage.memory_bytes,
+            paths
+                .memory
+                .as_ref()
+                .and_then(|memory| fs::read_to_string(memory.join("memory.stat")).ok())
+                .as_deref(),
+            "total_inactive_file",
+        );
+        usage.memory_limit_bytes = limits.memory_limit_bytes;
+
+        // PIDs current from pids.current
+        if let Some(pids) = &paths.pids
+            && let Ok(content) = fs::read_to_string(pids.join("pids.current"))
+            && let Ok(val) = content.trim().parse::<u64>()
+        {
+            usage.pids_current = val;
+        }
+        usage.pids_limit = limits.pids_limit;
+
+        // CPU limit percentage
+        usage.cpu_limit_percent = limits.cpu_limit_percent();
+
         usage
     }
 }
@@ -148,94 +324,204 @@ impl Default for LinuxQuotaReader {
 
 impl QuotaReader for LinuxQuotaReader {
     fn read_limits(&self, pid: i32) -> Result<QuotaLimits> {
-        let cgroup_path = self.get_cgroup_path(pid)?;
-
-        let limits = match self.cgroup_version {
-            CgroupVersion::V2 => self.read_cgroup_v2_limits(&cgroup_path),
-            CgroupVersion::V1 => self.read_cgroup_v1_limits(&cgroup_path),
-            CgroupVersion::Unknown => {
-                // Fall back to rlimits only
-                let mut limits = QuotaLimits::default();
-                read_rlimits_into(&mut limits);
-                limits
-            }
+        let mut limits = match self.cgroup_version {
+            CgroupVersion::V2 => self.read_cgroup_v2_limits(&self.cgroup_v2_path(pid)?),
+            CgroupVersion::V1 => self.read_cgroup_v1_limits(&self.cgroup_v1_paths(pid)?),
+            // Rlimits only
+            CgroupVersion::Unknown => QuotaLimits::default(),
         };
 
+        // Rlimits for nofile, cpu time, data (and pids when cgroups set none)
+        self.read_process_rlimits(pid, &mut limits);
+
         Ok(limits)
     }
 
     fn read_usage(&self, pid: i32) -> Result<QuotaUsage> {
         let limits = self.read_limits(pid)?;
-        let cgroup_path = self.get_cgroup_path(pid)?;
 
         let usage = match self.cgroup_version {
-            CgroupVersion::V2 => self.read_cgroup_v2_usage(&cgroup_path, &limits),
-            CgroupVersion::V1 => {
-                // V1 usage reading - simplified
-                QuotaUsage {
-                    memory_limit_bytes: limits.memory_limit_bytes,
-                    pids_limit: limits.pids_limit,
-                    cpu_limit_percent: limits.cpu_limit_percent(),
-                    ..Default::default()
-                }
-            }
+            CgroupVersion::V2 => self.read_cgroup_v2_usage(&self.cgroup_v2_path(pid)?, &limits),
+            CgroupVersion::V1 => self.read_cgroup_v1_usage(&self.cgroup_v1_paths(pid)?, &limits),
             CgroupVersion::Unknown => QuotaUsage::default(),
         };
 
         Ok(usage)
     }
+
+    fn self_cgroup_usage(&self) -> Result<QuotaUsage> {
+        let cgroup_path = self.cgroup_v2_path(std::process::id() as i32)?;
+        let limits = self.read_cgroup_v2_limits(&cgroup_path);
+        let mut usage = self.read_cgroup_v2_usage(&cgroup_path, &limits);
+
+        // CPU time is cumulative; the first call has no interval to cover
+        if let Ok(content) = fs::read_to_string(cgroup_path.join("cpu.stat"))
+            && let Some(usage_usec) = parse_cpu_stat_usage(&content)
+        {
+            let now = Instant::now();
+            let previous = self
+                .previous_self_cpu
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .replace((usage_usec, now));
+            if let Some((prev_usec, prev_at)) = previous {
+                let elapsed_us = now.duration_since(prev_at).as_micros() as f64;
+                if elapsed_us > 0.0 {
+                    usage.cpu_percent =
+                        usage_usec.saturating_sub(prev_usec) as f64 / elapsed_us * 100.0;
+                }
+            }
+        }
+
+        Ok(usage)
+    }
 }
 
-/// Detect cgroups version.
-fn detect_cgroup_version() -> CgroupVersion {
+/// Detect cgroups version from the layout under the cgroup mount point.
+///
+/// The unified hierarchy exposes `cgroup.controllers` at its root, while v1
+/// mounts one directory per controller (hybrid setups count as v1 since the
+/// controllers live there).
+fn detect_cgroup_version(cgroup_root: &Path) -> CgroupVersion {
     // Check for cgroups v2 unified hierarchy
-    if Path::new("/sys/fs/cgroup/cgroup.controllers").exists() {
+    if cgroup_root.join("cgroup.controllers").exists() {
         return CgroupVersion::V2;
     }
 
     // Check for cgroups v1
-    if Path::new("/sys/fs/cgroup/cpu").exists() || Path::new("/sys/fs/cgroup/memory").exists() {
+    if ["cpu", "memory", "pids"].iter().any(|c| cgroup_root.join(c).exists()) {
         return CgroupVersion::V1;
     }
 
     CgroupVersion::Unknown
 }
 
+/// How deep below the cgroup mount container scopes are searched
+/// (Kubernetes nests them four levels down).
+const CONTAINER_CGROUP_MAX_DEPTH: usize = 6;
+
+/// Shortest container ID prefix [`LinuxQuotaReader::container_cgroup`] accepts.
+const MIN_CONTAINER_ID_PREFIX: usize = 4;
+
+/// Runtimes whose systemd scopes are named `<runtime>-<id>.scope`.
+const CONTAINER_SCOPE_RUNTIMES: [&str; 4] = ["docker-", "libpod-", "cri-containerd-", "crio-"];
+
+/// Container ID named by a cgroup directory: `<runtime>-<id>.scope` for a
+/// known runtime, or a bare `<id>` with or without `.scope`, where `<id>`
+/// is 64 hex digits. Session scopes and user slices never match.
+fn container_id_of(name: &str) -> Option<&str> {
+    let id = match name.strip_suffix(".scope") {
+        Some(stem) => {
+            CONTAINER_SCOPE_RUNTIMES.iter().find_map(|rt| stem.strip_prefix(rt)).unwrap_or(stem)
+        }
+        None => name,
+    };
+    (id.len() == 64 && id.bytes().all(|b| b.is_ascii_hexdigit())).then_some(id)
+}
+
+/// Collect the cgroup directories of containers whose ID starts with
+/// `container_id` by walking the hierarchy. Stops once two are found, which
+/// is enough to tell the prefix is ambiguous.
+fn find_container_cgroups(dir: &Path, container_id: &str, depth: usize, found: &mut Vec<PathBuf>) {
+    if depth == 0 || found.len() > 1 {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    let mut children: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|entry| entry.path())
+        .collect();
+    children.sort();
+
+    for child in children {
+        let name = child.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        match container_id_of(name) {
+            Some(id) if id.starts_with(container_id) => found.push(child),
+            Some(_) => {}
+            None => find_container_cgroups(&child, container_id, depth - 1, found),
+        }
+        if found.len() > 1 {
+            return;
+        }
+    }
+}
+
 /// Parse cgroup v2 path from /proc/PID/cgroup.
 /// Format: "0::/path/to/cgroup"
-fn parse_cgroup_v2_path(content: &str) -> Result<PathBuf> {
+fn parse_cgroup_v2_path(content: &str, cgroup_root: &Path) -> PathBuf {
     for line in content.lines() {
         let parts: Vec<&str> = line.splitn(3, ':').collect();
         if parts.len() == 3 && parts[0] == "0" {
             let cgroup_relative = parts[2].trim();
-            let path =
-                PathBuf::from("/sys/fs/cgroup").join(cgroup_relative.trim_start_matches('/'));
+            let path = cgroup_root.join(cgroup_relative.trim_start_matches('/'));
             if path.exists() {
-                return Ok(path);
+                return path;
             }
         }
     }
 
     // Default to root cgroup
-    Ok(PathBuf::from("/sys/fs/cgroup"))
+    cgroup_root.to_path_buf()
 }
 
-/// Parse cgroup v1 path from /proc/PID/cgroup.
-/// Format: "hierarchy-id:controller-list:path"
-fn parse_cgroup_v1_path(content: &str) -> Result<PathBuf> {
+/// Parse cgroup v1 controller paths from /proc/PID/cgroup.
+/// Format: "hierarchy-id:controller-list:path", e.g. "4:cpu,cpuacct:/docker/abc"
+///
+/// Each hierarchy is mounted at `<root>/<controller-list>` (with per-controller
+/// symlinks on most distributions). When the process path does not exist
+/// under the mount, as seen from inside a container without a cgroup
+/// namespace, the hierarchy root is used.
+fn parse_cgroup_v1_paths(content: &str, cgroup_root: &Path) -> CgroupV1Paths {
+    let mut paths = CgroupV1Paths::default();
+
     for line in content.lines() {
         let parts: Vec<&str> = line.splitn(3, ':').collect();
-        if parts.len() == 3 {
-            // Look for memory or cpu controller
-            let controllers = parts[1];
-            if controllers.contains("memory") || controllers.contains("cpu") {
-                let path = parts[2].trim();
-                return Ok(PathBuf::from(format!("/sys/fs/cgroup/memory{}", path)));
+        if parts.len() != 3 {
+            continue;
+        }
+        let controllers = parts[1];
+        let relative = parts[2].trim().trim_start_matches('/');
+
+        for controller in controllers.split(',') {
+            let slot = match controller {
+                "cpu" => &mut paths.cpu,
+                "memory" => &mut paths.memory,
+                "pids" => &mut paths.pids,
+                "blkio" => &mut paths.blkio,
+                _ => continue,
+            };
+            let mount = [controllers, controller]
+                .iter()
+                .map(|name| cgroup_root.join(name))
+                .find(|dir| dir.exists());
+            if let Some(mount) = mount {
+                let dir = mount.join(relative);
+                *slot = Some(if dir.exists() { dir } else { mount });
             }
         }
     }
 
-    Ok(PathBuf::from("/sys/fs/cgroup"))
+    paths
+}
+
+/// Read cgroup v2 swap accounting from `memory.swap.max` and `memory.swap.current`.
+///
+/// Both files are absent when the kernel runs with swap accounting disabled
+/// (e.g. `swapaccount=0`), in which case accounting is reported as disabled.
+fn read_swap_accounting(cgroup_path: &Path) -> (bool, Option<u64>, Option<u64>) {
+    let swap_limit = fs::read_to_string(cgroup_path.join("memory.swap.max"))
+        .ok()
+        .and_then(|content| parse_cgroup_value(&content));
+    let swap_current = fs::read_to_string(cgroup_path.join("memory.swap.current"))
+        .ok()
+        .and_then(|content| content.trim().parse::<u64>().ok());
+
+    let enabled = swap_limit.is_some() || swap_current.is_some();
+    (enabled, swap_limit, swap_current)
 }
 
 /// Parse cpu.max format: "quota period" or "max period".
@@ -249,12 +535,44 @@ fn parse_cpu_max(content: &str) -> Option<(u64, u64)> {
     None
 }
 
+/// Parse `usage_usec` from a cgroup v2 `cpu.stat` file.
+fn parse_cpu_stat_usage(content: &str) -> Option<u64> {
+    content.lines().find_map(|line| line.strip_prefix("usage_usec ")?.trim().parse().ok())
+}
+
+/// Subtract the inactive file cache named `inactive_key` in `memory.stat`
+/// from a cgroup's memory usage (kubelet's working-set formula).
+fn working_set_bytes(usage: u64, memory_stat: Option<&str>, inactive_key: &str) -> u64 {
+    let inactive = memory_stat
+        .and_then(|content| {
+            content.lines().find_map(|line| {
+                let (key, value) = line.split_once(' ')?;
+                if key == inactive_key { value.trim().parse::<u64>().ok() } else { None }
+            })
+        })
+        .unwrap_or(0);
+    usage.saturating_sub(inactive)
+}
+
 /// Parse cgroup value that can be "max" or a number.
 fn parse_cgroup_value(content: &str) -> Option<u64> {
     let trimmed = content.trim();
     if trimmed == "max" { Some(u64::MAX) } else { trimmed.parse().ok() }
 }
 
+/// Parse a cgroup v1 byte limit, mapping the "no limit" sentinel to `u64::MAX`.
+fn parse_cgroup_v1_value(content: &str) -> Option<u64> {
+    let value: u64 = content.trim().parse().ok()?;
+    if value >= CGROUP_V1_UNLIMITED_THRESHOLD { Some(u64::MAX) } else { Some(value) }
+}
+
+/// Parse blkio throttle format: one "MAJ:MIN bps" line per device.
+///
+/// Like `parse_io_max`, the last device listed wins.
+fn parse_blkio_throttle(content: &str) -> Option<u64> {
+    content.lines().rev().find_map(|line| line.split_whitespace().nth(1)?.parse().ok())
+}
+
 /// Parse io.max format: "MAJ:MIN rbps=X wbps=X riops=Y wiops=Z".
 fn parse_io_max(content: &str) -> (Option<u64>, Option<u64>) {
     let mut rbps = None;
@@ -273,10 +591,32 @@ fn parse_io_max(content: &str) -> (Option<u64>, Option<u64>) {
     (rbps, wbps)
 }
 
-/// Convert rlimit value to u64, handling RLIM_INFINITY and 32-bit platforms.
-#[allow(clippy::unnecessary_cast)] // rlim_t is u32 on 32-bit, u64 on 64-bit
-fn rlimit_to_u64(val: libc::rlim_t) -> u64 {
-    if val == libc::RLIM_INFINITY { u64::MAX } else { val as u64 }
+/// Parse a `/proc/[pid]/limits` soft limit value ("unlimited" or a number).
+fn parse_proc_limit_value(value: &str) -> Option<u64> {
+    if value == "unlimited" { Some(u64::MAX) } else { value.parse().ok() }
+}
+
+/// Apply soft limits from `/proc/[pid]/limits` content to QuotaLimits.
+///
+/// Format: "Limit  Soft Limit  Hard Limit  Units" with multi-word limit names,
+/// e.g. "Max open files            1024                 524288               files".
+fn apply_proc_limits(content: &str, limits: &mut QuotaLimits) {
+    let soft = |rest: &str| rest.split_whitespace().next().and_then(parse_proc_limit_value);
+
+    for line in content.lines() {
+        if let Some(rest) = line.strip_prefix("Max cpu time") {
+            limits.cpu_time_limit_secs = soft(rest);
+        } else if let Some(rest) = line.strip_prefix("Max data size") {
+            limits.data_limit_bytes = soft(rest);
+        } else if let Some(rest) = line.strip_prefix("Max open files") {
+            limits.nofile_limit = soft(rest);
+        } else if let Some(rest) = line.strip_prefix("Max processes")
+            && limits.pids_limit.is_none()
+        {
+            // Cgroup pids.max takes precedence over RLIMIT_NPROC
+            limits.pids_limit = soft(rest);
+        }
+    }
 }
 
 /// Read rlimits into QuotaLimits.
@@ -390,10 +730,10 @@ fn extract_container_id(content: &str, hint: &str) -> Option<String> {
         let parts: Vec<&str> = line.splitn(3, ':').collect();
         if parts.len() == 3 {
             let path = parts[2];
-            // Look for 64-char hex ID (Docker/Podman container ID)
+            // Look for a 64-char hex ID, bare or in a runtime's scope
             for segment in path.split('/') {
-                if segment.len() == 64 && segment.chars().all(|c| c.is_ascii_hexdigit()) {
-                    return Some(segment.to_string());
+                if let Some(id) = container_id_of(segment) {
+                    return Some(id.to_string());
                 }
                 // Also check if segment contains the hint and has an ID after it
                 if !hint.is_empty()
@@ -428,6 +768,328 @@ mod tests {
         assert_eq!(parse_cgroup_value("100"), Some(100));
     }
 
+    #[test]
+    fn test_read_swap_accounting_enabled() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("memory.swap.max"), "max\n").unwrap();
+        fs::write(dir.path().join("memory.swap.current"), "4096\n").unwrap();
+
+        let (enabled, limit, current) = read_swap_accounting(dir.path());
+        assert!(enabled);
+        assert_eq!(limit, Some(u64::MAX));
+        assert_eq!(current, Some(4096));
+    }
+
+    #[test]
+    fn test_read_swap_accounting_disabled() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("memory.current"), "1048576\n").unwrap();
+
+        let (enabled, limit, current) = read_swap_accounting(dir.path());
+        assert!(!enabled);
+        assert_eq!(limit, None);
+        assert_eq!(current, None);
+    }
+
+    fn write_tree(root: &Path, files: &[(&str, &str)]) {
+        for (rel, content) in files {
+            let path = root.join(rel);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_cgroup_v1_v2_limits_parity() {
+        let v2 = tempfile::tempdir().unwrap();
+        write_tree(
+            v2.path(),
+            &[
+                ("proc/42/cgroup", "0::/docker/abc\n"),
+                ("cgroup/cgroup.controllers", "cpu memory pids io\n"),
+                ("cgroup/docker/abc/cpu.max", "50000 100000\n"),
+                ("cgroup/docker/abc/memory.max", "536870912\n"),
+                ("cgroup/docker/abc/pids.max", "max\n"),
+                ("cgroup/docker/abc/io.max", "8:0 rbps=1048576 wbps=524288\n"),
+            ],
+        );
+
+        let v1 = tempfile::tempdir().unwrap();
+        write_tree(
+            v1.path(),
+            &[
+                (
+                    "proc/42/cgroup",
+                    "12:pids:/docker/abc\n5:blkio:/docker/abc\n4:memory:/docker/abc\n\
+                     3:cpu,cpuacct:/docker/abc\n1:name=systemd:/docker/abc\n",
+                ),
+                ("cgroup/cpu,cpuacct/docker/abc/cpu.cfs_quota_us", "50000\n"),
+                ("cgroup/cpu,cpuacct/docker/abc/cpu.cfs_period_us", "100000\n"),
+                ("cgroup/memory/docker/abc/memory.limit_in_bytes", "536870912\n"),
+                ("cgroup/pids/docker/abc/pids.max", "max\n"),
+                ("cgroup/blkio/docker/abc/blkio.throttle.read_bps_device", "8:0 1048576\n"),
+                ("cgroup/blkio/docker/abc/blkio.throttle.write_bps_device", "8:0 524288\n"),
+            ],
+        );
+
+        let v2_reader =
+            LinuxQuotaReader::with_roots(v2.path().join("proc"), v2.path().join("cgroup"));
+        let v1_reader =
+            LinuxQuotaReader::with_roots(v1.path().join("proc"), v1.path().join("cgroup"));
+        assert_eq!(v2_reader.cgroup_version, CgroupVersion::V2);
+        assert_eq!(v1_reader.cgroup_version, CgroupVersion::V1);
+
+        let v2_limits = v2_reader.read_limits(42).unwrap();
+        let v1_limits = v1_reader.read_limits(42).unwrap();
+        assert_eq!(v1_limits, v2_limits);
+        assert_eq!(v1_limits.cpu_limit_percent(), Some(50.0));
+        assert_eq!(v1_limits.memory_limit_bytes, Some(536870912));
+        assert_eq!(v1_limits.pids_limit, Some(u64::MAX));
+        assert_eq!(v1_limits.io_read_bps, Some(1048576));
+    }
+
+    #[test]
+    fn test_cgroup_pids_of_container() {
+        let id = "4f1e9d2c3b5a69788a7b6c5d4e3f2a1b0c9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f";
+        let idle = "ffff9d2c3b5a69788a7b6c5d4e3f2a1b0c9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f";
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[
+                ("cgroup.controllers", "cpu memory pids\n"),
+                ("init.scope/cgroup.procs", "1\n"),
+                (&format!("system.slice/docker-{}.scope/cgroup.procs", id), "4242\n4250\n"),
+                (&format!("system.slice/docker-{}.scope/cgroup.procs", idle), ""),
+            ],
+        );
+        let reader = LinuxQuotaReader::with_roots(dir.path().join("proc"), dir.path());
+
+        let cgroup = reader.container_cgroup(&id[..12]).unwrap();
+        assert_eq!(cgroup, format!("/system.slice/docker-{}.scope", id));
+        assert_eq!(reader.cgroup_pids(&cgroup).unwrap(), vec![4242, 4250]);
+        assert_eq!(reader.container_pids(id).unwrap(), vec![4242, 4250]);
+        assert_eq!(reader.cgroup_pids("/init.scope").unwrap(), vec![1]);
+
+        assert_eq!(reader.container_pids("ffff").unwrap(), Vec::<i32>::new());
+        assert!(matches!(reader.container_pids("0123"), Err(Error::CgroupNotFound(_))));
+        assert!(matches!(reader.container_pids(""), Err(Error::Parse(_))));
+        assert!(matches!(reader.cgroup_pids("/missing.scope"), Err(Error::CgroupNotFound(_))));
+    }
+
+    #[test]
+    fn test_container_cgroup_ignores_sessions_and_user_slices() {
+        let id = "1000a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e";
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[
+                ("cgroup.controllers", "cpu memory pids\n"),
+                ("user.slice/user-1000.slice/session-5.scope/cgroup.procs", "900\n"),
+                ("user.slice/user-1000.slice/user@1000.service/cgroup.procs", "901\n"),
+                ("system.slice/cron.service/cgroup.procs", "812\n"),
+                (&format!("machine.slice/libpod-{}.scope/cgroup.procs", id), "4242\n"),
+            ],
+        );
+        let reader = LinuxQuotaReader::with_roots(dir.path().join("proc"), dir.path());
+
+        assert!(matches!(reader.container_cgroup("5"), Err(Error::Parse(_))));
+        assert!(matches!(reader.container_cgroup("1"), Err(Error::Parse(_))));
+        assert!(matches!(reader.container_cgroup("cron"), Err(Error::Parse(_))));
+        assert_eq!(
+            reader.container_cgroup("1000").unwrap(),
+            format!("/machine.slice/libpod-{}.scope", id)
+        );
+        assert!(matches!(reader.container_cgroup("dead"), Err(Error::CgroupNotFound(_))));
+    }
+
+    #[test]
+    fn test_container_cgroup_rejects_ambiguous_prefix() {
+        let first = "abcd01c3b5a69788a7b6c5d4e3f2a1b0c9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f4";
+        let second = "abcd02c3b5a69788a7b6c5d4e3f2a1b0c9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f4";
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[
+                ("cgroup.controllers", "cpu memory pids\n"),
+                (&format!("system.slice/docker-{}.scope/cgroup.procs", first), "10\n"),
+                (&format!("kubepods.slice/cri-containerd-{}.scope/cgroup.procs", second), "20\n"),
+            ],
+        );
+        let reader = LinuxQuotaReader::with_roots(dir.path().join("proc"), dir.path());
+
+        assert!(matches!(reader.container_cgroup("abcd"), Err(Error::AmbiguousContainer(_))));
+        assert_eq!(reader.container_pids("abcd01").unwrap(), vec![10]);
+        assert_eq!(reader.container_pids("abcd02").unwrap(), vec![20]);
+    }
+
+    #[test]
+    fn test_container_cgroup_of_crio_scope() {
+        let id = "7e3f0c9b8a7d6c5b4a39281706f5e4d3c2b1a0f9e8d7c6b5a4938271605f4e3d";
+        let pod = "kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1a2b.slice";
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[
+                ("cgroup.controllers", "cpu memory pids\n"),
+                (&format!("{}/crio-{}.scope/cgroup.procs", pod, id), "3100\n"),
+                (&format!("{}/crio-conmon-{}.scope/cgroup.procs", pod, id), "3099\n"),
+            ],
+        );
+        let reader = LinuxQuotaReader::with_roots(dir.path().join("proc"), dir.path());
+
+        // The conmon monitor scope is not the container
+        assert_eq!(
+            reader.container_cgroup(&id[..12]).unwrap(),
+            format!("/{}/crio-{}.scope", pod, id)
+        );
+        assert_eq!(reader.container_pids(id).unwrap(), vec![3100]);
+
+        let cgroup = format!("0::/{}/crio-{}.scope\n", pod, id);
+        assert_eq!(extract_container_id(&cgroup, "kubepods").as_deref(), Some(id));
+    }
+
+    #[test]
+    fn test_self_cgroup_usage() {
+        let pid = std::process::id();
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[
+                (&format!("proc/{}/cgroup", pid), "0::/docker/abc\n"),
+                ("cgroup/cgroup.controllers", "cpu memory pids\n"),
+                ("cgroup/docker/abc/cpu.max", "200000 100000\n"),
+                ("cgroup/docker/abc/cpu.stat", "usage_usec 1000000\nuser_usec 800000\n"),
+                ("cgroup/docker/abc/memory.max", "536870912\n"),
+                ("cgroup/docker/abc/memory.current", "134217728\n"),
+                ("cgroup/docker/abc/pids.max", "100\n"),
+                ("cgroup/docker/abc/pids.current", "7\n"),
+            ],
+        );
+        let reader =
+            LinuxQuotaReader::with_roots(dir.path().join("proc"), dir.path().join("cgroup"));
+
+        let usage = reader.self_cgroup_usage().unwrap();
+        assert_eq!(usage.memory_bytes, 134217728);
+        assert_eq!(usage.memory_usage_percent(), Some(25.0));
+        assert_eq!(usage.pids_current, 7);
+        assert_eq!(usage.pids_limit, Some(100));
+        assert_eq!(usage.cpu_limit_percent, Some(200.0));
+        assert_eq!(usage.cpu_percent, 0.0);
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        fs::write(dir.path().join("cgroup/docker/abc/cpu.stat"), "usage_usec 1005000\n").unwrap();
+        let usage = reader.self_cgroup_usage().unwrap();
+        assert!(usage.cpu_percent > 0.0 && usage.cpu_percent <= 50.0, "{}", usage.cpu_percent);
+
+        assert_eq!(parse_cpu_stat_usage("user_usec 5\nusage_usec 12\n"), Some(12));
+        assert_eq!(parse_cpu_stat_usage("nr_periods 0\n"), None);
+    }
+
+    #[test]
+    fn test_memory_working_set_excludes_inactive_file() {
+        let v2 = tempfile::tempdir().unwrap();
+        write_tree(
+            v2.path(),
+            &[
+                ("proc/42/cgroup", "0::/docker/abc\n"),
+                ("cgroup/cgroup.controllers", "cpu memory pids\n"),
+                ("cgroup/docker/abc/memory.current", "536870912\n"),
+                (
+                    "cgroup/docker/abc/memory.stat",
+                    "anon 268435456\nfile 268435456\nactive_file 67108864\n\
+                     inactive_file 201326592\n",
+                ),
+            ],
+        );
+
+        let v1 = tempfile::tempdir().unwrap();
+        write_tree(
+            v1.path(),
+            &[
+                ("proc/42/cgroup", "4:memory:/docker/abc\n"),
+                ("cgroup/memory/docker/abc/memory.usage_in_bytes", "536870912\n"),
+                (
+                    "cgroup/memory/docker/abc/memory.stat",
+                    "cache 268435456\ninactive_file 1048576\ntotal_cache 268435456\n\
+                     total_inactive_file 201326592\n",
+                ),
+            ],
+        );
+
+        for dir in [&v2, &v1] {
+            let reader =
+                LinuxQuotaReader::with_roots(dir.path().join("proc"), dir.path().join("cgroup"));
+            let usage = reader.read_usage(42).unwrap();
+            assert_eq!(usage.memory_bytes, 536870912);
+            assert_eq!(usage.memory_working_set_bytes, 335544320);
+        }
+
+        assert_eq!(working_set_bytes(4096, None, "inactive_file"), 4096);
+        assert_eq!(working_set_bytes(4096, Some("inactive_file 8192\n"), "inactive_file"), 0);
+    }
+
+    #[test]
+    fn test_cgroup_v1_unlimited_values() {
+        assert_eq!(parse_cgroup_v1_value("9223372036854771712\n"), Some(u64::MAX));
+        assert_eq!(parse_cgroup_v1_value("1073741824\n"), Some(1073741824));
+
+        // Without a cgroup namespace the host path is absent; fall back to the mount root
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(dir.path(), &[("memory/memory.limit_in_bytes", "9223372036854771712\n")]);
+        let paths = parse_cgroup_v1_paths("4:memory:/kubepods/pod1/abc\n", dir.path());
+        assert_eq!(paths.memory, Some(dir.path().join("memory")));
+        assert_eq!(paths.cpu, None);
+    }
+
+    const PROC_LIMITS: &str = "\
+Limit                     Soft Limit           Hard Limit           Units     
+Max cpu time              unlimited            unlimited            seconds   
+Max file size             unlimited            unlimited            bytes     
+Max data size             536870912            unlimited            bytes     
+Max stack size            8388608              unlimited            bytes     
+Max core file size        0                    unlimited            bytes     
+Max resident set          unlimited            unlimited            bytes     
+Max processes             63439                63439                processes 
+Max open files            1024                 524288               files     
+Max locked memory         8388608              8388608              bytes     
+Max address space         unlimited            unlimited            bytes     
+Max file locks            unlimited            unlimited            locks     
+Max pending signals       63439                63439                signals   
+Max msgqueue size         819200               819200               bytes     
+Max nice priority         0                    0                    
+Max realtime priority     0                    0                    
+Max realtime timeout      unlimited            unlimited            us        
+";
+
+    #[test]
+    fn test_apply_proc_limits() {
+        let mut limits = QuotaLimits::default();
+        apply_proc_limits(PROC_LIMITS, &mut limits);
+
+        assert_eq!(limits.cpu_time_limit_secs, Some(u64::MAX));
+        assert_eq!(limits.data_limit_bytes, Some(536870912));
+        assert_eq!(limits.pids_limit, Some(63439));
+        assert_eq!(limits.nofile_limit, Some(1024));
+
+        // Cgroup pids limit is kept
+        let mut limits = QuotaLimits { pids_limit: Some(100), ..Default::default() };
+        apply_proc_limits(PROC_LIMITS, &mut limits);
+        assert_eq!(limits.pids_limit, Some(100));
+    }
+
+    #[test]
+    fn test_read_limits_for_other_pid() {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(dir.path(), &[("proc/42/cgroup", "0::/\n"), ("proc/42/limits", PROC_LIMITS)]);
+
+        let reader =
+            LinuxQuotaReader::with_roots(dir.path().join("proc"), dir.path().join("cgroup"));
+        let limits = reader.read_limits(42).unwrap();
+
+        assert_eq!(limits.nofile_limit, Some(1024));
+        assert_eq!(limits.cpu_time_limit_secs, Some(u64::MAX));
+    }
+
     #[test]
     fn test_parse_io_max() {
         let content = "8:0 rbps=104857600 wbps=52428800\n";
@@ -3,7 +3,7 @@
 //! Reads resource limits using getrlimit() syscall.
 //! Used on macOS, OpenBSD, and NetBSD where cgroups/rctl are not available.
 
-use crate::{Error, QuotaLimits, QuotaReader, QuotaUsage, Result};
+use crate::{Error, QuotaLimits, QuotaReader, QuotaUsage, Result, rlimit_to_u64};
 use libc::{RLIMIT_CPU, RLIMIT_DATA, RLIMIT_NOFILE, RLIMIT_NPROC, getrlimit, rlimit};
 
 /// POSIX rlimit quota reader.
@@ -97,11 +97,6 @@ impl QuotaReader for RlimitQuotaReader {
     }
 }
 
-/// Convert rlimit value to u64, handling RLIM_INFINITY.
-fn rlimit_to_u64(val: libc::rlim_t) -> u64 {
-    if val == libc::RLIM_INFINITY { u64::MAX } else { val as u64 }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
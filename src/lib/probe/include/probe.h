@@ -22,6 +22,7 @@ extern "C" {
 #define PROBE_ERR_NOT_FOUND 3
 #define PROBE_ERR_INVALID_PARAM 4
 #define PROBE_ERR_IO 5
+#define PROBE_ERR_TIMEOUT 6
 #define PROBE_ERR_INTERNAL 99
 
 // ============================================================================
@@ -42,14 +43,21 @@ typedef struct {
 } ProbeResult;
 
 // System CPU metrics
+// Percentages are averages since boot; probe_sampler_collect reports them
+// over the interval between two calls
 typedef struct {
     double user_percent;
     double system_percent;
     double idle_percent;
     double iowait_percent;
     double steal_percent;
-    uint32_t cores;
+    uint32_t cores;             // Online cores (same as cores_online)
     uint64_t frequency_mhz;
+    double guest_percent;       // Included in user_percent (Linux only)
+    double guest_nice_percent;  // Linux only
+    uint32_t cores_present;     // Including offline cores
+    uint32_t cores_online;
+    uint32_t physical_cores;    // SMT siblings counted once
 } SystemCPU;
 
 // System memory metrics
@@ -80,6 +88,17 @@ typedef enum {
     PROCESS_STATE_UNKNOWN = 255,
 } ProcessState;
 
+// Process scheduling policy (values match Linux SCHED_*)
+typedef enum {
+    SCHED_POLICY_NORMAL = 0,
+    SCHED_POLICY_FIFO = 1,
+    SCHED_POLICY_ROUND_ROBIN = 2,
+    SCHED_POLICY_BATCH = 3,
+    SCHED_POLICY_IDLE = 5,
+    SCHED_POLICY_DEADLINE = 6,
+    SCHED_POLICY_UNKNOWN = 255,
+} SchedPolicy;
+
 // Process metrics
 typedef struct {
     int32_t pid;
@@ -91,9 +110,47 @@ typedef struct {
     uint32_t num_fds;
     uint64_t read_bytes_per_sec;
     uint64_t write_bytes_per_sec;
+    uint64_t minor_faults;      // Cumulative minor page faults
+    uint64_t major_faults;      // Cumulative major page faults (disk I/O)
     ProcessState state;
+    SchedPolicy sched_policy;   // UNKNOWN outside Linux
+    int8_t nice;                // Nice value (-20 to 19)
+    uint32_t rt_priority;       // Real-time priority (0 for non-real-time policies)
 } ProcessMetrics;
 
+// Process memory breakdown from smaps (bytes, Linux only)
+typedef struct {
+    uint64_t rss;
+    uint64_t pss;              // Proportional set size
+    uint64_t shared_clean;
+    uint64_t shared_dirty;
+    uint64_t private_clean;
+    uint64_t private_dirty;
+    uint64_t swap;
+} MemoryBreakdown;
+
+// Process scheduler statistics from schedstat (Linux only)
+typedef struct {
+    uint64_t run_ticks;             // Time running on a CPU (ns)
+    uint64_t wait_ticks;            // Time waiting on a run queue (ns)
+    uint64_t timeslices;            // Timeslices run on a CPU
+    double avg_wait_per_timeslice;  // wait_ticks / timeslices (ns)
+} SchedStats;
+
+// Process OOM killer ranking (Linux only)
+typedef struct {
+    int32_t oom_score;      // Badness score, higher is killed first
+    int32_t oom_score_adj;  // Adjustment from -1000 (never kill) to 1000
+} OomInfo;
+
+// Process capability sets (Linux only); bit n is capability number n
+typedef struct {
+    uint64_t inheritable;   // CapInh: preserved across execve
+    uint64_t permitted;     // CapPrm: capabilities the process may assume
+    uint64_t effective;     // CapEff: used for permission checks
+    uint64_t bounding;      // CapBnd: upper bound the process may ever gain
+} ProcessCapabilities;
+
 // CPU pressure metrics (PSI - Linux only)
 typedef struct {
     double some_avg10;
@@ -201,6 +258,12 @@ typedef struct {
     uint64_t tx_packets;
     uint64_t tx_errors;
     uint64_t tx_drops;
+    uint64_t rx_fifo;
+    uint64_t rx_compressed;
+    uint64_t rx_multicast;
+    uint64_t tx_fifo;
+    uint64_t tx_collisions;
+    uint64_t tx_compressed;
 } NetStats;
 
 // List of network stats
@@ -250,6 +313,10 @@ typedef struct {
     uint64_t pids_limit;          // PIDs limit (0 = no limit)
     double cpu_percent;           // Current CPU usage percentage
     double cpu_limit_percent;     // CPU limit percentage (0 = no limit)
+    uint64_t memory_swap_limit_bytes;   // Swap limit (0 = unknown, UINT64_MAX = no limit)
+    uint64_t memory_swap_current_bytes; // Current swap usage
+    bool swap_accounting_enabled;       // Swap accounting enabled (swap fields valid)
+    uint64_t memory_working_set_bytes;  // Memory usage minus inactive file cache
 } QuotaUsage;
 
 // Container runtime type (legacy - use RuntimeType for new code)
@@ -328,7 +395,13 @@ typedef struct {
 // Initialize the probe library. Must be called once at startup.
 ProbeResult probe_init(void);
 
-// Shutdown the probe library. Should be called at program exit.
+// Shutdown the probe library. Should be called at program exit or before
+// unloading the library. Stops and joins the background threads (link and
+// process watchers); process watcher handles must still be freed with
+// probe_process_watch_stop. Must not be called from a callback. Timed-out
+// workers (mount latency probes, probe_collect_all_deadline) cannot be
+// cancelled and are not joined; one still blocked in the kernel outlives
+// shutdown.
 void probe_shutdown(void);
 
 // ============================================================================
@@ -351,10 +424,36 @@ ProbeResult probe_collect_load(LoadAverage* out);
 // Collect metrics for a specific process
 ProbeResult probe_collect_process(int32_t pid, ProcessMetrics* out);
 
+// Collect metrics for `count` processes in one call
+// PIDs that no longer exist or cannot be read are skipped; *written receives the number
+// of entries stored
+// Returns PROBE_ERR_INVALID_PARAM if out_cap < count
+ProbeResult probe_collect_processes(const int32_t* pids, size_t count, ProcessMetrics* out,
+                                    size_t out_cap, size_t* written);
+
+// Collect the shared/private memory breakdown (PSS) of a process
+// Returns PROBE_ERR_PERMISSION if the process memory maps are not readable
+ProbeResult probe_collect_process_memory_breakdown(int32_t pid, MemoryBreakdown* out);
+
+// Collect scheduler run-queue statistics of a process
+// Returns PROBE_ERR_NOT_SUPPORTED on kernels without CONFIG_SCHEDSTATS
+ProbeResult probe_collect_process_sched_stats(int32_t pid, SchedStats* out);
+
+// Collect the OOM killer score and adjustment of a process
+// Returns PROBE_ERR_NOT_FOUND for dead processes
+ProbeResult probe_collect_process_oom_info(int32_t pid, OomInfo* out);
+
+// Collect the capability sets of a process
+// Returns PROBE_ERR_PERMISSION when the process status is not readable
+ProbeResult probe_collect_process_capabilities(int32_t pid, ProcessCapabilities* out);
+
 // ============================================================================
 // PRESSURE METRICS FUNCTIONS (PSI - Linux only)
 // ============================================================================
 
+// Check if pressure stall information is available (checked once, false before probe_init)
+bool probe_pressure_supported(void);
+
 // Collect CPU pressure metrics
 ProbeResult probe_collect_cpu_pressure(CPUPressure* out);
 
@@ -364,6 +463,142 @@ ProbeResult probe_collect_memory_pressure(MemoryPressure* out);
 // Collect I/O pressure metrics
 ProbeResult probe_collect_io_pressure(IOPressure* out);
 
+// Resource selectors for probe_pressure_level
+#define PROBE_PRESSURE_CPU    0
+#define PROBE_PRESSURE_MEMORY 1
+#define PROBE_PRESSURE_IO     2
+
+// Severity of a pressure stall, from the 10s averages
+typedef enum {
+    PRESSURE_LEVEL_NONE = 0,    // No task stalled
+    PRESSURE_LEVEL_SOME = 1,    // some_avg10 > 0
+    PRESSURE_LEVEL_FULL = 2,    // full_avg10 > 0 (never reported for CPU)
+} PressureLevel;
+
+// Classify current pressure of a resource (PROBE_PRESSURE_*)
+ProbeResult probe_pressure_level(uint32_t resource, PressureLevel* out);
+
+// Report whether all tasks were recently stalled on memory (full_avg10 > 0)
+ProbeResult probe_memory_stalled(bool* out);
+
+// ============================================================================
+// TRANSPARENT HUGE PAGES (Linux only)
+// ============================================================================
+
+// Transparent huge page status
+typedef struct {
+    char enabled[16];               // "always", "madvise", "never" or "unknown"
+    char defrag[16];                // "always", "defer", "defer+madvise", "madvise", "never" or "unknown"
+    uint64_t anon_hugepages_bytes;  // Anonymous memory backed by huge pages
+} ThpStatus;
+
+// Collect transparent huge page status
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms or kernels without THP
+ProbeResult probe_collect_thp_status(ThpStatus* out);
+
+// ============================================================================
+// MEMORY ZONES (Linux only)
+// ============================================================================
+
+// Memory zone watermarks summed across all zones (values in pages)
+typedef struct {
+    uint32_t zone_count;        // Zones reported in /proc/zoneinfo
+    uint32_t zones_below_low;   // Zones with free pages below the low watermark
+    uint64_t free_pages;        // Free pages
+    uint64_t min_watermark;     // Sum of min watermarks (direct reclaim threshold)
+    uint64_t low_watermark;     // Sum of low watermarks (kswapd wake-up threshold)
+    uint64_t high_watermark;    // Sum of high watermarks (kswapd stop threshold)
+} MemoryZoneSummary;
+
+// Collect memory zone watermarks summed across zones
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_collect_zone_summary(MemoryZoneSummary* out);
+
+// ============================================================================
+// VM STATISTICS (Linux only)
+// ============================================================================
+
+// Headline virtual memory counters since boot (from /proc/vmstat)
+// Counters missing from the running kernel are reported as 0
+typedef struct {
+    uint64_t page_faults;       // pgfault
+    uint64_t major_page_faults; // pgmajfault (faults requiring I/O)
+    uint64_t oom_kills;         // oom_kill (Linux 4.13+)
+    uint64_t swap_ins;          // pswpin (pages)
+    uint64_t swap_outs;         // pswpout (pages)
+} VmStatSummary;
+
+// Collect headline virtual memory counters
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_collect_vmstat(VmStatSummary* out);
+
+// ============================================================================
+// CPU VULNERABILITIES (Linux only)
+// ============================================================================
+
+// Mitigation status of a CPU vulnerability
+typedef enum {
+    CPU_VULN_STATUS_NOT_AFFECTED = 0,
+    CPU_VULN_STATUS_MITIGATED = 1,
+    CPU_VULN_STATUS_VULNERABLE = 2,
+    CPU_VULN_STATUS_UNKNOWN = 3,
+} CpuVulnStatus;
+
+// A CPU hardware vulnerability as reported by the kernel
+typedef struct {
+    char name[64];                  // e.g. "meltdown", "spectre_v2"
+    CpuVulnStatus status;           // Classified mitigation status
+    char detail[128];               // Raw kernel status line (truncated)
+} CpuVuln;
+
+// List of CPU vulnerabilities
+typedef struct {
+    CpuVuln* items;
+    size_t count;
+    size_t capacity;
+} CpuVulnList;
+
+// Collect CPU vulnerability mitigation status, sorted by name
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms or kernels without the report
+ProbeResult probe_collect_cpu_vulnerabilities(CpuVulnList* out);
+
+// Free a CPU vulnerability list
+void probe_free_cpu_vuln_list(CpuVulnList* list);
+
+// ============================================================================
+// CPU CACHES
+// ============================================================================
+
+// Kind of data a CPU cache holds
+typedef enum {
+    CACHE_KIND_DATA = 0,
+    CACHE_KIND_INSTRUCTION = 1,
+    CACHE_KIND_UNIFIED = 2,
+} CacheKind;
+
+// A CPU cache as seen from the first CPU
+typedef struct {
+    uint8_t level;                  // 1 for L1, 2 for L2, ...
+    CacheKind kind;                 // Data, instruction or unified
+    uint64_t size_bytes;            // Cache size in bytes
+    char shared_cpu_list[64];       // CPUs sharing the cache, e.g. "0-7" (empty if unknown)
+} CpuCache;
+
+// List of CPU caches
+typedef struct {
+    CpuCache* items;
+    size_t count;
+    size_t capacity;
+} CpuCacheList;
+
+// List the caches of the first CPU, ordered by level
+// Uses sysfs on Linux and hw.l*cachesize sysctls on macOS
+// Returns PROBE_ERR_NOT_SUPPORTED on other platforms
+ProbeResult probe_collect_cpu_caches(CpuCacheList* out);
+
+// Free a CPU cache list
+void probe_free_cpu_cache_list(CpuCacheList* list);
+
 // ============================================================================
 // DISK METRICS FUNCTIONS
 // ============================================================================
@@ -377,12 +612,58 @@ void probe_free_partition_list(PartitionList* list);
 // Collect disk usage for a specific path
 ProbeResult probe_collect_disk_usage(const char* path, DiskUsage* out);
 
+// Time a statvfs of the filesystem mounted at path, in microseconds
+// A growing latency is an early sign of a degrading network mount
+// Returns PROBE_ERR_TIMEOUT when the mount does not answer within 5s, and at
+// once while the previous probe of the same path is still stuck
+ProbeResult probe_mount_latency(const char* path, uint64_t* out_us);
+
 // Collect disk I/O statistics for all devices
 ProbeResult probe_collect_disk_io(DiskIOStatsList* out);
 
 // Free a disk I/O stats list
 void probe_free_disk_io_list(DiskIOStatsList* list);
 
+// Physical block device inventory entry
+typedef struct {
+    char name[32];              // e.g. "sda", "nvme0n1"
+    char model[64];             // Empty if unknown
+    char serial[64];            // Empty if unknown
+    uint64_t size_bytes;
+    bool rotational;            // true for HDD, false for SSD
+    bool removable;
+} BlockDevice;
+
+// List of block devices
+typedef struct {
+    BlockDevice* items;
+    size_t count;
+    size_t capacity;
+} BlockDeviceList;
+
+// List physical block devices (virtual devices such as loop/dm are skipped)
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_list_block_devices(BlockDeviceList* out);
+
+// Free a block device list
+void probe_free_block_device_list(BlockDeviceList* list);
+
+// Check whether the root filesystem is mounted read-only
+// Returns PROBE_ERR_NOT_FOUND when no filesystem is listed on "/"
+ProbeResult probe_root_filesystem_readonly(bool* out);
+
+// Request queue settings of a block device (/sys/block/<dev>/queue)
+typedef struct {
+    char scheduler[32];         // Active I/O scheduler, e.g. "mq-deadline"
+    uint32_t nr_requests;       // Maximum queued requests per direction
+    uint32_t read_ahead_kb;
+    bool rotational;            // true for HDD, false for SSD
+} DiskQueueConfig;
+
+// Read the I/O scheduler and queue settings of a block device (e.g. "sda")
+// Returns PROBE_ERR_NOT_FOUND for unknown devices
+ProbeResult probe_disk_queue_config(const char* device, DiskQueueConfig* out);
+
 // ============================================================================
 // NETWORK METRICS FUNCTIONS
 // ============================================================================
@@ -396,9 +677,68 @@ void probe_free_net_interface_list(NetInterfaceList* list);
 // Collect network statistics for all interfaces
 ProbeResult probe_collect_net_stats(NetStatsList* out);
 
+// Collect network statistics; with physical_only, loopback and virtual
+// interfaces (veth*, docker*, br-*, cni*) are excluded
+ProbeResult probe_collect_net_stats_filtered(bool physical_only, NetStatsList* out);
+
 // Free a network stats list
 void probe_free_net_stats_list(NetStatsList* list);
 
+// Netfilter connection tracking table usage
+typedef struct {
+    uint64_t count;             // Entries currently tracked
+    uint64_t max;               // Table capacity (nf_conntrack_max)
+} ConntrackStats;
+
+// Collect connection tracking table usage
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms or when nf_conntrack is not loaded
+ProbeResult probe_collect_conntrack_stats(ConntrackStats* out);
+
+// Kernel socket usage from /proc/net/sockstat (memory values in pages)
+typedef struct {
+    uint64_t tcp_inuse;         // TCP sockets in use
+    uint64_t tcp_mem_pages;     // Pages used by TCP buffers (compare to tcp_mem)
+    uint64_t tcp_orphan;        // TCP sockets not attached to a descriptor
+    uint64_t udp_inuse;         // UDP sockets in use
+    uint64_t udp_mem_pages;     // Pages used by UDP buffers
+    uint64_t sockets_used;      // Sockets in use across all protocols
+} SocketMemStats;
+
+// Collect kernel socket counts and buffer memory
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_collect_socket_memory(SocketMemStats* out);
+
+// UDP counters since boot (Udp: section of /proc/net/snmp)
+typedef struct {
+    uint64_t in_datagrams;      // Datagrams delivered to sockets
+    uint64_t no_ports;          // Datagrams for a port without a listener
+    uint64_t in_errors;         // Datagrams dropped on receive (other than no_ports)
+    uint64_t out_datagrams;     // Datagrams sent
+    uint64_t rcvbuf_errors;     // Drops because a receive buffer was full
+    uint64_t sndbuf_errors;     // Drops because a send buffer was full
+    uint64_t in_csum_errors;    // Drops for a bad checksum
+} UdpProtoStats;
+
+// Collect UDP datagram and error counters
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_collect_udp_stats(UdpProtoStats* out);
+
+// ICMP counters since boot (Icmp: section of /proc/net/snmp)
+typedef struct {
+    uint64_t in_msgs;           // Messages received
+    uint64_t in_errors;         // Messages received with errors
+    uint64_t in_csum_errors;    // Messages received with a bad checksum
+    uint64_t in_dest_unreachs;  // Destination unreachable received
+    uint64_t in_time_excds;     // Time exceeded received
+    uint64_t out_msgs;          // Messages sent
+    uint64_t out_errors;        // Messages not sent because of errors
+    uint64_t out_dest_unreachs; // Destination unreachable sent
+} IcmpStats;
+
+// Collect ICMP message and error counters
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_collect_icmp_stats(IcmpStats* out);
+
 // ============================================================================
 // I/O METRICS FUNCTIONS
 // ============================================================================
@@ -426,6 +766,227 @@ ProbeResult probe_collect_process_context_switches(int32_t pid, ContextSwitches*
 // Collect context switches for the current process
 ProbeResult probe_collect_self_context_switches(ContextSwitches* out);
 
+// ============================================================================
+// KERNEL STATISTICS (Linux only)
+// ============================================================================
+
+// Kernel-wide activity counters (from /proc/stat on Linux)
+typedef struct {
+    uint64_t context_switches_total;  // Total context switches since boot
+    uint64_t interrupts_total;        // Total interrupts serviced since boot
+    uint64_t processes_started_total; // Total processes/threads created since boot
+    uint32_t procs_running;           // Tasks currently runnable
+    uint32_t procs_blocked;           // Tasks currently blocked on I/O
+    uint64_t boot_time;               // Boot time (seconds since epoch)
+} KernelStats;
+
+// Collect kernel-wide activity counters
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_collect_kernel_stats(KernelStats* out);
+
+// Opaque fork-rate sampler handle
+typedef struct ForkRateSampler ForkRateSampler;
+
+// Process creation rate
+typedef struct {
+    double forks_per_sec;       // Processes/threads created per second
+    bool available;             // False on the first sample (primes the sampler)
+} ForkRate;
+
+// Create a fork-rate sampler (free with probe_fork_rate_sampler_free)
+ForkRateSampler* probe_fork_rate_sampler_new(void);
+
+// Sample the process creation counter; rate is relative to the previous call
+// A counter reset (reboot) reports 0 and starts a new baseline
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_fork_rate_sample(ForkRateSampler* sampler, ForkRate* out);
+
+// Free a fork-rate sampler
+void probe_fork_rate_sampler_free(ForkRateSampler* sampler);
+
+// Opaque page-fault-rate sampler handle, bound to one process
+typedef struct PageFaultSampler PageFaultSampler;
+
+// Process page fault rates
+typedef struct {
+    double minor_per_sec;       // Minor faults per second
+    double major_per_sec;       // Major faults per second
+    bool available;             // False on the first sample (primes the sampler)
+} PageFaultRate;
+
+// Create a page-fault-rate sampler for a process
+// (free with probe_page_fault_sampler_free)
+PageFaultSampler* probe_page_fault_sampler_new(int32_t pid);
+
+// Sample the process fault counters; rates are relative to the previous call
+// A reused PID (new start time) restarts the sampler: available is false
+ProbeResult probe_page_fault_sample(PageFaultSampler* sampler, PageFaultRate* out);
+
+// Free a page-fault-rate sampler
+void probe_page_fault_sampler_free(PageFaultSampler* sampler);
+
+// Opaque per-thread CPU sampler handle, bound to one process
+typedef struct ThreadSampler ThreadSampler;
+
+// A thread of a process (from /proc/[pid]/task/[tid]/stat)
+typedef struct {
+    int32_t tid;                // Thread ID
+    char comm[16];              // Thread name
+    ProcessState state;         // Thread state
+    uint64_t user_time_us;      // Cumulative user-mode CPU time
+    uint64_t system_time_us;    // Cumulative kernel-mode CPU time
+    double cpu_percent;         // CPU usage since the previous sample (0 for new threads)
+} ThreadInfo;
+
+// List of threads
+typedef struct {
+    ThreadInfo* items;
+    size_t count;
+    size_t capacity;
+} ThreadInfoList;
+
+// Create a per-thread CPU sampler for a process
+// (free with probe_thread_sampler_free)
+ThreadSampler* probe_thread_sampler_new(int32_t pid);
+
+// List the threads of the process with CPU usage since the previous call
+// Threads exiting during the read are skipped; a reused PID reports 0% for all
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_thread_sample(ThreadSampler* sampler, ThreadInfoList* out);
+
+// Free a thread list
+void probe_free_thread_info_list(ThreadInfoList* list);
+
+// Free a per-thread CPU sampler
+void probe_thread_sampler_free(ThreadSampler* sampler);
+
+// ============================================================================
+// KERNEL LOG (Linux only)
+// ============================================================================
+
+// Summary of recent kernel log errors (severity err or worse, from /dev/kmsg)
+typedef struct {
+    uint32_t error_count;           // Error-or-worse records
+    uint32_t oom_kills;             // Records reporting an OOM kill
+    uint32_t io_errors;             // Records reporting a block device I/O error
+    uint64_t latest_timestamp_us;   // Time since boot of the latest record (0 if none)
+    char latest_message[256];       // Message of the latest record (truncated)
+} KernelErrorSummary;
+
+// Summarize kernel log errors from the last since_secs seconds (0 = whole ring buffer)
+// Returns PROBE_ERR_PERMISSION if /dev/kmsg is not readable
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_kernel_error_summary(uint64_t since_secs, KernelErrorSummary* out);
+
+// ============================================================================
+// INOTIFY (Linux only)
+// ============================================================================
+
+// inotify limits (from /proc/sys/fs/inotify) and current usage
+typedef struct {
+    uint64_t max_user_instances;    // Maximum inotify instances per user
+    uint64_t max_user_watches;      // Maximum watches per user
+    uint64_t max_queued_events;     // Maximum events queued per instance
+    uint64_t user_instances;        // inotify descriptors held by the current user
+} InotifyStats;
+
+// Read inotify limits and the current user's instance count
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_inotify_stats(InotifyStats* out);
+
+// ============================================================================
+// KERNEL MODULES (Linux only)
+// ============================================================================
+
+// A loaded kernel module (from /proc/modules)
+typedef struct {
+    char name[64];          // Module name
+    uint64_t size_bytes;    // Memory used by the module
+    uint32_t ref_count;     // Number of users of the module
+} KernelModule;
+
+// List of loaded kernel modules
+typedef struct {
+    KernelModule* items;
+    size_t count;
+    size_t capacity;
+} KernelModuleList;
+
+// List loaded kernel modules
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms or kernels without module support
+ProbeResult probe_list_kernel_modules(KernelModuleList* out);
+
+// Free a kernel module list
+void probe_free_kernel_module_list(KernelModuleList* list);
+
+// ============================================================================
+// NAMESPACES (Linux only)
+// ============================================================================
+
+// Namespace inodes of the collecting process (from /proc/self/ns)
+// Equal values mean a shared namespace; tag metrics with them to tell
+// identical series from different containers apart
+typedef struct {
+    uint64_t net;       // Network namespace (0 if unavailable)
+    uint64_t pid;       // PID namespace (0 if unavailable)
+    uint64_t mnt;       // Mount namespace (0 if unavailable)
+    uint64_t ipc;       // IPC namespace (0 if unavailable)
+    uint64_t uts;       // UTS namespace (0 if unavailable)
+    uint64_t cgroup;    // Cgroup namespace (0 if unavailable)
+} NamespaceIds;
+
+// Read the namespace identifiers of the collecting process
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_namespace_ids(NamespaceIds* out);
+
+// ============================================================================
+// SYSTEM LIMITS
+// ============================================================================
+
+// Key system-wide limits (/proc/sys on Linux, sysctl on BSD and macOS)
+typedef struct {
+    uint64_t file_max;          // Maximum open file handles (0 if unavailable)
+    uint64_t pid_max;           // Highest process ID (0 if unavailable)
+    uint64_t threads_max;       // Maximum threads (0 if unavailable)
+    uint64_t max_map_count;     // Maximum memory maps per process (0 if unavailable)
+    uint64_t aio_max_nr;        // Maximum concurrent AIO requests (0 if unavailable)
+} SystemLimits;
+
+// Read key system-wide limits in one call
+ProbeResult probe_system_limits(SystemLimits* out);
+
+// ============================================================================
+// TIME (Linux only)
+// ============================================================================
+
+// Local timezone and clock synchronization state
+typedef struct {
+    char timezone[64];          // Timezone name (e.g. "Europe/Paris") or abbreviation
+    int32_t utc_offset_secs;    // Current offset from UTC in seconds
+    int8_t ntp_synchronized;    // 1 if NTP-synchronized, 0 if not, -1 if unknown
+} TimeInfo;
+
+// Read the local timezone, UTC offset and NTP synchronization state
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_time_info(TimeInfo* out);
+
+// ============================================================================
+// SYSTEMD UNITS (Linux only)
+// ============================================================================
+
+// State of a systemd unit
+typedef struct {
+    char active_state[32];      // e.g. "active", "inactive", "failed"
+    char sub_state[32];         // e.g. "running", "exited", "dead"
+    uint32_t main_pid;          // Main process of a service (0 when none or unknown)
+} UnitStatus;
+
+// Read the status of a systemd unit (e.g. "dbus.service")
+// Without D-Bus support, failed units are reported as inactive and main_pid is 0
+// Returns PROBE_ERR_NOT_FOUND for unknown units
+// Returns PROBE_ERR_NOT_SUPPORTED on hosts not running systemd
+ProbeResult probe_unit_status(const char* unit, UnitStatus* out);
+
 // ============================================================================
 // THERMAL METRICS (Linux via /sys/class/hwmon)
 // ============================================================================
@@ -460,6 +1021,89 @@ ProbeResult probe_collect_thermal_zones(ThermalZoneList* out);
 // Free a thermal zone list
 void probe_free_thermal_list(ThermalZoneList* list);
 
+// CPU temperature aggregated from its sensors (coretemp, k10temp, cpu_thermal...)
+typedef struct {
+    double package_celsius;     // Hottest package reading (valid if has_package)
+    bool has_package;
+    double core_max_celsius;    // Hottest core reading
+    double average_celsius;     // Average of the core readings
+} CpuTemp;
+
+// Aggregate the CPU temperature sensors
+// Returns PROBE_ERR_NOT_SUPPORTED when no CPU sensor is found
+ProbeResult probe_cpu_temperature(CpuTemp* out);
+
+// ============================================================================
+// WIRELESS METRICS (Linux only)
+// ============================================================================
+
+// Wireless interface signal metrics
+typedef struct {
+    char interface[16];
+    char ssid[33];              // Empty when unknown
+    double signal_dbm;
+    double link_quality;        // Driver-specific scale, commonly out of 70
+    double bitrate_mbps;        // 0 when unknown
+    uint32_t frequency_mhz;     // 0 when unknown
+} WirelessInfo;
+
+// Collect signal metrics for a wireless interface (NOT_SUPPORTED for wired ones)
+ProbeResult probe_collect_wireless(const char* interface, WirelessInfo* out);
+
+// ============================================================================
+// LINK EVENTS (Linux only)
+// ============================================================================
+
+// Interface up/down transition
+typedef struct {
+    char interface[16];
+    bool up;                    // Whether the interface is now up
+} LinkEvent;
+
+// Invoked from the watcher thread; the event is only valid during the call
+typedef void (*ProbeLinkCallback)(const LinkEvent* event, void* user_data);
+
+// Start watching interface up/down transitions, replacing any previous watch
+// Uses netlink where available and polls /sys/class/net otherwise
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_link_watch_start(ProbeLinkCallback callback, void* user_data);
+
+// Stop watching; blocks until the watcher thread exits (never call from the callback)
+ProbeResult probe_link_watch_stop(void);
+
+// ============================================================================
+// PROCESS EVENTS (Linux only)
+// ============================================================================
+
+// Identity of a process reported by a process watcher
+typedef struct {
+    int32_t pid;
+    char name[64];              // Command name
+    uint64_t start_time;        // Ticks since boot; differs when a PID is reused
+} ProcessIdentity;
+
+// Callbacks invoked from the watcher thread
+// The identity pointer is only valid for the duration of the call
+typedef void (*ProbeProcessStartCallback)(const ProcessIdentity* process, void* user_data);
+typedef void (*ProbeProcessExitCallback)(int32_t pid, void* user_data);
+
+// Opaque process watcher handle
+typedef struct ProcessWatcher ProcessWatcher;
+
+// Poll processes every interval_ms and report starts/exits of those whose PID
+// is in pids or whose name contains name_contains (all processes when pids is
+// empty and name_contains is NULL). Either callback may be NULL, not both.
+// A reused PID reports an exit followed by a start.
+// Returns PROBE_ERR_NOT_SUPPORTED on non-Linux platforms
+ProbeResult probe_process_watch_start(uint64_t interval_ms, const int32_t* pids, size_t pid_count,
+                                      const char* name_contains,
+                                      ProbeProcessStartCallback on_start,
+                                      ProbeProcessExitCallback on_exit, void* user_data,
+                                      ProcessWatcher** out);
+
+// Stop and free a watcher; blocks until its thread exits (never call from a callback)
+void probe_process_watch_stop(ProcessWatcher* watcher);
+
 // ============================================================================
 // AGGREGATED METRICS COLLECTION
 // ============================================================================
@@ -495,12 +1139,102 @@ typedef struct {
     DiskIOStats disk_io[MAX_ALL_METRICS_ITEMS];
     NetInterface net_interfaces[MAX_ALL_METRICS_ITEMS];
     NetStats net_stats[MAX_ALL_METRICS_ITEMS];
+    uint64_t collection_duration_us;  // Time spent gathering the snapshot
 } AllMetrics;
 
 // Collect all system metrics in one call
 // This is more efficient than calling each collector individually
 ProbeResult probe_collect_all(AllMetrics* out);
 
+// Binary encoding version written as the first byte by probe_collect_all_binary
+#define PROBE_BINARY_FORMAT_VERSION 2
+
+// Collect all system metrics as a compact binary snapshot (version byte, then
+// postcard-encoded fields); *written receives the encoded size even when buf
+// is too small, in which case PROBE_ERR_INVALID_PARAM is returned
+ProbeResult probe_collect_all_binary(uint8_t* buf, size_t len, size_t* written);
+
+// Collect all system metrics, bounded by a deadline (ms since the Unix epoch)
+// Returns PROBE_ERR_TIMEOUT if collection has not finished by the deadline;
+// the abandoned collection completes in the background and is discarded, and
+// calls made before it completes fail with PROBE_ERR_TIMEOUT at once
+ProbeResult probe_collect_all_deadline(uint64_t deadline_unix_ms, AllMetrics* out);
+
+// Metric selection bits for probe_collect_selected
+#define PROBE_SELECT_CPU      (1u << 0)  // System CPU usage
+#define PROBE_SELECT_MEMORY   (1u << 1)  // System memory usage
+#define PROBE_SELECT_LOAD     (1u << 2)  // Load average
+#define PROBE_SELECT_IO       (1u << 3)  // System-wide I/O statistics
+#define PROBE_SELECT_DISK     (1u << 4)  // Partitions, disk usage and disk I/O
+#define PROBE_SELECT_NETWORK  (1u << 5)  // Network interfaces and statistics
+#define PROBE_SELECT_PRESSURE (1u << 6)  // Pressure metrics (PSI)
+#define PROBE_SELECT_ALL      0x7Fu
+
+// Collect only the subsystems selected by mask (PROBE_SELECT_* bits)
+// Unselected subsystems are left zeroed in out
+ProbeResult probe_collect_selected(uint32_t mask, AllMetrics* out);
+
+// Per-device disk I/O rates
+typedef struct {
+    char device[64];
+    double reads_per_sec;
+    double writes_per_sec;
+    double read_bytes_per_sec;
+    double write_bytes_per_sec;
+    double utilization_percent;     // Percentage of the interval the device was busy
+    double avg_request_size_bytes;
+    double avg_queue_depth;
+} DiskIORate;
+
+// Per-interface network rates
+typedef struct {
+    char interface[64];
+    double rx_bytes_per_sec;
+    double rx_packets_per_sec;
+    double rx_errors_per_sec;
+    double rx_drops_per_sec;
+    double tx_bytes_per_sec;
+    double tx_packets_per_sec;
+    double tx_errors_per_sec;
+    double tx_drops_per_sec;
+} NetRate;
+
+// Rates of the cumulative counters of AllMetrics between two collections
+typedef struct {
+    uint64_t timestamp_us;          // Timestamp of the latest collection
+    uint64_t interval_us;           // Time between the two collections
+    SystemCPU cpu;                  // CPU usage between the two collections
+    double read_ops_per_sec;        // System-wide I/O rates
+    double read_bytes_per_sec;
+    double write_ops_per_sec;
+    double write_bytes_per_sec;
+
+    uint32_t disk_io_count;
+    uint32_t net_count;
+
+    DiskIORate disk_io[MAX_ALL_METRICS_ITEMS];
+    NetRate net[MAX_ALL_METRICS_ITEMS];
+    bool available;                 // False on the first collection (primes the sampler)
+} AllMetricsDelta;
+
+// Opaque handle keeping the previous counters between collections
+typedef struct Sampler Sampler;
+
+// Create a metrics sampler (free with probe_sampler_free)
+Sampler* probe_sampler_new(void);
+
+// Collect all metrics and report counter rates since the previous call
+// Devices and interfaces that appeared since then are omitted
+// Each handle must only be used by one thread at a time
+ProbeResult probe_sampler_collect(Sampler* sampler, AllMetricsDelta* out);
+
+// Collect all metrics twice, window_ms apart, and report the rates between them
+// Blocks for the whole window; bypasses the cache
+ProbeResult probe_collect_all_sampled(uint64_t window_ms, AllMetricsDelta* out);
+
+// Free a metrics sampler
+void probe_sampler_free(Sampler* sampler);
+
 // ============================================================================
 // RESOURCE QUOTA FUNCTIONS (READ-ONLY DETECTION)
 // ============================================================================
@@ -515,6 +1249,14 @@ ProbeResult probe_quota_read_limits(int32_t pid, QuotaLimits* out);
 // Read current resource usage for a process
 ProbeResult probe_quota_read_usage(int32_t pid, QuotaUsage* out);
 
+// Read the current usage of this process's own cgroup (cgroup v2 only)
+// cpu_percent covers the interval since the previous call (0 on the first one)
+ProbeResult probe_quota_self_cgroup_usage(QuotaUsage* out);
+
+// Read this process's file-descriptor limit (RLIMIT_NOFILE) without a PID
+// Unlimited values are reported as UINT64_MAX
+ProbeResult probe_self_nofile_limit(uint64_t* soft, uint64_t* hard);
+
 // Detect container runtime (legacy - use probe_detect_runtime for new code)
 ProbeResult probe_detect_container(ContainerInfo* out);
 
@@ -571,6 +1313,20 @@ bool probe_cache_is_enabled(void);
 // Set the TTL for a specific metric type (in milliseconds)
 ProbeResult probe_cache_set_ttl(uint8_t metric_type, uint64_t ttl_ms);
 
+// Set the TTL of a metric type by name (in milliseconds)
+// Names: cpu-system, cpu-pressure, memory-system, memory-pressure, load,
+// disk-partitions, disk-usage, disk-io, net-interfaces, net-stats,
+// io-stats, io-pressure
+ProbeResult probe_cache_set_ttl_by_name(const char* name, uint64_t ttl_ms);
+
+// Multiply every cache TTL by factor (saturating)
+// Returns PROBE_ERR_INVALID_PARAM if factor <= 0
+ProbeResult probe_cache_scale_ttls(double factor);
+
+// Populate every cached metric once (e.g. at startup)
+// Metrics unsupported on the platform are skipped
+ProbeResult probe_cache_prefetch(void);
+
 // Invalidate all cached metrics
 ProbeResult probe_cache_invalidate_all(void);
 
@@ -584,12 +1340,52 @@ ProbeResult probe_cache_invalidate(uint8_t metric_type);
 // Collect system CPU metrics with caching (if enabled)
 ProbeResult probe_collect_cpu_cached(SystemCPU* out);
 
+// Read system CPU metrics from the cache only, never collecting
+// Sets *found to true and fills out on a valid cached value, false otherwise
+// Returns PROBE_ERR_INTERNAL if caching is not enabled
+ProbeResult probe_try_collect_cpu(SystemCPU* out, bool* found);
+
 // Collect system memory metrics with caching (if enabled)
 ProbeResult probe_collect_memory_cached(SystemMemory* out);
 
 // Collect system load average with caching (if enabled)
 ProbeResult probe_collect_load_cached(LoadAverage* out);
 
+// ============================================================================
+// CACHE HANDLES
+// ============================================================================
+
+// Create a cached collector independent from the global one and from other
+// handles (e.g. for a different sampling cadence)
+// policy takes the presets of probe_cache_enable_with_policy
+// Returns 0 for an unknown policy
+uint64_t probe_cache_create(uint32_t policy);
+
+// Destroy a cached collector created with probe_cache_create
+// Returns PROBE_ERR_INVALID_PARAM for an unknown handle
+ProbeResult probe_cache_destroy(uint64_t handle);
+
+// Collect metrics through the cache of a handle
+ProbeResult probe_collect_cpu_cached_h(uint64_t handle, SystemCPU* out);
+ProbeResult probe_collect_memory_cached_h(uint64_t handle, SystemMemory* out);
+ProbeResult probe_collect_load_cached_h(uint64_t handle, LoadAverage* out);
+
+// ============================================================================
+// SYSTEM SUMMARY
+// ============================================================================
+
+// Headline gauges for a compact status line
+typedef struct {
+    double cpu_percent;         // 100 - idle
+    double memory_percent;      // used / total
+    double load_per_core;       // load_1min / online cores
+    double root_disk_percent;   // Used percentage of "/"
+    bool under_pressure;        // PSI full stall on memory or I/O (false without PSI)
+} SystemSummary;
+
+// Collect the headline gauges in one call, using the cache when enabled
+ProbeResult probe_collect_summary(SystemSummary* out);
+
 // ============================================================================
 // NETWORK CONNECTIONS (TCP, UDP, Unix sockets with process resolution)
 // ============================================================================
@@ -630,8 +1426,8 @@ typedef struct {
     int32_t pid;                       // Process ID (-1 if unknown)
     char process_name[64];             // Process name (empty if unknown)
     uint64_t inode;                    // Socket inode number
-    uint32_t rx_queue;                 // Receive queue size
-    uint32_t tx_queue;                 // Transmit queue size
+    uint32_t rx_queue;                 // Receive queue size (accept queue length for listeners)
+    uint32_t tx_queue;                 // Transmit queue size (max backlog for listeners, 0 if unknown)
 } TcpConnection;
 
 // List of TCP connections
@@ -671,6 +1467,8 @@ typedef struct {
     int32_t pid;                       // Process ID (-1 if unknown)
     char process_name[64];             // Process name (empty if unknown)
     uint64_t inode;                    // Socket inode number
+    int32_t peer_pid;                  // Peer process ID (-1 if unknown)
+    uint32_t peer_uid;                 // Peer user ID (0 if unknown)
 } UnixSocket;
 
 // List of Unix sockets
@@ -680,6 +1478,42 @@ typedef struct {
     size_t capacity;
 } UnixSocketList;
 
+// Transport protocol of a listening socket
+typedef enum {
+    TRANSPORT_PROTOCOL_TCP = 0,
+    TRANSPORT_PROTOCOL_UDP = 1,
+} TransportProtocol;
+
+// A socket accepting traffic on a local port
+typedef struct {
+    TransportProtocol protocol;        // TCP or UDP
+    AddressFamily family;              // IPv4 or IPv6
+    char address[MAX_ADDR_LEN];        // Bound local IP address
+    uint16_t port;                     // Local port
+    int32_t pid;                       // Process ID (-1 if unknown)
+    char process_name[64];             // Process name (empty if unknown)
+} ListeningPort;
+
+// List of listening ports
+typedef struct {
+    ListeningPort* items;
+    size_t count;
+    size_t capacity;
+} ListeningPortList;
+
+// Established TCP connections to a remote address
+typedef struct {
+    char remote_addr[MAX_ADDR_LEN];    // Remote IP address
+    uint32_t count;                    // Established connections
+} RemoteConnectionCount;
+
+// List of per-remote connection counts
+typedef struct {
+    RemoteConnectionCount* items;
+    size_t count;
+    size_t capacity;
+} RemoteConnectionCountList;
+
 // Aggregated TCP connection statistics
 typedef struct {
     uint32_t established;
@@ -698,6 +1532,10 @@ typedef struct {
 // Collect all TCP connections
 ProbeResult probe_collect_tcp_connections(TcpConnectionList* out);
 
+// Collect at most `limit` TCP connections (IPv4 first, then IPv6).
+// `truncated` is set to true when connections beyond the limit were left out.
+ProbeResult probe_collect_tcp_connections_limited(size_t limit, TcpConnectionList* out, bool* truncated);
+
 // Free a TCP connection list
 void probe_free_tcp_connection_list(TcpConnectionList* list);
 
@@ -720,6 +1558,20 @@ ProbeResult probe_collect_tcp_stats(TcpStats* out);
 // If no process is found, *out will be -1
 ProbeResult probe_find_process_by_port(uint16_t port, bool tcp, int32_t* out);
 
+// Collect listening TCP ports and bound UDP sockets
+// One entry per (protocol, address, port)
+ProbeResult probe_collect_listening_ports(ListeningPortList* out);
+
+// Free a listening port list
+void probe_free_listening_port_list(ListeningPortList* list);
+
+// Collect the remote addresses with the most established TCP connections
+// Sorted by descending count; at most top_n entries (all when top_n is 0)
+ProbeResult probe_tcp_connections_by_remote(size_t top_n, RemoteConnectionCountList* out);
+
+// Free a per-remote connection count list
+void probe_free_remote_connection_count_list(RemoteConnectionCountList* list);
+
 // ============================================================================
 // PLATFORM INFO FUNCTIONS
 // ============================================================================
@@ -727,6 +1579,52 @@ ProbeResult probe_find_process_by_port(uint16_t port, bool tcp, int32_t* out);
 // Get the platform name ("linux", "darwin", "freebsd", etc.)
 const char* probe_get_platform(void);
 
+// Get the system hostname into buf (INVALID_PARAM if len is too small)
+ProbeResult probe_get_hostname(char* buf, size_t len);
+
+// Get the machine identifier into buf; empty if the host has none
+// (Linux: /etc/machine-id, macOS: IOPlatformUUID, FreeBSD: kern.hostuuid)
+ProbeResult probe_get_machine_id(char* buf, size_t len);
+
+// Capability bits returned by probe_get_capabilities
+#define PROBE_CAP_PRESSURE    (1u << 0)  // Pressure stall information (PSI)
+#define PROBE_CAP_THERMAL     (1u << 1)  // Temperature sensors
+#define PROBE_CAP_PER_CORE    (1u << 2)  // Per-core CPU counters
+#define PROBE_CAP_CONNECTIONS (1u << 3)  // Connection enumeration
+#define PROBE_CAP_DISK_IO     (1u << 4)  // Per-device disk I/O statistics
+
+// Get the collector's capabilities as PROBE_CAP_* bits (0 if not initialized)
+uint32_t probe_get_capabilities(void);
+
+// Outcome of probing one subsystem
+typedef enum {
+    SUBSYSTEM_STATUS_OK = 0,
+    SUBSYSTEM_STATUS_UNSUPPORTED = 1,
+    SUBSYSTEM_STATUS_FAILED = 2,
+} SubsystemStatus;
+
+// Self-test result for one subsystem
+typedef struct {
+    SubsystemStatus status;
+    char reason[128];           // Error message when FAILED (truncated)
+} SubsystemResult;
+
+// Per-subsystem results of probe_self_test
+typedef struct {
+    SubsystemResult cpu;
+    SubsystemResult memory;
+    SubsystemResult load;
+    SubsystemResult process;    // Metrics of the calling process
+    SubsystemResult disk;
+    SubsystemResult network;
+    SubsystemResult io;
+    SubsystemResult pressure;   // CPU pressure (PSI)
+} SelfTestReport;
+
+// Query each subsystem once and report whether it works on this host
+// Subsystem failures are reported in *out, not as the return value
+ProbeResult probe_self_test(SelfTestReport* out);
+
 #ifdef __cplusplus
 }
 #endif